@@ -0,0 +1,39 @@
+#[test]
+fn curve_exponential_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_exponential.scen.json");
+}
+
+#[test]
+fn curve_linear_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_linear.scen.json");
+}
+
+#[test]
+fn curve_logarithmic_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_logarithmic.scen.json");
+}
+
+#[test]
+fn curve_piecewise_linear_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_piecewise_linear.scen.json");
+}
+
+#[test]
+fn curve_power_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_power.scen.json");
+}
+
+#[test]
+fn curve_reserve_ratio_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_reserve_ratio.scen.json");
+}
+
+#[test]
+fn curve_sell_curve_and_spread_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_sell_curve_and_spread.scen.json");
+}
+
+#[test]
+fn curve_sigmoid_go() {
+    dharitri_wasm_debug::denali_go("denali/curve_sigmoid.scen.json");
+}
@@ -0,0 +1,52 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/bonding-curve-contract");
+
+    blockchain.register_contract_builder(
+        "file:output/bonding-curve-contract.wasm",
+        bonding_curve_contract::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn curve_exponential_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_exponential.scen.json", world());
+}
+
+#[test]
+fn curve_linear_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_linear.scen.json", world());
+}
+
+#[test]
+fn curve_logarithmic_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_logarithmic.scen.json", world());
+}
+
+#[test]
+fn curve_piecewise_linear_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_piecewise_linear.scen.json", world());
+}
+
+#[test]
+fn curve_power_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_power.scen.json", world());
+}
+
+#[test]
+fn curve_reserve_ratio_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_reserve_ratio.scen.json", world());
+}
+
+#[test]
+fn curve_sell_curve_and_spread_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_sell_curve_and_spread.scen.json", world());
+}
+
+#[test]
+fn curve_sigmoid_rs() {
+    dharitri_wasm_debug::denali_rs("denali/curve_sigmoid.scen.json", world());
+}
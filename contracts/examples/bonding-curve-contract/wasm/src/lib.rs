@@ -16,6 +16,8 @@ dharitri_wasm_node::wasm_endpoints! {
         sellToken
         setBondingCurve
         setLocalRoles
+        setSellCurve
+        setSpread
         unsetLocalRoles
     )
 }
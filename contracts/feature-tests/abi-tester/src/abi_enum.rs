@@ -9,3 +9,11 @@ pub enum AbiEnum {
     SomethingMore(u8, OnlyShowsUpAsNested08),
     SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
 }
+
+/// Tests that explicit discriminants show up in the ABI.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiEnumWithDiscriminants {
+    First = 1,
+    Second,
+    Tenth = 10,
+}
@@ -1,4 +1,5 @@
 use crate::only_nested::*;
+use core::time::Duration;
 use dharitri_wasm::{
     api::ManagedTypeApi,
     types::{BigUint, Box, ManagedBuffer},
@@ -33,3 +34,65 @@ pub struct AbiManagedVecItem {
     pub value1: u32,
     pub value2: u32,
 }
+
+/// Its only purpose is to test that the ABI generator shows the encoding version.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(version = 2)]
+pub struct AbiVersionedType {
+    pub value: u32,
+
+    /// Values stored by the first version decode this as zero.
+    #[codec(since = 2)]
+    pub added_value: u64,
+}
+
+/// Tests that the ABI follows the codec attributes.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiCodecAttributesType {
+    pub value: u32,
+
+    /// Not encoded, so it should not show up in the ABI.
+    #[codec(skip)]
+    pub cached: u32,
+
+    /// Shows up as the `AbiType` of the codec module.
+    #[codec(with = "duration_seconds_codec")]
+    pub duration: Duration,
+
+    /// Shows up as the wrapped type.
+    pub transparent: AbiTransparentType,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(transparent)]
+pub struct AbiTransparentType(pub u64);
+
+/// Encodes durations as whole seconds.
+pub mod duration_seconds_codec {
+    use core::time::Duration;
+    use dharitri_wasm::dharitri_codec::*;
+
+    pub type AbiType = u64;
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &Duration,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        value.as_secs().dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Duration, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Duration::from_secs(u64::dep_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
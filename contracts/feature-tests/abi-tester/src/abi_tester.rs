@@ -37,6 +37,14 @@ pub trait AbiTester {
         e
     }
 
+    #[endpoint]
+    fn codec_attributes(
+        &self,
+        _arg: AbiCodecAttributesType,
+        _discriminants: AbiEnumWithDiscriminants,
+    ) {
+    }
+
     #[endpoint]
     #[only_owner]
     fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>) {}
@@ -112,6 +120,20 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Key arguments are appended to the storage key.
+    #[storage_mapper("sample_map_mapper")]
+    fn sample_map_mapper(&self, key_arg: &ManagedAddress) -> MapMapper<u64, BigUint>;
+
+    #[view]
+    #[storage_mapper("sample_versioned_storage_mapper")]
+    fn sample_versioned_storage_mapper(&self) -> SingleValueMapper<AbiVersionedType>;
+
+    #[storage_get("sample_storage_get_set")]
+    fn sample_storage_get(&self, key_arg: u32) -> ManagedVec<DctTokenPayment>;
+
+    #[storage_set("sample_storage_get_set")]
+    fn sample_storage_set(&self, key_arg: u32, value: &ManagedVec<DctTokenPayment>);
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -166,6 +188,17 @@ pub trait AbiTester {
     #[payable("*")]
     fn payable_any_token(&self) {}
 
+    #[endpoint]
+    #[payable("MOAX", "TOKEN-FOR-ABI", max_transfers = 2, distinct_tokens)]
+    fn payable_token_list(&self) {}
+
+    #[endpoint]
+    #[payable(storage = "accepted_tokens")]
+    fn payable_storage_tokens(&self) {}
+
+    #[storage_mapper("accepted_tokens")]
+    fn accepted_tokens(&self) -> SetMapper<TokenIdentifier>;
+
     #[external_view]
     fn external_view(&self) {}
 
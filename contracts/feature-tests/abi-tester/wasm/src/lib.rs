@@ -8,6 +8,7 @@ dharitri_wasm_node::wasm_endpoints! {
     abi_tester
     (
         address_vs_h256
+        codec_attributes
         echo_abi_test_type
         echo_enum
         dct_local_role
@@ -31,7 +32,10 @@ dharitri_wasm_node::wasm_endpoints! {
         payable_any_token
         payable_moax
         payable_some_token
+        payable_storage_tokens
+        payable_token_list
         sample_storage_mapper
+        sample_versioned_storage_mapper
         take_managed_type
         var_args
     )
@@ -36,4 +36,20 @@ pub trait EventFeatures {
     ) {
         self.event_b(arg1, arg2, data.into_vec());
     }
+
+    /// Has several non-indexed arguments, which end up in the data, each with a length prefix.
+    /// The `fee` was only added in the second version of the event.
+    #[event("event_c", version = 2)]
+    fn event_c(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        amount: &BigUint,
+        note: &ManagedBuffer,
+        #[since(2)] fee: &BigUint,
+    );
+
+    #[endpoint(logEventC)]
+    fn log_event_c(&self, amount: BigUint, note: ManagedBuffer, fee: BigUint) {
+        self.event_c(&self.blockchain().get_caller(), &amount, &note, &fee);
+    }
 }
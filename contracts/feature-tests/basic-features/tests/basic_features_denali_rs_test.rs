@@ -1,3 +1,4 @@
+use dharitri_wasm::contract_base::ContractAbiProvider;
 use dharitri_wasm_debug::*;
 
 fn world() -> BlockchainMock {
@@ -8,6 +9,10 @@ fn world() -> BlockchainMock {
         "file:output/basic-features.wasm",
         basic_features::ContractBuilder,
     );
+    blockchain.register_contract_abi(
+        "file:output/basic-features.wasm",
+        <basic_features::AbiProvider as ContractAbiProvider>::abi(),
+    );
     blockchain.register_contract_builder(
         "file:../dct-system-sc-mock/output/dct-system-sc-mock.wasm",
         dct_system_sc_mock::ContractBuilder,
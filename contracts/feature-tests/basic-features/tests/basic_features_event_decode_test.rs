@@ -0,0 +1,225 @@
+use basic_features::event_features::{typed_events, EventFeatures};
+use dharitri_wasm::{
+    contract_base::{CallableContract, ContractAbiProvider, ContractBase},
+    types::{
+        BigUint, ContractEvent, ManagedAddress, ManagedBuffer, ManagedVec, MultiValueManagedVec,
+    },
+};
+use dharitri_wasm_debug::{
+    managed_address, managed_biguint, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, ExpectedEvent},
+    tx_mock::TxResult,
+    DebugApi,
+};
+
+const WASM_PATH: &str = "output/basic-features.wasm";
+
+fn setup<CB, ContractObjBuilder>(
+    obj_builder: ContractObjBuilder,
+) -> (
+    BlockchainStateWrapper,
+    ContractObjWrapper<CB, ContractObjBuilder>,
+)
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(&rust_biguint!(0), None, obj_builder, WASM_PATH);
+    wrapper.register_contract_abi(&sc_wrapper, basic_features::AbiProvider::abi());
+    (wrapper, sc_wrapper)
+}
+
+fn log_event_b<CB, ContractObjBuilder>(
+    wrapper: &mut BlockchainStateWrapper,
+    sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+) -> TxResult
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + EventFeatures + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let caller = wrapper.create_user_account(&rust_biguint!(0));
+    let result = wrapper.execute_tx(&caller, sc_wrapper, &rust_biguint!(0), |sc| {
+        let mut data = MultiValueManagedVec::new();
+        data.push(ManagedBuffer::from(&b"first"[..]));
+        data.push(ManagedBuffer::from(&b"second"[..]));
+        sc.log_event_b(
+            &managed_biguint!(5),
+            &managed_address!(sc_wrapper.address_ref()),
+            data,
+        );
+    });
+    result.assert_ok();
+    result
+}
+
+fn log_event_c<CB, ContractObjBuilder>(
+    wrapper: &mut BlockchainStateWrapper,
+    sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+) -> TxResult
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + EventFeatures + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let caller = wrapper.create_user_account(&rust_biguint!(0));
+    let result = wrapper.execute_tx(&caller, sc_wrapper, &rust_biguint!(0), |sc| {
+        sc.log_event_c(
+            managed_biguint!(5),
+            ManagedBuffer::from(&b"note"[..]),
+            managed_biguint!(1),
+        );
+        sc.log_event_a(7);
+        sc.log_event_c(
+            managed_biguint!(6),
+            ManagedBuffer::new(),
+            managed_biguint!(2),
+        );
+    });
+    result.assert_ok();
+    result
+}
+
+#[test]
+fn module_events_in_abi_test() {
+    let abi = basic_features::AbiProvider::abi();
+    let identifiers: Vec<&str> = abi.events.iter().map(|event| event.identifier).collect();
+    assert!(identifiers.contains(&"event_a"));
+    assert!(identifiers.contains(&"event_b"));
+
+    let event_c = abi
+        .events
+        .iter()
+        .find(|event| event.identifier == "event_c")
+        .unwrap();
+    assert_eq!(event_c.version, Some(2));
+    assert!(event_c.has_structured_data());
+    let since: Vec<Option<u8>> = event_c.inputs.iter().map(|input| input.since).collect();
+    assert_eq!(since, vec![None, None, None, Some(2)]);
+}
+
+#[test]
+fn collect_typed_events_test() {
+    let (mut wrapper, sc_wrapper) = setup(basic_features::contract_obj);
+    let result = log_event_c(&mut wrapper, &sc_wrapper);
+
+    let events = result.collect_events::<typed_events::EventC<DebugApi>>();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].amount, managed_biguint!(5));
+    assert_eq!(events[0].note, ManagedBuffer::from(&b"note"[..]));
+    assert_eq!(events[0].fee, managed_biguint!(1));
+    assert_eq!(events[1].amount, managed_biguint!(6));
+    assert!(events[1].note.is_empty());
+    assert_eq!(events[1].caller, events[0].caller);
+
+    let events = result.collect_events::<typed_events::EventA>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].data, 7);
+    assert!(result
+        .collect_events::<typed_events::EventB<DebugApi>>()
+        .is_empty());
+}
+
+#[test]
+fn decode_older_event_version_test() {
+    let _ = DebugApi::dummy();
+    let topics = vec![b"event_c".to_vec(), vec![0u8; 32]];
+    // logged by version 1 of the contract, before the fee was added
+    let data = [&[1u8, 0, 0, 0, 1, 5, 0, 0, 0, 3][..], b"abc"].concat();
+    let event = typed_events::EventC::<DebugApi>::decode_event(&topics, &data).unwrap();
+    assert_eq!(event.amount, managed_biguint!(5));
+    assert_eq!(event.note, ManagedBuffer::from(&b"abc"[..]));
+    assert_eq!(event.fee, BigUint::zero());
+
+    // version 2 logs must contain the fee
+    let data = [&[2u8, 0, 0, 0, 1, 5, 0, 0, 0, 3][..], b"abc"].concat();
+    assert!(typed_events::EventC::<DebugApi>::decode_event(&topics, &data).is_err());
+}
+
+#[test]
+fn decode_structured_event_logs_test() {
+    let (mut wrapper, sc_wrapper) = setup(basic_features::contract_obj);
+    let result = log_event_c(&mut wrapper, &sc_wrapper);
+
+    let events = wrapper.decode_logs(&result);
+    assert_eq!(events.len(), 3);
+    let fee: BigUint<DebugApi> = events[0].field("fee").unwrap().decode().unwrap();
+    assert_eq!(fee, managed_biguint!(1));
+    wrapper.check_event(
+        &result,
+        &ExpectedEvent::new("event_c")
+            .with_field("amount", &6u32)
+            .with_field("fee", &2u32),
+    );
+}
+
+#[test]
+fn decode_logs_test() {
+    let (mut wrapper, sc_wrapper) = setup(basic_features::contract_obj);
+    let result = log_event_b(&mut wrapper, &sc_wrapper);
+
+    let events = wrapper.decode_logs(&result);
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.identifier, "event_b");
+    assert_eq!(
+        event
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.indexed))
+            .collect::<Vec<_>>(),
+        vec![("arg1", true), ("arg2", true), ("data", false)]
+    );
+
+    let arg1: BigUint<DebugApi> = event.field("arg1").unwrap().decode().unwrap();
+    assert_eq!(arg1, managed_biguint!(5));
+    let arg2: ManagedAddress<DebugApi> = event.field("arg2").unwrap().decode().unwrap();
+    assert_eq!(arg2, managed_address!(sc_wrapper.address_ref()));
+    let data: ManagedVec<DebugApi, ManagedBuffer<DebugApi>> =
+        event.field("data").unwrap().decode().unwrap();
+    assert_eq!(data.len(), 2);
+    assert!(event.field("arg1").unwrap().encodes_as(&5u64));
+    assert!(event.field("missing").is_none());
+}
+
+#[test]
+fn check_event_test() {
+    let (mut wrapper, sc_wrapper) = setup(basic_features::contract_obj);
+    let result = log_event_b(&mut wrapper, &sc_wrapper);
+
+    wrapper.check_event(&result, &ExpectedEvent::new("event_b"));
+    wrapper.check_event(
+        &result,
+        &ExpectedEvent::new("event_b")
+            .with_field("arg1", &5u32)
+            .with_field("arg2", sc_wrapper.address_ref()),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Event not found")]
+fn check_event_field_mismatch_test() {
+    let (mut wrapper, sc_wrapper) = setup(basic_features::contract_obj);
+    let result = log_event_b(&mut wrapper, &sc_wrapper);
+
+    wrapper.check_event(
+        &result,
+        &ExpectedEvent::new("event_b").with_field("arg1", &6u32),
+    );
+}
+
+#[test]
+fn decode_logs_without_abi_test() {
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let result = log_event_b(&mut wrapper, &sc_wrapper);
+
+    assert_eq!(result.result_logs.len(), 1);
+    assert!(wrapper.decode_logs(&result).is_empty());
+}
@@ -0,0 +1,129 @@
+use basic_features::{
+    big_num_methods::InteractorTrait as _, event_features::InteractorTrait as _, Interactor,
+    InteractorTrait as _,
+};
+use dharitri_wasm::{
+    contract_base::InteractorObjBase,
+    types::{BigUint, ManagedAddress, ManagedBuffer, ManagedVec, MultiValueManagedVec},
+};
+use dharitri_wasm_debug::{rust_biguint, testing_framework::BlockchainStateWrapper, DebugApi};
+
+const WASM_PATH: &str = "output/basic-features.wasm";
+
+#[test]
+fn interactor_deploy_and_query_test() {
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let owner = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let mut interactor =
+        Interactor::<DebugApi>::new_interactor_obj().contract(sc_wrapper.address_ref().clone());
+
+    let deploy = interactor.init();
+    wrapper
+        .execute_interactor_deploy(&owner, &sc_wrapper, &deploy)
+        .assert_ok();
+
+    let call = interactor.sqrt_big_uint(BigUint::from(49u32));
+    let result = wrapper.execute_interactor_query(&sc_wrapper, &call);
+    result.assert_ok();
+    let sqrt: BigUint<DebugApi> = call.decode_results(result.result_values).unwrap();
+    assert_eq!(sqrt, BigUint::from(7u32));
+
+    let call = interactor.log2_big_uint(BigUint::from(1024u32));
+    let result = wrapper.execute_interactor_query(&sc_wrapper, &call);
+    let log2: u32 = call.decode_results(result.result_values).unwrap();
+    assert_eq!(log2, 10);
+}
+
+#[test]
+fn interactor_event_test() {
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let mut interactor =
+        Interactor::<DebugApi>::new_interactor_obj().contract(sc_wrapper.address_ref().clone());
+
+    let mut data = MultiValueManagedVec::new();
+    data.push(ManagedBuffer::from(&b"first"[..]));
+    data.push(ManagedBuffer::from(&b"second"[..]));
+    let call = interactor.log_event_b(
+        BigUint::from(5u32),
+        ManagedAddress::from_address(&caller),
+        data,
+    );
+    let result = wrapper.execute_interactor_call(&caller, &sc_wrapper, &call);
+    result.assert_ok();
+    assert_eq!(result.result_logs.len(), 1);
+
+    let log = &result.result_logs[0];
+    let (arg1, arg2, data) = interactor
+        .decode_event_b(log.topics.as_slice(), log.data.as_slice())
+        .unwrap();
+    assert_eq!(arg1, BigUint::from(5u32));
+    assert_eq!(arg2, ManagedAddress::from_address(&caller));
+    let mut expected_data = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+    expected_data.push(ManagedBuffer::from(&b"first"[..]));
+    expected_data.push(ManagedBuffer::from(&b"second"[..]));
+    assert_eq!(data, expected_data);
+
+    // wrong identifier
+    assert!(interactor
+        .decode_event_a(log.topics.as_slice(), log.data.as_slice())
+        .is_err());
+
+    let call = interactor.log_event_a(42u32);
+    let result = wrapper.execute_interactor_call(&caller, &sc_wrapper, &call);
+    let log = &result.result_logs[0];
+    let data: u32 = interactor
+        .decode_event_a(log.topics.as_slice(), log.data.as_slice())
+        .unwrap();
+    assert_eq!(data, 42);
+
+    let call = interactor.log_event_c(
+        BigUint::from(5u32),
+        ManagedBuffer::from(&b"note"[..]),
+        BigUint::from(1u32),
+    );
+    let result = wrapper.execute_interactor_call(&caller, &sc_wrapper, &call);
+    let log = &result.result_logs[0];
+    let (event_caller, amount, note, fee) = interactor
+        .decode_event_c(log.topics.as_slice(), log.data.as_slice())
+        .unwrap();
+    assert_eq!(event_caller, ManagedAddress::from_address(&caller));
+    assert_eq!(amount, BigUint::from(5u32));
+    assert_eq!(note, ManagedBuffer::from(&b"note"[..]));
+    assert_eq!(fee, BigUint::from(1u32));
+}
+
+#[test]
+fn interactor_unknown_endpoint_test() {
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        basic_features::contract_obj,
+        WASM_PATH,
+    );
+    let mut interactor =
+        Interactor::<DebugApi>::new_interactor_obj().contract(sc_wrapper.address_ref().clone());
+
+    let mut call = interactor.log_event_a(1u32);
+    call.endpoint_name = b"unknownEndpoint"[..].into();
+    wrapper
+        .execute_interactor_call(&caller, &sc_wrapper, &call)
+        .assert_error(1, "invalid function (not found)");
+}
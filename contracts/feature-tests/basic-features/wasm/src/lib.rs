@@ -178,6 +178,7 @@ dharitri_wasm_node::wasm_endpoints! {
         logEventA
         logEventARepeat
         logEventB
+        logEventC
         maddress_from_array
         maddress_from_managed_buffer
         managed_address_eq
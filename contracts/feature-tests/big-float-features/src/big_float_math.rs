@@ -0,0 +1,94 @@
+dharitri_wasm::imports!();
+
+/// Checks the BigFloat transcendental and rounding functions.
+///
+/// Inputs are fractions and outputs are fixed-point integers,
+/// so the same scenarios run on the VM and on the debug API,
+/// which serialize BigFloat differently.
+#[dharitri_wasm::module]
+pub trait BigFloatMath {
+    #[endpoint]
+    fn ln_big_float(
+        &self,
+        numerator: i64,
+        denominator: i64,
+        decimals: u32,
+    ) -> OptionalValue<BigInt> {
+        BigFloat::from_frac(numerator, denominator)
+            .ln()
+            .map(|result| self.to_fixed_point(&result, decimals))
+            .into()
+    }
+
+    #[endpoint]
+    fn log10_big_float(
+        &self,
+        numerator: i64,
+        denominator: i64,
+        decimals: u32,
+    ) -> OptionalValue<BigInt> {
+        BigFloat::from_frac(numerator, denominator)
+            .log10()
+            .map(|result| self.to_fixed_point(&result, decimals))
+            .into()
+    }
+
+    #[endpoint]
+    fn exp_big_float(&self, numerator: i64, denominator: i64, decimals: u32) -> BigInt {
+        let result = BigFloat::from_frac(numerator, denominator).exp();
+        self.to_fixed_point(&result, decimals)
+    }
+
+    #[endpoint]
+    fn ln_exp_round_trip_big_float(
+        &self,
+        numerator: i64,
+        denominator: i64,
+        decimals: u32,
+    ) -> OptionalValue<BigInt> {
+        BigFloat::from_frac(numerator, denominator)
+            .exp()
+            .ln()
+            .map(|result| self.to_fixed_point(&result, decimals))
+            .into()
+    }
+
+    #[endpoint]
+    fn floor_big_float(&self, numerator: i64, denominator: i64) -> BigInt {
+        BigFloat::from_frac(numerator, denominator).floor()
+    }
+
+    #[endpoint]
+    fn ceil_big_float(&self, numerator: i64, denominator: i64) -> BigInt {
+        BigFloat::from_frac(numerator, denominator).ceil()
+    }
+
+    #[endpoint]
+    fn trunc_big_float(&self, numerator: i64, denominator: i64) -> BigInt {
+        BigFloat::from_frac(numerator, denominator).trunc()
+    }
+
+    #[endpoint]
+    fn round_big_float(&self, numerator: i64, denominator: i64) -> BigInt {
+        BigFloat::from_frac(numerator, denominator).round()
+    }
+
+    /// Rounds to `decimals`, then shows the result with `output_decimals`,
+    /// so the dropped digits are visible as trailing zeroes.
+    #[endpoint]
+    fn round_to_decimals_big_float(
+        &self,
+        numerator: i64,
+        denominator: i64,
+        decimals: u32,
+        output_decimals: u32,
+    ) -> BigInt {
+        let result = BigFloat::from_frac(numerator, denominator).round_to_decimals(decimals);
+        self.to_fixed_point(&result, output_decimals)
+    }
+
+    /// The value multiplied by `10^decimals` and rounded to the nearest integer.
+    fn to_fixed_point(&self, value: &BigFloat, decimals: u32) -> BigInt {
+        (value * &BigFloat::from(10i64).pow(decimals)).round()
+    }
+}
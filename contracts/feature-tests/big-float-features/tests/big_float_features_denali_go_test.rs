@@ -0,0 +1,9 @@
+#[test]
+fn big_float_math_go() {
+    dharitri_wasm_debug::denali_go("denali/big_float_math.scen.json");
+}
+
+#[test]
+fn big_floats_go() {
+    dharitri_wasm_debug::denali_go("denali/big_floats.scen.json");
+}
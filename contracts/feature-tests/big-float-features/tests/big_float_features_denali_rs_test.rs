@@ -0,0 +1,18 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/big-float-features");
+
+    blockchain.register_contract_builder(
+        "file:output/big-float-features.wasm",
+        big_float_features::ContractBuilder,
+    );
+
+    blockchain
+}
+
+#[test]
+fn big_float_math_rs() {
+    dharitri_wasm_debug::denali_rs("denali/big_float_math.scen.json", world());
+}
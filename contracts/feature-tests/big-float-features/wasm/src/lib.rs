@@ -20,10 +20,16 @@ dharitri_wasm_node::wasm_endpoints! {
         big_float_from_man_buf_ref
         big_float_neg
         big_float_zero
+        ceil_big_float
         div_assign_big_float
         div_assign_big_float_ref
         div_big_float
         div_big_float_ref
+        exp_big_float
+        floor_big_float
+        ln_big_float
+        ln_exp_round_trip_big_float
+        log10_big_float
         mul_assign_big_float
         mul_assign_big_float_ref
         mul_big_float
@@ -33,12 +39,15 @@ dharitri_wasm_node::wasm_endpoints! {
         new_from_sci_big_float
         pow_big_float
         pow_big_float_ref
+        round_big_float
+        round_to_decimals_big_float
         sqrt_big_float
         sqrt_big_float_ref
         sub_assign_big_float
         sub_assign_big_float_ref
         sub_big_float
         sub_big_float_ref
+        trunc_big_float
     )
 }
 
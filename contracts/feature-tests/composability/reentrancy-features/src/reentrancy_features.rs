@@ -0,0 +1,79 @@
+#![no_std]
+
+dharitri_wasm::imports!();
+
+/// Test contract for `#[non_reentrant]` and the call stack API.
+///
+/// Two instances call each other back synchronously, via `guardedBounce` and `bounce`.
+#[dharitri_wasm::contract]
+pub trait ReentrancyFeatures {
+    #[proxy]
+    fn self_proxy(&self) -> self::Proxy<Self::Api>;
+
+    #[init]
+    fn init(&self) {}
+
+    #[non_reentrant]
+    #[endpoint(guardedCall)]
+    fn guarded_call(&self) {
+        self.record_call();
+    }
+
+    #[endpoint(unguardedCall)]
+    fn unguarded_call(&self) {
+        self.record_call();
+    }
+
+    /// Has `via` call back either `guardedCall` or `unguardedCall` on this contract.
+    #[non_reentrant]
+    #[endpoint(guardedBounce)]
+    fn guarded_bounce(&self, via: ManagedAddress, call_back_guarded: bool) {
+        self.record_call();
+        let sc_address = self.blockchain().get_sc_address();
+        self.self_proxy()
+            .contract(via)
+            .bounce(sc_address, call_back_guarded)
+            .execute_on_dest_context_ignore_result();
+    }
+
+    /// Ends in an async call to `unguardedCall` on `to`.
+    /// The async call never returns, so the lock has to be released before it is made.
+    #[non_reentrant]
+    #[endpoint(guardedAsyncCall)]
+    fn guarded_async_call(&self, to: ManagedAddress) {
+        self.record_call();
+        self.self_proxy()
+            .contract(to)
+            .unguarded_call()
+            .async_call()
+            .call_and_exit_ignore_callback();
+    }
+
+    #[endpoint]
+    fn bounce(&self, back_to: ManagedAddress, guarded: bool) {
+        self.record_call();
+        let mut proxy = self.self_proxy().contract(back_to);
+        if guarded {
+            proxy.guarded_call().execute_on_dest_context_ignore_result();
+        } else {
+            proxy
+                .unguarded_call()
+                .execute_on_dest_context_ignore_result();
+        }
+    }
+
+    fn record_call(&self) {
+        self.last_call_depth()
+            .set(self.blockchain().get_call_depth());
+        self.last_original_caller()
+            .set(self.blockchain().get_original_caller());
+    }
+
+    #[view(getLastCallDepth)]
+    #[storage_mapper("lastCallDepth")]
+    fn last_call_depth(&self) -> SingleValueMapper<u32>;
+
+    #[view(getLastOriginalCaller)]
+    #[storage_mapper("lastOriginalCaller")]
+    fn last_original_caller(&self) -> SingleValueMapper<ManagedAddress>;
+}
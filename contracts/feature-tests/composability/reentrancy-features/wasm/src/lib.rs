@@ -0,0 +1,20 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+dharitri_wasm_node::wasm_endpoints! {
+    reentrancy_features
+    (
+        bounce
+        getLastCallDepth
+        getLastOriginalCaller
+        guardedAsyncCall
+        guardedBounce
+        guardedCall
+        unguardedCall
+    )
+}
+
+dharitri_wasm_node::wasm_empty_callback! {}
@@ -446,6 +446,16 @@ fn recursive_caller_dct_1_go() {
     dharitri_wasm_debug::denali_go("denali/recursive_caller_dct_1.scen.json");
 }
 
+#[test]
+fn reentrancy_features_go() {
+    dharitri_wasm_debug::denali_go("denali/reentrancy_features.scen.json");
+}
+
+#[test]
+fn reentrancy_features_async_call_go() {
+    dharitri_wasm_debug::denali_go("denali/reentrancy_features_async_call.scen.json");
+}
+
 #[test]
 fn send_moax_go() {
     dharitri_wasm_debug::denali_go("denali/send_moax.scen.json");
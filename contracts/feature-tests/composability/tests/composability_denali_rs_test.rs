@@ -28,6 +28,10 @@ fn world() -> BlockchainMock {
         "file:recursive-caller/output/recursive-caller.wasm",
         recursive_caller::ContractBuilder,
     );
+    blockchain.register_contract_builder(
+        "file:reentrancy-features/output/reentrancy-features.wasm",
+        reentrancy_features::ContractBuilder,
+    );
     blockchain.register_contract_builder("file:vault/output/vault.wasm", vault::ContractBuilder);
     blockchain
 }
@@ -571,6 +575,16 @@ fn recursive_caller_dct_1_rs() {
     dharitri_wasm_debug::denali_rs("denali/recursive_caller_dct_1.scen.json", world());
 }
 
+#[test]
+fn reentrancy_features_rs() {
+    dharitri_wasm_debug::denali_rs("denali/reentrancy_features.scen.json", world());
+}
+
+#[test]
+fn reentrancy_features_async_call_rs() {
+    dharitri_wasm_debug::denali_rs("denali/reentrancy_features_async_call.scen.json", world());
+}
+
 #[test]
 fn send_moax_rs() {
     dharitri_wasm_debug::denali_rs("denali/send_moax.scen.json", world());
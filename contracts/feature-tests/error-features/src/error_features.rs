@@ -0,0 +1,122 @@
+#![no_std]
+
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+/// Everything that can go wrong when using the vault.
+#[derive(TypeAbi, ContractError)]
+pub enum VaultError<M: ManagedTypeApi> {
+    /// Depositing nothing is most likely a mistake on the caller side.
+    #[error(code = 1, message = "deposit amount must be positive")]
+    ZeroDeposit,
+
+    #[error(code = 2, message = "not enough funds: requested {}, available {}")]
+    InsufficientFunds {
+        requested: BigUint<M>,
+        available: BigUint<M>,
+    },
+
+    #[error(code = 3, message = "vault is frozen")]
+    Frozen,
+}
+
+/// Showcases endpoints returning contract errors, with codes.
+///
+/// The vault can also withdraw from other vaults, to show how callbacks can decode the errors.
+#[dharitri_wasm::contract]
+pub trait ErrorFeatures {
+    #[proxy]
+    fn vault_proxy(&self) -> self::Proxy<Self::Api>;
+
+    #[init]
+    fn init(&self) {}
+
+    /// Returns the new balance.
+    #[endpoint]
+    fn deposit(&self, amount: BigUint) -> Result<BigUint, VaultError<Self::Api>> {
+        self.require_not_frozen()?;
+        if amount == 0 {
+            return Result::Err(VaultError::ZeroDeposit);
+        }
+        self.balance().update(|balance| *balance += &amount);
+        Result::Ok(self.balance().get())
+    }
+
+    /// Returns the remaining balance.
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) -> Result<BigUint, VaultError<Self::Api>> {
+        self.require_not_frozen()?;
+        let available = self.balance().get();
+        if amount > available {
+            return Result::Err(VaultError::InsufficientFunds {
+                requested: amount,
+                available,
+            });
+        }
+        self.balance().set(&(available - amount));
+        Result::Ok(self.balance().get())
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn freeze(&self) {
+        self.frozen().set(true);
+    }
+
+    #[endpoint(withdrawFrom)]
+    fn withdraw_from(&self, vault: ManagedAddress, amount: BigUint) {
+        self.vault_proxy()
+            .contract(vault)
+            .withdraw(amount)
+            .async_call()
+            .with_callback(self.callbacks().withdraw_callback())
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn withdraw_callback(&self, #[call_result] result: ManagedAsyncCallResult<BigUint>) {
+        match result {
+            ManagedAsyncCallResult::Ok(remaining) => {
+                self.last_error_code().clear();
+                self.last_error_message().clear();
+                self.last_remaining_balance().set(&remaining);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                self.last_error_code()
+                    .set(err.contract_error_code().unwrap_or_default());
+                self.last_error_message().set(
+                    err.contract_error_message()
+                        .unwrap_or_else(|| err.err_msg.clone()),
+                );
+            },
+        }
+    }
+
+    fn require_not_frozen(&self) -> Result<(), VaultError<Self::Api>> {
+        if self.frozen().get() {
+            return Result::Err(VaultError::Frozen);
+        }
+        Result::Ok(())
+    }
+
+    #[view(getBalance)]
+    #[storage_mapper("balance")]
+    fn balance(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(isFrozen)]
+    #[storage_mapper("frozen")]
+    fn frozen(&self) -> SingleValueMapper<bool>;
+
+    /// 0 if the last withdrawal from another vault succeeded, or if the error was not a contract error.
+    #[view(getLastErrorCode)]
+    #[storage_mapper("lastErrorCode")]
+    fn last_error_code(&self) -> SingleValueMapper<u32>;
+
+    #[view(getLastErrorMessage)]
+    #[storage_mapper("lastErrorMessage")]
+    fn last_error_message(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getLastRemainingBalance)]
+    #[storage_mapper("lastRemainingBalance")]
+    fn last_remaining_balance(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,4 @@
+#[test]
+fn error_features_go() {
+    dharitri_wasm_debug::denali_go("denali/error_features.scen.json");
+}
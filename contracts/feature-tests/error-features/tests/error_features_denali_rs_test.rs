@@ -0,0 +1,16 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/error-features");
+    blockchain.register_contract_builder(
+        "file:output/error-features.wasm",
+        error_features::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn error_features_rs() {
+    dharitri_wasm_debug::denali_rs("denali/error_features.scen.json", world());
+}
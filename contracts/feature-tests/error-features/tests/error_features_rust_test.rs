@@ -0,0 +1,77 @@
+use dharitri_wasm::{
+    contract_base::{ContractAbiProvider, InteractorObjBase},
+    types::BigUint,
+};
+use dharitri_wasm_debug::{rust_biguint, testing_framework::BlockchainStateWrapper, DebugApi};
+use error_features::{Interactor, InteractorTrait as _};
+
+const WASM_PATH: &str = "output/error-features.wasm";
+
+#[test]
+fn error_features_contract_error_test() {
+    let _ = DebugApi::dummy();
+    let mut wrapper = BlockchainStateWrapper::new();
+    let owner = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        error_features::contract_obj,
+        WASM_PATH,
+    );
+    let mut interactor =
+        Interactor::<DebugApi>::new_interactor_obj().contract(sc_wrapper.address_ref().clone());
+
+    let call = interactor.deposit(BigUint::zero());
+    let result = wrapper.execute_interactor_call(&owner, &sc_wrapper, &call);
+    assert_eq!(result.contract_error_code(), Some(1));
+    result.assert_contract_error(1, "deposit amount must be positive");
+
+    let call = interactor.deposit(BigUint::from(20u32));
+    let result = wrapper.execute_interactor_call(&owner, &sc_wrapper, &call);
+    result.assert_ok();
+    assert_eq!(result.contract_error_code(), None);
+    let balance: BigUint<DebugApi> = call.decode_results(result.result_values).unwrap();
+    assert_eq!(balance, BigUint::from(20u32));
+
+    let call = interactor.withdraw(BigUint::from(25u32));
+    wrapper
+        .execute_interactor_call(&owner, &sc_wrapper, &call)
+        .assert_contract_error(2, "not enough funds: requested 25, available 20");
+
+    let call = interactor.freeze();
+    wrapper
+        .execute_interactor_call(&owner, &sc_wrapper, &call)
+        .assert_ok();
+
+    let call = interactor.withdraw(BigUint::from(5u32));
+    wrapper
+        .execute_interactor_call(&owner, &sc_wrapper, &call)
+        .assert_user_error("E3: vault is frozen");
+}
+
+#[test]
+fn error_features_abi_test() {
+    let abi = error_features::AbiProvider::abi();
+
+    let codes: Vec<u32> = abi.errors.iter().map(|error| error.code).collect();
+    assert_eq!(codes, vec![1, 2, 3]);
+    let insufficient_funds = &abi.errors[1];
+    assert_eq!(insufficient_funds.error_type, "VaultError");
+    assert_eq!(insufficient_funds.name, "InsufficientFunds");
+    assert_eq!(
+        insufficient_funds.message,
+        "not enough funds: requested {}, available {}"
+    );
+
+    let endpoint_errors = |name: &str| {
+        abi.endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == name)
+            .unwrap()
+            .errors
+            .clone()
+    };
+    assert_eq!(endpoint_errors("deposit"), vec![1, 2, 3]);
+    assert_eq!(endpoint_errors("freeze"), Vec::<u32>::new());
+    assert!(abi.type_descriptions.0.contains_key("VaultError"));
+}
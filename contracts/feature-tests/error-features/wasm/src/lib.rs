@@ -0,0 +1,21 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+dharitri_wasm_node::wasm_endpoints! {
+    error_features
+    (
+        callBack
+        deposit
+        freeze
+        getBalance
+        getLastErrorCode
+        getLastErrorMessage
+        getLastRemainingBalance
+        isFrozen
+        withdraw
+        withdrawFrom
+    )
+}
@@ -0,0 +1,3 @@
+fn main() {
+    dharitri_wasm_debug::meta::perform::<migration_features::AbiProvider>();
+}
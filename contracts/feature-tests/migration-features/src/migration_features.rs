@@ -0,0 +1,101 @@
+#![no_std]
+
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+/// Kept low, to show migrations spanning several calls.
+pub const MIGRATION_BATCH_SIZE: usize = 2;
+
+/// Version 2 added the `migrated` flag.
+/// Records written by the first version of the contract decode with the flag unset.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Debug,
+)]
+#[codec(version = 2)]
+pub struct Record {
+    pub value: u64,
+    #[codec(since = 2)]
+    pub migrated: bool,
+}
+
+/// Contract that migrates the storage written by its first version, on upgrade.
+///
+/// The first version stored records without the `migrated` flag, and balances in whole units.
+#[dharitri_wasm::contract]
+pub trait MigrationFeatures {
+    #[init]
+    fn init(&self) {}
+
+    /// Called instead of `init` on upgrade.
+    #[upgrade]
+    fn upgrade(&self) -> OperationCompletionStatus {
+        self.run_migrations()
+    }
+
+    /// Continues a migration that could not complete in the upgrade transaction.
+    #[only_owner]
+    #[endpoint(continueMigration)]
+    fn continue_migration(&self) -> OperationCompletionStatus {
+        self.run_migrations()
+    }
+
+    #[view(getSchemaVersion)]
+    fn get_schema_version(&self) -> u32 {
+        dharitri_wasm::storage::migration::schema_version::<Self::Api>()
+    }
+
+    #[endpoint(addRecord)]
+    fn add_record(&self, value: u64) {
+        self.records().push(&Record {
+            value,
+            migrated: true,
+        });
+        self.record_count().update(|count| *count += 1);
+    }
+
+    /// The balance is given in hundredths of a unit.
+    #[endpoint(setBalance)]
+    fn set_balance(&self, id: u32, balance: u64) {
+        self.balances().insert(id, balance);
+    }
+
+    #[migration(version = 1)]
+    fn migrate_records(
+        &self,
+        cursor: &mut MigrationCursor<Self::Api>,
+    ) -> OperationCompletionStatus {
+        cursor.set_max_items(MIGRATION_BATCH_SIZE);
+        cursor.migrate_vec_mapper(&self.records(), |record| Record {
+            migrated: true,
+            ..record
+        })
+    }
+
+    /// Balances used to be kept in whole units.
+    #[migration(version = 2)]
+    fn migrate_balances(
+        &self,
+        cursor: &mut MigrationCursor<Self::Api>,
+    ) -> OperationCompletionStatus {
+        cursor.set_max_items(MIGRATION_BATCH_SIZE);
+        cursor.migrate_map_mapper(&self.balances(), |_, balance| balance * 100)
+    }
+
+    /// The record count was introduced in the second version of the contract.
+    #[migration(version = 3)]
+    fn init_record_count(&self) {
+        self.record_count().set(self.records().len() as u32);
+    }
+
+    #[view(getRecords)]
+    #[storage_mapper("records")]
+    fn records(&self) -> VecMapper<Record>;
+
+    #[view(getBalances)]
+    #[storage_mapper("balances")]
+    fn balances(&self) -> MapMapper<u32, u64>;
+
+    #[view(getRecordCount)]
+    #[storage_mapper("recordCount")]
+    fn record_count(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,14 @@
+#[test]
+fn migration_batches_go() {
+    dharitri_wasm_debug::denali_go("denali/migration_batches.scen.json");
+}
+
+#[test]
+fn migration_deploy_upgrade_go() {
+    dharitri_wasm_debug::denali_go("denali/migration_deploy_upgrade.scen.json");
+}
+
+#[test]
+fn migration_legacy_upgrade_go() {
+    dharitri_wasm_debug::denali_go("denali/migration_legacy_upgrade.scen.json");
+}
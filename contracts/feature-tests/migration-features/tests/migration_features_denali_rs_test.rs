@@ -0,0 +1,26 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/migration-features");
+    blockchain.register_contract_builder(
+        "file:output/migration-features.wasm",
+        migration_features::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn migration_batches_rs() {
+    dharitri_wasm_debug::denali_rs("denali/migration_batches.scen.json", world());
+}
+
+#[test]
+fn migration_deploy_upgrade_rs() {
+    dharitri_wasm_debug::denali_rs("denali/migration_deploy_upgrade.scen.json", world());
+}
+
+#[test]
+fn migration_legacy_upgrade_rs() {
+    dharitri_wasm_debug::denali_rs("denali/migration_legacy_upgrade.scen.json", world());
+}
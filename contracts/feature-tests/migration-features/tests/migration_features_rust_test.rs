@@ -0,0 +1,114 @@
+use dharitri_wasm::{contract_base::CallableContract, types::OperationCompletionStatus};
+use dharitri_wasm_debug::{rust_biguint, testing_framework::BlockchainStateWrapper};
+use migration_features::{MigrationFeatures, Record};
+
+const WASM_PATH: &str = "output/migration-features.wasm";
+
+/// The first version of the contract, before records got the `migrated` flag
+/// and before balances were kept in hundredths of a unit.
+mod migration_features_v1 {
+    dharitri_wasm::imports!();
+    dharitri_wasm::derive_imports!();
+
+    #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+    pub struct RecordV1 {
+        pub value: u64,
+    }
+
+    #[dharitri_wasm::contract]
+    pub trait MigrationFeaturesV1 {
+        #[init]
+        fn init(&self) {}
+
+        #[upgrade]
+        fn upgrade(&self) {}
+
+        #[storage_mapper("records")]
+        fn records(&self) -> VecMapper<RecordV1>;
+
+        #[storage_mapper("balances")]
+        fn balances(&self) -> MapMapper<u32, u64>;
+    }
+}
+
+use migration_features_v1::{MigrationFeaturesV1, RecordV1};
+
+#[test]
+fn migration_features_upgrade_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let v1_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        migration_features_v1::contract_obj,
+        WASM_PATH,
+    );
+
+    b_mock
+        .execute_tx(&owner, &v1_wrapper, &rust_zero, |sc| {
+            assert!(sc.call(b"init"));
+            for value in 1..=5u64 {
+                sc.records().push(&RecordV1 { value });
+            }
+            for id in 1..=3u32 {
+                sc.balances().insert(id, id as u64);
+            }
+        })
+        .assert_ok();
+
+    let _v2_wrapper = b_mock.upgrade_wrapper_with_migration(
+        &owner,
+        v1_wrapper,
+        migration_features::contract_obj,
+        Vec::new(),
+        |sc| sc.continue_migration(),
+        |sc| {
+            assert_eq!(sc.get_schema_version(), 3);
+            assert_eq!(sc.record_count().get(), 5);
+            for (index, value) in (1..=5u64).enumerate() {
+                assert_eq!(
+                    sc.records().get(index + 1),
+                    Record {
+                        value,
+                        migrated: true
+                    }
+                );
+            }
+            for id in 1..=3u32 {
+                assert_eq!(sc.balances().get(&id), Some(id as u64 * 100));
+            }
+        },
+    );
+}
+
+#[test]
+fn migration_features_deploy_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let sc_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        migration_features::contract_obj,
+        WASM_PATH,
+    );
+
+    b_mock
+        .execute_tx(&owner, &sc_wrapper, &rust_zero, |sc| {
+            assert!(sc.call(b"init"));
+            sc.add_record(7);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &sc_wrapper, &rust_zero, |sc| {
+            assert_eq!(sc.get_schema_version(), 3);
+            assert_eq!(
+                sc.continue_migration(),
+                OperationCompletionStatus::Completed
+            );
+            assert_eq!(sc.record_count().get(), 1);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,21 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+dharitri_wasm_node::wasm_endpoints! {
+    migration_features
+    (
+        upgrade
+        addRecord
+        continueMigration
+        getBalances
+        getRecordCount
+        getRecords
+        getSchemaVersion
+        setBalance
+    )
+}
+
+dharitri_wasm_node::wasm_empty_callback! {}
@@ -15,6 +15,7 @@ pub trait MultiContractFeatures {
     }
 
     #[view]
+    #[tag("lite")]
     #[storage_mapper("sample-value")]
     fn sample_value(&self) -> SingleValueMapper<BigUint>;
 
@@ -29,4 +30,10 @@ pub trait MultiContractFeatures {
     fn sample_value_external_set(&self, sample_value: BigUint) {
         self.sample_value().set(sample_value);
     }
+
+    /// Only part of the full contract, the lite one leaves it out.
+    #[view]
+    fn example_feature_message(&self) -> ManagedBuffer {
+        ManagedBuffer::from(b"example-feature on")
+    }
 }
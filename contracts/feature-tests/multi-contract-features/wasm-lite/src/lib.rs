@@ -0,0 +1,14 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+dharitri_wasm_node::wasm_endpoints! {
+    multi_contract_features
+    (
+        sample_value
+    )
+}
+
+dharitri_wasm_node::wasm_empty_callback! {}
@@ -7,6 +7,7 @@
 dharitri_wasm_node::wasm_endpoints! {
     multi_contract_features
     (
+        example_feature_message
         sample_value
     )
 }
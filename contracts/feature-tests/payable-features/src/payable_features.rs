@@ -150,4 +150,48 @@ pub trait PayableFeatures {
         let token = self.call_value().single_dct().token_identifier;
         (payment, token).into()
     }
+
+    #[endpoint]
+    #[payable(
+        "PAYABLE-FEATURES-TOKEN",
+        "OTHER-TOKEN",
+        max_transfers = 2,
+        distinct_tokens
+    )]
+    fn payable_token_list(&self) -> ManagedVec<DctTokenPayment<Self::Api>> {
+        self.call_value().all_dct_transfers()
+    }
+
+    #[endpoint]
+    #[payable("MOAX", "PAYABLE-FEATURES-TOKEN")]
+    fn payable_moax_or_token(&self) -> MultiValue2<BigUint, MoaxOrDctTokenIdentifier> {
+        let payment = self.call_value().moax_or_single_dct();
+        (payment.amount, payment.token_identifier).into()
+    }
+
+    #[endpoint]
+    #[payable(storage = "accepted_token")]
+    fn payable_storage_token(&self) -> MultiValue2<BigUint, TokenIdentifier> {
+        let payment = self.call_value().single_dct();
+        (payment.amount, payment.token_identifier).into()
+    }
+
+    #[endpoint]
+    #[payable(storage = "accepted_token_set", max_transfers = 2)]
+    fn payable_storage_token_set(&self) -> ManagedVec<DctTokenPayment<Self::Api>> {
+        self.call_value().all_dct_transfers()
+    }
+
+    #[endpoint]
+    fn add_accepted_tokens(&self, tokens: MultiValueEncoded<TokenIdentifier>) {
+        for token in tokens {
+            self.accepted_token_set().insert(token);
+        }
+    }
+
+    #[storage_mapper("acceptedToken")]
+    fn accepted_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("acceptedTokenSet")]
+    fn accepted_token_set(&self) -> SetMapper<TokenIdentifier>;
 }
@@ -67,3 +67,13 @@ fn payable_token_3_go() {
 fn payable_token_4_go() {
     dharitri_wasm_debug::denali_go("denali/payable_token_4.scen.json");
 }
+
+#[test]
+fn payable_storage_tokens_go() {
+    dharitri_wasm_debug::denali_go("denali/payable_storage_tokens.scen.json");
+}
+
+#[test]
+fn payable_token_list_go() {
+    dharitri_wasm_debug::denali_go("denali/payable_token_list.scen.json");
+}
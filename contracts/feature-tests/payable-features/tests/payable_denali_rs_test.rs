@@ -84,3 +84,13 @@ fn payable_token_3_rs() {
 fn payable_token_4_rs() {
     dharitri_wasm_debug::denali_rs("denali/payable_token_4.scen.json", world());
 }
+
+#[test]
+fn payable_storage_tokens_rs() {
+    dharitri_wasm_debug::denali_rs("denali/payable_storage_tokens.scen.json", world());
+}
+
+#[test]
+fn payable_token_list_rs() {
+    dharitri_wasm_debug::denali_rs("denali/payable_token_list.scen.json", world());
+}
@@ -7,6 +7,7 @@
 dharitri_wasm_node::wasm_endpoints! {
     payable_features
     (
+        add_accepted_tokens
         echo_call_value
         payable_any_1
         payable_any_2
@@ -16,10 +17,14 @@ dharitri_wasm_node::wasm_endpoints! {
         payable_moax_2
         payable_moax_3
         payable_moax_4
+        payable_moax_or_token
+        payable_storage_token
+        payable_storage_token_set
         payable_token_1
         payable_token_2
         payable_token_3
         payable_token_4
+        payable_token_list
         payment_array_3
         payment_multiple
     )
@@ -0,0 +1,43 @@
+dharitri_wasm::imports!();
+
+/// Example of endpoints protected by guard attributes.
+///
+/// Its storage keys are namespaced, e.g. `guards:whitelist`.
+#[dharitri_wasm::module(storage_prefix = "guards:")]
+pub trait GuardsModule: dharitri_wasm_modules::pause::PauseModule {
+    #[require_not_paused]
+    #[endpoint(guardedNotPaused)]
+    fn guarded_not_paused(&self) {}
+
+    #[only_whitelisted(guard_whitelist)]
+    #[endpoint(guardedWhitelisted)]
+    fn guarded_whitelisted(&self) {}
+
+    #[payable("*")]
+    #[nonzero_payment]
+    #[endpoint(guardedNonzeroPayment)]
+    fn guarded_nonzero_payment(&self) {}
+
+    #[require_not_paused]
+    #[before_timestamp(guard_deadline)]
+    #[endpoint(guardedBeforeDeadline)]
+    fn guarded_before_deadline(&self) {}
+
+    #[only_owner]
+    #[endpoint(addToGuardWhitelist)]
+    fn add_to_guard_whitelist(&self, address: ManagedAddress) {
+        self.guard_whitelist().add(&address);
+    }
+
+    #[only_owner]
+    #[endpoint(setGuardDeadline)]
+    fn set_guard_deadline(&self, deadline: u64) {
+        self.guard_deadline().set(deadline);
+    }
+
+    #[storage_mapper("whitelist")]
+    fn guard_whitelist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    #[storage_mapper("deadline")]
+    fn guard_deadline(&self) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,21 @@
+dharitri_wasm::imports!();
+
+/// Example of endpoints restricted to the members of a role.
+#[dharitri_wasm::module]
+pub trait OnlyRoleModule: dharitri_wasm_modules::access_control::AccessControlModule {
+    /// The constructor is in another module, so the first admin is set up here.
+    #[only_owner]
+    #[endpoint(initAccessControl)]
+    fn init_access_control(&self) {
+        let caller = self.blockchain().get_caller();
+        self.init_access_control_module(&caller);
+    }
+
+    #[only_role("MINTER")]
+    #[endpoint]
+    fn only_minter_endpoint(&self) {}
+
+    #[only_role("BURNER")]
+    #[view]
+    fn only_burner_view(&self) {}
+}
@@ -1,5 +1,6 @@
 #![no_std]
 
+mod guards_mod;
 mod internal_mod_a;
 mod internal_mod_b;
 mod internal_mod_c;
@@ -7,33 +8,42 @@ mod internal_mod_d;
 mod internal_mod_init;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod only_role_mod;
 
 dharitri_wasm::imports!();
 
 /// Contract that tests that using modules works correctly.
 /// Also provides testing for the most common modules:
+/// - AccessControlModule
 /// - DnsModule
 /// - FeaturesModule
 /// - DctModule
 /// - GovernanceModule
 /// - PauseModule
+/// - VestingModule
 #[dharitri_wasm::contract]
 pub trait UseModule:
-    internal_mod_a::InternalModuleA
+    guards_mod::GuardsModule
+    + internal_mod_a::InternalModuleA
     + internal_mod_b::InternalModuleB
     + internal_mod_c::InternalModuleC
     + internal_mod_init::InternalModuleInit
     + only_owner_mod::OnlyOwnerModule
     + only_owner_derived_mod::OnlyOwnerDerivedModule
+    + only_role_mod::OnlyRoleModule
+    + dharitri_wasm_modules::access_control::AccessControlModule
     + dharitri_wasm_modules::dns::DnsModule
     + dharitri_wasm_modules::dct::DctModule
     + dharitri_wasm_modules::features::FeaturesModule
     + dharitri_wasm_modules::governance::GovernanceModule
     + dharitri_wasm_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + dharitri_wasm_modules::governance::governance_events::GovernanceEventsModule
+    + dharitri_wasm_modules::governance::governance_voting_power::GovernanceVotingPowerModule
     + dharitri_wasm_modules::pause::PauseModule
     + dharitri_wasm_modules::staking::StakingModule
     + dharitri_wasm_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + dharitri_wasm_modules::vesting::VestingModule
+    + dharitri_wasm_modules::vesting::vesting_events::VestingEventsModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
@@ -42,6 +52,29 @@ pub trait UseModule:
         self.check_feature_on(b"featureName", true);
     }
 
+    /// Governance parameters are usually set in the constructor.
+    /// Here they get their own endpoint, to keep the other scenarios unchanged.
+    #[only_owner]
+    #[endpoint(initGovernance)]
+    fn init_governance(
+        &self,
+        governance_token_id: TokenIdentifier,
+        quorum: BigUint,
+        min_token_balance_for_proposal: BigUint,
+        voting_delay_in_blocks: u64,
+        voting_period_in_blocks: u64,
+        lock_time_after_voting_ends_in_blocks: u64,
+    ) {
+        self.init_governance_module(
+            governance_token_id,
+            quorum,
+            min_token_balance_for_proposal,
+            voting_delay_in_blocks,
+            voting_period_in_blocks,
+            lock_time_after_voting_ends_in_blocks,
+        );
+    }
+
     #[endpoint(checkPause)]
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
@@ -6,11 +6,14 @@ use dharitri_wasm_debug::{
     DebugApi,
 };
 use dharitri_wasm_modules::governance::{
-    governance_configurable::GovernanceConfigurablePropertiesModule, GovernanceModule,
+    governance_configurable::GovernanceConfigurablePropertiesModule,
+    governance_voting_power::GovernanceVotingPowerModule, GovernanceModule,
 };
 
 static GOV_TOKEN_ID: &[u8] = b"GOV-123456";
-const QUORUM: u64 = 1_500;
+const QUORUM: u64 = 1_000;
+const QUORUM_PERCENTAGE: u64 = 5_000;
+const GOV_TOKEN_SUPPLY: u64 = 3_000;
 const MIN_BALANCE_PROPOSAL: u64 = 500;
 const VOTING_DELAY_BLOCKS: u64 = 10;
 const VOTING_PERIOD_BLOCKS: u64 = 20;
@@ -41,6 +44,8 @@ impl<GovBuilder> GovSetup<GovBuilder>
 where
     GovBuilder: 'static + Copy + Fn() -> use_module::ContractObj<DebugApi>,
 {
+    /// All 3 users deposit their governance tokens before the first proposal.
+    /// The quorum is half of the 3_000 supply, which is stricter than the absolute quorum.
     pub fn new(gov_builder: GovBuilder) -> Self {
         let rust_zero = rust_biguint!(0);
         let initial_gov = rust_biguint!(INITIAL_GOV_TOKEN_BALANCE);
@@ -69,38 +74,84 @@ where
                     VOTING_PERIOD_BLOCKS,
                     LOCKING_PERIOD_BLOCKS,
                 );
+                sc.init_governance_quorum_percentage(
+                    QUORUM_PERCENTAGE,
+                    managed_biguint!(GOV_TOKEN_SUPPLY),
+                );
             })
             .assert_ok();
 
-        b_mock.set_block_nonce(10);
+        b_mock.set_block_nonce(5);
 
-        Self {
+        let mut gov_setup = Self {
             b_mock,
             owner,
             first_user,
             second_user,
             gov_wrapper,
-            current_block: 10,
+            current_block: 5,
+        };
+
+        for user in [
+            gov_setup.owner.clone(),
+            gov_setup.first_user.clone(),
+            gov_setup.second_user.clone(),
+        ] {
+            gov_setup
+                .deposit(&user, INITIAL_GOV_TOKEN_BALANCE)
+                .assert_ok();
         }
+
+        gov_setup.set_block_nonce(10);
+        gov_setup
+    }
+
+    pub fn deposit(&mut self, caller: &Address, gov_token_amount: u64) -> TxResult {
+        self.b_mock.execute_dct_transfer(
+            caller,
+            &self.gov_wrapper,
+            GOV_TOKEN_ID,
+            0,
+            &rust_biguint!(gov_token_amount),
+            |sc| {
+                sc.deposit_governance_tokens();
+            },
+        )
+    }
+
+    pub fn withdraw(&mut self, caller: &Address, gov_token_amount: u64) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.withdraw_deposited_tokens(managed_biguint!(gov_token_amount));
+            })
+    }
+
+    pub fn withdraw_for_proposal(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.withdraw_governance_tokens(proposal_id);
+            })
+    }
+
+    pub fn delegate(&mut self, caller: &Address, delegatee: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.delegate(managed_address!(delegatee));
+            })
     }
 
     pub fn propose(
         &mut self,
         proposer: &Address,
-        gov_token_amount: u64,
         dest_address: &Address,
         payments: Vec<Payment>,
         endpoint_name: &[u8],
         args: Vec<Vec<u8>>,
     ) -> (TxResult, usize) {
         let mut proposal_id = 0;
-        let result = self.b_mock.execute_dct_transfer(
-            proposer,
-            &self.gov_wrapper,
-            GOV_TOKEN_ID,
-            0,
-            &rust_biguint!(gov_token_amount),
-            |sc| {
+        let result = self
+            .b_mock
+            .execute_tx(proposer, &self.gov_wrapper, &rust_biguint!(0), |sc| {
                 let mut payments_managed = ManagedVec::new();
                 for p in payments {
                     payments_managed.push(DctTokenPayment::new(
@@ -128,41 +179,41 @@ where
                 );
 
                 proposal_id = sc.propose(managed_buffer!(b"change quorum"), actions);
-            },
-        );
+            });
 
         (result, proposal_id)
     }
 
-    pub fn vote(&mut self, voter: &Address, proposal_id: usize, gov_token_amount: u64) -> TxResult {
-        self.b_mock.execute_dct_transfer(
-            voter,
-            &self.gov_wrapper,
-            GOV_TOKEN_ID,
-            0,
-            &rust_biguint!(gov_token_amount),
-            |sc| {
-                sc.vote(proposal_id);
-            },
+    pub fn propose_change_quorum(&mut self, proposer: &Address) -> (TxResult, usize) {
+        let sc_addr = self.gov_wrapper.address_ref().clone();
+        self.propose(
+            proposer,
+            &sc_addr,
+            Vec::new(),
+            b"changeQuorumPercentage",
+            vec![2_000u64.to_be_bytes().to_vec()],
         )
     }
 
-    pub fn downvote(
-        &mut self,
-        voter: &Address,
-        proposal_id: usize,
-        gov_token_amount: u64,
-    ) -> TxResult {
-        self.b_mock.execute_dct_transfer(
-            voter,
-            &self.gov_wrapper,
-            GOV_TOKEN_ID,
-            0,
-            &rust_biguint!(gov_token_amount),
-            |sc| {
+    pub fn vote(&mut self, voter: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.vote(proposal_id);
+            })
+    }
+
+    pub fn downvote(&mut self, voter: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
                 sc.downvote(proposal_id);
-            },
-        )
+            })
+    }
+
+    pub fn abstain(&mut self, voter: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.abstain(proposal_id);
+            })
     }
 
     pub fn queue(&mut self, proposal_id: usize) -> TxResult {
@@ -194,13 +245,6 @@ where
             })
     }
 
-    pub fn withdraw(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
-        self.b_mock
-            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
-                sc.withdraw_governance_tokens(proposal_id);
-            })
-    }
-
     pub fn increment_block_nonce(&mut self, inc_amount: u64) {
         self.current_block += inc_amount;
         self.b_mock.set_block_nonce(self.current_block);
@@ -224,28 +268,21 @@ fn change_gov_config_test() {
     let owner_addr = gov_setup.owner.clone();
     let first_user_addr = gov_setup.first_user.clone();
     let second_user_addr = gov_setup.second_user.clone();
-    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
-    let (result, proposal_id) = gov_setup.propose(
-        &first_user_addr,
-        500,
-        &sc_addr,
-        Vec::new(),
-        b"changeQuorum",
-        vec![1_000u64.to_be_bytes().to_vec()],
-    );
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
     result.assert_ok();
     assert_eq!(proposal_id, 1);
 
     // vote too early
     gov_setup
-        .vote(&second_user_addr, proposal_id, 999)
+        .vote(&second_user_addr, proposal_id)
         .assert_user_error("Proposal is not active");
 
     gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
 
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
     gov_setup
-        .vote(&second_user_addr, proposal_id, 999)
-        .assert_ok();
+        .vote(&first_user_addr, proposal_id)
+        .assert_user_error("Already voted for this proposal");
 
     // try execute before queue
     gov_setup
@@ -257,40 +294,29 @@ fn change_gov_config_test() {
         .queue(proposal_id)
         .assert_user_error("Can only queue succeeded proposals");
 
-    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
-
-    // try queue not enough votes
+    // abstain votes only count towards the quorum
     gov_setup
-        .queue(proposal_id)
-        .assert_user_error("Can only queue succeeded proposals");
-
-    // user 1 vote again
-    gov_setup.set_block_nonce(20);
-    gov_setup
-        .vote(&first_user_addr, proposal_id, 200)
+        .abstain(&second_user_addr, proposal_id)
         .assert_ok();
 
-    // owner downvote
     gov_setup
-        .downvote(&owner_addr, proposal_id, 200)
-        .assert_ok();
-
-    // try queue too many downvotes
-    gov_setup.set_block_nonce(45);
-    gov_setup
-        .queue(proposal_id)
-        .assert_user_error("Can only queue succeeded proposals");
-
-    // user 1 vote again
-    gov_setup.set_block_nonce(20);
-    gov_setup
-        .vote(&first_user_addr, proposal_id, 200)
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.get_proposal_quorum(1), managed_biguint!(1_500));
+            assert_eq!(sc.total_votes(1).get(), managed_biguint!(1_000));
+            assert_eq!(sc.total_abstain_votes(1).get(), managed_biguint!(1_000));
+        })
         .assert_ok();
 
     // queue ok
-    gov_setup.set_block_nonce(45);
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
     gov_setup.queue(proposal_id).assert_ok();
 
+    // owner did not vote, but it is too late now
+    gov_setup
+        .downvote(&owner_addr, proposal_id)
+        .assert_user_error("Proposal is not active");
+
     // try execute too early
     gov_setup
         .execute(proposal_id)
@@ -300,69 +326,307 @@ fn change_gov_config_test() {
     gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
     gov_setup.execute(proposal_id).assert_ok();
 
-    // after execution, quorum changed from 1_500 to the proposed 1_000
+    // after execution, quorum changed from 50% to the proposed 20%
     gov_setup
         .b_mock
         .execute_query(&gov_setup.gov_wrapper, |sc| {
-            assert_eq!(sc.quorum().get(), managed_biguint!(1_000));
+            assert_eq!(sc.quorum_percentage().get(), 2_000);
             assert!(sc.proposals().item_is_empty(1));
         })
         .assert_ok();
 
     // withdraw tokens
     gov_setup
-        .withdraw(&first_user_addr, proposal_id)
-        .assert_ok();
+        .withdraw(&first_user_addr, INITIAL_GOV_TOKEN_BALANCE + 1)
+        .assert_user_error("Invalid amount of governance tokens to withdraw");
     gov_setup
-        .withdraw(&second_user_addr, proposal_id)
+        .withdraw(&first_user_addr, INITIAL_GOV_TOKEN_BALANCE)
         .assert_ok();
-    gov_setup.withdraw(&owner_addr, proposal_id).assert_ok();
 
     gov_setup.b_mock.check_dct_balance(
         &first_user_addr,
         GOV_TOKEN_ID,
         &rust_biguint!(INITIAL_GOV_TOKEN_BALANCE),
     );
-    gov_setup.b_mock.check_dct_balance(
-        &second_user_addr,
-        GOV_TOKEN_ID,
-        &rust_biguint!(INITIAL_GOV_TOKEN_BALANCE),
-    );
-    gov_setup.b_mock.check_dct_balance(
-        &owner_addr,
-        GOV_TOKEN_ID,
-        &rust_biguint!(INITIAL_GOV_TOKEN_BALANCE),
-    );
+}
+
+#[test]
+fn gov_quorum_not_reached_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
+
+    // 1_000 votes, 1_500 needed
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup
+        .queue(proposal_id)
+        .assert_user_error("Can only queue succeeded proposals");
+
+    // anyone can cancel a defeated proposal
+    gov_setup.cancel(&second_user_addr, proposal_id).assert_ok();
 }
 
 #[test]
 fn gov_cancel_defeated_proposal_test() {
     let mut gov_setup = GovSetup::new(use_module::contract_obj);
 
+    let owner_addr = gov_setup.owner.clone();
     let first_user_addr = gov_setup.first_user.clone();
     let second_user_addr = gov_setup.second_user.clone();
-    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
-    let (result, proposal_id) = gov_setup.propose(
-        &first_user_addr,
-        500,
-        &sc_addr,
-        Vec::new(),
-        b"changeQuorum",
-        vec![1_000u64.to_be_bytes().to_vec()],
-    );
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
     result.assert_ok();
     assert_eq!(proposal_id, 1);
 
     gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
     gov_setup
-        .downvote(&second_user_addr, proposal_id, 999)
+        .downvote(&second_user_addr, proposal_id)
         .assert_ok();
+    gov_setup.abstain(&owner_addr, proposal_id).assert_ok();
 
     // try cancel too early
     gov_setup
         .cancel(&second_user_addr, proposal_id)
-        .assert_user_error("Action may not be cancelled");
+        .assert_user_error("Only original proposer may cancel a proposal before it is defeated");
 
+    // quorum reached, but not more votes than downvotes
     gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup
+        .queue(proposal_id)
+        .assert_user_error("Can only queue succeeded proposals");
     gov_setup.cancel(&second_user_addr, proposal_id).assert_ok();
 }
+
+#[test]
+fn gov_proposer_cancel_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup.vote(&second_user_addr, proposal_id).assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup.queue(proposal_id).assert_ok();
+
+    // the proposer can still cancel a queued proposal
+    gov_setup
+        .cancel(&second_user_addr, proposal_id)
+        .assert_user_error("Only original proposer may cancel a proposal before it is defeated");
+    gov_setup.cancel(&first_user_addr, proposal_id).assert_ok();
+
+    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
+    gov_setup
+        .execute(proposal_id)
+        .assert_user_error("Can only execute queued proposals");
+}
+
+#[test]
+fn gov_delegation_and_snapshot_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let owner_addr = gov_setup.owner.clone();
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.b_mock.create_user_account(&rust_biguint!(0));
+    gov_setup.b_mock.set_dct_balance(
+        &third_user_addr,
+        GOV_TOKEN_ID,
+        &rust_biguint!(INITIAL_GOV_TOKEN_BALANCE),
+    );
+
+    // no deposit, no voting power
+    gov_setup
+        .propose_change_quorum(&third_user_addr)
+        .0
+        .assert_user_error("Not enough tokens for proposing action");
+
+    gov_setup
+        .delegate(&second_user_addr, &second_user_addr)
+        .assert_user_error("Already delegated to this address");
+    gov_setup
+        .delegate(&second_user_addr, &first_user_addr)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(
+                sc.get_voting_power(&managed_address!(&first_user_addr)),
+                managed_biguint!(2_000)
+            );
+            assert_eq!(
+                sc.get_voting_power(&managed_address!(&second_user_addr)),
+                managed_biguint!(0)
+            );
+            assert_eq!(sc.get_total_voting_power(), managed_biguint!(3_000));
+        })
+        .assert_ok();
+
+    // changes in the proposal block do not count
+    gov_setup.increment_block_nonce(1);
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
+    result.assert_ok();
+    gov_setup
+        .deposit(&third_user_addr, INITIAL_GOV_TOKEN_BALANCE)
+        .assert_ok();
+    gov_setup
+        .delegate(&owner_addr, &first_user_addr)
+        .assert_ok();
+
+    // the proposer's voting power is also taken from the start of the block
+    gov_setup
+        .propose_change_quorum(&third_user_addr)
+        .0
+        .assert_user_error("Not enough tokens for proposing action");
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    // tokens withdrawn after the proposal was created still count
+    gov_setup
+        .withdraw(&first_user_addr, INITIAL_GOV_TOKEN_BALANCE)
+        .assert_ok();
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
+
+    // voting power was delegated, or came too late
+    gov_setup
+        .vote(&second_user_addr, proposal_id)
+        .assert_user_error("No voting power");
+    gov_setup
+        .downvote(&third_user_addr, proposal_id)
+        .assert_user_error("No voting power");
+
+    gov_setup.downvote(&owner_addr, proposal_id).assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.total_votes(1).get(), managed_biguint!(2_000));
+            assert_eq!(sc.total_downvotes(1).get(), managed_biguint!(1_000));
+            assert_eq!(sc.get_proposal_quorum(1), managed_biguint!(1_500));
+            assert_eq!(
+                sc.get_voting_power(&managed_address!(&first_user_addr)),
+                managed_biguint!(2_000)
+            );
+        })
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup.queue(proposal_id).assert_ok();
+}
+
+#[test]
+fn gov_quorum_from_token_supply_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let owner_addr = gov_setup.owner.clone();
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+
+    // only half of the supply is deposited
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init_governance_quorum_percentage(QUORUM_PERCENTAGE, managed_biguint!(6_000));
+            },
+        )
+        .assert_ok();
+
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
+    result.assert_ok();
+
+    // later changes do not affect the proposal
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init_governance_quorum_percentage(0, managed_biguint!(6_000));
+            },
+        )
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+    gov_setup.vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup.vote(&second_user_addr, proposal_id).assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.get_proposal_quorum(1), managed_biguint!(3_000));
+        })
+        .assert_ok();
+
+    // 2_000 votes, all the deposited voting power but the owner's, 3_000 needed
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup
+        .queue(proposal_id)
+        .assert_user_error("Can only queue succeeded proposals");
+}
+
+#[test]
+fn gov_withdraw_tokens_locked_for_proposal_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let (result, proposal_id) = gov_setup.propose_change_quorum(&first_user_addr);
+    result.assert_ok();
+
+    // tokens locked by voting with an earlier version of the module
+    let gov_sc_address = gov_setup.gov_wrapper.address_ref().clone();
+    gov_setup.b_mock.set_dct_balance(
+        &gov_sc_address,
+        GOV_TOKEN_ID,
+        &rust_biguint!(3 * INITIAL_GOV_TOKEN_BALANCE + 300),
+    );
+    gov_setup
+        .b_mock
+        .set_dct_balance(&second_user_addr, GOV_TOKEN_ID, &rust_biguint!(0));
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &second_user_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.votes(proposal_id, &managed_address!(&second_user_addr))
+                    .set(managed_biguint!(200));
+                sc.downvotes(proposal_id, &managed_address!(&second_user_addr))
+                    .set(managed_biguint!(100));
+            },
+        )
+        .assert_ok();
+
+    gov_setup
+        .withdraw_for_proposal(&second_user_addr, proposal_id)
+        .assert_user_error("Proposal has to be executed or canceled first");
+
+    gov_setup.cancel(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .withdraw_for_proposal(&second_user_addr, proposal_id)
+        .assert_ok();
+    gov_setup
+        .withdraw_for_proposal(&second_user_addr, proposal_id)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .check_dct_balance(&second_user_addr, GOV_TOKEN_ID, &rust_biguint!(300));
+}
@@ -1,3 +1,8 @@
+#[test]
+fn use_module_access_control_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_access_control.scen.json");
+}
+
 #[test]
 fn use_module_dns_register_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_dns_register.scen.json");
@@ -8,6 +13,11 @@ fn use_module_features_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_features.scen.json");
 }
 
+#[test]
+fn use_module_guards_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_guards.scen.json");
+}
+
 #[test]
 fn use_module_internal_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_internal.scen.json");
@@ -27,3 +37,8 @@ fn use_module_no_endpoint_go() {
 fn use_module_pause_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_pause.scen.json");
 }
+
+#[test]
+fn use_module_vesting_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_vesting.scen.json");
+}
@@ -44,6 +44,11 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
+#[test]
+fn use_module_access_control_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_access_control.scen.json", world());
+}
+
 #[test]
 fn use_module_dns_register_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_dns_register.scen.json", world());
@@ -54,6 +59,11 @@ fn use_module_features_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_features.scen.json", world());
 }
 
+#[test]
+fn use_module_guards_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_guards.scen.json", world());
+}
+
 #[test]
 fn use_module_internal_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_internal.scen.json", world());
@@ -68,3 +78,8 @@ fn use_module_no_endpoint_rs() {
 fn use_module_pause_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_pause.scen.json", world());
 }
+
+#[test]
+fn use_module_vesting_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_vesting.scen.json", world());
+}
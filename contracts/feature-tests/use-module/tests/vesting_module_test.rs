@@ -0,0 +1,165 @@
+use dharitri_wasm_debug::{
+    managed_address, managed_biguint, rust_biguint, testing_framework::BlockchainStateWrapper,
+};
+use dharitri_wasm_modules::vesting::VestingModule;
+
+static VESTED_TOKEN_ID: &[u8] = b"VEST-123456";
+const GRANT_AMOUNT: u64 = 1_000_000;
+const START_TIMESTAMP: u64 = 1_000;
+const DURATION: u64 = 1_000;
+
+#[test]
+fn vesting_module_linear_grant_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let alice = b_mock.create_user_account(&rust_zero);
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock.set_dct_balance(&owner, VESTED_TOKEN_ID, &rust_biguint!(GRANT_AMOUNT));
+
+    b_mock
+        .execute_dct_transfer(
+            &owner,
+            &vesting_sc,
+            VESTED_TOKEN_ID,
+            0,
+            &rust_biguint!(GRANT_AMOUNT),
+            |sc| {
+                let grant_id = sc.create_linear_grant(
+                    managed_address!(&alice),
+                    START_TIMESTAMP,
+                    DURATION,
+                    false,
+                );
+                assert_eq!(grant_id, 1);
+            },
+        )
+        .assert_ok();
+
+    // views, at any timestamp
+    b_mock
+        .execute_query(&vesting_sc, |sc| {
+            assert_eq!(sc.get_claimable_amount(1, 0), managed_biguint!(0));
+            assert_eq!(
+                sc.get_claimable_amount(1, START_TIMESTAMP + DURATION / 4),
+                managed_biguint!(GRANT_AMOUNT / 4)
+            );
+            assert_eq!(
+                sc.get_claimable_amount(1, START_TIMESTAMP + 2 * DURATION),
+                managed_biguint!(GRANT_AMOUNT)
+            );
+        })
+        .assert_ok();
+
+    // nothing before the start
+    b_mock.set_block_timestamp(START_TIMESTAMP / 2);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_user_error("Nothing to claim");
+
+    // only the beneficiary can claim
+    b_mock.set_block_timestamp(START_TIMESTAMP + DURATION / 2);
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_user_error("Only the beneficiary can claim");
+
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_ok();
+    b_mock.check_dct_balance(&alice, VESTED_TOKEN_ID, &rust_biguint!(GRANT_AMOUNT / 2));
+
+    // claimed tokens are not claimable again
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_user_error("Nothing to claim");
+
+    // non-revocable
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.revoke_grant(1);
+        })
+        .assert_user_error("Grant is not revocable");
+
+    b_mock.set_block_timestamp(START_TIMESTAMP + DURATION);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_ok();
+    b_mock.check_dct_balance(&alice, VESTED_TOKEN_ID, &rust_biguint!(GRANT_AMOUNT));
+    b_mock.check_dct_balance(vesting_sc.address_ref(), VESTED_TOKEN_ID, &rust_zero);
+}
+
+#[test]
+fn vesting_module_revoke_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(GRANT_AMOUNT));
+    let alice = b_mock.create_user_account(&rust_zero);
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    // 4 periods, with MOAX
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_biguint!(GRANT_AMOUNT), |sc| {
+            let grant_id = sc.create_periodic_grant(
+                managed_address!(&alice),
+                START_TIMESTAMP,
+                DURATION / 4,
+                4,
+                true,
+            );
+            assert_eq!(grant_id, 1);
+        })
+        .assert_ok();
+
+    // just before the end of the second period, only the first one is vested
+    b_mock.set_block_timestamp(START_TIMESTAMP + DURATION / 2 - 1);
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            let returned_amount = sc.revoke_grant(1);
+            assert_eq!(returned_amount, managed_biguint!(GRANT_AMOUNT * 3 / 4));
+        })
+        .assert_ok();
+    b_mock.check_moax_balance(&owner, &rust_biguint!(GRANT_AMOUNT * 3 / 4));
+
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.revoke_grant(1);
+        })
+        .assert_user_error("Grant already revoked");
+
+    // vesting stopped at the revoke timestamp
+    b_mock.set_block_timestamp(START_TIMESTAMP + DURATION);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_ok();
+    b_mock.check_moax_balance(&alice, &rust_biguint!(GRANT_AMOUNT / 4));
+    b_mock.check_moax_balance(vesting_sc.address_ref(), &rust_zero);
+
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim(1);
+        })
+        .assert_user_error("Nothing to claim");
+}
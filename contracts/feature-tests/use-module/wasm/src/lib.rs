@@ -8,47 +8,92 @@ dharitri_wasm_node::wasm_endpoints! {
     use_module
     (
         callBack
+        abstain
+        addToGuardWhitelist
         call_derived_not_owner_only
         call_mod_a
         call_mod_b
         call_mod_c
         cancel
+        changeGovernanceTokenSupply
         changeLockTimeAfterVotingEndsInBlocks
         changeMinTokenBalanceForProposing
         changeQuorum
+        changeQuorumPercentage
         changeVotingDelayInBlocks
         changeVotingPeriodInBlocks
         checkFeatureGuard
         checkPause
+        claim
+        createCliffGrant
+        createLinearGrant
+        createPeriodicGrant
+        delegate
+        depositGovernanceTokens
         depositTokensForAction
         dnsRegister
         downvote
         execute
+        getBeneficiaryGrantIds
+        getClaimableAmount
+        getDelegatee
+        getDepositedTokens
         getGovernanceTokenId
+        getGovernanceTokenSupply
+        getGrant
+        getLastGrantId
         getLockTimeAfterVotingEndsInBlocks
         getMinTokenBalanceForProposing
         getProposalActions
         getProposalDescription
+        getProposalExecutionBlock
+        getProposalQuorum
         getProposalStatus
         getProposer
         getQuorum
+        getQuorumPercentage
+        getRoleAdmin
+        getRoleMembers
+        getTotalAbstainVotes
         getTotalDownvotes
         getTotalVotes
+        getTotalVotingPower
+        getTotalVotingPowerAtBlock
+        getVestedAmount
         getVotingDelayInBlocks
         getVotingPeriodInBlocks
+        getVotingPower
+        getVotingPowerAtBlock
+        grantRole
+        guardedBeforeDeadline
+        guardedNonzeroPayment
+        guardedNotPaused
+        guardedWhitelisted
+        hasRole
+        hasVoted
+        initAccessControl
+        initGovernance
         isPaused
         issueToken
+        only_burner_view
+        only_minter_endpoint
         only_owner_mod_endpoint
         pause
         propose
         queue
+        renounceRole
+        revokeGrant
+        revokeRole
         setFeatureFlag
+        setGuardDeadline
+        setRoleAdmin
         slashMember
         stake
         unpause
         unstake
         vote
         voteSlashMember
+        withdrawDepositedTokens
         withdrawGovernanceTokens
     )
 }
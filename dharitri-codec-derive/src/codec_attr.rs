@@ -0,0 +1,270 @@
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
+static ATTR_CODEC: &str = "codec";
+static ARG_VERSION: &str = "version";
+static ARG_TRANSPARENT: &str = "transparent";
+static ARG_SINCE: &str = "since";
+static ARG_DEFAULT: &str = "default";
+static ARG_SKIP: &str = "skip";
+static ARG_WITH: &str = "with";
+
+/// One `name` or `name = value` entry of a `#[codec(...)]` attribute.
+struct CodecArg {
+    name: syn::Ident,
+    value: Option<syn::Expr>,
+}
+
+impl syn::parse::Parse for CodecArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(CodecArg { name, value })
+    }
+}
+
+fn codec_args(attrs: &[syn::Attribute]) -> Vec<CodecArg> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_CODEC))
+        .flat_map(|attr| {
+            attr.parse_args_with(|input: ParseStream| {
+                Punctuated::<CodecArg, Token![,]>::parse_terminated(input)
+            })
+            .unwrap_or_else(|err| panic!("malformed codec attribute: {}", err))
+        })
+        .collect()
+}
+
+fn int_lit_u8(expr: &syn::Expr, what: &str) -> u8 {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit_int),
+        ..
+    }) = expr
+    {
+        lit_int
+            .base10_parse()
+            .unwrap_or_else(|_| panic!("{} must fit in a u8", what))
+    } else {
+        panic!("{} must be an integer literal", what)
+    }
+}
+
+fn arg_value_u8(arg: &CodecArg) -> u8 {
+    let value = arg
+        .value
+        .as_ref()
+        .unwrap_or_else(|| panic!("codec `{}` expects a value", arg.name));
+    int_lit_u8(value, format!("codec `{}`", arg.name).as_str())
+}
+
+fn arg_value_path(arg: &CodecArg) -> syn::Path {
+    if let Some(syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit_str),
+        ..
+    })) = &arg.value
+    {
+        lit_str
+            .parse()
+            .unwrap_or_else(|_| panic!("codec `{}` expects a module path", arg.name))
+    } else {
+        panic!("codec `{}` expects a string literal", arg.name)
+    }
+}
+
+fn assert_no_value(arg: &CodecArg) {
+    assert!(
+        arg.value.is_none(),
+        "codec `{}` does not take a value",
+        arg.name
+    );
+}
+
+/// Type-level codec attributes, e.g. `#[codec(version = 2)]` or `#[codec(transparent)]`.
+pub struct TypeCodecAttributes {
+    /// The latest version of a struct encoding, unversioned structs are considered to be at version 1.
+    pub version: u8,
+
+    /// Newtypes encoded exactly like their only field.
+    pub transparent: bool,
+}
+
+impl TypeCodecAttributes {
+    pub fn parse(ast: &syn::DeriveInput) -> Self {
+        let mut result = TypeCodecAttributes {
+            version: 1,
+            transparent: false,
+        };
+        for arg in codec_args(ast.attrs.as_slice()) {
+            assert!(
+                matches!(ast.data, syn::Data::Struct(_)),
+                "codec `{}` is only supported for structs",
+                arg.name
+            );
+            if arg.name == ARG_VERSION {
+                result.version = arg_value_u8(&arg);
+                assert!(result.version >= 1, "codec `version` must be at least 1");
+            } else if arg.name == ARG_TRANSPARENT {
+                assert_no_value(&arg);
+                result.transparent = true;
+            } else {
+                panic!("unknown codec attribute `{}`", arg.name);
+            }
+        }
+        assert!(
+            !(result.transparent && result.version > 1),
+            "transparent structs cannot be versioned"
+        );
+        result
+    }
+}
+
+/// Field-level codec attributes, e.g. `#[codec(since = 2, default = 5)]`, `#[codec(skip)]` or `#[codec(with = "module")]`.
+pub struct FieldCodecAttributes {
+    /// The struct version that introduced the field, 1 for the original fields.
+    pub since: u8,
+
+    /// The value to use when the field is skipped or missing from an older encoding,
+    /// `Default::default()` if not given.
+    pub default: Option<syn::Expr>,
+
+    /// Skipped fields are not encoded at all.
+    pub skip: bool,
+
+    /// Module providing `dep_encode_or_handle_err` and `dep_decode_or_handle_err` functions for the field type.
+    pub with: Option<syn::Path>,
+}
+
+impl FieldCodecAttributes {
+    pub fn parse(field: &syn::Field) -> Self {
+        let mut result = FieldCodecAttributes {
+            since: 1,
+            default: None,
+            skip: false,
+            with: None,
+        };
+        for arg in codec_args(field.attrs.as_slice()) {
+            if arg.name == ARG_SINCE {
+                result.since = arg_value_u8(&arg);
+            } else if arg.name == ARG_DEFAULT {
+                result.default = Some(
+                    arg.value
+                        .unwrap_or_else(|| panic!("codec `default` expects a value")),
+                );
+            } else if arg.name == ARG_SKIP {
+                assert_no_value(&arg);
+                result.skip = true;
+            } else if arg.name == ARG_WITH {
+                result.with = Some(arg_value_path(&arg));
+            } else {
+                panic!("unknown codec attribute `{}`", arg.name);
+            }
+        }
+        assert!(
+            !(result.skip && (result.since > 1 || result.with.is_some())),
+            "skipped fields cannot have `since` or `with`"
+        );
+        result
+    }
+
+    /// Used for skipped fields and for fields missing from older encodings.
+    pub fn default_snippet(&self, ty: &syn::Type) -> proc_macro2::TokenStream {
+        match &self.default {
+            Some(default) => quote::quote! { #default },
+            None => quote::quote! { <#ty as core::default::Default>::default() },
+        }
+    }
+}
+
+/// Field versions, in field order.
+///
+/// Checks that fields added in later versions come after the older ones,
+/// since older encodings are recognized by the input ending early.
+pub fn field_versions(ast: &syn::DeriveInput, fields: &syn::Fields) -> Vec<FieldCodecAttributes> {
+    let version = TypeCodecAttributes::parse(ast).version;
+    let field_attributes: Vec<FieldCodecAttributes> =
+        fields.iter().map(FieldCodecAttributes::parse).collect();
+    let mut previous_since = 1;
+    for field_attr in field_attributes
+        .iter()
+        .filter(|field_attr| !field_attr.skip)
+    {
+        assert!(
+            field_attr.since >= 1 && field_attr.since <= version,
+            "codec `since` must be between 1 and the struct version ({})",
+            version
+        );
+        assert!(
+            field_attr.since >= previous_since,
+            "fields added in later codec versions must come after the older fields"
+        );
+        previous_since = field_attr.since;
+    }
+    field_attributes
+}
+
+/// Checks that no versioning attributes were used where they are not supported, e.g. on enum variant fields.
+pub fn assert_no_field_versions(fields: &syn::Fields) {
+    for field in fields.iter() {
+        assert!(
+            FieldCodecAttributes::parse(field).since == 1,
+            "codec `since` is only supported for struct fields"
+        );
+    }
+}
+
+/// The only encoded field of a `#[codec(transparent)]` struct, `None` for other types.
+pub fn transparent_field(ast: &syn::DeriveInput) -> Option<(usize, &syn::Field)> {
+    if !TypeCodecAttributes::parse(ast).transparent {
+        return None;
+    }
+    let data_struct = match &ast.data {
+        syn::Data::Struct(data_struct) => data_struct,
+        _ => unreachable!(),
+    };
+    let mut encoded_fields = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !FieldCodecAttributes::parse(field).skip);
+    let encoded_field = encoded_fields.next();
+    assert!(
+        encoded_field.is_some() && encoded_fields.next().is_none(),
+        "transparent structs must have exactly one field that is not skipped"
+    );
+    let (index, field) = encoded_field.unwrap();
+    assert!(
+        FieldCodecAttributes::parse(field).with.is_none(),
+        "codec `with` is not supported for the field of a transparent struct"
+    );
+    Some((index, field))
+}
+
+/// The encoded discriminant of each variant.
+///
+/// Explicit discriminants (`Variant = 5`) are honored, the others follow the previous one, just like in Rust.
+///
+/// The `TypeAbi` derive in `dharitri-wasm-derive` has a copy of this function, keep the two identical.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants: Vec<u8> = Vec::with_capacity(data_enum.variants.len());
+    for variant in data_enum.variants.iter() {
+        let discriminant = match (&variant.discriminant, discriminants.last()) {
+            (Some((_, expr)), _) => int_lit_u8(expr, "enum discriminant"),
+            (None, Some(previous)) => previous
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("enum discriminant must fit in a u8")),
+            (None, None) => 0,
+        };
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate enum discriminant {}",
+            discriminant
+        );
+        discriminants.push(discriminant);
+    }
+    discriminants
+}
@@ -1,25 +1,122 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
+
+/// Decodes a field, or provides its default value if skipped.
+fn field_dep_decode_expr(
+    field: &syn::Field,
+    field_attr: &FieldCodecAttributes,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    if field_attr.skip {
+        return field_attr.default_snippet(ty);
+    }
+    if let Some(with) = &field_attr.with {
+        return quote! {
+            #with::dep_decode_or_handle_err(#input_value, h)?
+        };
+    }
+    quote! {
+        <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+    }
+}
 
 pub fn dep_decode_snippet(
     _index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let ty = &field.ty;
+    let decode_expr =
+        field_dep_decode_expr(field, &FieldCodecAttributes::parse(field), input_value);
     if let Some(ident) = &field.ident {
         quote! {
-            #ident: <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            #ident: #decode_expr
         }
     } else {
-        quote! {
-            <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+        decode_expr
+    }
+}
+
+/// Decodes the struct fields into local variables, then builds the struct.
+///
+/// For versioned structs, the input ending before the fields of a later version means
+/// the value was encoded by an older version, so those fields get their default values instead.
+/// This is why older encodings can only be recognized where the struct comes last, e.g. in storage.
+pub fn struct_dep_decode_snippet(
+    ast: &syn::DeriveInput,
+    fields: &syn::Fields,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let field_versions = field_versions(ast, fields);
+    if field_versions
+        .iter()
+        .all(|field_attr| field_attr.since == 1)
+    {
+        let field_dep_decode_snippets = fields_decl_syntax(fields, |index, field| {
+            dep_decode_snippet(index, field, input_value)
+        });
+        return quote! {
+            #name #field_dep_decode_snippets
+        };
+    }
+
+    let mut previous_since = 1;
+    let field_decode_statements: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .zip(field_versions.iter())
+        .enumerate()
+        .map(|(index, (field, field_attr))| {
+            let ty = &field.ty;
+            let local_var = versioned_field_local_variable(index);
+            let decode_expr = field_dep_decode_expr(field, field_attr, input_value);
+            if field_attr.since == 1 {
+                return quote! {
+                    let #local_var: #ty = #decode_expr;
+                };
+            }
+
+            let older_version_check = if field_attr.since == previous_since {
+                quote! {}
+            } else if previous_since == 1 {
+                quote! {
+                    let older_version = dharitri_codec::NestedDecodeInput::is_depleted(&*#input_value);
+                }
+            } else {
+                quote! {
+                    let older_version = older_version || dharitri_codec::NestedDecodeInput::is_depleted(&*#input_value);
+                }
+            };
+            previous_since = field_attr.since;
+            let default_expr = field_attr.default_snippet(ty);
+            quote! {
+                #older_version_check
+                let #local_var: #ty = if older_version { #default_expr } else { #decode_expr };
+            }
+        })
+        .collect();
+    let field_assignments = fields_decl_syntax(fields, |index, field| {
+        let local_var = versioned_field_local_variable(index);
+        if let Some(ident) = &field.ident {
+            quote! { #ident: #local_var }
+        } else {
+            quote! { #local_var }
+        }
+    });
+    quote! {
+        {
+            #(#field_decode_statements)*
+            #name #field_assignments
         }
     }
 }
 
+fn versioned_field_local_variable(index: usize) -> syn::Ident {
+    syn::Ident::new(&format!("field_{}", index), proc_macro2::Span::call_site())
+}
+
 pub fn variant_dep_decode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -28,15 +125,15 @@ pub fn variant_dep_decode_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
+			assert_no_field_versions(&variant.fields);
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
 				dep_decode_snippet(index, field, input_value)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -47,10 +144,8 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
-                });
+            let struct_dep_decode_snippet =
+                struct_dep_decode_snippet(ast, &data_struct.fields, &quote! {input});
             quote! {
                 impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
                     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> core::result::Result<Self, H::HandledErr>
@@ -59,7 +154,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         H: dharitri_codec::DecodeErrorHandler,
                     {
                         core::result::Result::Ok(
-                            #name #field_dep_decode_snippets
+                            #struct_dep_decode_snippet
                         )
                     }
                 }
@@ -1,13 +1,37 @@
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_attr = FieldCodecAttributes::parse(field);
+    if field_attr.skip {
+        return quote! {};
+    }
+    if let Some(with) = &field_attr.with {
+        return quote! {
+            #with::dep_encode_or_handle_err(&#value, dest, h)?;
+        };
+    }
     quote! {
         dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#value, dest, h)?;
     }
 }
 
+/// Binds the variant fields to local variables, except for the skipped ones, which are not used.
+pub fn variant_field_pattern(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    if !FieldCodecAttributes::parse(field).skip {
+        return local_variable_for_field(index, field);
+    }
+    if let Some(ident) = &field.ident {
+        quote! { #ident: _ }
+    } else {
+        quote! { _ }
+    }
+}
+
 fn variant_dep_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -15,18 +39,17 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, variant_field_pattern);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                    dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -40,7 +63,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             quote! {
                 impl #impl_generics dharitri_codec::NestedEncode for #name #ty_generics #where_clause {
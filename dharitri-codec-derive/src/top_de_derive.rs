@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{codec_attr::*, nested_de_derive::*, util::*};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
@@ -10,19 +10,19 @@ fn fieldless_enum_match_arm_result_ok(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless,
+/// since that variant is top-encoded as empty bytes.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
@@ -31,15 +31,21 @@ fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
-            let auto_default = quote! {
-                if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
-                }
-            };
-            return auto_default;
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(variant_discriminants(data_enum))
+            .find(|(_, discriminant)| *discriminant == 0);
+        if let Some((zero_variant, _)) = zero_variant {
+            if zero_variant.fields.is_empty() {
+                let zero_variant_ident = &zero_variant.ident;
+                let auto_default = quote! {
+                    if top_input.byte_len() == 0 {
+                        return core::result::Result::Ok(#name::#zero_variant_ident);
+                    }
+                };
+                return auto_default;
+            }
         }
     }
 
@@ -52,14 +58,32 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
+            if let Some((transparent_index, _)) = transparent_field(ast) {
+                let field_snippets = fields_decl_syntax(&data_struct.fields, |index, field| {
+                    if index == transparent_index {
+                        let ty = &field.ty;
+                        let decode_expr = quote! {
+                            <#ty as dharitri_codec::TopDecode>::top_decode_or_handle_err(top_input, h)?
+                        };
+                        match &field.ident {
+                            Some(ident) => quote! { #ident: #decode_expr },
+                            None => decode_expr,
+                        }
+                    } else {
+                        dep_decode_snippet(index, field, &quote! {})
+                    }
                 });
+                return quote! {
+                    core::result::Result::Ok(#name #field_snippets)
+                };
+            }
+
+            let struct_dep_decode_snippet =
+                struct_dep_decode_snippet(ast, &data_struct.fields, &quote! {&mut nested_buffer});
 
             quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
-                let result = #name #field_dep_decode_snippets ;
+                let result = #struct_dep_decode_snippet ;
                 if !dharitri_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
                     return core::result::Result::Err(h.handle_error(dharitri_codec::DecodeError::INPUT_TOO_LONG));
                 }
@@ -1,7 +1,11 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_en_derive::dep_encode_snippet, util::*};
+use crate::{
+    codec_attr::*,
+    nested_en_derive::{dep_encode_snippet, variant_field_pattern},
+    util::*,
+};
 
 pub fn variant_top_encode_snippets(
     name: &syn::Ident,
@@ -10,28 +14,27 @@ pub fn variant_top_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             if variant.fields.is_empty() {
                 // top-encode discriminant directly
                 quote! {
                     #name::#variant_ident =>
-                        dharitri_codec::TopEncode::top_encode_or_handle_err(&#variant_index_u8, output, h),
+                        dharitri_codec::TopEncode::top_encode_or_handle_err(&#discriminant, output, h),
                 }
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, variant_field_pattern);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    dep_encode_snippet(field, &local_variable_for_field(index, field))
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
                         let mut buffer = output.start_nested_encode();
                         let dest = &mut buffer;
-                        dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                        dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                         #(#variant_field_snippets)*
                         output.finalize_nested_encode(buffer);
                         core::result::Result::Ok(())
@@ -47,8 +50,15 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            if let Some((index, field)) = transparent_field(ast) {
+                let field_expr = self_field_expr(index, field);
+                return quote! {
+                    dharitri_codec::TopEncode::top_encode_or_handle_err(&#field_expr, output, h)
+                };
+            }
+
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             quote! {
                 let mut buffer = output.start_nested_encode();
@@ -0,0 +1,147 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::test_util::{
+    check_dep_encode_decode, check_top_decode, check_top_encode, check_top_encode_decode,
+};
+
+// to test, run the following command in dharitri-codec folder:
+// cargo expand --test derive_codec_attributes_test > expanded.rs
+
+/// A type from another crate, that does not implement the codec traits.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ForeignTimestamp {
+    pub seconds: u64,
+}
+
+/// Encodes the timestamp as a plain u64.
+mod timestamp_codec {
+    use super::ForeignTimestamp;
+    use dharitri_codec::*;
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &ForeignTimestamp,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        value.seconds.dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(
+        input: &mut I,
+        h: H,
+    ) -> Result<ForeignTimestamp, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ForeignTimestamp {
+            seconds: u64::dep_decode_or_handle_err(input, h)?,
+        })
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithAttributes {
+    pub int: u16,
+    #[codec(skip)]
+    pub cache: Vec<u8>,
+    #[codec(skip, default = 7)]
+    pub computed: u8,
+    #[codec(with = "timestamp_codec")]
+    pub created: ForeignTimestamp,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub enum EnumWithDiscriminants {
+    First = 1,
+    Second,
+    Tenth = 10,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub enum EnumWithSkippedFields {
+    Empty,
+    Data {
+        value: u32,
+        #[codec(skip)]
+        note: Vec<u8>,
+    },
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(transparent)]
+pub struct TransparentNewtype(pub Vec<u8>);
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(transparent)]
+pub struct TransparentWithSkip {
+    pub value: u64,
+    #[codec(skip, default = 3)]
+    pub unit: u8,
+}
+
+#[test]
+fn skip_and_with_test() {
+    let s = StructWithAttributes {
+        int: 0x42,
+        cache: Vec::new(),
+        computed: 7,
+        created: ForeignTimestamp { seconds: 0x1234 },
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* created */ 0, 0, 0, 0, 0, 0, 0x12, 0x34,
+    ];
+
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s.clone(), bytes);
+
+    // skipped fields are not encoded, so they get their defaults back
+    let with_cache = StructWithAttributes {
+        cache: vec![1, 2, 3],
+        computed: 100,
+        ..s.clone()
+    };
+    assert_eq!(check_top_encode(&with_cache).as_slice(), bytes);
+    assert_eq!(check_top_decode::<StructWithAttributes>(bytes), s);
+}
+
+#[test]
+fn explicit_discriminants_test() {
+    check_top_encode_decode(EnumWithDiscriminants::First, &[1]);
+    check_top_encode_decode(EnumWithDiscriminants::Second, &[2]);
+    check_top_encode_decode(EnumWithDiscriminants::Tenth, &[10]);
+    check_dep_encode_decode(EnumWithDiscriminants::Tenth, &[10]);
+    assert!(<EnumWithDiscriminants as dharitri_codec::TopDecode>::top_decode(&[0u8][..]).is_err());
+    assert!(<EnumWithDiscriminants as dharitri_codec::TopDecode>::top_decode(&[3u8][..]).is_err());
+}
+
+#[test]
+fn enum_skipped_fields_test() {
+    check_top_encode_decode(EnumWithSkippedFields::Empty, &[]);
+    let data = EnumWithSkippedFields::Data {
+        value: 5,
+        note: Vec::new(),
+    };
+    check_top_encode_decode(data.clone(), &[1, 0, 0, 0, 5]);
+    check_dep_encode_decode(data, &[1, 0, 0, 0, 5]);
+}
+
+#[test]
+fn transparent_test() {
+    // top-encoded exactly like the inner Vec, i.e. without a length prefix
+    check_top_encode_decode(TransparentNewtype(vec![1, 2]), &[1, 2]);
+    check_dep_encode_decode(TransparentNewtype(vec![1, 2]), &[0, 0, 0, 2, 1, 2]);
+
+    let with_skip = TransparentWithSkip { value: 5, unit: 3 };
+    check_top_encode_decode(with_skip.clone(), &[5]);
+    check_dep_encode_decode(with_skip, &[0, 0, 0, 0, 0, 0, 0, 5]);
+    check_top_encode_decode(TransparentWithSkip { value: 0, unit: 3 }, &[]);
+}
@@ -0,0 +1,105 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::{
+    test_util::{check_dep_decode, check_top_decode, check_top_encode_decode},
+    DecodeError, TopDecode,
+};
+
+// to test, run the following command in dharitri-codec folder:
+// cargo expand --test derive_versioned_struct_test > expanded.rs
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(version = 3)]
+pub struct VersionedStruct {
+    pub int: u16,
+    #[codec(since = 2)]
+    pub seq: Vec<u8>,
+    #[codec(since = 2, default = 7)]
+    pub another_byte: u8,
+    #[codec(since = 3, default = u32::MAX)]
+    pub uint_32: u32,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(version = 2)]
+pub struct VersionedTupleStruct(u8, #[codec(since = 2)] u16);
+
+#[test]
+fn versioned_struct_latest_test() {
+    let s = VersionedStruct {
+        int: 0x42,
+        seq: vec![0x1, 0x2],
+        another_byte: 0x6,
+        uint_32: 0x12345,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* seq length */ 0, 0, 0, 2,
+        /* seq contents */ 1, 2,
+        /* another_byte */ 6,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+    ];
+
+    check_top_encode_decode(s, bytes);
+}
+
+#[test]
+fn versioned_struct_older_encodings_test() {
+    let v1 = VersionedStruct {
+        int: 0x42,
+        seq: Vec::new(),
+        another_byte: 7,
+        uint_32: u32::MAX,
+    };
+    assert_eq!(check_top_decode::<VersionedStruct>(&[0, 0x42]), v1);
+    assert_eq!(check_dep_decode::<VersionedStruct>(&[0, 0x42]), v1);
+
+    let v2 = VersionedStruct {
+        int: 0x42,
+        seq: vec![0x1],
+        another_byte: 6,
+        uint_32: u32::MAX,
+    };
+    #[rustfmt::skip]
+    let v2_bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 1, 1,
+        /* another_byte */ 6,
+    ];
+    assert_eq!(check_top_decode::<VersionedStruct>(v2_bytes), v2);
+    assert_eq!(check_dep_decode::<VersionedStruct>(v2_bytes), v2);
+}
+
+#[test]
+fn versioned_struct_migrate_on_write_test() {
+    let v1 = check_top_decode::<VersionedStruct>(&[0, 0x42]);
+    #[rustfmt::skip]
+    let migrated_bytes = &[
+        /* int */ 0, 0x42,
+        /* seq length */ 0, 0, 0, 0,
+        /* another_byte */ 7,
+        /* uint_32 */ 0xff, 0xff, 0xff, 0xff,
+    ];
+    check_top_encode_decode(v1, migrated_bytes);
+}
+
+#[test]
+fn versioned_struct_incomplete_version_test() {
+    // `another_byte` was added together with `seq`, so it cannot be missing on its own
+    assert_eq!(
+        VersionedStruct::top_decode(&[0, 0x42, 0, 0, 0, 0][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn versioned_tuple_struct_test() {
+    check_top_encode_decode(VersionedTupleStruct(1, 2), &[1, 0, 2]);
+    assert_eq!(
+        check_top_decode::<VersionedTupleStruct>(&[1]),
+        VersionedTupleStruct(1, 0)
+    );
+}
@@ -7,6 +7,10 @@ pub struct BuildInfoAbiJson {
     pub rustc: RustcAbiJson,
     pub contract_crate: ContractCrateBuildAbiJson,
     pub framework: FrameworkBuildAbiJson,
+    /// Only present if the contract was built with `--reproducible`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub reproducible_build: Option<ReproducibleBuildAbiJson>,
 }
 
 impl From<&BuildInfoAbi> for BuildInfoAbiJson {
@@ -15,11 +19,12 @@ impl From<&BuildInfoAbi> for BuildInfoAbiJson {
             rustc: RustcAbiJson::create(),
             contract_crate: ContractCrateBuildAbiJson::from(&abi.contract_crate),
             framework: FrameworkBuildAbiJson::from(&abi.framework),
+            reproducible_build: None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct RustcAbiJson {
     pub version: String,
@@ -31,7 +36,10 @@ pub struct RustcAbiJson {
 
 impl RustcAbiJson {
     pub fn create() -> Self {
-        let meta = rustc_version::version_meta().unwrap();
+        Self::from_version_meta(rustc_version::version_meta().unwrap())
+    }
+
+    pub fn from_version_meta(meta: rustc_version::VersionMeta) -> Self {
         RustcAbiJson {
             version: meta.semver.to_string(),
             commit_hash: meta.commit_hash.unwrap_or_default(),
@@ -47,6 +55,7 @@ pub struct ContractCrateBuildAbiJson {
     pub name: String,
     pub version: String,
     #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default)]
     pub git_version: String,
 }
 
@@ -74,3 +83,34 @@ impl From<&FrameworkBuildAbi> for FrameworkBuildAbiJson {
         }
     }
 }
+
+/// Everything that went into a reproducible build, except for the source code itself.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReproducibleBuildAbiJson {
+    /// The pinned toolchain, if any, as passed to `cargo +<toolchain>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub toolchain: Option<String>,
+    /// The compiler that actually performed the build.
+    pub rustc: RustcAbiJson,
+    pub target: String,
+    /// Path remapping flags are left out, since they depend on the local directories.
+    pub rustflags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub wasm_opt: Option<WasmOptAbiJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cargo_lock_hash: Option<String>,
+    pub source_hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WasmOptAbiJson {
+    pub version: String,
+    pub flags: Vec<String>,
+}
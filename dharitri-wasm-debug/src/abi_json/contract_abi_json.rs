@@ -9,13 +9,27 @@ use std::collections::BTreeMap;
 pub struct ContractAbiJson {
     pub build_info: BuildInfoAbiJson,
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub upgrade_constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
+    #[serde(default)]
     pub events: Vec<EventAbiJson>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub errors: Vec<ErrorAbiJson>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub storage: Vec<StorageAbiJson>,
     pub has_callback: bool,
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub schema_version: u32,
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
 
@@ -26,9 +40,16 @@ impl From<&ContractAbi> for ContractAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
+            upgrade_constructor: abi
+                .upgrade_constructors
+                .get(0)
+                .map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            errors: abi.errors.iter().map(ErrorAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
             has_callback: abi.has_callback,
+            schema_version: abi.schema_version,
             types: BTreeMap::new(),
         };
         for (type_name, type_description) in abi.type_descriptions.0.iter() {
@@ -43,6 +64,10 @@ impl From<&ContractAbi> for ContractAbiJson {
     }
 }
 
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
 pub fn serialize_abi_to_json(abi_json: &ContractAbiJson) -> String {
     let buf = Vec::new();
     let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
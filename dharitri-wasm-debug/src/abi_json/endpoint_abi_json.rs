@@ -27,6 +27,7 @@ impl From<&InputAbi> for InputAbiJson {
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default)]
     pub output_name: String,
     #[serde(rename = "type")]
     pub type_name: String,
@@ -57,12 +58,18 @@ pub enum EndpointMutabilityAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
     pub outputs: Vec<OutputAbiJson>,
+    /// Codes of the errors listed in the contract `errors` section.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub errors: Vec<u32>,
 }
 
 impl From<&EndpointAbi> for ConstructorAbiJson {
@@ -76,6 +83,7 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
                 .collect(),
             inputs: abi.inputs.iter().map(InputAbiJson::from).collect(),
             outputs: abi.outputs.iter().map(OutputAbiJson::from).collect(),
+            errors: abi.errors.clone(),
         }
     }
 }
@@ -83,17 +91,32 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "onlyOwner")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_owner: Option<bool>,
+    #[serde(rename = "onlyRole")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_role: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub guards: Vec<String>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
     pub outputs: Vec<OutputAbiJson>,
+    /// Codes of the errors listed in the contract `errors` section.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub errors: Vec<u32>,
 }
 
 impl From<&EndpointAbi> for EndpointAbiJson {
@@ -102,6 +125,9 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
+            only_role: abi.only_role.map(|role| role.to_string()),
+            tags: abi.tags.iter().map(|tag| tag.to_string()).collect(),
+            guards: abi.guards.iter().map(|guard| guard.to_string()).collect(),
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
@@ -114,6 +140,7 @@ impl From<&EndpointAbi> for EndpointAbiJson {
                 .collect(),
             inputs: abi.inputs.iter().map(InputAbiJson::from).collect(),
             outputs: abi.outputs.iter().map(OutputAbiJson::from).collect(),
+            errors: abi.errors.clone(),
         }
     }
 }
@@ -0,0 +1,28 @@
+use alloc::vec::Vec;
+use dharitri_wasm::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorAbiJson {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub code: u32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub message: String,
+}
+
+impl From<&ErrorAbi> for ErrorAbiJson {
+    fn from(abi: &ErrorAbi) -> Self {
+        ErrorAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            code: abi.code,
+            name: abi.name.to_string(),
+            error_type: abi.error_type.to_string(),
+            message: abi.message.to_string(),
+        }
+    }
+}
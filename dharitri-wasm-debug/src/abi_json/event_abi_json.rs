@@ -11,6 +11,9 @@ pub struct EventInputAbiJson {
     /// Bool that is only serialized when true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed: Option<bool>,
+    /// The event version that introduced the argument, only serialized for arguments added after the first version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<u8>,
 }
 
 impl From<&EventInputAbi> for EventInputAbiJson {
@@ -19,6 +22,7 @@ impl From<&EventInputAbi> for EventInputAbiJson {
             arg_name: abi.arg_name.to_string(),
             type_name: abi.type_name.clone(),
             indexed: if abi.indexed { Some(true) } else { None },
+            since: abi.since,
         }
     }
 }
@@ -26,8 +30,17 @@ impl From<&EventInputAbi> for EventInputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct EventAbiJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     pub identifier: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
+    /// The non-indexed arguments are length-prefixed in the data, after the version byte, if versioned.
+    /// Only serialized when true.
+    #[serde(rename = "structuredData")]
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    #[serde(default)]
+    pub structured_data: bool,
     pub inputs: Vec<EventInputAbiJson>,
 }
 
@@ -36,6 +49,8 @@ impl From<&EventAbi> for EventAbiJson {
         EventAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             identifier: abi.identifier.to_string(),
+            version: abi.version,
+            structured_data: abi.has_structured_data(),
             inputs: abi.inputs.iter().map(EventInputAbiJson::from).collect(),
         }
     }
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+use dharitri_wasm::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageKindAbiJson {
+    Get,
+    Set,
+    Mapper,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageKeyArgAbiJson {
+    #[serde(rename = "name")]
+    pub arg_name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+impl From<&StorageKeyArgAbi> for StorageKeyArgAbiJson {
+    fn from(abi: &StorageKeyArgAbi) -> Self {
+        StorageKeyArgAbiJson {
+            arg_name: abi.arg_name.to_string(),
+            type_name: abi.type_name.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAbiJson {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub name: String,
+    pub key: String,
+    pub kind: StorageKindAbiJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapper: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub key_args: Vec<StorageKeyArgAbiJson>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default)]
+    pub value_type: String,
+}
+
+impl From<&StorageAbi> for StorageAbiJson {
+    fn from(abi: &StorageAbi) -> Self {
+        StorageAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            name: abi.name.to_string(),
+            key: abi.key.to_string(),
+            kind: match abi.kind {
+                StorageKindAbi::Get => StorageKindAbiJson::Get,
+                StorageKindAbi::Set => StorageKindAbiJson::Set,
+                StorageKindAbi::Mapper => StorageKindAbiJson::Mapper,
+            },
+            mapper: abi.mapper.map(|mapper| mapper.to_string()),
+            key_args: abi
+                .key_args
+                .iter()
+                .map(StorageKeyArgAbiJson::from)
+                .collect(),
+            value_type: abi.value_type.clone(),
+        }
+    }
+}
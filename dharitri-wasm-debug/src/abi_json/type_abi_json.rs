@@ -8,12 +8,19 @@ pub struct TypeDescriptionJson {
     pub content_type: String,
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub version: Option<u8>,
+
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
 
@@ -27,6 +34,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
         let mut type_desc_json = TypeDescriptionJson {
             content_type: content_type.to_string(),
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
+            version: abi.version,
             variants: Vec::new(),
             fields: Vec::new(),
         };
@@ -55,10 +63,14 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "type")]
     pub field_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub since: Option<u8>,
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -67,6 +79,7 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.clone(),
+            since: abi.since,
         }
     }
 }
@@ -74,10 +87,12 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
 
@@ -1,5 +1,6 @@
 use crate::{
     num_bigint,
+    tx_mock::TxContextStack,
     world_mock::{is_smart_contract_address, DctData, DctInstance},
     DebugApi,
 };
@@ -38,6 +39,14 @@ impl BlockchainApiImpl for DebugApi {
         })
     }
 
+    fn get_original_caller_legacy(&self) -> Address {
+        TxContextStack::static_original_caller()
+    }
+
+    fn get_call_depth(&self) -> u32 {
+        TxContextStack::static_call_depth() as u32
+    }
+
     fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
         panic!("get_shard_of_address not implemented")
     }
@@ -1,14 +1,14 @@
-use crate::DebugApi;
+use crate::{num_bigint, DebugApi};
 use core::{
     cmp::Ordering,
     ops::{Add, Div, Mul, Neg, Sub},
 };
 
 use dharitri_wasm::{
-    api::{BigFloatApi, BigIntApi, ErrorApiImpl, Handle, Sign},
+    api::{BigFloatApi, ErrorApiImpl, Handle, Sign},
     err_msg,
 };
-use num_traits::{Signed, ToPrimitive};
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
 
 impl DebugApi {
     pub(crate) fn bf_get_f64(&self, handle: Handle) -> f64 {
@@ -43,6 +43,18 @@ macro_rules! unary_op_method {
     };
 }
 
+/// Rounding operations produce a big int, same as the VM hooks.
+macro_rules! to_big_int_method {
+    ($method_name:ident, $rust_op_name:ident) => {
+        fn $method_name(&self, dest: Handle, x: Handle) {
+            let bf_x = self.bf_get_f64(x);
+            let result = num_bigint::BigInt::from_f64(bf_x.$rust_op_name())
+                .unwrap_or_else(|| self.signal_error(err_msg::NUMBER_IS_NOT_NORMAL));
+            self.bi_overwrite(dest, result);
+        }
+    };
+}
+
 impl BigFloatApi for DebugApi {
     fn bf_from_parts(&self, integral_part: i32, fractional_part: i32, exponent: i32) -> Handle {
         if exponent > 0 {
@@ -59,7 +71,7 @@ impl BigFloatApi for DebugApi {
     fn bf_from_frac(&self, numerator: i64, denominator: i64) -> Handle {
         let f_numerator = numerator.to_f64();
         let f_denominator = denominator.to_f64();
-        let value = if f_numerator == None || f_denominator == None {
+        let value = if f_numerator.is_none() || f_denominator.is_none() {
             f64::from(0)
         } else {
             f_numerator.unwrap() / f_denominator.unwrap()
@@ -75,7 +87,7 @@ impl BigFloatApi for DebugApi {
         }
 
         let f_significand = significand.to_f64();
-        let value = if f_significand == None {
+        let value = if f_significand.is_none() {
             f64::from(0)
         } else {
             let exponent_multiplier = (10.0_f64).powi(exponent as i32);
@@ -98,7 +110,7 @@ impl BigFloatApi for DebugApi {
         let bf_x = managed_types.big_float_map.get(x);
         let bf_y = managed_types.big_float_map.get(y);
         let order_opt = bf_x.partial_cmp(bf_y);
-        if order_opt == None {
+        if order_opt.is_none() {
             self.signal_error(err_msg::CANNOT_COMPARE_VALUES)
         }
         order_opt.unwrap()
@@ -107,10 +119,13 @@ impl BigFloatApi for DebugApi {
     fn bf_sign(&self, x: Handle) -> Sign {
         let managed_types = self.m_types_borrow();
         let bf = managed_types.big_float_map.get(x);
-        if !bf.is_normal() {
+        if !bf.is_normal() && *bf != 0.0 {
             self.signal_error(err_msg::NUMBER_IS_NOT_NORMAL)
         }
 
+        if *bf == 0.0 {
+            return dharitri_wasm::api::Sign::NoSign;
+        }
         if bf.is_positive() {
             return dharitri_wasm::api::Sign::Plus;
         } else if bf.is_negative() {
@@ -131,9 +146,9 @@ impl BigFloatApi for DebugApi {
         self.bf_overwrite(dest, value.powi(exp));
     }
 
-    unary_op_method!(bf_floor, floor);
-    unary_op_method!(bf_ceil, ceil);
-    unary_op_method!(bf_trunc, trunc);
+    to_big_int_method!(bf_floor, floor);
+    to_big_int_method!(bf_ceil, ceil);
+    to_big_int_method!(bf_trunc, trunc);
 
     fn bf_is_bi(&self, x: Handle) -> bool {
         let managed_types = self.m_types_borrow();
@@ -149,7 +164,10 @@ impl BigFloatApi for DebugApi {
     }
 
     fn bf_set_bi(&self, dest: Handle, bi: Handle) {
-        let f64_value = self.bi_to_i64(bi).unwrap().to_f64().unwrap();
+        let f64_value = {
+            let managed_types = self.m_types_borrow();
+            managed_types.big_int_map.get(bi).to_f64().unwrap()
+        };
         self.bf_overwrite(dest, f64_value);
     }
 
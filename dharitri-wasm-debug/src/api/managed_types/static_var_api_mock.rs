@@ -62,4 +62,12 @@ impl StaticVarApiImpl for DebugApi {
     fn get_call_value_multi_dct_handle(&self) -> Handle {
         self.static_vars_cell.borrow().call_value_multi_dct_handle
     }
+
+    fn set_non_reentrant_lock_held(&self, held: bool) {
+        self.static_vars_cell.borrow_mut().non_reentrant_lock_held = held;
+    }
+
+    fn is_non_reentrant_lock_held(&self) -> bool {
+        self.static_vars_cell.borrow().non_reentrant_lock_held
+    }
 }
@@ -11,6 +11,7 @@ use dharitri_wasm::{
     },
     dharitri_codec::top_encode_to_vec_u8,
     err_msg,
+    io::endpoint_guards::release_non_reentrant_before_async_call,
     types::{
         heap::Address, ArgBuffer, BigUint, BoxedBytes, CodeMetadata, DctTokenPayment,
         ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier,
@@ -154,6 +155,7 @@ impl DebugApi {
     }
 
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
+        release_non_reentrant_before_async_call::<DebugApi>();
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
         tx_result.result_calls.async_call = Some(call);
@@ -3,13 +3,14 @@ use crate::DebugApi;
 use super::*;
 
 use alloc::{boxed::Box, vec::Vec};
-use dharitri_wasm::contract_base::CallableContract;
+use dharitri_wasm::{abi::ContractAbi, contract_base::CallableContract};
 use std::{collections::HashMap, fmt};
 
 pub type ContractCallFactory = Box<dyn Fn(DebugApi) -> Box<dyn CallableContract>>;
 
 pub struct ContractMap {
     contract_objs: HashMap<Vec<u8>, Box<dyn CallableContract>>,
+    contract_abis: HashMap<Vec<u8>, ContractAbi>,
 }
 
 impl fmt::Debug for ContractMap {
@@ -22,6 +23,7 @@ impl ContractMap {
     pub fn new() -> Self {
         ContractMap {
             contract_objs: HashMap::new(),
+            contract_abis: HashMap::new(),
         }
     }
 
@@ -49,6 +51,22 @@ impl ContractMap {
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
         self.contract_objs.contains_key(contract_bytes)
     }
+
+    /// Decides whether `upgrade` or `init` gets called on upgrade.
+    pub fn has_upgrade_constructor(&self, contract_bytes: &[u8]) -> bool {
+        self.contract_objs
+            .get(contract_bytes)
+            .is_some_and(|contract_obj| contract_obj.has_upgrade_constructor())
+    }
+
+    /// Only used for decoding the logs, registering it again replaces the previous one.
+    pub fn register_contract_abi(&mut self, contract_bytes: Vec<u8>, abi: ContractAbi) {
+        self.contract_abis.insert(contract_bytes, abi);
+    }
+
+    pub fn get_contract_abi(&self, contract_bytes: &[u8]) -> Option<&ContractAbi> {
+        self.contract_abis.get(contract_bytes)
+    }
 }
 
 fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
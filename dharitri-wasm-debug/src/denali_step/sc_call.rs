@@ -77,7 +77,7 @@ fn execute_and_check(
 ) -> (TxResult, BlockchainMock) {
     let (tx_result, state) = execute(state, sc_call_step);
     if let Some(tx_expect) = &sc_call_step.expect {
-        check_tx_output(&sc_call_step.tx_id, tx_expect, &tx_result);
+        check_tx_output(&sc_call_step.tx_id, tx_expect, &tx_result, &state);
     }
     (tx_result, state)
 }
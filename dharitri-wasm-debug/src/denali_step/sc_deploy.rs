@@ -84,7 +84,7 @@ fn execute_and_check(
 ) -> (TxResult, Address, BlockchainMock) {
     let (tx_result, address, state) = execute(state, sc_deploy_step);
     if let Some(tx_expect) = &sc_deploy_step.expect {
-        check_tx_output(&sc_deploy_step.tx_id, tx_expect, &tx_result);
+        check_tx_output(&sc_deploy_step.tx_id, tx_expect, &tx_result, &state);
     }
     (tx_result, address, state)
 }
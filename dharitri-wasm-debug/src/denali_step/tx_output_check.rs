@@ -1,8 +1,18 @@
 use denali::model::{CheckLogs, Checkable, TxExpect};
 
-use crate::{address_hex, bytes_to_string, tx_mock::TxResult, verbose_hex, verbose_hex_list};
+use crate::{
+    address_hex, bytes_to_string,
+    tx_mock::{TxLog, TxResult},
+    verbose_hex, verbose_hex_list,
+    world_mock::BlockchainMock,
+};
 
-pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
+pub fn check_tx_output(
+    tx_id: &str,
+    tx_expect: &TxExpect,
+    tx_result: &TxResult,
+    state: &BlockchainMock,
+) {
     let have_str = tx_result.result_message.as_str();
     assert!(
         tx_expect.status.check(tx_result.result_status),
@@ -45,7 +55,7 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
                     let expected_log = &expected_logs.list[i];
                     assert!(
                         actual_log.denali_check(expected_log),
-                        "Logs do not match. Tx id: '{}'. Index: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
+                        "Logs do not match. Tx id: '{}'. Index: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}{}",
                         tx_id,
                         i,
                         &expected_log.address,
@@ -56,19 +66,30 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
                         bytes_to_string(&actual_log.endpoint),
                         verbose_hex_list(actual_log.topics.as_slice()),
                         verbose_hex(&actual_log.data),
+                        decoded_log_description(state, actual_log),
                     );
                 } else if !expected_logs.more_allowed_at_end {
                     panic!(
-                        "Unexpected log. Tx id: '{}'. Index: {}.\nAddress: {}, Endpoint: {}, Topics: {:?}, Data: {}",
+                        "Unexpected log. Tx id: '{}'. Index: {}.\nAddress: {}, Endpoint: {}, Topics: {:?}, Data: {}{}",
                         tx_id,
                         i,
                         address_hex(&actual_log.address),
                         bytes_to_string(&actual_log.endpoint),
                         verbose_hex_list(actual_log.topics.as_slice()),
                         verbose_hex(&actual_log.data),
+                        decoded_log_description(state, actual_log),
                     )
                 }
             }
         },
     }
 }
+
+/// Only available if the ABI of the emitting contract was registered.
+fn decoded_log_description(state: &BlockchainMock, log: &TxLog) -> String {
+    match state.decode_log(log) {
+        Some(Ok(decoded_event)) => format!("\nDecoded: {}", decoded_event),
+        Some(Err(err)) => format!("\nCould not decode: {}", err),
+        None => String::new(),
+    }
+}
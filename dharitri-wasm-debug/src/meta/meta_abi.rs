@@ -3,12 +3,18 @@ use std::{
     io::Write,
 };
 
-use crate::abi_json::{serialize_abi_to_json, ContractAbiJson};
+use crate::abi_json::{serialize_abi_to_json, ContractAbiJson, ReproducibleBuildAbiJson};
 
 use super::meta_config::{ContractMetadata, MetaConfig};
 
-fn write_contract_abi(contract_metadata: &ContractMetadata, output_path: &str) {
-    let abi_json = ContractAbiJson::from(&contract_metadata.abi);
+/// The build settings are only known after a `--reproducible` build, so the ABI gets rewritten then.
+pub(super) fn write_contract_abi(
+    contract_metadata: &ContractMetadata,
+    output_path: &str,
+    reproducible_build: Option<ReproducibleBuildAbiJson>,
+) {
+    let mut abi_json = ContractAbiJson::from(&contract_metadata.abi);
+    abi_json.build_info.reproducible_build = reproducible_build;
     let abi_string = serialize_abi_to_json(&abi_json);
 
     let abi_file_path = format!("{}/{}", output_path, contract_metadata.abi_output_name(),);
@@ -20,14 +26,9 @@ impl MetaConfig {
     pub fn write_abi(&self) {
         create_dir_all(&self.output_dir).unwrap();
 
-        if let Some(main_contract) = &self.main_contract {
-            write_contract_abi(main_contract, self.output_dir.as_str());
-            main_contract.create_dir_all();
-        }
-
-        if let Some(view_contract) = &self.view_contract {
-            write_contract_abi(view_contract, self.output_dir.as_str());
-            view_contract.create_dir_all();
+        for output_contract in &self.output_contracts {
+            write_contract_abi(output_contract, self.output_dir.as_str(), None);
+            output_contract.create_dir_all();
         }
     }
 }
@@ -0,0 +1,305 @@
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::abi_json::ReproducibleBuildAbiJson;
+
+use super::{
+    meta_config::MetaConfig,
+    meta_wasm_build::{build_contract, reproducible_build_info, STRIP_SYMBOLS_FLAG},
+};
+
+/// Folders in the contract root that do not count as source code.
+const NON_SOURCE_DIRS: &[&str] = &["target", "output"];
+
+/// Written next to the wasm by `meta build --reproducible`, as `<name>.build.json`.
+///
+/// Holds everything needed to rebuild the contract, and the hash it should yield.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildManifestJson {
+    pub wasm_name: String,
+    pub wasm_size: u64,
+    pub wasm_hash: String,
+    pub build: ReproducibleBuildAbiJson,
+}
+
+impl BuildManifestJson {
+    pub fn create(wasm_path: &str, build: ReproducibleBuildAbiJson) -> Self {
+        let wasm_bytes = fs::read(wasm_path).expect("failed to read the built contract");
+        BuildManifestJson {
+            wasm_name: wasm_path
+                .rsplit('/')
+                .next()
+                .unwrap_or(wasm_path)
+                .to_string(),
+            wasm_size: wasm_bytes.len() as u64,
+            wasm_hash: sha256_hex(wasm_bytes.as_slice()),
+            build,
+        }
+    }
+
+    pub fn manifest_path(wasm_path: &str) -> String {
+        format!("{}.build.json", wasm_path.trim_end_matches(".wasm"))
+    }
+
+    pub fn load(wasm_path: &str) -> Option<Self> {
+        let manifest_string = fs::read_to_string(Self::manifest_path(wasm_path)).ok()?;
+        Some(serde_json::from_str(manifest_string.as_str()).expect("invalid build manifest"))
+    }
+
+    pub fn write(&self, wasm_path: &str) {
+        let manifest_json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(Self::manifest_path(wasm_path), manifest_json)
+            .expect("failed to write the build manifest");
+    }
+}
+
+/// A difference between the recorded build and the one performed by `meta verify`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BuildMismatch {
+    pub wasm_name: String,
+    pub field: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for BuildMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} differs, expected `{}`, got `{}`",
+            self.wasm_name, self.field, self.expected, self.actual
+        )
+    }
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Hash of all files in the contract crate, build outputs and hidden files excluded.
+///
+/// File paths are part of the hash, so renaming a file also changes it.
+pub fn source_hash(contract_root: &Path) -> String {
+    let mut file_paths = Vec::new();
+    collect_source_files(contract_root, contract_root, &mut file_paths);
+    file_paths.sort();
+
+    let mut hasher = Sha256::new();
+    for relative_path in &file_paths {
+        let contents =
+            fs::read(contract_root.join(relative_path)).expect("failed to read source file");
+        hasher.update(relative_path.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(Sha256::digest(contents.as_slice()));
+    }
+    hex::encode(hasher.finalize())
+}
+
+fn collect_source_files(contract_root: &Path, dir: &Path, file_paths: &mut Vec<String>) {
+    let entries = fs::read_dir(dir).expect("failed to read source directory");
+    for entry in entries {
+        let entry = entry.expect("failed to read source directory entry");
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            if !NON_SOURCE_DIRS.contains(&file_name.as_str()) {
+                collect_source_files(contract_root, path.as_path(), file_paths);
+            }
+        } else {
+            let relative_path = path.strip_prefix(contract_root).unwrap();
+            let components: Vec<_> = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect();
+            file_paths.push(components.join("/"));
+        }
+    }
+}
+
+/// Hashes the `Cargo.lock` that cargo would use for the crate, i.e. the closest one up the path.
+pub fn cargo_lock_hash(crate_path: &Path) -> Option<String> {
+    let crate_path = fs::canonicalize(crate_path).ok()?;
+    let lock_path = crate_path
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path: &PathBuf| path.is_file())?;
+    let contents = fs::read(lock_path).ok()?;
+    Some(sha256_hex(contents.as_slice()))
+}
+
+/// Lists everything that differs between the recorded build and the new one.
+pub fn compare_build_manifests(
+    expected: &BuildManifestJson,
+    actual: &BuildManifestJson,
+) -> Vec<BuildMismatch> {
+    let fields = vec![
+        (
+            "rustc version",
+            expected.build.rustc.short.clone(),
+            actual.build.rustc.short.clone(),
+        ),
+        (
+            "target",
+            expected.build.target.clone(),
+            actual.build.target.clone(),
+        ),
+        (
+            "RUSTFLAGS",
+            expected.build.rustflags.join(" "),
+            actual.build.rustflags.join(" "),
+        ),
+        (
+            "features",
+            expected.build.features.join(","),
+            actual.build.features.join(","),
+        ),
+        (
+            "wasm-opt",
+            wasm_opt_description(&expected.build),
+            wasm_opt_description(&actual.build),
+        ),
+        (
+            "Cargo.lock hash",
+            expected.build.cargo_lock_hash.clone().unwrap_or_default(),
+            actual.build.cargo_lock_hash.clone().unwrap_or_default(),
+        ),
+        (
+            "source hash",
+            expected.build.source_hash.clone(),
+            actual.build.source_hash.clone(),
+        ),
+        (
+            "wasm hash",
+            expected.wasm_hash.clone(),
+            actual.wasm_hash.clone(),
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, expected_value, actual_value)| expected_value != actual_value)
+        .map(|(field, expected_value, actual_value)| BuildMismatch {
+            wasm_name: expected.wasm_name.clone(),
+            field,
+            expected: expected_value,
+            actual: actual_value,
+        })
+        .collect()
+}
+
+fn wasm_opt_description(build: &ReproducibleBuildAbiJson) -> String {
+    match &build.wasm_opt {
+        Some(wasm_opt) => format!("{} {}", wasm_opt.version, wasm_opt.flags.join(" ")),
+        None => "none".to_string(),
+    }
+}
+
+impl MetaConfig {
+    /// Replaces the build settings given in the command line with the ones recorded in the manifests,
+    /// which come in the same order as the output contracts.
+    ///
+    /// The toolchain from the command line is only used if the manifests do not record one.
+    pub fn apply_recorded_builds(&mut self, manifests: &[BuildManifestJson]) {
+        self.build_args.reproducible = true;
+        if let Some(toolchain) = manifests
+            .first()
+            .and_then(|manifest| manifest.build.toolchain.clone())
+        {
+            self.build_args.rust_toolchain = Some(toolchain);
+        }
+        // the recorded settings of each output contract take precedence over these
+        self.build_args.wasm_opt = true;
+        self.build_args.debug_symbols = false;
+
+        for (output_contract, manifest) in self.output_contracts.iter_mut().zip(manifests) {
+            let recorded = &manifest.build;
+            output_contract.wasm_opt = Some(recorded.wasm_opt.is_some());
+            output_contract.debug_symbols = Some(
+                !recorded
+                    .rustflags
+                    .iter()
+                    .any(|flag| flag == STRIP_SYMBOLS_FLAG),
+            );
+            output_contract.features = recorded.features.clone();
+        }
+    }
+
+    /// Rebuilds all outputs with the recorded settings and compares the results against their manifests.
+    ///
+    /// The rebuilt contracts go to a temporary folder, the output folder is left untouched.
+    /// Optionally also checks that a deployed wasm is identical to the rebuilt main contract.
+    pub fn verify_build(&mut self, deployed_wasm_path: Option<&str>) {
+        let mut expected_manifests = Vec::new();
+        for output_contract in &self.output_contracts {
+            let wasm_path = format!(
+                "{}/{}",
+                self.output_dir,
+                self.build_args.wasm_name(output_contract)
+            );
+            let manifest = BuildManifestJson::load(wasm_path.as_str()).unwrap_or_else(|| {
+                panic!(
+                    "build manifest `{}` not found, build with `--reproducible` first",
+                    BuildManifestJson::manifest_path(wasm_path.as_str())
+                )
+            });
+            expected_manifests.push(manifest);
+        }
+        self.apply_recorded_builds(expected_manifests.as_slice());
+        self.check_wasm_opt();
+
+        let verify_dir = env::temp_dir().join(format!("dharitri-verify-{}", process::id()));
+        fs::create_dir_all(&verify_dir).expect("failed to create the verification build folder");
+        let verify_dir_str = verify_dir.to_string_lossy().to_string();
+
+        let mut mismatches = Vec::new();
+        let mut main_wasm_hash = String::new();
+        for (output_contract, expected) in self.output_contracts.iter().zip(&expected_manifests) {
+            let wasm_path =
+                build_contract(output_contract, &self.build_args, verify_dir_str.as_str());
+            let actual = BuildManifestJson::create(
+                wasm_path.as_str(),
+                reproducible_build_info(output_contract, &self.build_args),
+            );
+            if main_wasm_hash.is_empty() {
+                main_wasm_hash = actual.wasm_hash.clone();
+            }
+            mismatches.extend(compare_build_manifests(expected, &actual));
+        }
+        let _ = fs::remove_dir_all(&verify_dir);
+
+        if let Some(deployed_wasm_path) = deployed_wasm_path {
+            let deployed_wasm =
+                fs::read(deployed_wasm_path).expect("failed to read the deployed contract");
+            let deployed_wasm_hash = sha256_hex(deployed_wasm.as_slice());
+            if deployed_wasm_hash != main_wasm_hash {
+                mismatches.push(BuildMismatch {
+                    wasm_name: deployed_wasm_path.to_string(),
+                    field: "deployed code hash",
+                    expected: main_wasm_hash,
+                    actual: deployed_wasm_hash,
+                });
+            }
+        }
+
+        if mismatches.is_empty() {
+            println!("Build verified, the contract code matches the source.");
+            return;
+        }
+
+        println!("Build verification failed:");
+        for mismatch in &mismatches {
+            println!("- {}", mismatch);
+        }
+        std::process::exit(1);
+    }
+}
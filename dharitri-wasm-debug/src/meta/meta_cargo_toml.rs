@@ -5,12 +5,12 @@ use std::fs::{self, create_dir_all};
 use super::meta_config::{ContractMetadata, MetaConfig};
 
 impl MetaConfig {
+    /// The secondary wasm crates all get a copy of the main wasm crate `Cargo.toml`.
     pub fn create_wasm_view_cargo_toml(&self) {
-        if let Some(main_contract) = &self.main_contract {
-            if let Some(view_contract) = &self.view_contract {
-                create_dir_all(&view_contract.wasm_crate_path).unwrap();
-                create_cargo_toml_from_source(main_contract, view_contract);
-            }
+        let main_contract = self.main_contract();
+        for secondary_contract in self.secondary_contracts() {
+            create_dir_all(&secondary_contract.wasm_crate_path).unwrap();
+            create_cargo_toml_from_source(main_contract, secondary_contract);
         }
     }
 }
@@ -2,6 +2,8 @@ use std::{fs::create_dir_all, path::PathBuf};
 
 use dharitri_wasm::abi::{ContractAbi, EndpointLocationAbi};
 
+use super::meta_dharitri_json::{DharitriJson, OutputContractJson};
+
 #[derive(Debug)]
 pub struct BuildArgs {
     pub debug_symbols: bool,
@@ -9,6 +11,9 @@ pub struct BuildArgs {
     pub wasm_name_suffix: Option<String>,
     pub wasm_opt: bool,
     pub target_dir: Option<String>,
+    pub report: bool,
+    pub reproducible: bool,
+    pub rust_toolchain: Option<String>,
 }
 
 impl Default for BuildArgs {
@@ -19,6 +24,9 @@ impl Default for BuildArgs {
             wasm_name_suffix: None,
             wasm_opt: true,
             target_dir: None,
+            report: false,
+            reproducible: false,
+            rust_toolchain: None,
         }
     }
 }
@@ -45,6 +53,9 @@ pub struct ContractMetadata {
     pub wasm_crate_path: String,
     pub output_base_name: String,
     pub abi: ContractAbi,
+    pub wasm_opt: Option<bool>,
+    pub debug_symbols: Option<bool>,
+    pub features: Vec<String>,
 }
 
 impl ContractMetadata {
@@ -76,8 +87,9 @@ impl ContractMetadata {
 pub struct MetaConfig {
     pub build_args: BuildArgs,
     pub output_dir: String,
-    pub main_contract: Option<ContractMetadata>,
-    pub view_contract: Option<ContractMetadata>,
+    /// The main contract comes first, followed by the view contract, if any,
+    /// and then by the outputs declared in `dharitri.json`.
+    pub output_contracts: Vec<ContractMetadata>,
 }
 
 pub fn process_args(args: &[String]) -> BuildArgs {
@@ -109,6 +121,18 @@ pub fn process_args(args: &[String]) -> BuildArgs {
                     .expect("argument `--target-dir` must be followed by argument");
                 result.target_dir = Some(arg.clone());
             },
+            "--report" => {
+                result.report = true;
+            },
+            "--reproducible" => {
+                result.reproducible = true;
+            },
+            "--rust-toolchain" => {
+                let toolchain = iter
+                    .next()
+                    .expect("argument `--rust-toolchain` must be followed by the toolchain name");
+                result.rust_toolchain = Some(toolchain.clone());
+            },
             _ => {},
         }
     }
@@ -118,41 +142,106 @@ pub fn process_args(args: &[String]) -> BuildArgs {
 
 impl MetaConfig {
     pub fn create(original_contract_abi: &ContractAbi, args: &[String]) -> MetaConfig {
-        let build_args = process_args(args);
+        let dharitri_json = DharitriJson::load_default();
+        Self::create_with_dharitri_json(original_contract_abi, args, &dharitri_json)
+    }
+
+    pub fn create_with_dharitri_json(
+        original_contract_abi: &ContractAbi,
+        args: &[String],
+        dharitri_json: &DharitriJson,
+    ) -> MetaConfig {
+        let mut build_args = process_args(args);
+        if build_args.rust_toolchain.is_none() {
+            build_args.rust_toolchain = dharitri_json.rust_toolchain.clone();
+        }
 
         let main_contract_abi = original_contract_abi.main_contract();
         let main_contract_crate_name = main_contract_abi.get_crate_name();
+        let wasm_crate_name = format!("{}-wasm", main_contract_crate_name);
 
-        let main_contract = ContractMetadata {
+        let mut output_contracts = vec![ContractMetadata {
             location: EndpointLocationAbi::MainContract,
-            wasm_crate_name: format!("{}-wasm", &main_contract_crate_name),
+            wasm_crate_name: wasm_crate_name.clone(),
             wasm_crate_path: "../wasm".to_string(),
             output_base_name: main_contract_crate_name.to_string(),
             abi: main_contract_abi.clone(),
-        };
-
-        let view_contract_opt =
-            if original_contract_abi.location_exists(EndpointLocationAbi::ViewContract) {
-                let view_contract_abi =
-                    original_contract_abi.secondary_contract(EndpointLocationAbi::ViewContract);
-                Some(ContractMetadata {
-                    location: EndpointLocationAbi::ViewContract,
-                    wasm_crate_name: format!("{}-wasm", &main_contract_crate_name),
-                    wasm_crate_path: "../wasm-view".to_string(),
-                    output_base_name: format!("{}-view", main_contract_crate_name),
-                    abi: view_contract_abi,
-                })
-            } else {
-                None
-            };
+            wasm_opt: None,
+            debug_symbols: None,
+            features: Vec::new(),
+        }];
+
+        if original_contract_abi.location_exists(EndpointLocationAbi::ViewContract) {
+            let view_contract_abi =
+                original_contract_abi.secondary_contract(EndpointLocationAbi::ViewContract);
+            output_contracts.push(ContractMetadata {
+                location: EndpointLocationAbi::ViewContract,
+                wasm_crate_name: wasm_crate_name.clone(),
+                wasm_crate_path: "../wasm-view".to_string(),
+                output_base_name: format!("{}-view", main_contract_crate_name),
+                abi: view_contract_abi,
+                wasm_opt: None,
+                debug_symbols: None,
+                features: Vec::new(),
+            });
+        }
+
+        for output_json in &dharitri_json.outputs {
+            output_contracts.push(output_contract_metadata(
+                original_contract_abi,
+                main_contract_crate_name,
+                &wasm_crate_name,
+                output_json,
+            ));
+        }
 
         MetaConfig {
             build_args,
             output_dir: "../output".to_string(),
-            main_contract: Some(main_contract),
-            view_contract: view_contract_opt,
+            output_contracts,
         }
     }
+
+    pub fn main_contract(&self) -> &ContractMetadata {
+        &self.output_contracts[0]
+    }
+
+    /// All wasm crates except the main one, which is written by hand.
+    pub fn secondary_contracts(&self) -> &[ContractMetadata] {
+        &self.output_contracts[1..]
+    }
+}
+
+fn output_contract_metadata(
+    original_contract_abi: &ContractAbi,
+    main_contract_crate_name: &str,
+    wasm_crate_name: &str,
+    output_json: &OutputContractJson,
+) -> ContractMetadata {
+    let location = if output_json.external_view {
+        EndpointLocationAbi::ViewContract
+    } else {
+        EndpointLocationAbi::MainContract
+    };
+    let abi = original_contract_abi.select_endpoints(location.clone(), |endpoint| {
+        output_json.selects_endpoint(endpoint.name, endpoint.tags, endpoint.module)
+    });
+    ContractMetadata {
+        location,
+        wasm_crate_name: wasm_crate_name.to_string(),
+        wasm_crate_path: output_json
+            .wasm_crate_path
+            .clone()
+            .unwrap_or_else(|| format!("../wasm-{}", output_json.id)),
+        output_base_name: output_json
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("{}-{}", main_contract_crate_name, output_json.id)),
+        abi,
+        wasm_opt: output_json.wasm_opt,
+        debug_symbols: output_json.debug_symbols,
+        features: output_json.features.clone(),
+    }
 }
 
 impl ContractMetadata {
@@ -0,0 +1,117 @@
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+/// Relative to the meta crate, where the build is launched from.
+const DHARITRI_JSON_PATH: &str = "../dharitri.json";
+
+/// Contents of the `dharitri.json` file, found in the contract crate root.
+#[derive(Deserialize, Default, Debug)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct DharitriJson {
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Toolchain used by `--reproducible` builds, e.g. `"nightly-2022-01-10"`,
+    /// unless overridden by `--rust-toolchain`.
+    #[serde(default)]
+    pub rust_toolchain: Option<String>,
+
+    /// Contracts built from the same crate, in addition to the main (and view) contract.
+    #[serde(default)]
+    pub outputs: Vec<OutputContractJson>,
+}
+
+/// One additional output contract.
+///
+/// If none of `endpoints`, `tags` and `modules` are given, all endpoints are included.
+/// Otherwise, an endpoint is included if it matches at least one of them.
+#[derive(Deserialize, Default, Debug)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct OutputContractJson {
+    /// Distinguishes the output, e.g. `"lite"` produces `<crate>-lite.wasm`, `<crate>-lite.abi.json`,
+    /// built from the `wasm-lite` crate.
+    pub id: String,
+
+    /// Overrides the output file base name, `<crate>-<id>` by default.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Overrides the wasm crate path, `../wasm-<id>` by default.
+    #[serde(default)]
+    pub wasm_crate_path: Option<String>,
+
+    /// Produces a contract with external view endpoints only, like the `wasm-view` one.
+    #[serde(default)]
+    pub external_view: bool,
+
+    /// Endpoint names to include.
+    #[serde(default)]
+    pub endpoints: Vec<String>,
+
+    /// Includes all endpoints annotated with any of these `#[tag("...")]`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Includes all endpoints declared in any of these traits (contract or module trait name).
+    #[serde(default)]
+    pub modules: Vec<String>,
+
+    /// Endpoint names to leave out, even if otherwise selected.
+    #[serde(default)]
+    pub exclude_endpoints: Vec<String>,
+
+    /// Overrides `--no-wasm-opt` for this output.
+    #[serde(default)]
+    pub wasm_opt: Option<bool>,
+
+    /// Overrides `--wasm-symbols` for this output.
+    #[serde(default)]
+    pub debug_symbols: Option<bool>,
+
+    /// Cargo features enabled when building this output, e.g. `"use-module/lite"`.
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+impl DharitriJson {
+    pub fn parse(json: &str) -> Self {
+        serde_json::from_str(json).expect("invalid dharitri.json")
+    }
+
+    /// An empty config if the file is missing.
+    pub fn load_default() -> Self {
+        if Path::new(DHARITRI_JSON_PATH).exists() {
+            let json =
+                fs::read_to_string(DHARITRI_JSON_PATH).expect("could not read dharitri.json");
+            Self::parse(json.as_str())
+        } else {
+            DharitriJson::default()
+        }
+    }
+}
+
+impl OutputContractJson {
+    fn has_selection(&self) -> bool {
+        !self.endpoints.is_empty() || !self.tags.is_empty() || !self.modules.is_empty()
+    }
+
+    pub fn selects_endpoint(&self, name: &str, tags: &[&str], module: &str) -> bool {
+        if self
+            .exclude_endpoints
+            .iter()
+            .any(|excluded| excluded == name)
+        {
+            return false;
+        }
+        if !self.has_selection() {
+            return true;
+        }
+
+        self.endpoints.iter().any(|endpoint| endpoint == name)
+            || self.tags.iter().any(|tag| tags.contains(&tag.as_str()))
+            || self.modules.iter().any(|selected| selected == module)
+    }
+}
@@ -1,15 +1,38 @@
 use dharitri_wasm::contract_base::ContractAbiProvider;
 use std::env;
 
-use super::meta_config::MetaConfig;
+use super::{meta_config::MetaConfig, meta_upgrade_check::read_previous_abi};
 
 pub fn perform<AbiObj: ContractAbiProvider>() {
     let original_contract_abi = <AbiObj as ContractAbiProvider>::abi();
     super::meta_validate_abi::validate_abi(&original_contract_abi).unwrap();
+    for warning in super::storage_key_prefix_warnings(&original_contract_abi) {
+        println!("warning: {}", warning);
+    }
 
     let args: Vec<String> = env::args().collect();
     let mut meta_config = MetaConfig::create(&original_contract_abi, args.as_slice());
 
+    // compares the existing outputs against a fresh build, so nothing gets regenerated beforehand
+    if args.get(1).map(String::as_str) == Some("verify") {
+        let deployed_wasm_path = args
+            .get(2)
+            .filter(|arg| !arg.starts_with("--"))
+            .map(String::as_str);
+        meta_config.verify_build(deployed_wasm_path);
+        return;
+    }
+
+    // the previous ABI can be the one in the output folder, so it is read before the ABI is rewritten
+    let previous_abi = if args.get(1).map(String::as_str) == Some("upgrade-check") {
+        let previous_abi_path = args
+            .get(2)
+            .expect("argument `upgrade-check` must be followed by the path to the previous ABI");
+        Some(read_previous_abi(previous_abi_path))
+    } else {
+        None
+    };
+
     meta_config.write_abi();
 
     meta_config.create_wasm_view_cargo_toml();
@@ -22,7 +45,12 @@ pub fn perform<AbiObj: ContractAbiProvider>() {
         match args[1].as_str() {
             "build" => meta_config.build_wasm(),
             "clean" => meta_config.clean_wasm(),
+            "ts-bindings" => meta_config.write_ts_bindings(),
             _ => (),
         }
     }
+
+    if let Some(previous_abi) = &previous_abi {
+        meta_config.check_upgrade(previous_abi);
+    }
 }
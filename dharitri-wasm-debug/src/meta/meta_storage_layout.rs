@@ -0,0 +1,34 @@
+use dharitri_wasm::abi::{ContractAbi, StorageAbi};
+
+/// Declarations that can produce longer keys than their base key,
+/// because key arguments or mapper-specific suffixes get appended to it.
+fn is_extended_key(storage: &StorageAbi) -> bool {
+    !storage.key_args.is_empty()
+        || matches!(storage.mapper, Some(mapper) if mapper != "SingleValueMapper")
+}
+
+/// Warns about storage keys that are a prefix of other storage keys of the contract, including its modules.
+///
+/// Collisions of identical keys are compilation errors, but a base key that is a prefix of another,
+/// e.g. `balance` with an address argument and `balanceOf`, only overlaps for some argument values.
+pub fn storage_key_prefix_warnings(abi: &ContractAbi) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for storage in abi
+        .storage
+        .iter()
+        .filter(|storage| is_extended_key(storage))
+    {
+        for other in abi.storage.iter() {
+            if other.key.len() > storage.key.len() && other.key.starts_with(storage.key) {
+                let warning = format!(
+                    "storage key \"{}\" (`{}`) is a prefix of storage key \"{}\" (`{}`), their keys can overlap once key arguments are appended",
+                    storage.key, storage.name, other.key, other.name
+                );
+                if !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+        }
+    }
+    warnings
+}
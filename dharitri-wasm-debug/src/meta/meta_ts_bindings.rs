@@ -0,0 +1,688 @@
+use std::{fmt::Write, fs};
+
+use crate::abi_json::{
+    ConstructorAbiJson, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, EventAbiJson,
+    InputAbiJson, OutputAbiJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+
+use super::meta_config::MetaConfig;
+
+const MOAX: &str = "MOAX";
+const ANY_TOKEN: &str = "*";
+/// Prefix of the `payableInTokens` entries whose accepted tokens are read from contract storage.
+/// The bindings cannot know those tokens, so they leave the check to the contract.
+const STORAGE_TOKENS_PREFIX: &str = "storage:";
+
+/// Header of the generated file, everything else is derived from the ABI.
+const TS_PRELUDE: &str = r#"import BigNumber from "bignumber.js";
+import {
+    AbiRegistry,
+    BinaryCodec,
+    CodeMetadata,
+    EndpointDefinition,
+    EndpointModifiers,
+    EndpointParameterDefinition,
+    IAddress,
+    Interaction,
+    NativeSerializer,
+    ResultsParser,
+    ReturnCode,
+    SmartContract,
+    SmartContractAbi,
+    TokenPayment,
+    TypedValue,
+    TypeExpressionParser,
+    TypeMapper,
+} from "@dharitrinetwork/erdjs";
+
+type TransactionOnNetwork = Parameters<ResultsParser["parseOutcome"]>[0];
+type QueryResponse = Parameters<ResultsParser["parseQueryResponse"]>[0];
+type TypedOutcomeBundle = ReturnType<ResultsParser["parseOutcome"]>;
+
+export interface ContractResult<T> {
+    returnCode: ReturnCode;
+    returnMessage: string;
+    value: T;
+}
+
+function typedResult<T>(bundle: TypedOutcomeBundle, multiResult: boolean): ContractResult<T> {
+    const values = bundle.values.map((value) => value.valueOf());
+    return {
+        returnCode: bundle.returnCode,
+        returnMessage: bundle.returnMessage,
+        value: <T>(multiResult ? values : values.length > 0 ? values[0] : null),
+    };
+}
+
+function checkPaymentToken(payment: TokenPayment, acceptedTokens: string[]) {
+    if (!acceptedTokens.includes(payment.tokenIdentifier)) {
+        throw new Error(`payment in ${payment.tokenIdentifier} not accepted, expected one of: ${acceptedTokens.join(", ")}`);
+    }
+}
+
+function withPayment(interaction: Interaction, payment: TokenPayment, sender: IAddress): Interaction {
+    if (payment.isMoax()) {
+        return interaction.withValue(payment);
+    }
+    if (payment.isFungible()) {
+        return interaction.withSingleDCTTransfer(payment);
+    }
+    return interaction.withSingleDCTNFTTransfer(payment, sender);
+}
+"#;
+
+/// How a type is used in the bindings.
+///
+/// Results are whatever `valueOf()` yields in erdjs, arguments are anything its `NativeSerializer` accepts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TsTypeUse {
+    Argument,
+    Result,
+}
+
+/// Splits `name<arg1, arg2<...>>` into the name and the top-level type arguments.
+fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let type_name = type_name.trim();
+    let open = match type_name.find('<') {
+        Some(open) if type_name.ends_with('>') => open,
+        _ => return (type_name, Vec::new()),
+    };
+    let inner = &type_name[open + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[start..].trim());
+    (&type_name[..open], args)
+}
+
+/// Custom type names can contain generics, which are not valid TypeScript identifiers.
+pub fn ts_type_identifier(type_name: &str) -> String {
+    type_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn ts_nullable(ts_type: String) -> String {
+    if ts_type.ends_with(" | null") {
+        ts_type
+    } else {
+        format!("{} | null", ts_type)
+    }
+}
+
+fn ts_array(ts_type: String) -> String {
+    if ts_type.contains(' ') {
+        format!("({})[]", ts_type)
+    } else {
+        format!("{}[]", ts_type)
+    }
+}
+
+/// The TypeScript equivalent of an ABI type name.
+pub fn ts_type(type_name: &str, type_use: TsTypeUse) -> String {
+    let (name, args) = split_type_name(type_name);
+    let arg_types = || {
+        args.iter()
+            .map(|arg| ts_type(arg, type_use))
+            .collect::<Vec<_>>()
+    };
+    match (name, args.len()) {
+        ("Option" | "optional", 1) => ts_nullable(ts_type(args[0], type_use)),
+        ("List" | "variadic" | "counted-variadic", 1) => ts_array(ts_type(args[0], type_use)),
+        (array, 1) if array.starts_with("array") => ts_array(ts_type(args[0], type_use)),
+        ("multi", _) => format!("[{}]", arg_types().join(", ")),
+        ("tuple", _) => {
+            let fields: Vec<String> = arg_types()
+                .into_iter()
+                .enumerate()
+                .map(|(i, field_type)| format!("field{}: {}", i, field_type))
+                .collect();
+            format!("{{ {} }}", fields.join("; "))
+        },
+        ("u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize", 0)
+        | ("BigUint" | "BigInt", 0) => match type_use {
+            TsTypeUse::Argument => "BigNumber.Value".to_string(),
+            TsTypeUse::Result => "BigNumber".to_string(),
+        },
+        ("bool", 0) => "boolean".to_string(),
+        ("bytes" | "H256", 0) => "Buffer".to_string(),
+        ("utf-8 string" | "TokenIdentifier" | "MoaxOrDctTokenIdentifier", 0) => {
+            "string".to_string()
+        },
+        ("Address", 0) => "IAddress".to_string(),
+        ("CodeMetadata", 0) => "CodeMetadata".to_string(),
+        ("ignore" | "()", 0) => "null".to_string(),
+        (_, 0) => ts_type_identifier(name),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// `buy_ticket` becomes `buyTicket`, names already in camel case are kept.
+pub fn ts_method_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = false;
+    for c in name.chars() {
+        if c == '_' || c == '-' {
+            capitalize_next = !result.is_empty();
+        } else if capitalize_next {
+            result.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn ts_pascal_name(name: &str) -> String {
+    let method_name = ts_method_name(name);
+    let mut chars = method_name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn write_docs(ts: &mut String, docs: &[String], indent: &str) {
+    if docs.is_empty() {
+        return;
+    }
+    let _ = writeln!(ts, "{}/**", indent);
+    for line in docs {
+        let _ = writeln!(ts, "{} *{}", indent, line.trim_end());
+    }
+    let _ = writeln!(ts, "{} */", indent);
+}
+
+fn write_struct_fields(ts: &mut String, fields: &[StructFieldDescriptionJson]) {
+    for field in fields {
+        write_docs(ts, &field.docs, "    ");
+        let _ = writeln!(
+            ts,
+            "    {}: {};",
+            field.name,
+            ts_type(field.field_type.as_str(), TsTypeUse::Result)
+        );
+    }
+}
+
+fn write_custom_type(ts: &mut String, type_name: &str, type_description: &TypeDescriptionJson) {
+    let identifier = ts_type_identifier(type_name);
+    write_docs(ts, &type_description.docs, "");
+    if type_description.content_type == "enum" {
+        // erdjs represents enum values as `{ name, fields }`, with the fields in declaration order
+        let _ = writeln!(ts, "export type {} =", identifier);
+        for variant in &type_description.variants {
+            let field_types: Vec<String> = variant
+                .fields
+                .iter()
+                .map(|field| ts_type(field.field_type.as_str(), TsTypeUse::Result))
+                .collect();
+            let _ = writeln!(
+                ts,
+                "    | {{ name: \"{}\"; fields: [{}] }}",
+                variant.name,
+                field_types.join(", ")
+            );
+        }
+        let _ = writeln!(ts, "    ;");
+    } else {
+        let _ = writeln!(ts, "export interface {} {{", identifier);
+        write_struct_fields(ts, &type_description.fields);
+        let _ = writeln!(ts, "}}");
+    }
+    let _ = writeln!(ts);
+}
+
+fn write_event_type(ts: &mut String, event: &EventAbiJson) {
+    write_docs(ts, &event.docs, "");
+    let _ = writeln!(
+        ts,
+        "export interface {}Event {{",
+        ts_pascal_name(event.identifier.as_str())
+    );
+    for input in &event.inputs {
+        let _ = writeln!(
+            ts,
+            "    {}: {};",
+            input.arg_name,
+            ts_type(input.type_name.as_str(), TsTypeUse::Result)
+        );
+    }
+    let _ = writeln!(ts, "}}");
+    let _ = writeln!(ts);
+}
+
+fn argument_list(inputs: &[InputAbiJson]) -> Vec<String> {
+    inputs
+        .iter()
+        .map(|input| {
+            format!(
+                "{}: {}",
+                ts_method_name(input.arg_name.as_str()),
+                ts_type(input.type_name.as_str(), TsTypeUse::Argument)
+            )
+        })
+        .collect()
+}
+
+fn argument_names(inputs: &[InputAbiJson]) -> String {
+    inputs
+        .iter()
+        .map(|input| ts_method_name(input.arg_name.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn result_type(outputs: &[OutputAbiJson]) -> String {
+    match outputs {
+        [] => "null".to_string(),
+        [output] => ts_type(output.type_name.as_str(), TsTypeUse::Result),
+        _ => format!(
+            "[{}]",
+            outputs
+                .iter()
+                .map(|output| ts_type(output.type_name.as_str(), TsTypeUse::Result))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn write_payable_docs(ts: &mut String, payable_in_tokens: &[String]) {
+    if payable_in_tokens.iter().any(|token| token == ANY_TOKEN) {
+        let _ = writeln!(ts, "    /** Accepts payments in any token. */");
+    } else if !payable_in_tokens.is_empty() {
+        let accepted: Vec<String> = payable_in_tokens
+            .iter()
+            .map(|token| match token.strip_prefix(STORAGE_TOKENS_PREFIX) {
+                Some(mapper) => format!("the tokens in storage `{}`", mapper),
+                None => token.clone(),
+            })
+            .collect();
+        let _ = writeln!(
+            ts,
+            "    /** Accepts payments in: {}. */",
+            accepted.join(", ")
+        );
+    }
+}
+
+fn write_guard_docs(ts: &mut String, guards: &[String]) {
+    if !guards.is_empty() {
+        let _ = writeln!(ts, "    /** Guards: {}. */", guards.join(", "));
+    }
+}
+
+fn write_endpoint(ts: &mut String, endpoint: &EndpointAbiJson) {
+    let method_name = ts_method_name(endpoint.name.as_str());
+    let mut parameters = argument_list(&endpoint.inputs);
+    let payable = &endpoint.payable_in_tokens;
+    let moax_only = payable.len() == 1 && payable[0] == MOAX;
+    if !payable.is_empty() {
+        parameters.push("payment: TokenPayment".to_string());
+        if !moax_only {
+            parameters.push("sender: IAddress".to_string());
+        }
+    }
+
+    write_docs(ts, &endpoint.docs, "    ");
+    write_payable_docs(ts, payable);
+    write_guard_docs(ts, &endpoint.guards);
+    let _ = writeln!(
+        ts,
+        "    {}({}): Interaction {{",
+        method_name,
+        parameters.join(", ")
+    );
+    if payable.is_empty() {
+        let _ = writeln!(
+            ts,
+            "        return <Interaction>this.contract.methods.{}([{}]);",
+            endpoint.name,
+            argument_names(&endpoint.inputs)
+        );
+    } else {
+        let _ = writeln!(
+            ts,
+            "        const interaction = <Interaction>this.contract.methods.{}([{}]);",
+            endpoint.name,
+            argument_names(&endpoint.inputs)
+        );
+        if !payable
+            .iter()
+            .any(|token| token == ANY_TOKEN || token.starts_with(STORAGE_TOKENS_PREFIX))
+        {
+            let accepted_tokens: Vec<String> = payable
+                .iter()
+                .map(|token| format!("\"{}\"", token))
+                .collect();
+            let _ = writeln!(
+                ts,
+                "        checkPaymentToken(payment, [{}]);",
+                accepted_tokens.join(", ")
+            );
+        }
+        if moax_only {
+            let _ = writeln!(ts, "        return interaction.withValue(payment);");
+        } else {
+            let _ = writeln!(
+                ts,
+                "        return withPayment(interaction, payment, sender);"
+            );
+        }
+    }
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+
+    let multi_result = endpoint.outputs.len() > 1;
+    let result_type = result_type(&endpoint.outputs);
+    match endpoint.mutability {
+        EndpointMutabilityAbiJson::Mutable => {
+            let _ = writeln!(
+                ts,
+                "    parse{}Outcome(transactionOnNetwork: TransactionOnNetwork): ContractResult<{}> {{",
+                ts_pascal_name(endpoint.name.as_str()),
+                result_type
+            );
+            let _ = writeln!(
+                ts,
+                "        const bundle = this.resultsParser.parseOutcome(transactionOnNetwork, this.contract.getEndpoint(\"{}\"));",
+                endpoint.name
+            );
+        },
+        EndpointMutabilityAbiJson::Readonly | EndpointMutabilityAbiJson::Pure => {
+            let _ = writeln!(
+                ts,
+                "    parse{}Query(queryResponse: QueryResponse): ContractResult<{}> {{",
+                ts_pascal_name(endpoint.name.as_str()),
+                result_type
+            );
+            let _ = writeln!(
+                ts,
+                "        const bundle = this.resultsParser.parseQueryResponse(queryResponse, this.contract.getEndpoint(\"{}\"));",
+                endpoint.name
+            );
+        },
+    }
+    let _ = writeln!(ts, "        return typedResult(bundle, {});", multi_result);
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+fn write_event_decoder(ts: &mut String, event: &EventAbiJson) {
+    let event_type = format!("{}Event", ts_pascal_name(event.identifier.as_str()));
+    let _ = writeln!(
+        ts,
+        "    /** Decodes a `{}` event, from its topics (identifier first) and data. */",
+        event.identifier
+    );
+    let _ = writeln!(
+        ts,
+        "    decode{}(topics: Buffer[], data: Buffer): {} {{",
+        event_type, event_type
+    );
+    let _ = writeln!(
+        ts,
+        "        if (topics.length === 0 || topics[0].toString() !== \"{}\") {{",
+        event.identifier
+    );
+    let _ = writeln!(
+        ts,
+        "            throw new Error(\"not a `{}` event\");",
+        event.identifier
+    );
+    let _ = writeln!(ts, "        }}");
+    if event.structured_data {
+        let _ = writeln!(
+            ts,
+            "        const eventData = this.eventDataFields(data, {});",
+            event.version.is_some()
+        );
+    }
+    let _ = writeln!(ts, "        return {{");
+    let mut topic_index = 1;
+    let mut data_field_index = 0;
+    for input in &event.inputs {
+        let source = if input.indexed == Some(true) {
+            let source = format!("topics[{}]", topic_index);
+            topic_index += 1;
+            source
+        } else if event.structured_data {
+            let source = match input.since {
+                Some(since) => format!(
+                    "eventData.version >= {} ? eventData.fields[{}] : Buffer.alloc(0)",
+                    since, data_field_index
+                ),
+                None => format!("eventData.fields[{}]", data_field_index),
+            };
+            data_field_index += 1;
+            source
+        } else {
+            "data".to_string()
+        };
+        let _ = writeln!(
+            ts,
+            "            {}: this.decodeValue({}, \"{}\"),",
+            input.arg_name, source, input.type_name
+        );
+    }
+    let _ = writeln!(ts, "        }};");
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+/// Splits structured event data into the length-prefixed fields,
+/// after reading the version byte of versioned events.
+fn write_event_data_fields(ts: &mut String) {
+    let _ = writeln!(
+        ts,
+        "    private eventDataFields(data: Buffer, versioned: boolean): {{ version: number, fields: Buffer[] }} {{"
+    );
+    let _ = writeln!(
+        ts,
+        "        const version = versioned ? data.readUInt8(0) : 1;"
+    );
+    let _ = writeln!(ts, "        const fields: Buffer[] = [];");
+    let _ = writeln!(ts, "        let offset = versioned ? 1 : 0;");
+    let _ = writeln!(ts, "        while (offset < data.length) {{");
+    let _ = writeln!(ts, "            const length = data.readUInt32BE(offset);");
+    let _ = writeln!(
+        ts,
+        "            fields.push(data.subarray(offset + 4, offset + 4 + length));"
+    );
+    let _ = writeln!(ts, "            offset += 4 + length;");
+    let _ = writeln!(ts, "        }}");
+    let _ = writeln!(ts, "        return {{ version: version, fields: fields }};");
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+fn write_decode_value(ts: &mut String) {
+    let _ = writeln!(
+        ts,
+        "    private decodeValue(data: Buffer, typeExpression: string): any {{"
+    );
+    let _ = writeln!(
+        ts,
+        "        const type = new TypeMapper(this.abiRegistry.customTypes).mapType(new TypeExpressionParser().parse(typeExpression));"
+    );
+    let _ = writeln!(
+        ts,
+        "        return this.codec.decodeTopLevel(data, type).valueOf();"
+    );
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+/// Without an `#[upgrade]` method, upgrading calls the constructor again, with the same arguments.
+fn write_init_arguments(ts: &mut String, constructor: &ConstructorAbiJson, used_on_upgrade: bool) {
+    write_docs(ts, &constructor.docs, "    ");
+    let _ = writeln!(
+        ts,
+        "    /** Arguments of the constructor, to be passed as `initArguments` on {}. */",
+        if used_on_upgrade {
+            "deploy or upgrade"
+        } else {
+            "deploy"
+        }
+    );
+    let _ = writeln!(
+        ts,
+        "    initArguments({}): TypedValue[] {{",
+        argument_list(&constructor.inputs).join(", ")
+    );
+    let _ = writeln!(
+        ts,
+        "        return NativeSerializer.nativeToTypedValues([{}], this.contract.getAbi().getConstructorDefinition());",
+        argument_names(&constructor.inputs)
+    );
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+/// The erdjs ABI registry only knows the `#[init]` constructor,
+/// so the definition of the `#[upgrade]` method is built here, with the custom types resolved like in `decodeValue`.
+fn write_upgrade_arguments(ts: &mut String, upgrade_constructor: &ConstructorAbiJson) {
+    write_docs(ts, &upgrade_constructor.docs, "    ");
+    let _ = writeln!(
+        ts,
+        "    /** Arguments of the `#[upgrade]` method, to be passed as `initArguments` on upgrade. */"
+    );
+    let _ = writeln!(
+        ts,
+        "    upgradeArguments({}): TypedValue[] {{",
+        argument_list(&upgrade_constructor.inputs).join(", ")
+    );
+    let _ = writeln!(
+        ts,
+        "        const mapper = new TypeMapper(this.abiRegistry.customTypes);"
+    );
+    let _ = writeln!(ts, "        const parser = new TypeExpressionParser();");
+    let _ = writeln!(ts, "        const inputs = [");
+    for input in &upgrade_constructor.inputs {
+        let _ = writeln!(
+            ts,
+            "            new EndpointParameterDefinition(\"{}\", \"\", mapper.mapType(parser.parse(\"{}\"))),",
+            input.arg_name, input.type_name
+        );
+    }
+    let _ = writeln!(ts, "        ];");
+    let _ = writeln!(
+        ts,
+        "        const definition = new EndpointDefinition(\"upgrade\", inputs, [], new EndpointModifiers(\"\", []));"
+    );
+    let _ = writeln!(
+        ts,
+        "        return NativeSerializer.nativeToTypedValues([{}], definition);",
+        argument_names(&upgrade_constructor.inputs)
+    );
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+}
+
+/// Generates the TypeScript bindings of a contract, on top of erdjs.
+///
+/// Produces a type for each struct and enum in the ABI, an interface for each event,
+/// and a contract class with a call builder and a typed result parser for each endpoint.
+pub fn generate_ts_bindings(abi: &ContractAbiJson) -> String {
+    let mut ts = String::new();
+    let _ = writeln!(
+        ts,
+        "// Code generated by the dharitri-wasm meta crate, from the `{}` ABI. DO NOT EDIT.",
+        abi.name
+    );
+    let _ = writeln!(ts);
+    ts.push_str(TS_PRELUDE);
+    let _ = writeln!(ts);
+
+    for (type_name, type_description) in &abi.types {
+        write_custom_type(&mut ts, type_name.as_str(), type_description);
+    }
+    for event in &abi.events {
+        write_event_type(&mut ts, event);
+    }
+
+    write_docs(&mut ts, &abi.docs, "");
+    let _ = writeln!(
+        ts,
+        "export class {}Contract {{",
+        ts_type_identifier(&abi.name)
+    );
+    let _ = writeln!(ts, "    readonly contract: SmartContract;");
+    let _ = writeln!(ts, "    private readonly abiRegistry: AbiRegistry;");
+    let _ = writeln!(
+        ts,
+        "    private readonly resultsParser: ResultsParser = new ResultsParser();"
+    );
+    if !abi.events.is_empty() {
+        let _ = writeln!(
+            ts,
+            "    private readonly codec: BinaryCodec = new BinaryCodec();"
+        );
+    }
+    let _ = writeln!(ts);
+    let _ = writeln!(
+        ts,
+        "    constructor(abiRegistry: AbiRegistry, address?: IAddress) {{"
+    );
+    let _ = writeln!(ts, "        this.abiRegistry = abiRegistry;");
+    let _ = writeln!(
+        ts,
+        "        this.contract = new SmartContract({{ address: address, abi: new SmartContractAbi(abiRegistry) }});"
+    );
+    let _ = writeln!(ts, "    }}");
+    let _ = writeln!(ts);
+
+    if let Some(constructor) = &abi.constructor {
+        write_init_arguments(&mut ts, constructor, abi.upgrade_constructor.is_none());
+    }
+    if let Some(upgrade_constructor) = &abi.upgrade_constructor {
+        write_upgrade_arguments(&mut ts, upgrade_constructor);
+    }
+
+    for endpoint in &abi.endpoints {
+        write_endpoint(&mut ts, endpoint);
+    }
+    for event in &abi.events {
+        write_event_decoder(&mut ts, event);
+    }
+    if abi.events.iter().any(|event| event.structured_data) {
+        write_event_data_fields(&mut ts);
+    }
+    if !abi.events.is_empty() {
+        write_decode_value(&mut ts);
+    }
+
+    // drops the blank line after the last member
+    ts.pop();
+    let _ = writeln!(ts, "}}");
+    ts
+}
+
+impl MetaConfig {
+    /// Writes `<name>.ts` next to each ABI.
+    pub fn write_ts_bindings(&self) {
+        for output_contract in &self.output_contracts {
+            let abi_json = ContractAbiJson::from(&output_contract.abi);
+            let ts_path = format!(
+                "{}/{}.ts",
+                self.output_dir, output_contract.output_base_name
+            );
+            fs::write(ts_path, generate_ts_bindings(&abi_json))
+                .expect("failed to write the TypeScript bindings");
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use std::{collections::BTreeMap, fmt, fs};
+
+use crate::abi_json::{
+    ConstructorAbiJson, ContractAbiJson, EndpointAbiJson, EnumVariantDescriptionJson, InputAbiJson,
+    OutputAbiJson, StorageAbiJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+
+use super::meta_config::MetaConfig;
+
+/// A change that makes the upgraded contract incompatible with its callers or with its existing storage.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpgradeIssue {
+    ConstructorInputsChanged {
+        constructor: String,
+        previous: String,
+        current: String,
+    },
+    EndpointRemoved {
+        endpoint: String,
+    },
+    EndpointInputsChanged {
+        endpoint: String,
+        previous: String,
+        current: String,
+    },
+    EndpointOutputsChanged {
+        endpoint: String,
+        previous: String,
+        current: String,
+    },
+    EndpointPayabilityChanged {
+        endpoint: String,
+        previous: String,
+        current: String,
+    },
+    TypeChanged {
+        type_name: String,
+        previous: String,
+        current: String,
+    },
+    StorageLayoutChanged {
+        key: String,
+        previous: String,
+        current: String,
+    },
+}
+
+impl fmt::Display for UpgradeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeIssue::ConstructorInputsChanged {
+                constructor,
+                previous,
+                current,
+            } => write!(
+                f,
+                "constructor `{}` arguments changed from ({}) to ({})",
+                constructor, previous, current
+            ),
+            UpgradeIssue::EndpointRemoved { endpoint } => {
+                write!(f, "endpoint `{}` was removed or renamed", endpoint)
+            },
+            UpgradeIssue::EndpointInputsChanged {
+                endpoint,
+                previous,
+                current,
+            } => write!(
+                f,
+                "endpoint `{}` arguments changed from ({}) to ({})",
+                endpoint, previous, current
+            ),
+            UpgradeIssue::EndpointOutputsChanged {
+                endpoint,
+                previous,
+                current,
+            } => write!(
+                f,
+                "endpoint `{}` results changed from ({}) to ({})",
+                endpoint, previous, current
+            ),
+            UpgradeIssue::EndpointPayabilityChanged {
+                endpoint,
+                previous,
+                current,
+            } => write!(
+                f,
+                "endpoint `{}` payability changed from [{}] to [{}]",
+                endpoint, previous, current
+            ),
+            UpgradeIssue::TypeChanged {
+                type_name,
+                previous,
+                current,
+            } => write!(
+                f,
+                "type `{}` encoding changed from {} to {}",
+                type_name, previous, current
+            ),
+            UpgradeIssue::StorageLayoutChanged {
+                key,
+                previous,
+                current,
+            } => write!(
+                f,
+                "storage `{}` changed from {} to {}",
+                key, previous, current
+            ),
+        }
+    }
+}
+
+/// Lists the breaking changes between a previously deployed ABI and the current one.
+///
+/// Additions (new endpoints, new enum variants, new storage) are compatible and not reported.
+/// Renamed endpoints cannot be told apart from removed ones.
+pub fn check_upgrade_compatibility(
+    previous: &ContractAbiJson,
+    current: &ContractAbiJson,
+) -> Vec<UpgradeIssue> {
+    let mut issues = Vec::new();
+    check_constructors(previous, current, &mut issues);
+    check_endpoints(previous, current, &mut issues);
+    check_types(previous, current, &mut issues);
+    check_storage(previous, current, &mut issues);
+    issues
+}
+
+fn inputs_to_string(inputs: &[InputAbiJson]) -> String {
+    inputs
+        .iter()
+        .map(|input| {
+            if input.multi_arg.unwrap_or(false) {
+                format!("{}: {}...", input.arg_name, input.type_name)
+            } else {
+                format!("{}: {}", input.arg_name, input.type_name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Argument names are not part of the call data, so only the types are compared.
+fn input_types(inputs: &[InputAbiJson]) -> Vec<(&str, bool)> {
+    inputs
+        .iter()
+        .map(|input| (input.type_name.as_str(), input.multi_arg.unwrap_or(false)))
+        .collect()
+}
+
+fn outputs_to_string(outputs: &[OutputAbiJson]) -> String {
+    outputs
+        .iter()
+        .map(|output| {
+            if output.multi_result.unwrap_or(false) {
+                format!("{}...", output.type_name)
+            } else {
+                output.type_name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn check_constructor(
+    constructor: &str,
+    previous: Option<&ConstructorAbiJson>,
+    current: Option<&ConstructorAbiJson>,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    let previous_inputs = previous.map(|c| c.inputs.as_slice()).unwrap_or_default();
+    let current_inputs = current.map(|c| c.inputs.as_slice()).unwrap_or_default();
+    if input_types(previous_inputs) != input_types(current_inputs) {
+        issues.push(UpgradeIssue::ConstructorInputsChanged {
+            constructor: constructor.to_string(),
+            previous: inputs_to_string(previous_inputs),
+            current: inputs_to_string(current_inputs),
+        });
+    }
+}
+
+/// The upgrade constructor is called on upgrade, if there is one, `init` otherwise.
+/// Deploy and upgrade scripts written for the previous arguments would no longer work.
+fn check_constructors(
+    previous: &ContractAbiJson,
+    current: &ContractAbiJson,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    check_constructor(
+        "init",
+        previous.constructor.as_ref(),
+        current.constructor.as_ref(),
+        issues,
+    );
+    if previous.upgrade_constructor.is_some() || current.upgrade_constructor.is_some() {
+        check_constructor(
+            "upgrade",
+            previous
+                .upgrade_constructor
+                .as_ref()
+                .or(previous.constructor.as_ref()),
+            current
+                .upgrade_constructor
+                .as_ref()
+                .or(current.constructor.as_ref()),
+            issues,
+        );
+    }
+}
+
+fn check_endpoint(
+    previous: &EndpointAbiJson,
+    current: &EndpointAbiJson,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    if input_types(&previous.inputs) != input_types(&current.inputs) {
+        issues.push(UpgradeIssue::EndpointInputsChanged {
+            endpoint: previous.name.clone(),
+            previous: inputs_to_string(&previous.inputs),
+            current: inputs_to_string(&current.inputs),
+        });
+    }
+
+    let previous_outputs = outputs_to_string(&previous.outputs);
+    let current_outputs = outputs_to_string(&current.outputs);
+    if previous_outputs != current_outputs {
+        issues.push(UpgradeIssue::EndpointOutputsChanged {
+            endpoint: previous.name.clone(),
+            previous: previous_outputs,
+            current: current_outputs,
+        });
+    }
+
+    let mut previous_payable = previous.payable_in_tokens.clone();
+    let mut current_payable = current.payable_in_tokens.clone();
+    previous_payable.sort();
+    current_payable.sort();
+    if previous_payable != current_payable {
+        issues.push(UpgradeIssue::EndpointPayabilityChanged {
+            endpoint: previous.name.clone(),
+            previous: previous_payable.join(", "),
+            current: current_payable.join(", "),
+        });
+    }
+}
+
+fn check_endpoints(
+    previous: &ContractAbiJson,
+    current: &ContractAbiJson,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    for previous_endpoint in &previous.endpoints {
+        match current
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == previous_endpoint.name)
+        {
+            Some(current_endpoint) => check_endpoint(previous_endpoint, current_endpoint, issues),
+            None => issues.push(UpgradeIssue::EndpointRemoved {
+                endpoint: previous_endpoint.name.clone(),
+            }),
+        }
+    }
+}
+
+fn fields_to_string(fields: &[StructFieldDescriptionJson]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, field.field_type))
+        .collect();
+    format!("{{ {} }}", fields.join(", "))
+}
+
+fn variant_to_string(variant: &EnumVariantDescriptionJson) -> String {
+    if variant.fields.is_empty() {
+        format!("{} = {}", variant.name, variant.discriminant)
+    } else {
+        format!(
+            "{} {} = {}",
+            variant.name,
+            fields_to_string(&variant.fields),
+            variant.discriminant
+        )
+    }
+}
+
+/// Field names are not encoded, only their types and order matter.
+fn field_types(fields: &[StructFieldDescriptionJson]) -> Vec<&str> {
+    fields
+        .iter()
+        .map(|field| field.field_type.as_str())
+        .collect()
+}
+
+fn variants_to_string(variants: &[EnumVariantDescriptionJson]) -> String {
+    let variants: Vec<String> = variants.iter().map(variant_to_string).collect();
+    format!("enum {{ {} }}", variants.join(", "))
+}
+
+fn type_to_string(type_description: &TypeDescriptionJson) -> String {
+    match type_description.content_type.as_str() {
+        "enum" => variants_to_string(&type_description.variants),
+        "struct" => format!("struct {}", fields_to_string(&type_description.fields)),
+        other => other.to_string(),
+    }
+}
+
+/// Struct fields are encoded in order, so any type change breaks decoding,
+/// except for versioned structs getting new fields at the end, in a new version.
+/// Enums can get new variants, but existing ones must keep their discriminant and field types.
+/// Renaming fields or variants is compatible.
+fn is_type_compatible(previous: &TypeDescriptionJson, current: &TypeDescriptionJson) -> bool {
+    if previous.content_type != current.content_type {
+        return false;
+    }
+
+    match previous.content_type.as_str() {
+        "enum" => previous.variants.iter().all(|previous_variant| {
+            current.variants.iter().any(|current_variant| {
+                previous_variant.discriminant == current_variant.discriminant
+                    && field_types(&previous_variant.fields) == field_types(&current_variant.fields)
+            })
+        }),
+        _ => {
+            if current.fields.len() < previous.fields.len() {
+                return false;
+            }
+            let (current_fields, added_fields) = current.fields.split_at(previous.fields.len());
+            let previous_version = previous.version.unwrap_or(1);
+            field_types(&previous.fields) == field_types(current_fields)
+                && added_fields.iter().all(|field| match field.since {
+                    Some(since) => since > previous_version,
+                    None => false,
+                })
+        },
+    }
+}
+
+fn check_types(
+    previous: &ContractAbiJson,
+    current: &ContractAbiJson,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    for (type_name, previous_type) in &previous.types {
+        if let Some(current_type) = current.types.get(type_name) {
+            if !is_type_compatible(previous_type, current_type) {
+                issues.push(UpgradeIssue::TypeChanged {
+                    type_name: type_name.clone(),
+                    previous: type_to_string(previous_type),
+                    current: type_to_string(current_type),
+                });
+            }
+        }
+    }
+}
+
+/// Getters, setters and `SingleValueMapper`s with the same key share the same layout.
+fn storage_layout(storage: &StorageAbiJson) -> String {
+    let key_arg_types: Vec<&str> = storage
+        .key_args
+        .iter()
+        .map(|key_arg| key_arg.type_name.as_str())
+        .collect();
+    format!(
+        "{}<{}> (key args: {})",
+        storage.mapper.as_deref().unwrap_or("SingleValueMapper"),
+        storage.value_type,
+        key_arg_types.join(", ")
+    )
+}
+
+/// Storage entries that are no longer declared are not reported, their data simply stays unused.
+fn check_storage(
+    previous: &ContractAbiJson,
+    current: &ContractAbiJson,
+    issues: &mut Vec<UpgradeIssue>,
+) {
+    let current_layouts: BTreeMap<&str, String> = current
+        .storage
+        .iter()
+        .map(|storage| (storage.key.as_str(), storage_layout(storage)))
+        .collect();
+    let mut checked_keys = Vec::new();
+    for previous_storage in &previous.storage {
+        let key = previous_storage.key.as_str();
+        if checked_keys.contains(&key) {
+            continue;
+        }
+        checked_keys.push(key);
+
+        if let Some(current_layout) = current_layouts.get(key) {
+            let previous_layout = storage_layout(previous_storage);
+            if &previous_layout != current_layout {
+                issues.push(UpgradeIssue::StorageLayoutChanged {
+                    key: key.to_string(),
+                    previous: previous_layout,
+                    current: current_layout.clone(),
+                });
+            }
+        }
+    }
+}
+
+pub fn read_previous_abi(previous_abi_path: &str) -> ContractAbiJson {
+    let previous_abi_string =
+        fs::read_to_string(previous_abi_path).expect("failed to read the previous ABI file");
+    serde_json::from_str(previous_abi_string.as_str()).expect("invalid ABI file")
+}
+
+impl MetaConfig {
+    /// Compares the main contract against a previously generated ABI.
+    pub fn check_upgrade(&self, previous_abi: &ContractAbiJson) {
+        let current_abi = ContractAbiJson::from(&self.main_contract().abi);
+
+        let issues = check_upgrade_compatibility(previous_abi, &current_abi);
+        if issues.is_empty() {
+            println!("No breaking changes found.");
+            return;
+        }
+
+        println!("Breaking changes found:");
+        for issue in &issues {
+            println!("- {}", issue);
+        }
+        std::process::exit(1);
+    }
+}
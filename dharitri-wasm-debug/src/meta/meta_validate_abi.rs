@@ -8,7 +8,29 @@ fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     }
 }
 
+fn validate_abi_upgrade_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
+    if abi.upgrade_constructors.len() > 1 {
+        return Err("More than one upgrade constructor present. At most one method can be annotated with `#[upgrade]`.");
+    }
+    Ok(())
+}
+
+/// Callers only get the error code, so it needs to identify the error unambiguously.
+fn validate_abi_error_codes(abi: &ContractAbi) -> Result<(), &'static str> {
+    for (index, error) in abi.errors.iter().enumerate() {
+        if abi.errors[..index]
+            .iter()
+            .any(|other| other.code == error.code)
+        {
+            return Err("The same error code is used by more than one contract error. Error codes need to be unique across the contract.");
+        }
+    }
+    Ok(())
+}
+
 pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
     validate_abi_constructor(abi)?;
+    validate_abi_upgrade_constructor(abi)?;
+    validate_abi_error_codes(abi)?;
     Ok(())
 }
@@ -1,36 +1,88 @@
-use std::{fs, process::Command};
+use std::{env, fs, path::Path, process::Command};
 
-use super::meta_config::{BuildArgs, ContractMetadata, MetaConfig};
+use crate::abi_json::{ReproducibleBuildAbiJson, RustcAbiJson, WasmOptAbiJson};
+
+use super::{
+    meta_abi::write_contract_abi,
+    meta_build_manifest::{cargo_lock_hash, source_hash, BuildManifestJson},
+    meta_config::{BuildArgs, ContractMetadata, MetaConfig},
+    meta_wasm_report::write_wasm_report,
+};
 
 const WASM_OPT_NAME: &str = "wasm-opt";
+const WASM_OPT_FLAGS: &[&str] = &["-Oz"];
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Recorded in the build manifest whenever the debug symbols are stripped.
+pub(super) const STRIP_SYMBOLS_FLAG: &str = "-C link-arg=-s";
+
+/// Relative to the meta crate, where the build is launched from.
+const CONTRACT_ROOT_PATH: &str = "..";
 
 impl MetaConfig {
     pub fn build_wasm(&mut self) {
-        if self.build_args.wasm_opt && !is_wasm_opt_installed() {
-            println!("Warning: {} not installed", WASM_OPT_NAME);
-            self.build_args.wasm_opt = false;
-        }
+        self.check_wasm_opt();
+
+        for output_contract in &self.output_contracts {
+            let wasm_path =
+                build_contract(output_contract, &self.build_args, self.output_dir.as_str());
 
-        if let Some(main_contract) = &self.main_contract {
-            build_contract(main_contract, &self.build_args, self.output_dir.as_str());
+            if self.build_args.reproducible {
+                let reproducible_build = reproducible_build_info(output_contract, &self.build_args);
+                BuildManifestJson::create(wasm_path.as_str(), reproducible_build.clone())
+                    .write(wasm_path.as_str());
+                write_contract_abi(
+                    output_contract,
+                    self.output_dir.as_str(),
+                    Some(reproducible_build),
+                );
+            }
         }
+    }
 
-        if let Some(view_contract) = &self.view_contract {
-            build_contract(view_contract, &self.build_args, self.output_dir.as_str());
+    pub(super) fn check_wasm_opt(&mut self) {
+        if self.build_args.wasm_opt && !is_wasm_opt_installed() {
+            println!("Warning: {} not installed", WASM_OPT_NAME);
+            self.build_args.wasm_opt = false;
         }
     }
 }
 
-fn build_contract(contract_metadata: &ContractMetadata, build_args: &BuildArgs, output_path: &str) {
+/// Builds the contract and copies it to the output folder. Returns the path of the resulting wasm.
+pub(super) fn build_contract(
+    contract_metadata: &ContractMetadata,
+    build_args: &BuildArgs,
+    output_path: &str,
+) -> String {
     let mut command = Command::new("cargo");
+    if build_args.reproducible {
+        if let Some(toolchain) = &build_args.rust_toolchain {
+            command.arg(format!("+{}", toolchain));
+        }
+    }
     command
-        .args(["build", "--target=wasm32-unknown-unknown", "--release"])
+        .args([
+            "build",
+            format!("--target={}", WASM_TARGET).as_str(),
+            "--release",
+        ])
         .current_dir(&contract_metadata.wasm_crate_path);
+    if build_args.reproducible {
+        command.arg("--locked");
+    }
     if let Some(target_dir) = &build_args.target_dir {
         command.args(["--target-dir", target_dir]);
     }
-    if !build_args.debug_symbols {
-        command.env("RUSTFLAGS", "-C link-arg=-s");
+    if !contract_metadata.features.is_empty() {
+        command.args(["--features", contract_metadata.features.join(",").as_str()]);
+    }
+    let mut rustflags = recorded_rustflags(contract_metadata, build_args);
+    if build_args.reproducible {
+        // the RUSTFLAGS from the environment are ignored, the build must only depend on what gets recorded
+        rustflags.extend(remap_path_prefix_flags());
+        command.env("RUSTFLAGS", rustflags.join(" "));
+    } else if !rustflags.is_empty() {
+        command.env("RUSTFLAGS", rustflags.join(" "));
     }
     let exit_status = command
         .spawn()
@@ -45,8 +97,102 @@ fn build_contract(contract_metadata: &ContractMetadata, build_args: &BuildArgs,
     let dest_wasm_path = format!("{}/{}", output_path, dest_wasm_name);
     fs::copy(source_wasm_path.as_str(), dest_wasm_path.as_str())
         .expect("failed to copy compiled contract to output directory");
+    let size_before_opt = wasm_file_size(dest_wasm_path.as_str());
+
+    let optimized = optimize_contract(
+        uses_wasm_opt(contract_metadata, build_args),
+        dest_wasm_path.as_str(),
+    );
+
+    if build_args.report {
+        write_wasm_report(
+            contract_metadata,
+            dest_wasm_path.as_str(),
+            size_before_opt,
+            optimized,
+        );
+    }
+
+    dest_wasm_path
+}
 
-    optimize_contract(build_args, dest_wasm_path.as_str());
+fn uses_wasm_opt(contract_metadata: &ContractMetadata, build_args: &BuildArgs) -> bool {
+    build_args.wasm_opt && contract_metadata.wasm_opt.unwrap_or(true)
+}
+
+fn recorded_rustflags(contract_metadata: &ContractMetadata, build_args: &BuildArgs) -> Vec<String> {
+    let debug_symbols = contract_metadata
+        .debug_symbols
+        .unwrap_or(build_args.debug_symbols);
+    if debug_symbols {
+        Vec::new()
+    } else {
+        vec![STRIP_SYMBOLS_FLAG.to_string()]
+    }
+}
+
+/// Keeps the local paths of the contract and of the dependencies out of the binary.
+fn remap_path_prefix_flags() -> Vec<String> {
+    let mut flags = Vec::new();
+    if let Ok(contract_root) = fs::canonicalize(CONTRACT_ROOT_PATH) {
+        flags.push(format!(
+            "--remap-path-prefix={}=/contract",
+            contract_root.display()
+        ));
+    }
+    let cargo_home = env::var("CARGO_HOME")
+        .ok()
+        .or_else(|| env::var("HOME").ok().map(|home| format!("{}/.cargo", home)));
+    if let Some(cargo_home) = cargo_home {
+        flags.push(format!("--remap-path-prefix={}=/cargo", cargo_home));
+    }
+    flags
+}
+
+pub(super) fn reproducible_build_info(
+    contract_metadata: &ContractMetadata,
+    build_args: &BuildArgs,
+) -> ReproducibleBuildAbiJson {
+    let wasm_opt = if uses_wasm_opt(contract_metadata, build_args) {
+        Some(WasmOptAbiJson {
+            version: wasm_opt_version(),
+            flags: WASM_OPT_FLAGS.iter().map(|flag| flag.to_string()).collect(),
+        })
+    } else {
+        None
+    };
+    ReproducibleBuildAbiJson {
+        toolchain: build_args.rust_toolchain.clone(),
+        rustc: build_rustc_version(&build_args.rust_toolchain),
+        target: WASM_TARGET.to_string(),
+        rustflags: recorded_rustflags(contract_metadata, build_args),
+        features: contract_metadata.features.clone(),
+        wasm_opt,
+        cargo_lock_hash: cargo_lock_hash(Path::new(&contract_metadata.wasm_crate_path)),
+        source_hash: source_hash(Path::new(CONTRACT_ROOT_PATH)),
+    }
+}
+
+/// The compiler used by `cargo build`, which can differ from the one running the meta crate.
+fn build_rustc_version(toolchain: &Option<String>) -> RustcAbiJson {
+    let mut command = Command::new("rustc");
+    if let Some(toolchain) = toolchain {
+        command.arg(format!("+{}", toolchain));
+    }
+    let output = command
+        .arg("-vV")
+        .output()
+        .expect("failed to retrieve the rustc version");
+    let version_string = String::from_utf8_lossy(&output.stdout);
+    let meta = rustc_version::version_meta_for(&version_string)
+        .expect("failed to parse the rustc version");
+    RustcAbiJson::from_version_meta(meta)
+}
+
+fn wasm_file_size(wasm_path: &str) -> u64 {
+    fs::metadata(wasm_path)
+        .expect("failed to read compiled contract metadata")
+        .len()
 }
 
 fn is_wasm_opt_installed() -> bool {
@@ -56,15 +202,27 @@ fn is_wasm_opt_installed() -> bool {
         .is_ok()
 }
 
-fn optimize_contract(build_args: &BuildArgs, wasm_path: &str) {
-    if !build_args.wasm_opt {
-        return;
+fn wasm_opt_version() -> String {
+    let output = Command::new(WASM_OPT_NAME)
+        .args(["--version"])
+        .output()
+        .expect("failed to retrieve the wasm-opt version");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Returns whether `wasm-opt` ran successfully.
+fn optimize_contract(wasm_opt: bool, wasm_path: &str) -> bool {
+    if !wasm_opt {
+        return false;
     }
 
-    let _ = Command::new(WASM_OPT_NAME)
-        .args([wasm_path, "-Oz", "--output", wasm_path])
+    Command::new(WASM_OPT_NAME)
+        .arg(wasm_path)
+        .args(WASM_OPT_FLAGS)
+        .args(["--output", wasm_path])
         .spawn()
         .expect("failed to spawn wasm-out process")
         .wait()
-        .expect("wasm-out was not running");
+        .expect("wasm-out was not running")
+        .success()
 }
@@ -63,6 +63,9 @@ fn write_wasm_src_lib(contract_metadata: &ContractMetadata) {
     if contract_metadata.abi.has_callback {
         mandatory_endpoints.push("callBack".to_string());
     }
+    if !contract_metadata.abi.upgrade_constructors.is_empty() {
+        mandatory_endpoints.push("upgrade".to_string());
+    }
     let all_endpoint_names = mandatory_endpoints.iter().chain(endpoint_names.iter());
 
     write_endpoints_macro(
@@ -87,12 +90,8 @@ pub fn copy_to_wasm_unmanaged_ei() {
 
 impl MetaConfig {
     pub fn write_wasm_src_lib(&self) {
-        if let Some(main_contract) = &self.main_contract {
-            write_wasm_src_lib(main_contract);
-        }
-
-        if let Some(view_contract) = &self.view_contract {
-            write_wasm_src_lib(view_contract);
+        for output_contract in &self.output_contracts {
+            write_wasm_src_lib(output_contract);
         }
     }
 }
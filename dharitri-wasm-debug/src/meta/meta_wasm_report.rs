@@ -0,0 +1,387 @@
+use std::{collections::BTreeMap, fmt::Write, fs};
+
+use dharitri_wasm::abi::ContractAbi;
+use serde::Serialize;
+use wasmparser::{
+    ExternalKind, ImportSectionEntryType, Name, NameSectionReader, Operator, Parser, Payload,
+};
+
+use super::meta_config::ContractMetadata;
+
+/// VM hooks only available from EI 1.2 onwards, used behind the `ei-1-2` feature of `dharitri-wasm`.
+pub const EI_1_2_HOOKS: &[&str] = &[
+    "managedIsDCTFrozen",
+    "managedIsDCTPaused",
+    "managedIsDCTLimitedTransfer",
+    "managedRipemd160",
+    "verifyBLS",
+    "managedVerifyBLS",
+    "managedVerifyEd25519",
+    "managedVerifySecp256k1",
+    "managedVerifyCustomSecp256k1",
+    "managedEncodeSecp256k1DerSignature",
+    "managedBufferToHex",
+    "bigIntToString",
+    "createEC",
+    "managedCreateEC",
+    "managedMarshalEC",
+    "managedMarshalCompressedEC",
+];
+
+/// Exported by every contract, without appearing among the ABI endpoints.
+const IMPLICIT_EXPORTS: &[&str] = &["init", "callBack"];
+
+/// Exported by contracts with an `#[upgrade]` method, which is not among the ABI endpoints either.
+const UPGRADE_EXPORT: &str = "upgrade";
+
+/// How many functions are listed in the report.
+const LARGEST_FUNCTIONS_COUNT: usize = 10;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmFunctionSize {
+    pub name: String,
+    pub size: usize,
+}
+
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmMemoryReport {
+    /// Initial memory size, in 64KiB pages.
+    pub initial_pages: u64,
+    pub max_pages: Option<u64>,
+    /// Total size of the static data segments.
+    pub data_size: usize,
+    /// Set if the contract can grow its memory, i.e. it uses a heap allocator.
+    pub uses_memory_grow: bool,
+}
+
+/// Produced by `meta build --report`, for each output wasm.
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmReport {
+    pub wasm_name: String,
+    pub size_before_opt: u64,
+    /// Missing if `wasm-opt` did not run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_after_opt: Option<u64>,
+    pub imports: Vec<String>,
+    /// Imports that require EI 1.2.
+    pub ei_1_2_imports: Vec<String>,
+    pub exports: Vec<String>,
+    /// ABI endpoints that are not exported by the wasm.
+    pub missing_endpoints: Vec<String>,
+    /// Exported functions that are not endpoints in the ABI.
+    pub unexpected_exports: Vec<String>,
+    pub has_floating_point: bool,
+    pub memory: WasmMemoryReport,
+    pub largest_functions: Vec<WasmFunctionSize>,
+}
+
+/// Scalar floating point instructions, including conversions from and to integers.
+fn is_floating_point_operator(operator: &Operator) -> bool {
+    matches!(
+        operator,
+        Operator::F32Load { .. }
+            | Operator::F64Load { .. }
+            | Operator::F32Store { .. }
+            | Operator::F64Store { .. }
+            | Operator::F32Const { .. }
+            | Operator::F64Const { .. }
+            | Operator::F32Eq
+            | Operator::F32Ne
+            | Operator::F32Lt
+            | Operator::F32Gt
+            | Operator::F32Le
+            | Operator::F32Ge
+            | Operator::F64Eq
+            | Operator::F64Ne
+            | Operator::F64Lt
+            | Operator::F64Gt
+            | Operator::F64Le
+            | Operator::F64Ge
+            | Operator::F32Abs
+            | Operator::F32Neg
+            | Operator::F32Ceil
+            | Operator::F32Floor
+            | Operator::F32Trunc
+            | Operator::F32Nearest
+            | Operator::F32Sqrt
+            | Operator::F32Add
+            | Operator::F32Sub
+            | Operator::F32Mul
+            | Operator::F32Div
+            | Operator::F32Min
+            | Operator::F32Max
+            | Operator::F32Copysign
+            | Operator::F64Abs
+            | Operator::F64Neg
+            | Operator::F64Ceil
+            | Operator::F64Floor
+            | Operator::F64Trunc
+            | Operator::F64Nearest
+            | Operator::F64Sqrt
+            | Operator::F64Add
+            | Operator::F64Sub
+            | Operator::F64Mul
+            | Operator::F64Div
+            | Operator::F64Min
+            | Operator::F64Max
+            | Operator::F64Copysign
+            | Operator::I32TruncF32S
+            | Operator::I32TruncF32U
+            | Operator::I32TruncF64S
+            | Operator::I32TruncF64U
+            | Operator::I64TruncF32S
+            | Operator::I64TruncF32U
+            | Operator::I64TruncF64S
+            | Operator::I64TruncF64U
+            | Operator::F32ConvertI32S
+            | Operator::F32ConvertI32U
+            | Operator::F32ConvertI64S
+            | Operator::F32ConvertI64U
+            | Operator::F32DemoteF64
+            | Operator::F64ConvertI32S
+            | Operator::F64ConvertI32U
+            | Operator::F64ConvertI64S
+            | Operator::F64ConvertI64U
+            | Operator::F64PromoteF32
+            | Operator::I32ReinterpretF32
+            | Operator::I64ReinterpretF64
+            | Operator::F32ReinterpretI32
+            | Operator::F64ReinterpretI64
+            | Operator::I32TruncSatF32S
+            | Operator::I32TruncSatF32U
+            | Operator::I32TruncSatF64S
+            | Operator::I32TruncSatF64U
+            | Operator::I64TruncSatF32S
+            | Operator::I64TruncSatF32U
+            | Operator::I64TruncSatF64S
+            | Operator::I64TruncSatF64U
+    )
+}
+
+impl WasmReport {
+    /// Inspects the wasm bytecode, comparing it against the ABI of the same output contract.
+    pub fn analyze(wasm_name: &str, wasm_bytes: &[u8], abi: &ContractAbi) -> Result<Self, String> {
+        let mut report = WasmReport {
+            wasm_name: wasm_name.to_string(),
+            size_before_opt: wasm_bytes.len() as u64,
+            ..Default::default()
+        };
+        let mut imported_function_count = 0u32;
+        let mut function_names = BTreeMap::new();
+        let mut function_sizes = Vec::new();
+
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            match payload.map_err(|err| err.to_string())? {
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import.map_err(|err| err.to_string())?;
+                        match import.ty {
+                            ImportSectionEntryType::Function(_) => {
+                                imported_function_count += 1;
+                                report
+                                    .imports
+                                    .push(import.field.unwrap_or_default().to_string());
+                            },
+                            ImportSectionEntryType::Memory(memory_type) => {
+                                report.memory.initial_pages = memory_type.initial;
+                                report.memory.max_pages = memory_type.maximum;
+                            },
+                            _ => {},
+                        }
+                    }
+                },
+                Payload::MemorySection(reader) => {
+                    for memory_type in reader {
+                        let memory_type = memory_type.map_err(|err| err.to_string())?;
+                        report.memory.initial_pages = memory_type.initial;
+                        report.memory.max_pages = memory_type.maximum;
+                    }
+                },
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export.map_err(|err| err.to_string())?;
+                        if let ExternalKind::Function = export.kind {
+                            report.exports.push(export.field.to_string());
+                            function_names.insert(export.index, export.field.to_string());
+                        }
+                    }
+                },
+                Payload::DataSection(reader) => {
+                    for data in reader {
+                        report.memory.data_size += data.map_err(|err| err.to_string())?.data.len();
+                    }
+                },
+                Payload::CodeSectionEntry(body) => {
+                    let function_index = imported_function_count + function_sizes.len() as u32;
+                    let range = body.range();
+                    function_sizes.push((function_index, range.end - range.start));
+
+                    let operators = body.get_operators_reader().map_err(|err| err.to_string())?;
+                    for operator in operators {
+                        let operator = operator.map_err(|err| err.to_string())?;
+                        if let Operator::MemoryGrow { .. } = operator {
+                            report.memory.uses_memory_grow = true;
+                        } else if is_floating_point_operator(&operator) {
+                            report.has_floating_point = true;
+                        }
+                    }
+                },
+                Payload::CustomSection {
+                    name: "name",
+                    data,
+                    data_offset,
+                    ..
+                } => {
+                    read_function_names(data, data_offset, &mut function_names)?;
+                },
+                _ => {},
+            }
+        }
+
+        report.ei_1_2_imports = report
+            .imports
+            .iter()
+            .filter(|import| EI_1_2_HOOKS.contains(&import.as_str()))
+            .cloned()
+            .collect();
+
+        for endpoint in &abi.endpoints {
+            if !report.exports.iter().any(|export| export == endpoint.name) {
+                report.missing_endpoints.push(endpoint.name.to_string());
+            }
+        }
+        let has_upgrade = !abi.upgrade_constructors.is_empty();
+        for export in &report.exports {
+            let is_endpoint = abi.endpoints.iter().any(|endpoint| endpoint.name == export);
+            let is_implicit = IMPLICIT_EXPORTS.contains(&export.as_str())
+                || (has_upgrade && export == UPGRADE_EXPORT);
+            if !is_endpoint && !is_implicit {
+                report.unexpected_exports.push(export.clone());
+            }
+        }
+
+        function_sizes.sort_by(|(_, a), (_, b)| b.cmp(a));
+        report.largest_functions = function_sizes
+            .into_iter()
+            .take(LARGEST_FUNCTIONS_COUNT)
+            .map(|(index, size)| WasmFunctionSize {
+                name: function_names
+                    .get(&index)
+                    .cloned()
+                    .unwrap_or_else(|| format!("func[{}]", index)),
+                size,
+            })
+            .collect();
+
+        Ok(report)
+    }
+
+    /// Short human-readable version, printed after the build.
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+        let _ = write!(
+            summary,
+            "{}: {} bytes",
+            self.wasm_name, self.size_before_opt
+        );
+        if let Some(size_after_opt) = self.size_after_opt {
+            let _ = write!(summary, ", {} bytes after wasm-opt", size_after_opt);
+        }
+        let _ = writeln!(summary);
+        let _ = writeln!(
+            summary,
+            "    {} imports, {} exports, memory: {} pages, {} bytes of data{}",
+            self.imports.len(),
+            self.exports.len(),
+            self.memory.initial_pages,
+            self.memory.data_size,
+            if self.memory.uses_memory_grow {
+                ", allocates"
+            } else {
+                ""
+            }
+        );
+        if !self.ei_1_2_imports.is_empty() {
+            let _ = writeln!(
+                summary,
+                "    Warning: requires EI 1.2: {}",
+                self.ei_1_2_imports.join(", ")
+            );
+        }
+        if !self.missing_endpoints.is_empty() {
+            let _ = writeln!(
+                summary,
+                "    Warning: endpoints not exported: {}",
+                self.missing_endpoints.join(", ")
+            );
+        }
+        if !self.unexpected_exports.is_empty() {
+            let _ = writeln!(
+                summary,
+                "    Warning: exports not in the ABI: {}",
+                self.unexpected_exports.join(", ")
+            );
+        }
+        if self.has_floating_point {
+            let _ = writeln!(summary, "    Warning: uses floating point instructions");
+        }
+        if let Some(largest) = self.largest_functions.first() {
+            let _ = writeln!(
+                summary,
+                "    largest function: {} ({} bytes)",
+                largest.name, largest.size
+            );
+        }
+        summary
+    }
+}
+
+/// Function names are only available if the contract was built with `--wasm-symbols`.
+fn read_function_names(
+    data: &[u8],
+    data_offset: usize,
+    function_names: &mut BTreeMap<u32, String>,
+) -> Result<(), String> {
+    let mut reader = NameSectionReader::new(data, data_offset).map_err(|err| err.to_string())?;
+    while !reader.eof() {
+        if let Name::Function(name_map) = reader.read().map_err(|err| err.to_string())? {
+            let mut naming_reader = name_map.get_map().map_err(|err| err.to_string())?;
+            for _ in 0..naming_reader.get_count() {
+                let naming = naming_reader.read().map_err(|err| err.to_string())?;
+                function_names
+                    .entry(naming.index)
+                    .or_insert_with(|| naming.name.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Analyzes the built wasm and writes `<name>.report.json` next to it.
+pub fn write_wasm_report(
+    contract_metadata: &ContractMetadata,
+    wasm_path: &str,
+    size_before_opt: u64,
+    optimized: bool,
+) {
+    let wasm_bytes = fs::read(wasm_path).expect("failed to read the built contract");
+    let mut report = WasmReport::analyze(
+        wasm_path.rsplit('/').next().unwrap_or(wasm_path),
+        wasm_bytes.as_slice(),
+        &contract_metadata.abi,
+    )
+    .expect("failed to analyze the built contract");
+    report.size_before_opt = size_before_opt;
+    if optimized {
+        report.size_after_opt = Some(wasm_bytes.len() as u64);
+    }
+
+    let report_path = format!("{}.report.json", wasm_path.trim_end_matches(".wasm"));
+    let report_json = serde_json::to_string_pretty(&report).unwrap();
+    fs::write(report_path.as_str(), report_json).expect("failed to write the wasm report");
+
+    print!("{}", report.summary());
+}
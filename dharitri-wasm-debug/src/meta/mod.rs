@@ -1,10 +1,26 @@
 mod meta_abi;
+mod meta_build_manifest;
 mod meta_cargo_toml;
 mod meta_config;
+mod meta_dharitri_json;
 mod meta_main;
+mod meta_storage_layout;
+mod meta_ts_bindings;
+mod meta_upgrade_check;
 mod meta_validate_abi;
 mod meta_wasm_build;
 mod meta_wasm_clean;
 mod meta_wasm_crates;
+mod meta_wasm_report;
 
+pub use meta_build_manifest::{
+    cargo_lock_hash, compare_build_manifests, sha256_hex, source_hash, BuildManifestJson,
+    BuildMismatch,
+};
+pub use meta_config::{BuildArgs, ContractMetadata, MetaConfig};
+pub use meta_dharitri_json::{DharitriJson, OutputContractJson};
 pub use meta_main::perform;
+pub use meta_storage_layout::storage_key_prefix_warnings;
+pub use meta_ts_bindings::{generate_ts_bindings, ts_method_name, ts_type, TsTypeUse};
+pub use meta_upgrade_check::{check_upgrade_compatibility, UpgradeIssue};
+pub use meta_wasm_report::{WasmFunctionSize, WasmMemoryReport, WasmReport, EI_1_2_HOOKS};
@@ -1,11 +1,12 @@
 use std::{collections::HashMap, path::PathBuf, rc::Rc, str::FromStr};
 
 use dharitri_wasm::{
+    abi::ContractAbi,
     contract_base::{CallableContract, ContractBase},
     dharitri_codec::{TopDecode, TopEncode},
     types::{
         heap::{Address, H256},
-        DctLocalRole,
+        DctLocalRole, InteractorCall, InteractorDeploy, OperationCompletionStatus,
     },
 };
 use num_traits::Zero;
@@ -14,14 +15,16 @@ use crate::{
     num_bigint,
     testing_framework::raw_converter::bytes_to_hex,
     tx_execution::{execute_async_call_and_callback, interpret_panic_as_tx_result},
-    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxResult},
+    tx_mock::{
+        DecodedEvent, TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxPanic, TxResult,
+    },
     world_mock::{is_smart_contract_address, AccountData, AccountDct, DctInstanceMetadata},
     BlockchainMock, DebugApi,
 };
 
 use super::{
     tx_denali::{ScCallDenali, TxExpectDenali},
-    AddressFactory, DenaliGenerator, ScQueryDenali,
+    AddressFactory, DenaliGenerator, ExpectedEvent, ScQueryDenali,
 };
 
 pub struct ContractObjWrapper<
@@ -168,6 +171,40 @@ impl BlockchainStateWrapper {
             );
         }
     }
+
+    /// Decodes the logs of a transaction, using the ABIs given via `register_contract_abi`.
+    ///
+    /// Logs of contracts without a registered ABI are skipped.
+    pub fn decode_logs(&self, tx_result: &TxResult) -> Vec<DecodedEvent> {
+        tx_result
+            .result_logs
+            .iter()
+            .filter_map(|log| self.rc_b_mock.decode_log(log))
+            .map(|decode_result| {
+                decode_result.unwrap_or_else(|err| panic!("Could not decode log: {}", err))
+            })
+            .collect()
+    }
+
+    pub fn check_event(&self, tx_result: &TxResult, expected_event: &ExpectedEvent) {
+        let decoded_events = self.decode_logs(tx_result);
+        if decoded_events
+            .iter()
+            .any(|decoded_event| expected_event.matches(decoded_event))
+        {
+            return;
+        }
+
+        let mut have = String::new();
+        for decoded_event in &decoded_events {
+            have.push_str("\n  ");
+            have.push_str(decoded_event.to_string().as_str());
+        }
+        panic!(
+            "Event not found\n Expected: {}\n Have:{}\n",
+            expected_event, have
+        );
+    }
 }
 
 impl BlockchainStateWrapper {
@@ -260,6 +297,35 @@ impl BlockchainStateWrapper {
         ContractObjWrapper::new(address.clone(), obj_builder)
     }
 
+    /// Allows decoding the logs of the contract, with `decode_logs` and `check_event`.
+    ///
+    /// The ABI is shared by all the contracts with the same code.
+    pub fn register_contract_abi<CB, ContractObjBuilder>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        abi: ContractAbi,
+    ) where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        let contract_path = self
+            .rc_b_mock
+            .accounts
+            .get(sc_wrapper.address_ref())
+            .and_then(|acc| acc.contract_path.clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "No contract at address {}",
+                    address_to_hex(sc_wrapper.address_ref())
+                )
+            });
+
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref
+            .contract_map
+            .register_contract_abi(contract_path, abi);
+    }
+
     pub fn create_account_raw(
         &mut self,
         address: &Address,
@@ -323,6 +389,91 @@ impl BlockchainStateWrapper {
         ContractObjWrapper::new(old_wrapper.address, new_builder)
     }
 
+    /// Upgrades the contract to the new code and calls `upgrade` with the given arguments, like the VM does on upgrade,
+    /// falling back to `init` for contracts without an `#[upgrade]` method.
+    ///
+    /// The migration transaction, typically one calling `run_migrations`, is then repeated until it completes,
+    /// just like a migration split over several gas-limited calls.
+    /// Finally, the check runs as a query against the new code, to verify that the old storage decodes correctly after the migration.
+    pub fn upgrade_wrapper_with_migration<
+        OldCB,
+        OldContractObjBuilder,
+        NewCB,
+        NewContractObjBuilder,
+        MigrationFn,
+        CheckFn,
+    >(
+        &mut self,
+        caller: &Address,
+        old_wrapper: ContractObjWrapper<OldCB, OldContractObjBuilder>,
+        new_builder: NewContractObjBuilder,
+        upgrade_args: Vec<Vec<u8>>,
+        migration_fn: MigrationFn,
+        check_fn: CheckFn,
+    ) -> ContractObjWrapper<NewCB, NewContractObjBuilder>
+    where
+        OldCB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        OldContractObjBuilder: 'static + Copy + Fn() -> OldCB,
+        NewCB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        NewContractObjBuilder: 'static + Copy + Fn() -> NewCB,
+        MigrationFn: Fn(NewCB) -> OperationCompletionStatus,
+        CheckFn: FnOnce(NewCB),
+    {
+        let new_wrapper = self.upgrade_wrapper(old_wrapper, new_builder);
+        let upgrade_func_name = if new_builder().has_upgrade_constructor() {
+            UPGRADE_FUNC_NAME
+        } else {
+            INIT_FUNC_NAME
+        };
+
+        let upgrade_result = self.execute_tx_any(
+            caller,
+            &new_wrapper,
+            &num_bigint::BigUint::zero(),
+            Vec::new(),
+            upgrade_func_name,
+            upgrade_args,
+            |sc| call_endpoint(&sc, upgrade_func_name),
+        );
+        assert!(
+            upgrade_result.result_status == 0,
+            "upgrade failed: {}",
+            upgrade_result.result_message
+        );
+
+        let mut num_migration_calls = 0;
+        loop {
+            let mut status = OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            let migration_result =
+                self.execute_tx(caller, &new_wrapper, &num_bigint::BigUint::zero(), |sc| {
+                    status = migration_fn(sc)
+                });
+            assert!(
+                migration_result.result_status == 0,
+                "migration failed: {}",
+                migration_result.result_message
+            );
+            if status.is_completed() {
+                break;
+            }
+            num_migration_calls += 1;
+            assert!(
+                num_migration_calls < MAX_MIGRATION_CALLS,
+                "migration did not complete after {} calls",
+                MAX_MIGRATION_CALLS
+            );
+        }
+
+        let check_result = self.execute_query(&new_wrapper, check_fn);
+        assert!(
+            check_result.result_status == 0,
+            "storage check failed after migration: {}",
+            check_result.result_message
+        );
+
+        new_wrapper
+    }
+
     pub fn set_moax_balance(&mut self, address: &Address, balance: &num_bigint::BigUint) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         match b_mock_ref.accounts.get_mut(address) {
@@ -597,7 +748,15 @@ impl BlockchainStateWrapper {
         ContractObjBuilder: 'static + Copy + Fn() -> CB,
         TxFn: FnOnce(CB),
     {
-        self.execute_tx_any(caller, sc_wrapper, moax_payment, Vec::new(), tx_fn)
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            moax_payment,
+            Vec::new(),
+            &[],
+            Vec::new(),
+            tx_fn,
+        )
     }
 
     pub fn execute_dct_transfer<CB, ContractObjBuilder, TxFn>(
@@ -624,6 +783,8 @@ impl BlockchainStateWrapper {
             sc_wrapper,
             &num_bigint::BigUint::zero(),
             dct_transfer,
+            &[],
+            Vec::new(),
             tx_fn,
         )
     }
@@ -644,6 +805,8 @@ impl BlockchainStateWrapper {
             sc_wrapper,
             &num_bigint::BigUint::zero(),
             dct_transfers.to_vec(),
+            &[],
+            Vec::new(),
             tx_fn,
         )
     }
@@ -665,13 +828,89 @@ impl BlockchainStateWrapper {
         )
     }
 
+    /// Executes a call built by a generated `Interactor`, through the contract function selector.
+    ///
+    /// Unlike the closure-based methods, the result contains the encoded endpoint results.
+    pub fn execute_interactor_call<CB, ContractObjBuilder, OriginalResult>(
+        &mut self,
+        caller: &Address,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        call: &InteractorCall<OriginalResult>,
+    ) -> TxResult
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        assert_eq!(
+            &call.to,
+            sc_wrapper.address_ref(),
+            "interactor call targets a different contract"
+        );
+        let dct_payments = call
+            .payments
+            .iter()
+            .map(|payment| TxInputDCT {
+                token_identifier: payment.token_identifier.as_slice().to_vec(),
+                nonce: payment.nonce,
+                value: num_bigint::BigUint::from_bytes_be(payment.amount.as_slice()),
+            })
+            .collect();
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            &num_bigint::BigUint::from_bytes_be(call.moax_payment.as_slice()),
+            dct_payments,
+            call.endpoint_name.as_slice(),
+            call.arg_buffer.clone(),
+            |sc| call_endpoint(&sc, call.endpoint_name.as_slice()),
+        )
+    }
+
+    pub fn execute_interactor_query<CB, ContractObjBuilder, OriginalResult>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        call: &InteractorCall<OriginalResult>,
+    ) -> TxResult
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        self.execute_interactor_call(sc_wrapper.address_ref(), sc_wrapper, call)
+    }
+
+    /// Runs the constructor of an account created with `create_sc_account`,
+    /// with the arguments of a deploy built by a generated `Interactor`.
+    pub fn execute_interactor_deploy<CB, ContractObjBuilder, OriginalResult>(
+        &mut self,
+        caller: &Address,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        deploy: &InteractorDeploy<OriginalResult>,
+    ) -> TxResult
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            &num_bigint::BigUint::from_bytes_be(deploy.moax_payment.as_slice()),
+            Vec::new(),
+            INIT_FUNC_NAME,
+            deploy.arg_buffer.clone(),
+            |sc| call_endpoint(&sc, INIT_FUNC_NAME),
+        )
+    }
+
     // deduplicates code for execution
+    #[allow(clippy::too_many_arguments)]
     fn execute_tx_any<CB, ContractObjBuilder, TxFn: FnOnce(CB)>(
         &mut self,
         caller: &Address,
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         moax_payment: &num_bigint::BigUint,
         dct_payments: Vec<TxInputDCT>,
+        func_name: &[u8],
+        args: Vec<Vec<u8>>,
         tx_fn: TxFn,
     ) -> TxResult
     where
@@ -705,7 +944,9 @@ impl BlockchainStateWrapper {
             }
         }
 
-        let tx_input = build_tx_input(caller, sc_address, moax_payment, dct_payments);
+        let mut tx_input = build_tx_input(caller, sc_address, moax_payment, dct_payments);
+        tx_input.func_name = func_name.to_vec();
+        tx_input.args = args;
         let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
         TxContextStack::static_push(tx_context_rc);
 
@@ -713,9 +954,16 @@ impl BlockchainStateWrapper {
         let exec_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tx_fn(sc)));
 
         let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
+        let exec_output = api_after_exec.extract_result();
         let updates = api_after_exec.into_blockchain_updates();
         let tx_result = match exec_result {
-            Ok(()) => TxResult::empty(),
+            Ok(()) => {
+                // async calls from the closures are not performed
+                let mut tx_result = TxResult::empty();
+                tx_result.result_values = exec_output.result_values;
+                tx_result.result_logs = exec_output.result_logs;
+                tx_result
+            },
             Err(panic_any) => interpret_panic_as_tx_result(panic_any),
         };
 
@@ -889,6 +1137,19 @@ fn build_tx_input(
     }
 }
 
+const INIT_FUNC_NAME: &[u8] = b"init";
+const UPGRADE_FUNC_NAME: &[u8] = b"upgrade";
+const MAX_MIGRATION_CALLS: usize = 1000;
+
+fn call_endpoint<CB: CallableContract>(sc: &CB, func_name: &[u8]) {
+    if !sc.call(func_name) {
+        std::panic::panic_any(TxPanic {
+            status: 1,
+            message: "invalid function (not found)".to_string(),
+        });
+    }
+}
+
 fn address_to_hex(address: &Address) -> String {
     hex::encode(address.as_bytes())
 }
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use dharitri_wasm::dharitri_codec::TopEncodeMulti;
+
+use crate::{tx_mock::DecodedEvent, verbose_hex_list};
+
+/// Describes an event that a transaction is expected to emit, see `BlockchainStateWrapper::check_event`.
+///
+/// Only the given fields are checked, the others can have any value.
+pub struct ExpectedEvent {
+    identifier: String,
+    fields: Vec<(String, Vec<Vec<u8>>)>,
+}
+
+impl ExpectedEvent {
+    pub fn new(identifier: &str) -> Self {
+        ExpectedEvent {
+            identifier: identifier.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// The value is encoded right away, so managed types need to be created in a managed environment.
+    #[must_use]
+    pub fn with_field<T: TopEncodeMulti>(mut self, name: &str, value: &T) -> Self {
+        let mut encoded = Vec::new();
+        value.multi_encode(&mut encoded).unwrap();
+        self.fields.push((name.to_string(), encoded));
+        self
+    }
+
+    pub fn matches(&self, event: &DecodedEvent) -> bool {
+        event.identifier == self.identifier
+            && self
+                .fields
+                .iter()
+                .all(|(name, encoded)| match event.field(name.as_str()) {
+                    Some(field) => &field.raw == encoded,
+                    None => false,
+                })
+    }
+}
+
+impl fmt::Display for ExpectedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.identifier)?;
+        for (i, (name, encoded)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {}", name, verbose_hex_list(encoded.as_slice()))?;
+        }
+        write!(f, ")")
+    }
+}
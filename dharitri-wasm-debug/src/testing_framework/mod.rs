@@ -1,12 +1,14 @@
 mod address_factory;
 mod contract_obj_wrapper;
-mod helper_macros;
 mod denali_generator;
+mod expected_event;
+mod helper_macros;
 mod raw_converter;
 mod tx_denali;
 
 use address_factory::*;
 pub use contract_obj_wrapper::*;
-pub use helper_macros::*;
 use denali_generator::*;
+pub use expected_event::*;
+pub use helper_macros::*;
 pub use tx_denali::*;
@@ -25,6 +25,17 @@ pub fn execute_upgrade_contract(
         Vec::new()
     };
 
+    // contracts with an `#[upgrade]` method export it as `upgrade`, the others get `init` called again
+    let func_name = if tx_cache
+        .blockchain_ref()
+        .contract_map
+        .has_upgrade_constructor(new_code.as_slice())
+    {
+        b"upgrade".to_vec()
+    } else {
+        b"init".to_vec()
+    };
+
     tx_cache.with_account_mut(&tx_input.to, |account| {
         account.contract_path = Some(new_code);
     });
@@ -34,7 +45,7 @@ pub fn execute_upgrade_contract(
         to: tx_input.to,
         moax_value: tx_input.moax_value,
         dct_values: Vec::new(),
-        func_name: b"init".to_vec(),
+        func_name,
         args,
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
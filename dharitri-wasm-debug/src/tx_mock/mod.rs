@@ -12,6 +12,7 @@ mod tx_context_stack;
 mod tx_input;
 mod tx_input_util;
 mod tx_log;
+mod tx_log_decode;
 mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
@@ -28,6 +29,7 @@ pub use tx_context_stack::*;
 pub use tx_input::*;
 pub use tx_input_util::*;
 pub use tx_log::*;
+pub use tx_log_decode::*;
 pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
@@ -1,15 +1,38 @@
 use super::TxContext;
 
+use dharitri_wasm::types::heap::Address;
 use std::{cell::RefCell, rc::Rc};
 
 thread_local!(
     static API_STACK: RefCell<TxContextStack> = RefCell::new(TxContextStack::default())
 );
 
+/// Holds one context for each contract execution in progress.
+///
+/// Synchronous calls push their context on top of the caller's,
+/// so contracts calling each other back and forth, reentrant calls included, show up as consecutive contexts.
 #[derive(Debug, Default)]
 pub struct TxContextStack(Vec<Rc<TxContext>>);
 
 impl TxContextStack {
+    /// The contexts of the transaction currently executing, outermost first.
+    ///
+    /// Nested calls inherit the hash of the transaction that started them,
+    /// which tells them apart from unrelated contexts lower in the stack, e.g. the ones created by `DebugApi::dummy()`.
+    fn current_tx_contexts(&self) -> &[Rc<TxContext>] {
+        let top_tx_hash = match self.0.last() {
+            Some(top) => &top.input_ref().tx_hash,
+            None => return &[],
+        };
+        let num_current = self
+            .0
+            .iter()
+            .rev()
+            .take_while(|tx_context| &tx_context.input_ref().tx_hash == top_tx_hash)
+            .count();
+        &self.0[self.0.len() - num_current..]
+    }
+
     pub fn static_peek() -> Rc<TxContext> {
         API_STACK.with(|cell| {
             let stack = cell.borrow();
@@ -30,4 +53,26 @@ impl TxContextStack {
             stack.0.pop().unwrap()
         })
     }
+
+    /// 0 for the contract called directly by the transaction, 1 for a contract it called synchronously, etc.
+    pub fn static_call_depth() -> usize {
+        API_STACK.with(|cell| {
+            let stack = cell.borrow();
+            stack.current_tx_contexts().len().saturating_sub(1)
+        })
+    }
+
+    /// The sender of the transaction currently executing.
+    pub fn static_original_caller() -> Address {
+        API_STACK.with(|cell| {
+            let stack = cell.borrow();
+            stack
+                .current_tx_contexts()
+                .first()
+                .expect("no transaction executing")
+                .input_ref()
+                .from
+                .clone()
+        })
+    }
 }
@@ -0,0 +1,221 @@
+use std::fmt;
+
+use dharitri_wasm::{
+    abi::{ContractAbi, EventInputAbi},
+    dharitri_codec::{
+        DecodeError, DefaultErrorHandler, TopDecode, TopDecodeMulti, TopDecodeMultiInput,
+        TopEncodeMulti,
+    },
+    types::{heap::Address, InteractorEventData},
+};
+
+use crate::{bytes_to_string, verbose_hex_list};
+
+use super::TxLog;
+
+/// An event argument, as found in a transaction log.
+#[derive(Clone, Debug)]
+pub struct DecodedEventField {
+    pub name: String,
+    pub type_name: String,
+    pub indexed: bool,
+
+    /// The topics of the argument if indexed, the log data otherwise.
+    ///
+    /// For events with structured data, only the part of the data belonging to the argument.
+    pub raw: Vec<Vec<u8>>,
+}
+
+impl DecodedEventField {
+    pub fn decode<T: TopDecodeMulti>(&self) -> Result<T, DecodeError> {
+        let mut raw = self.raw.clone();
+        let value = T::multi_decode(&mut raw)?;
+        raw.assert_no_more_args(DefaultErrorHandler)?;
+        Ok(value)
+    }
+
+    /// Compares encoded forms, so any type with the same encoding will do,
+    /// e.g. `u64` for a `BigUint` field.
+    pub fn encodes_as<T: TopEncodeMulti>(&self, value: &T) -> bool {
+        let mut encoded = Vec::new();
+        value.multi_encode(&mut encoded).unwrap();
+        encoded == self.raw
+    }
+}
+
+/// A transaction log, with its topics and data assigned to the event arguments declared in the ABI.
+#[derive(Clone, Debug)]
+pub struct DecodedEvent {
+    pub address: Address,
+    pub identifier: String,
+    pub fields: Vec<DecodedEventField>,
+}
+
+impl DecodedEvent {
+    pub fn field(&self, name: &str) -> Option<&DecodedEventField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl fmt::Display for DecodedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.identifier)?;
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(
+                f,
+                "{}: {} = {}",
+                field.name,
+                field.type_name,
+                verbose_hex_list(field.raw.as_slice())
+            )?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl TxLog {
+    /// Identifies the event by the first topic and splits the other topics between the indexed arguments.
+    ///
+    /// Multi-value arguments can take up several topics, or none.
+    /// Events with structured data get their data split between the non-indexed arguments.
+    pub fn decode(&self, abi: &ContractAbi) -> Result<DecodedEvent, String> {
+        let (identifier_topic, mut remaining_topics) = self
+            .topics
+            .split_first()
+            .ok_or_else(|| "log has no topics".to_string())?;
+        let event_abi = abi
+            .events
+            .iter()
+            .find(|event| event.identifier.as_bytes() == identifier_topic.as_slice())
+            .ok_or_else(|| format!("unknown event `{}`", bytes_to_string(identifier_topic)))?;
+
+        let mut structured_data = if event_abi.has_structured_data() {
+            let event_data = InteractorEventData::new(self.data.as_slice(), event_abi.version)
+                .map_err(|err| format!("invalid event data: {}", err.message_str()))?;
+            Some(event_data)
+        } else {
+            None
+        };
+
+        let mut fields = Vec::new();
+        let mut data_used = false;
+        for input in &event_abi.inputs {
+            let raw = if input.indexed {
+                let topic_count = topic_count(input.type_name.as_str(), remaining_topics)
+                    .map_err(|err| format!("argument `{}`: {}", input.arg_name, err))?;
+                let (topics, rest) = remaining_topics.split_at(topic_count);
+                remaining_topics = rest;
+                topics.to_vec()
+            } else if let Some(event_data) = &mut structured_data {
+                let raw_field = event_data
+                    .next_raw_field(input.since.unwrap_or(1))
+                    .map_err(|err| {
+                        format!("argument `{}`: {}", input.arg_name, err.message_str())
+                    })?;
+                vec![raw_field]
+            } else {
+                data_used = true;
+                vec![self.data.clone()]
+            };
+            fields.push(decoded_field(input, raw));
+        }
+
+        if !remaining_topics.is_empty() {
+            return Err(format!(
+                "event `{}` has {} unexpected topics",
+                event_abi.identifier,
+                remaining_topics.len()
+            ));
+        }
+        if let Some(event_data) = structured_data {
+            event_data.finish().map_err(|err| {
+                format!("event `{}`: {}", event_abi.identifier, err.message_str())
+            })?;
+        } else if !data_used && !self.data.is_empty() {
+            return Err(format!(
+                "event `{}` has unexpected data",
+                event_abi.identifier
+            ));
+        }
+
+        Ok(DecodedEvent {
+            address: self.address.clone(),
+            identifier: event_abi.identifier.to_string(),
+            fields,
+        })
+    }
+}
+
+fn decoded_field(input: &EventInputAbi, raw: Vec<Vec<u8>>) -> DecodedEventField {
+    DecodedEventField {
+        name: input.arg_name.to_string(),
+        type_name: input.type_name.clone(),
+        indexed: input.indexed,
+        raw,
+    }
+}
+
+/// How many of the remaining topics belong to an argument of the given ABI type.
+fn topic_count(type_name: &str, remaining_topics: &[Vec<u8>]) -> Result<usize, String> {
+    let count = if type_name == "ignore" || type_name.starts_with("variadic<") {
+        remaining_topics.len()
+    } else if let Some(item_type) = generic_arg(type_name, "counted-variadic<") {
+        let (count_topic, rest) = remaining_topics
+            .split_first()
+            .ok_or_else(|| "missing item count".to_string())?;
+        let item_count = usize::top_decode(count_topic.as_slice())
+            .map_err(|err| format!("invalid item count: {}", err.message_str()))?;
+        let mut count = 1;
+        for _ in 0..item_count {
+            count += topic_count(item_type, &rest[count - 1..])?;
+        }
+        count
+    } else if let Some(item_type) = generic_arg(type_name, "optional<") {
+        if remaining_topics.is_empty() {
+            0
+        } else {
+            topic_count(item_type, remaining_topics)?
+        }
+    } else if let Some(item_types) = generic_arg(type_name, "multi<") {
+        let mut count = 0;
+        for item_type in split_top_level(item_types) {
+            count += topic_count(item_type, &remaining_topics[count..])?;
+        }
+        count
+    } else {
+        1
+    };
+
+    if count > remaining_topics.len() {
+        Err("too few topics".to_string())
+    } else {
+        Ok(count)
+    }
+}
+
+fn generic_arg<'a>(type_name: &'a str, prefix: &str) -> Option<&'a str> {
+    type_name.strip_prefix(prefix)?.strip_suffix('>')
+}
+
+/// Splits `A,B<C,D>,E` into `A`, `B<C,D>` and `E`.
+fn split_top_level(type_names: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in type_names.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(&type_names[start..i]);
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    result.push(&type_names[start..]);
+    result
+}
@@ -82,6 +82,7 @@ pub struct TxStaticVars {
     pub(crate) num_arguments: i32,
     pub(crate) call_value_moax_handle: Handle,
     pub(crate) call_value_multi_dct_handle: Handle,
+    pub(crate) non_reentrant_lock_held: bool,
 }
 
 impl Default for TxStaticVars {
@@ -92,6 +93,7 @@ impl Default for TxStaticVars {
             num_arguments: -1,
             call_value_moax_handle: const_handles::UNINITIALIZED_HANDLE,
             call_value_multi_dct_handle: const_handles::UNINITIALIZED_HANDLE,
+            non_reentrant_lock_held: false,
         }
     }
 }
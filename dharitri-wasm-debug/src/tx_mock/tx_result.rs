@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
 
+use dharitri_wasm::types::{parse_contract_error_code, ContractEvent};
 use std::fmt;
 
 use super::{TxLog, TxPanic, TxResultCalls};
@@ -109,6 +110,55 @@ impl TxResult {
     pub fn assert_user_error(&self, expected_message: &str) {
         self.assert_error(4, expected_message);
     }
+
+    /// The code of the error, if the endpoint returned a `ContractError`.
+    pub fn contract_error_code(&self) -> Option<u32> {
+        parse_contract_error_code(self.result_message.as_bytes()).map(|(code, _)| code)
+    }
+
+    /// The formatted message of the error, without the code prefix,
+    /// if the endpoint returned a `ContractError`.
+    pub fn contract_error_message(&self) -> Option<&str> {
+        parse_contract_error_code(self.result_message.as_bytes())
+            .map(|(_, message_start)| &self.result_message[message_start..])
+    }
+
+    pub fn assert_contract_error(&self, expected_code: u32, expected_message: &str) {
+        assert!(
+            self.result_status == 4
+                && self.contract_error_code() == Some(expected_code)
+                && self.contract_error_message() == Some(expected_message),
+            "Tx contract error mismatch. Want code {}, message \"{}\". Have status {}, message \"{}\"",
+            expected_code,
+            expected_message,
+            self.result_status,
+            self.result_message.as_str()
+        );
+    }
+
+    /// Decodes all logs of the given event, in the order in which they were emitted.
+    ///
+    /// Event types are generated in the `typed_events` module of each contract or module.
+    /// Panics if one of the matching logs cannot be decoded.
+    pub fn collect_events<E: ContractEvent>(&self) -> Vec<E> {
+        self.result_logs
+            .iter()
+            .filter(|log| {
+                log.topics
+                    .first()
+                    .is_some_and(|identifier| identifier.as_slice() == E::IDENTIFIER.as_bytes())
+            })
+            .map(|log| {
+                E::decode_event(log.topics.as_slice(), log.data.as_slice()).unwrap_or_else(|err| {
+                    panic!(
+                        "could not decode event `{}`: {}",
+                        E::IDENTIFIER,
+                        err.message_str()
+                    )
+                })
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for TxResult {
@@ -1,4 +1,8 @@
-use crate::{num_bigint::BigUint, tx_mock::BlockchainUpdate, ContractMap};
+use crate::{
+    num_bigint::BigUint,
+    tx_mock::{BlockchainUpdate, DecodedEvent, TxLog},
+    ContractMap,
+};
 use dharitri_wasm::types::heap::Address;
 use denali::{
     interpret_trait::InterpreterContext, model::Scenario, value_interpreter::interpret_string,
@@ -57,6 +61,17 @@ impl BlockchainMock {
         self.contract_map.contains_contract(&contract_bytes)
     }
 
+    /// Decodes a log using the ABI registered for the contract that emitted it.
+    ///
+    /// Returns `None` if the emitter is unknown, or if no ABI was registered for its code.
+    pub fn decode_log(&self, log: &TxLog) -> Option<Result<DecodedEvent, String>> {
+        let contract_path = self.accounts.get(&log.address)?.contract_path.as_ref()?;
+        let abi = self
+            .contract_map
+            .get_contract_abi(contract_path.as_slice())?;
+        Some(log.decode(abi))
+    }
+
     pub fn commit_updates(&mut self, updates: BlockchainUpdate) {
         updates.apply(self);
     }
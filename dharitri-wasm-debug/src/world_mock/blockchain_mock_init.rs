@@ -1,7 +1,10 @@
 use std::path::{Path, PathBuf};
 
-use dharitri_wasm::contract_base::{CallableContract, CallableContractBuilder};
 use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
+use dharitri_wasm::{
+    abi::ContractAbi,
+    contract_base::{CallableContract, CallableContractBuilder},
+};
 
 use crate::DebugApi;
 
@@ -47,6 +50,12 @@ impl BlockchainMock {
             .register_contract(contract_bytes, new_contract_obj);
     }
 
+    /// Allows the logs of the contract to be decoded, e.g. when reporting mismatches.
+    pub fn register_contract_abi(&mut self, expression: &str, abi: ContractAbi) {
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.contract_map.register_contract_abi(contract_bytes, abi);
+    }
+
     pub fn register_contract_builder<B: CallableContractBuilder>(
         &mut self,
         expression: &str,
@@ -0,0 +1,13 @@
+/// Contracts rejected by the validation of the contract macros, each with the expected diagnostics.
+#[test]
+fn derive_validation_ui_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+/// Contracts accepted by the validation of the contract macros, in cases that are easy to reject by mistake.
+#[test]
+fn derive_validation_ui_pass_test() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui_pass/*.rs");
+}
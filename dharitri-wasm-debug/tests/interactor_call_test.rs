@@ -0,0 +1,132 @@
+use dharitri_wasm::types::{
+    heap::Address, BigUint, CodeMetadata, ContractCall, ContractDeploy, InteractorCall,
+    InteractorDeploy, ManagedAddress, ManagedBuffer, TokenIdentifier,
+};
+use dharitri_wasm_debug::{num_bigint, DebugApi};
+
+const TOKEN_HEX: &str = "544f4b454e2d313233343536"; // TOKEN-123456
+const ADD_HEX: &str = "616464"; // add
+
+fn sender() -> Address {
+    Address::from([0x11; 32])
+}
+
+fn contract() -> Address {
+    Address::from([0x22; 32])
+}
+
+fn add_call() -> ContractCall<DebugApi, BigUint<DebugApi>> {
+    let mut contract_call = ContractCall::new(
+        ManagedAddress::from_address(&contract()),
+        ManagedBuffer::from(&b"add"[..]),
+    );
+    contract_call.push_endpoint_arg(&5u32);
+    contract_call
+}
+
+fn token() -> TokenIdentifier<DebugApi> {
+    TokenIdentifier::from(&b"TOKEN-123456"[..])
+}
+
+#[test]
+fn interactor_call_moax_test() {
+    let _ = DebugApi::dummy();
+    let call = InteractorCall::from(add_call().with_moax_transfer(BigUint::from(1000u32)));
+    assert_eq!(call.gas_limit, None);
+
+    let tx = call.with_gas_limit(5_000_000).to_transaction(&sender());
+    assert_eq!(tx.receiver, contract());
+    assert_eq!(tx.moax_value.as_slice(), &[0x03, 0xe8]);
+    assert_eq!(tx.data, "add@05");
+    assert_eq!(tx.gas_limit, Some(5_000_000));
+}
+
+#[test]
+fn interactor_call_single_dct_test() {
+    let _ = DebugApi::dummy();
+    let call =
+        InteractorCall::from(add_call().add_dct_token_transfer(token(), 0, BigUint::from(100u32)));
+    let tx = call.to_transaction(&sender());
+    assert_eq!(tx.receiver, contract());
+    assert!(tx.moax_value.is_empty());
+    assert_eq!(
+        tx.data,
+        format!("DCTTransfer@{}@64@{}@05", TOKEN_HEX, ADD_HEX)
+    );
+}
+
+#[test]
+fn interactor_call_single_nft_test() {
+    let _ = DebugApi::dummy();
+    let call =
+        InteractorCall::from(add_call().add_dct_token_transfer(token(), 3, BigUint::from(1u32)));
+    let tx = call.to_transaction(&sender());
+    assert_eq!(tx.receiver, sender());
+    assert_eq!(
+        tx.data,
+        format!(
+            "DCTNFTTransfer@{}@03@01@{}@{}@05",
+            TOKEN_HEX,
+            "22".repeat(32),
+            ADD_HEX
+        )
+    );
+}
+
+#[test]
+fn interactor_call_multi_transfer_test() {
+    let _ = DebugApi::dummy();
+    let call = InteractorCall::from(
+        add_call()
+            .add_dct_token_transfer(token(), 0, BigUint::from(100u32))
+            .add_dct_token_transfer(token(), 3, BigUint::from(1u32)),
+    );
+    let tx = call.to_transaction(&sender());
+    assert_eq!(tx.receiver, sender());
+    assert_eq!(
+        tx.data,
+        format!(
+            "MultiDCTNFTTransfer@{}@02@{}@@64@{}@03@01@{}@05",
+            "22".repeat(32),
+            TOKEN_HEX,
+            TOKEN_HEX,
+            ADD_HEX
+        )
+    );
+}
+
+#[test]
+fn interactor_call_decode_results_test() {
+    let _ = DebugApi::dummy();
+    let call = InteractorCall::from(add_call());
+    let result: BigUint<DebugApi> = call.decode_results(vec![vec![0x01, 0x00]]).unwrap();
+    assert_eq!(result, BigUint::from(256u32));
+    // decoding into an equivalent type, no API needed
+    let result: num_bigint::BigUint = call.decode_results(vec![vec![0x01, 0x00]]).unwrap();
+    assert_eq!(result, num_bigint::BigUint::from(256u32));
+
+    assert!(call
+        .decode_results::<BigUint<DebugApi>>(vec![vec![1], vec![2]])
+        .is_err());
+}
+
+#[test]
+fn interactor_deploy_test() {
+    let _ = DebugApi::dummy();
+    let mut contract_deploy = ContractDeploy::<DebugApi, ()>::new();
+    contract_deploy.push_endpoint_arg(&5u32);
+    let deploy = InteractorDeploy::from(contract_deploy);
+    assert_eq!(deploy.to, None);
+
+    let tx = deploy.to_deploy_transaction(&[0xca, 0xfe], CodeMetadata::UPGRADEABLE);
+    assert_eq!(tx.receiver, Address::zero());
+    assert_eq!(tx.data, "cafe@0500@0100@05");
+
+    let mut contract_deploy = ContractDeploy::<DebugApi, ()>::new();
+    contract_deploy.to =
+        dharitri_wasm::types::ManagedOption::some(ManagedAddress::from_address(&contract()));
+    let upgrade = InteractorDeploy::from(contract_deploy);
+    let tx = upgrade.to_upgrade_transaction(&[0xca, 0xfe], CodeMetadata::DEFAULT);
+    assert_eq!(tx.receiver, contract());
+    assert_eq!(tx.data, "upgradeContract@cafe@0000");
+}
@@ -0,0 +1,234 @@
+use std::{fs, path::PathBuf};
+
+use dharitri_wasm::abi::ContractAbi;
+use dharitri_wasm_debug::{
+    abi_json::{
+        serialize_abi_to_json, ContractAbiJson, ReproducibleBuildAbiJson, RustcAbiJson,
+        WasmOptAbiJson,
+    },
+    meta::{
+        cargo_lock_hash, compare_build_manifests, source_hash, BuildManifestJson, DharitriJson,
+        MetaConfig,
+    },
+};
+
+fn sample_build() -> ReproducibleBuildAbiJson {
+    ReproducibleBuildAbiJson {
+        toolchain: Some("nightly-2022-01-10".to_string()),
+        rustc: RustcAbiJson {
+            version: "1.60.0-nightly".to_string(),
+            commit_hash: "abcdef".to_string(),
+            commit_date: "2022-01-09".to_string(),
+            channel: "Nightly".to_string(),
+            short: "rustc 1.60.0-nightly (abcdef 2022-01-09)".to_string(),
+        },
+        target: "wasm32-unknown-unknown".to_string(),
+        rustflags: vec!["-C link-arg=-s".to_string()],
+        features: Vec::new(),
+        wasm_opt: Some(WasmOptAbiJson {
+            version: "wasm-opt version 105".to_string(),
+            flags: vec!["-Oz".to_string()],
+        }),
+        cargo_lock_hash: Some("11".repeat(32)),
+        source_hash: "22".repeat(32),
+    }
+}
+
+fn sample_manifest() -> BuildManifestJson {
+    BuildManifestJson {
+        wasm_name: "sample.wasm".to_string(),
+        wasm_size: 1024,
+        wasm_hash: "33".repeat(32),
+        build: sample_build(),
+    }
+}
+
+/// A fresh directory, unique per test.
+fn temp_contract_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "dharitri-build-manifest-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("wasm/src")).unwrap();
+    fs::create_dir_all(dir.join("output")).unwrap();
+    fs::create_dir_all(dir.join("wasm/target")).unwrap();
+    fs::create_dir_all(dir.join(".git")).unwrap();
+    fs::write(dir.join("Cargo.toml"), "[package]").unwrap();
+    fs::write(dir.join("src/sample.rs"), "// contract").unwrap();
+    fs::write(dir.join("wasm/src/lib.rs"), "// wasm").unwrap();
+    dir
+}
+
+#[test]
+fn source_hash_test() {
+    let dir = temp_contract_dir("source");
+    let hash = source_hash(&dir);
+    assert_eq!(hash.len(), 64);
+
+    // build outputs and hidden files are not source code
+    fs::write(dir.join("output/sample.wasm"), [0, 1, 2]).unwrap();
+    fs::write(dir.join("wasm/target/sample.wasm"), [0, 1, 2]).unwrap();
+    fs::write(dir.join(".git/HEAD"), "ref").unwrap();
+    assert_eq!(source_hash(&dir), hash);
+
+    fs::write(dir.join("src/sample.rs"), "// contract, changed").unwrap();
+    let changed_hash = source_hash(&dir);
+    assert_ne!(changed_hash, hash);
+
+    fs::rename(dir.join("src/sample.rs"), dir.join("src/renamed.rs")).unwrap();
+    assert_ne!(source_hash(&dir), changed_hash);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn cargo_lock_hash_test() {
+    let dir = temp_contract_dir("lock");
+    assert_eq!(cargo_lock_hash(&dir.join("wasm")), None);
+
+    // the lock of an enclosing workspace is used if the crate has none
+    fs::write(dir.join("Cargo.lock"), "# workspace lock").unwrap();
+    let workspace_lock_hash = cargo_lock_hash(&dir.join("wasm")).unwrap();
+    assert_eq!(Some(workspace_lock_hash.clone()), cargo_lock_hash(&dir));
+
+    fs::write(dir.join("wasm/Cargo.lock"), "# crate lock").unwrap();
+    assert_ne!(
+        cargo_lock_hash(&dir.join("wasm")).unwrap(),
+        workspace_lock_hash
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn compare_build_manifests_test() {
+    let expected = sample_manifest();
+    assert!(compare_build_manifests(&expected, &expected.clone()).is_empty());
+
+    let mut actual = sample_manifest();
+    actual.wasm_hash = "44".repeat(32);
+    actual.build.rustc.short = "rustc 1.61.0".to_string();
+    actual.build.wasm_opt = None;
+    let mismatches = compare_build_manifests(&expected, &actual);
+    let fields: Vec<&str> = mismatches.iter().map(|mismatch| mismatch.field).collect();
+    assert_eq!(fields, vec!["rustc version", "wasm-opt", "wasm hash"]);
+    assert_eq!(
+        mismatches[1].to_string(),
+        "sample.wasm: wasm-opt differs, expected `wasm-opt version 105 -Oz`, got `none`"
+    );
+}
+
+#[test]
+fn build_manifest_json_test() {
+    let manifest = sample_manifest();
+    let json = serde_json::to_string_pretty(&manifest).unwrap();
+    assert!(json.contains("\"wasmHash\""));
+    assert!(json.contains("\"cargoLockHash\""));
+    assert!(!json.contains("\"features\""));
+    let parsed: BuildManifestJson = serde_json::from_str(json.as_str()).unwrap();
+    assert_eq!(parsed, manifest);
+}
+
+#[test]
+fn abi_reproducible_build_test() {
+    let mut abi_json = ContractAbiJson::from(&ContractAbi::default());
+    assert!(!serialize_abi_to_json(&abi_json).contains("reproducibleBuild"));
+
+    abi_json.build_info.reproducible_build = Some(sample_build());
+    let abi_string = serialize_abi_to_json(&abi_json);
+    assert!(abi_string.contains("\"reproducibleBuild\""));
+    let parsed: ContractAbiJson = serde_json::from_str(abi_string.as_str()).unwrap();
+    assert_eq!(parsed.build_info.reproducible_build, Some(sample_build()));
+}
+
+#[test]
+fn reproducible_args_test() {
+    let args: Vec<String> = ["meta", "build", "--reproducible"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let meta_config = MetaConfig::create_with_dharitri_json(
+        &ContractAbi::default(),
+        &args,
+        &DharitriJson::default(),
+    );
+    assert!(meta_config.build_args.reproducible);
+    assert_eq!(meta_config.build_args.rust_toolchain, None);
+
+    // the toolchain comes from dharitri.json, unless given explicitly
+    let dharitri_json = DharitriJson::parse(r#"{ "rust-toolchain": "nightly-2022-01-10" }"#);
+    let meta_config =
+        MetaConfig::create_with_dharitri_json(&ContractAbi::default(), &args, &dharitri_json);
+    assert_eq!(
+        meta_config.build_args.rust_toolchain.as_deref(),
+        Some("nightly-2022-01-10")
+    );
+
+    let mut args = args;
+    args.push("--rust-toolchain".to_string());
+    args.push("1.60.0".to_string());
+    let meta_config =
+        MetaConfig::create_with_dharitri_json(&ContractAbi::default(), &args, &dharitri_json);
+    assert_eq!(
+        meta_config.build_args.rust_toolchain.as_deref(),
+        Some("1.60.0")
+    );
+}
+
+#[test]
+fn apply_recorded_builds_test() {
+    let args: Vec<String> = [
+        "meta",
+        "verify",
+        "--wasm-symbols",
+        "--no-wasm-opt",
+        "--rust-toolchain",
+        "1.60.0",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    let mut meta_config = MetaConfig::create_with_dharitri_json(
+        &ContractAbi::default(),
+        &args,
+        &DharitriJson::default(),
+    );
+    assert!(meta_config.build_args.debug_symbols);
+    assert!(!meta_config.build_args.wasm_opt);
+
+    let mut manifest = sample_manifest();
+    manifest.build.features = vec!["lite".to_string()];
+    meta_config.apply_recorded_builds(&[manifest]);
+    assert!(meta_config.build_args.reproducible);
+    assert_eq!(
+        meta_config.build_args.rust_toolchain.as_deref(),
+        Some("nightly-2022-01-10")
+    );
+    let output_contract = &meta_config.output_contracts[0];
+    assert_eq!(output_contract.wasm_opt, Some(true));
+    assert_eq!(output_contract.debug_symbols, Some(false));
+    assert_eq!(output_contract.features, vec!["lite".to_string()]);
+
+    // recorded with debug symbols and without wasm-opt, the opposite of the command line defaults
+    let args: Vec<String> = ["meta", "verify"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let mut meta_config = MetaConfig::create_with_dharitri_json(
+        &ContractAbi::default(),
+        &args,
+        &DharitriJson::default(),
+    );
+    let mut manifest = sample_manifest();
+    manifest.build.toolchain = None;
+    manifest.build.rustflags = Vec::new();
+    manifest.build.wasm_opt = None;
+    meta_config.apply_recorded_builds(&[manifest]);
+    assert_eq!(meta_config.build_args.rust_toolchain, None);
+    let output_contract = &meta_config.output_contracts[0];
+    assert_eq!(output_contract.wasm_opt, Some(false));
+    assert_eq!(output_contract.debug_symbols, Some(true));
+}
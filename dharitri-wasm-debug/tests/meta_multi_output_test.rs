@@ -0,0 +1,144 @@
+use dharitri_wasm::abi::{ContractAbi, EndpointAbi, EndpointLocationAbi, EndpointMutabilityAbi};
+use dharitri_wasm_debug::meta::{DharitriJson, MetaConfig};
+
+fn endpoint(
+    name: &'static str,
+    tags: &'static [&'static str],
+    module: &'static str,
+    location: EndpointLocationAbi,
+) -> EndpointAbi {
+    EndpointAbi {
+        docs: &[],
+        name,
+        only_owner: false,
+        only_role: None,
+        tags,
+        guards: &[],
+        module,
+        mutability: EndpointMutabilityAbi::Mutable,
+        location,
+        payable_in_tokens: &[],
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        errors: Vec::new(),
+    }
+}
+
+fn sample_abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.build_info.contract_crate.name = "sample";
+    abi.constructors.push(endpoint(
+        "init",
+        &[],
+        "Sample",
+        EndpointLocationAbi::MainContract,
+    ));
+    abi.endpoints.push(endpoint(
+        "full",
+        &[],
+        "Sample",
+        EndpointLocationAbi::MainContract,
+    ));
+    abi.endpoints.push(endpoint(
+        "tagged",
+        &["lite"],
+        "Sample",
+        EndpointLocationAbi::MainContract,
+    ));
+    abi.endpoints.push(endpoint(
+        "fromModule",
+        &[],
+        "PauseModule",
+        EndpointLocationAbi::MainContract,
+    ));
+    abi.endpoints.push(endpoint(
+        "externalView",
+        &["lite"],
+        "Sample",
+        EndpointLocationAbi::ViewContract,
+    ));
+    abi.has_callback = true;
+    abi
+}
+
+fn endpoint_names(abi: &ContractAbi) -> Vec<&'static str> {
+    abi.endpoints.iter().map(|endpoint| endpoint.name).collect()
+}
+
+#[test]
+fn meta_no_outputs_test() {
+    let meta_config =
+        MetaConfig::create_with_dharitri_json(&sample_abi(), &[], &DharitriJson::default());
+
+    assert_eq!(meta_config.output_contracts.len(), 2);
+    let main_contract = meta_config.main_contract();
+    assert_eq!(main_contract.wasm_crate_path, "../wasm");
+    assert_eq!(main_contract.abi_output_name(), "sample.abi.json");
+    assert_eq!(
+        endpoint_names(&main_contract.abi),
+        vec!["full", "tagged", "fromModule"]
+    );
+
+    let view_contract = &meta_config.secondary_contracts()[0];
+    assert_eq!(view_contract.wasm_crate_path, "../wasm-view");
+    assert_eq!(view_contract.wasm_output_name(), "sample-view.wasm");
+    assert_eq!(endpoint_names(&view_contract.abi), vec!["externalView"]);
+    assert!(view_contract.abi.constructors.is_empty());
+    assert!(!view_contract.abi.has_callback);
+}
+
+#[test]
+fn meta_multi_output_test() {
+    let dharitri_json = DharitriJson::parse(
+        r#"{
+            "language": "rust",
+            "outputs": [
+                {
+                    "id": "lite",
+                    "tags": ["lite"],
+                    "modules": ["PauseModule"],
+                    "wasm-opt": false,
+                    "features": ["sample/lite"]
+                },
+                {
+                    "id": "no-module",
+                    "name": "sample-custom",
+                    "wasm-crate-path": "../wasm-custom",
+                    "exclude-endpoints": ["fromModule"]
+                },
+                {
+                    "id": "lite-view",
+                    "external-view": true,
+                    "endpoints": ["externalView"]
+                }
+            ]
+        }"#,
+    );
+    let meta_config = MetaConfig::create_with_dharitri_json(&sample_abi(), &[], &dharitri_json);
+    assert_eq!(meta_config.output_contracts.len(), 5);
+
+    let lite = &meta_config.output_contracts[2];
+    assert_eq!(lite.wasm_crate_path, "../wasm-lite");
+    assert_eq!(lite.wasm_output_name(), "sample-lite.wasm");
+    assert_eq!(endpoint_names(&lite.abi), vec!["tagged", "fromModule"]);
+    assert_eq!(lite.abi.constructors.len(), 1);
+    assert!(lite.abi.has_callback);
+    assert_eq!(lite.wasm_opt, Some(false));
+    assert_eq!(lite.features, vec!["sample/lite".to_string()]);
+
+    let no_module = &meta_config.output_contracts[3];
+    assert_eq!(no_module.wasm_crate_path, "../wasm-custom");
+    assert_eq!(no_module.abi_output_name(), "sample-custom.abi.json");
+    assert_eq!(endpoint_names(&no_module.abi), vec!["full", "tagged"]);
+
+    let lite_view = &meta_config.output_contracts[4];
+    assert_eq!(lite_view.location, EndpointLocationAbi::ViewContract);
+    assert_eq!(endpoint_names(&lite_view.abi), vec!["externalView"]);
+    assert!(lite_view.abi.constructors.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn meta_unknown_output_field_test() {
+    let _ = DharitriJson::parse(r#"{ "outputs": [ { "id": "lite", "tag": ["lite"] } ] }"#);
+}
@@ -0,0 +1,320 @@
+use dharitri_wasm_debug::{
+    abi_json::ContractAbiJson,
+    meta::{generate_ts_bindings, ts_method_name, ts_type, TsTypeUse},
+};
+
+const LOTTERY_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "x.x.x-nightly",
+            "commitHash": "<commit hash here>",
+            "commitDate": "<commit date here>",
+            "channel": "Channel",
+            "short": "rustc <version> (<short hash> <date>)"
+        },
+        "contractCrate": {
+            "name": "lottery",
+            "version": "0.0.0"
+        },
+        "framework": {
+            "name": "dharitri-wasm",
+            "version": "0.5.0"
+        }
+    },
+    "docs": [" A simple lottery."],
+    "name": "Lottery",
+    "constructor": {
+        "inputs": [
+            { "name": "ticket_price", "type": "BigUint" }
+        ],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "buy_ticket",
+            "mutability": "mutable",
+            "payableInTokens": ["LOTTERY-123456", "MOAX"],
+            "inputs": [
+                { "name": "lottery_name", "type": "bytes" }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "donate",
+            "mutability": "mutable",
+            "payableInTokens": ["MOAX"],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["*"],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "name": "stake",
+            "mutability": "mutable",
+            "payableInTokens": ["storage:staking_token", "MOAX"],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "docs": [" Details of a running lottery."],
+            "name": "getLotteryInfo",
+            "mutability": "readonly",
+            "inputs": [
+                { "name": "lottery_name", "type": "bytes" }
+            ],
+            "outputs": [
+                { "type": "LotteryInfo" }
+            ]
+        },
+        {
+            "name": "status",
+            "mutability": "readonly",
+            "inputs": [
+                { "name": "lottery_name", "type": "bytes" }
+            ],
+            "outputs": [
+                { "type": "Status" },
+                { "type": "variadic<Address>", "multi_result": true }
+            ]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "ticket_bought",
+            "inputs": [
+                { "name": "buyer", "type": "Address", "indexed": true },
+                { "name": "lottery_name", "type": "bytes", "indexed": true },
+                { "name": "price", "type": "BigUint" }
+            ]
+        },
+        {
+            "identifier": "prize_claimed",
+            "version": 2,
+            "structuredData": true,
+            "inputs": [
+                { "name": "winner", "type": "Address", "indexed": true },
+                { "name": "prize", "type": "BigUint" },
+                { "name": "fee", "type": "BigUint", "since": 2 }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "LotteryInfo": {
+            "type": "struct",
+            "fields": [
+                { "name": "token_identifier", "type": "TokenIdentifier" },
+                { "name": "ticket_price", "type": "BigUint" },
+                { "name": "winners", "type": "List<Address>" },
+                { "name": "deadline", "type": "Option<u64>" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                {
+                    "name": "Running",
+                    "discriminant": 1,
+                    "fields": [
+                        { "name": "0", "type": "u32" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+fn lottery_bindings() -> String {
+    let abi: ContractAbiJson = serde_json::from_str(LOTTERY_ABI).unwrap();
+    generate_ts_bindings(&abi)
+}
+
+#[test]
+fn ts_type_test() {
+    assert_eq!(ts_type("u32", TsTypeUse::Argument), "BigNumber.Value");
+    assert_eq!(ts_type("BigUint", TsTypeUse::Result), "BigNumber");
+    assert_eq!(
+        ts_type("Option<Address>", TsTypeUse::Result),
+        "IAddress | null"
+    );
+    assert_eq!(
+        ts_type("List<Option<bytes>>", TsTypeUse::Result),
+        "(Buffer | null)[]"
+    );
+    assert_eq!(
+        ts_type("multi<TokenIdentifier,u64>", TsTypeUse::Result),
+        "[string, BigNumber]"
+    );
+    assert_eq!(
+        ts_type("tuple<bool,utf-8 string>", TsTypeUse::Result),
+        "{ field0: boolean; field1: string }"
+    );
+    assert_eq!(ts_type("array32<u8>", TsTypeUse::Result), "BigNumber[]");
+    assert_eq!(
+        ts_type("optional<MyStruct>", TsTypeUse::Argument),
+        "MyStruct | null"
+    );
+    assert_eq!(ts_type("Generic<u8>", TsTypeUse::Argument), "unknown");
+}
+
+#[test]
+fn ts_method_name_test() {
+    assert_eq!(ts_method_name("buy_ticket"), "buyTicket");
+    assert_eq!(ts_method_name("getLotteryInfo"), "getLotteryInfo");
+    assert_eq!(ts_method_name("_private"), "private");
+}
+
+#[test]
+fn ts_bindings_types_test() {
+    let bindings = lottery_bindings();
+    assert!(bindings.contains(
+        r#"export interface LotteryInfo {
+    token_identifier: string;
+    ticket_price: BigNumber;
+    winners: IAddress[];
+    deadline: BigNumber | null;
+}"#
+    ));
+    assert!(bindings.contains(
+        r#"export type Status =
+    | { name: "Inactive"; fields: [] }
+    | { name: "Running"; fields: [BigNumber] }
+    ;"#
+    ));
+    assert!(bindings.contains(
+        r#"export interface TicketBoughtEvent {
+    buyer: IAddress;
+    lottery_name: Buffer;
+    price: BigNumber;
+}"#
+    ));
+}
+
+#[test]
+fn ts_bindings_contract_test() {
+    let bindings = lottery_bindings();
+    assert!(bindings.contains("/**\n * A simple lottery.\n */\nexport class LotteryContract {"));
+    assert!(bindings.contains("    initArguments(ticketPrice: BigNumber.Value): TypedValue[] {"));
+
+    // payable in specific tokens
+    assert!(bindings.contains(
+        r#"    buyTicket(lotteryName: Buffer, payment: TokenPayment, sender: IAddress): Interaction {
+        const interaction = <Interaction>this.contract.methods.buy_ticket([lotteryName]);
+        checkPaymentToken(payment, ["LOTTERY-123456", "MOAX"]);
+        return withPayment(interaction, payment, sender);
+    }"#
+    ));
+    // MOAX only
+    assert!(bindings.contains(
+        r#"    donate(payment: TokenPayment): Interaction {
+        const interaction = <Interaction>this.contract.methods.donate([]);
+        checkPaymentToken(payment, ["MOAX"]);
+        return interaction.withValue(payment);
+    }"#
+    ));
+    // any token
+    assert!(bindings.contains(
+        r#"    /** Accepts payments in any token. */
+    deposit(payment: TokenPayment, sender: IAddress): Interaction {
+        const interaction = <Interaction>this.contract.methods.deposit([]);
+        return withPayment(interaction, payment, sender);"#
+    ));
+    // tokens from storage, only checked by the contract
+    assert!(bindings.contains(
+        r#"    /** Accepts payments in: the tokens in storage `staking_token`, MOAX. */
+    stake(payment: TokenPayment, sender: IAddress): Interaction {
+        const interaction = <Interaction>this.contract.methods.stake([]);
+        return withPayment(interaction, payment, sender);
+    }"#
+    ));
+
+    assert!(bindings.contains(
+        "    parseBuyTicketOutcome(transactionOnNetwork: TransactionOnNetwork): ContractResult<null> {"
+    ));
+    assert!(bindings.contains(
+        r#"    /**
+     * Details of a running lottery.
+     */
+    getLotteryInfo(lotteryName: Buffer): Interaction {
+        return <Interaction>this.contract.methods.getLotteryInfo([lotteryName]);
+    }"#
+    ));
+    assert!(bindings.contains(
+        "    parseGetLotteryInfoQuery(queryResponse: QueryResponse): ContractResult<LotteryInfo> {"
+    ));
+    assert!(bindings.contains(
+        r#"    parseStatusQuery(queryResponse: QueryResponse): ContractResult<[Status, IAddress[]]> {
+        const bundle = this.resultsParser.parseQueryResponse(queryResponse, this.contract.getEndpoint("status"));
+        return typedResult(bundle, true);
+    }"#
+    ));
+
+    assert!(bindings.contains(
+        r#"    decodeTicketBoughtEvent(topics: Buffer[], data: Buffer): TicketBoughtEvent {
+        if (topics.length === 0 || topics[0].toString() !== "ticket_bought") {
+            throw new Error("not a `ticket_bought` event");
+        }
+        return {
+            buyer: this.decodeValue(topics[1], "Address"),
+            lottery_name: this.decodeValue(topics[2], "bytes"),
+            price: this.decodeValue(data, "BigUint"),
+        };
+    }"#
+    ));
+    assert!(bindings.contains(
+        r#"        const eventData = this.eventDataFields(data, true);
+        return {
+            winner: this.decodeValue(topics[1], "Address"),
+            prize: this.decodeValue(eventData.fields[0], "BigUint"),
+            fee: this.decodeValue(eventData.version >= 2 ? eventData.fields[1] : Buffer.alloc(0), "BigUint"),
+        };"#
+    ));
+    assert!(bindings.contains(
+        "    private eventDataFields(data: Buffer, versioned: boolean): { version: number, fields: Buffer[] } {"
+    ));
+    assert!(bindings.ends_with("    }\n}\n"));
+}
+
+#[test]
+fn ts_bindings_upgrade_test() {
+    // without `#[upgrade]`, the constructor arguments are used on upgrade too
+    let bindings = lottery_bindings();
+    assert!(bindings.contains(
+        "    /** Arguments of the constructor, to be passed as `initArguments` on deploy or upgrade. */"
+    ));
+    assert!(!bindings.contains("upgradeArguments("));
+
+    let mut abi: ContractAbiJson = serde_json::from_str(LOTTERY_ABI).unwrap();
+    abi.upgrade_constructor = serde_json::from_value(serde_json::json!({
+        "inputs": [
+            { "name": "schema_version", "type": "u32" },
+            { "name": "status", "type": "Status" }
+        ],
+        "outputs": []
+    }))
+    .unwrap();
+    let bindings = generate_ts_bindings(&abi);
+    assert!(bindings.contains(
+        r#"    /** Arguments of the constructor, to be passed as `initArguments` on deploy. */
+    initArguments(ticketPrice: BigNumber.Value): TypedValue[] {"#
+    ));
+    assert!(bindings.contains(
+        r#"    /** Arguments of the `#[upgrade]` method, to be passed as `initArguments` on upgrade. */
+    upgradeArguments(schemaVersion: BigNumber.Value, status: Status): TypedValue[] {
+        const mapper = new TypeMapper(this.abiRegistry.customTypes);
+        const parser = new TypeExpressionParser();
+        const inputs = [
+            new EndpointParameterDefinition("schema_version", "", mapper.mapType(parser.parse("u32"))),
+            new EndpointParameterDefinition("status", "", mapper.mapType(parser.parse("Status"))),
+        ];
+        const definition = new EndpointDefinition("upgrade", inputs, [], new EndpointModifiers("", []));
+        return NativeSerializer.nativeToTypedValues([schemaVersion, status], definition);
+    }"#
+    ));
+}
@@ -0,0 +1,270 @@
+use dharitri_wasm_debug::{
+    abi_json::ContractAbiJson,
+    meta::{check_upgrade_compatibility, UpgradeIssue},
+};
+
+const PREVIOUS_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "x.x.x-nightly",
+            "commitHash": "<commit hash here>",
+            "commitDate": "<commit date here>",
+            "channel": "Channel",
+            "short": "rustc <version> (<short hash> <date>)"
+        },
+        "contractCrate": {
+            "name": "sample",
+            "version": "0.0.0"
+        },
+        "framework": {
+            "name": "dharitri-wasm",
+            "version": "0.5.0"
+        }
+    },
+    "name": "Sample",
+    "constructor": {
+        "inputs": [
+            { "name": "owner", "type": "Address" }
+        ],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["MOAX"],
+            "inputs": [
+                { "name": "to", "type": "Address" }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getItem",
+            "mutability": "readonly",
+            "inputs": [
+                { "name": "id", "type": "u64" }
+            ],
+            "outputs": [
+                { "type": "Item" }
+            ]
+        },
+        {
+            "name": "legacy",
+            "mutability": "mutable",
+            "inputs": [],
+            "outputs": []
+        }
+    ],
+    "events": [],
+    "storage": [
+        {
+            "name": "items",
+            "key": "items",
+            "kind": "mapper",
+            "mapper": "SingleValueMapper",
+            "keyArgs": [
+                { "name": "id", "type": "u64" }
+            ],
+            "valueType": "Item"
+        },
+        {
+            "name": "total",
+            "key": "total",
+            "kind": "get",
+            "valueType": "BigUint"
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Item": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "owner", "type": "Address" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Active", "discriminant": 0 },
+                { "name": "Inactive", "discriminant": 1 }
+            ]
+        }
+    }
+}"#;
+
+fn parse_abi(json: &str) -> ContractAbiJson {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn upgrade_check_same_abi_test() {
+    let previous = parse_abi(PREVIOUS_ABI);
+    let current = parse_abi(PREVIOUS_ABI);
+    assert!(check_upgrade_compatibility(&previous, &current).is_empty());
+}
+
+#[test]
+fn upgrade_check_compatible_additions_test() {
+    let previous = parse_abi(PREVIOUS_ABI);
+    let mut current = parse_abi(PREVIOUS_ABI);
+
+    // new endpoint, new enum variant, new storage, renamed argument, renamed struct field
+    current
+        .endpoints
+        .push(parse_abi(PREVIOUS_ABI).endpoints.remove(2));
+    current.endpoints[3].name = "newEndpoint".to_string();
+    current.endpoints[0].inputs[0].arg_name = "recipient".to_string();
+    current.constructor.as_mut().unwrap().inputs[0].arg_name = "admin".to_string();
+    current.types.get_mut("Item").unwrap().fields[0].name = "value".to_string();
+    let mut new_variant = parse_abi(PREVIOUS_ABI).types.remove("Status").unwrap();
+    current
+        .types
+        .get_mut("Status")
+        .unwrap()
+        .variants
+        .push(new_variant.variants.remove(1));
+    current.types.get_mut("Status").unwrap().variants[2].name = "Closed".to_string();
+    current.types.get_mut("Status").unwrap().variants[2].discriminant = 2;
+    current
+        .storage
+        .push(parse_abi(PREVIOUS_ABI).storage.remove(1));
+    current.storage[2].key = "newTotal".to_string();
+
+    assert!(check_upgrade_compatibility(&previous, &current).is_empty());
+}
+
+#[test]
+fn upgrade_check_breaking_changes_test() {
+    let previous = parse_abi(PREVIOUS_ABI);
+    let mut current = parse_abi(PREVIOUS_ABI);
+
+    current.constructor.as_mut().unwrap().inputs.clear();
+    current.endpoints.remove(2);
+    current.endpoints[0].payable_in_tokens = vec!["*".to_string()];
+    current.endpoints[0].inputs[0].type_name = "ManagedBuffer".to_string();
+    current.endpoints[1].outputs[0].type_name = "Option<Item>".to_string();
+
+    // reordered struct fields
+    let item = current.types.get_mut("Item").unwrap();
+    item.fields.swap(0, 1);
+
+    // removed enum variant
+    current.types.get_mut("Status").unwrap().variants.remove(0);
+
+    current.storage[0].mapper = Some("VecMapper".to_string());
+    current.storage[1].value_type = "BigInt".to_string();
+
+    let issues = check_upgrade_compatibility(&previous, &current);
+    assert_eq!(
+        issues,
+        vec![
+            UpgradeIssue::ConstructorInputsChanged {
+                constructor: "init".to_string(),
+                previous: "owner: Address".to_string(),
+                current: "".to_string(),
+            },
+            UpgradeIssue::EndpointInputsChanged {
+                endpoint: "deposit".to_string(),
+                previous: "to: Address".to_string(),
+                current: "to: ManagedBuffer".to_string(),
+            },
+            UpgradeIssue::EndpointPayabilityChanged {
+                endpoint: "deposit".to_string(),
+                previous: "MOAX".to_string(),
+                current: "*".to_string(),
+            },
+            UpgradeIssue::EndpointOutputsChanged {
+                endpoint: "getItem".to_string(),
+                previous: "Item".to_string(),
+                current: "Option<Item>".to_string(),
+            },
+            UpgradeIssue::EndpointRemoved {
+                endpoint: "legacy".to_string(),
+            },
+            UpgradeIssue::TypeChanged {
+                type_name: "Item".to_string(),
+                previous: "struct { amount: BigUint, owner: Address }".to_string(),
+                current: "struct { owner: Address, amount: BigUint }".to_string(),
+            },
+            UpgradeIssue::TypeChanged {
+                type_name: "Status".to_string(),
+                previous: "enum { Active = 0, Inactive = 1 }".to_string(),
+                current: "enum { Inactive = 1 }".to_string(),
+            },
+            UpgradeIssue::StorageLayoutChanged {
+                key: "items".to_string(),
+                previous: "SingleValueMapper<Item> (key args: u64)".to_string(),
+                current: "VecMapper<Item> (key args: u64)".to_string(),
+            },
+            UpgradeIssue::StorageLayoutChanged {
+                key: "total".to_string(),
+                previous: "SingleValueMapper<BigUint> (key args: )".to_string(),
+                current: "SingleValueMapper<BigInt> (key args: )".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        issues[4].to_string(),
+        "endpoint `legacy` was removed or renamed"
+    );
+}
+
+#[test]
+fn upgrade_check_versioned_struct_test() {
+    let previous = parse_abi(PREVIOUS_ABI);
+    let mut current = parse_abi(PREVIOUS_ABI);
+
+    // fields added in a new version are decoded with their default values from the old storage
+    let item = current.types.get_mut("Item").unwrap();
+    item.version = Some(2);
+    item.fields
+        .push(serde_json::from_str(r#"{ "name": "created", "type": "u64", "since": 2 }"#).unwrap());
+    assert!(check_upgrade_compatibility(&previous, &current).is_empty());
+
+    // unless they are missing the version
+    current.types.get_mut("Item").unwrap().fields[2].since = None;
+    assert_eq!(
+        check_upgrade_compatibility(&previous, &current),
+        vec![UpgradeIssue::TypeChanged {
+            type_name: "Item".to_string(),
+            previous: "struct { amount: BigUint, owner: Address }".to_string(),
+            current: "struct { amount: BigUint, owner: Address, created: u64 }".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn upgrade_check_upgrade_constructor_test() {
+    let previous = parse_abi(PREVIOUS_ABI);
+    let mut current = parse_abi(PREVIOUS_ABI);
+
+    // the upgrade constructor replaces `init` on upgrade, so it must accept the same arguments
+    current.upgrade_constructor = parse_abi(PREVIOUS_ABI).constructor;
+    current.upgrade_constructor.as_mut().unwrap().inputs[0].type_name = "u64".to_string();
+    let issues = check_upgrade_compatibility(&previous, &current);
+    assert_eq!(
+        issues,
+        vec![UpgradeIssue::ConstructorInputsChanged {
+            constructor: "upgrade".to_string(),
+            previous: "owner: Address".to_string(),
+            current: "owner: u64".to_string(),
+        }]
+    );
+    assert_eq!(
+        issues[0].to_string(),
+        "constructor `upgrade` arguments changed from (owner: Address) to (owner: u64)"
+    );
+}
+
+#[test]
+fn upgrade_check_generated_abi_round_trip_test() {
+    let abi_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../contracts/feature-tests/use-module/use_module_expected_main.abi.json"
+    );
+    let abi_json = std::fs::read_to_string(abi_path).unwrap();
+    let previous = parse_abi(abi_json.as_str());
+    let current = parse_abi(abi_json.as_str());
+    assert!(check_upgrade_compatibility(&previous, &current).is_empty());
+}
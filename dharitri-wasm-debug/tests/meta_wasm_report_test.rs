@@ -0,0 +1,149 @@
+use dharitri_wasm::abi::{ContractAbi, EndpointAbi, EndpointLocationAbi, EndpointMutabilityAbi};
+use dharitri_wasm_debug::meta::{WasmFunctionSize, WasmReport};
+
+const SAMPLE_WAT: &str = r#"
+(module
+    (import "env" "bigIntAdd" (func $bigIntAdd (param i32 i32 i32)))
+    (import "env" "managedIsDCTPaused" (func $managedIsDCTPaused (param i32) (result i32)))
+    (memory 2)
+    (data (i32.const 1024) "hello")
+    (func $init)
+    (func $add
+        (call $bigIntAdd (i32.const 1) (i32.const 2) (i32.const 3))
+        (drop (memory.grow (i32.const 1)))
+        (drop (i32.add (i32.const 1) (i32.const 2)))
+    )
+    (func $leftover
+        (drop (f64.add (f64.const 1) (f64.const 2)))
+    )
+    (func $callBack)
+    (export "memory" (memory 0))
+    (export "init" (func $init))
+    (export "add" (func $add))
+    (export "leftover" (func $leftover))
+    (export "callBack" (func $callBack))
+)
+"#;
+
+fn endpoint(name: &'static str) -> EndpointAbi {
+    EndpointAbi {
+        docs: &[],
+        name,
+        only_owner: false,
+        only_role: None,
+        tags: &[],
+        guards: &[],
+        module: "Sample",
+        mutability: EndpointMutabilityAbi::Mutable,
+        location: EndpointLocationAbi::MainContract,
+        payable_in_tokens: &[],
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        errors: Vec::new(),
+    }
+}
+
+fn sample_abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.endpoints.push(endpoint("add"));
+    abi.endpoints.push(endpoint("getSum"));
+    abi
+}
+
+#[test]
+fn wasm_report_test() {
+    let wasm_bytes = wat::parse_str(SAMPLE_WAT).unwrap();
+    let report = WasmReport::analyze("sample.wasm", wasm_bytes.as_slice(), &sample_abi()).unwrap();
+
+    assert_eq!(report.wasm_name, "sample.wasm");
+    assert_eq!(report.size_before_opt, wasm_bytes.len() as u64);
+    assert_eq!(report.size_after_opt, None);
+    assert_eq!(report.imports, vec!["bigIntAdd", "managedIsDCTPaused"]);
+    assert_eq!(report.ei_1_2_imports, vec!["managedIsDCTPaused"]);
+    assert_eq!(report.exports, vec!["init", "add", "leftover", "callBack"]);
+    assert_eq!(report.missing_endpoints, vec!["getSum"]);
+    assert_eq!(report.unexpected_exports, vec!["leftover"]);
+    assert!(report.has_floating_point);
+
+    assert_eq!(report.memory.initial_pages, 2);
+    assert_eq!(report.memory.max_pages, None);
+    assert_eq!(report.memory.data_size, 5);
+    assert!(report.memory.uses_memory_grow);
+
+    assert_eq!(report.largest_functions.len(), 4);
+    assert_eq!(report.largest_functions[0].name, "leftover");
+    assert_eq!(report.largest_functions[1].name, "add");
+    assert!(report
+        .largest_functions
+        .windows(2)
+        .all(|pair| pair[0].size >= pair[1].size));
+
+    let summary = report.summary();
+    assert!(summary.contains("requires EI 1.2: managedIsDCTPaused"));
+    assert!(summary.contains("endpoints not exported: getSum"));
+    assert!(summary.contains("floating point"));
+}
+
+#[test]
+fn wasm_report_upgrade_export_test() {
+    let wasm_bytes = wat::parse_str(
+        r#"(module
+            (func $init)
+            (func $upgrade)
+            (export "init" (func $init))
+            (export "upgrade" (func $upgrade))
+        )"#,
+    )
+    .unwrap();
+
+    // only expected from contracts with an `#[upgrade]` method
+    let report =
+        WasmReport::analyze("plain.wasm", wasm_bytes.as_slice(), &ContractAbi::default()).unwrap();
+    assert_eq!(report.unexpected_exports, vec!["upgrade"]);
+
+    let mut abi = ContractAbi::default();
+    abi.upgrade_constructors.push(endpoint("upgrade"));
+    let report = WasmReport::analyze("upgradeable.wasm", wasm_bytes.as_slice(), &abi).unwrap();
+    assert!(report.unexpected_exports.is_empty());
+    assert!(report.missing_endpoints.is_empty());
+}
+
+#[test]
+fn wasm_report_function_names_test() {
+    // names come from the name section if present, otherwise from the exports
+    let wasm_bytes = wat::parse_str(
+        r#"(module
+            (func $internal_helper (drop (i32.add (i32.const 1) (i32.const 2))))
+            (func $init (call $internal_helper))
+            (export "init" (func $init))
+        )"#,
+    )
+    .unwrap();
+    let report =
+        WasmReport::analyze("names.wasm", wasm_bytes.as_slice(), &ContractAbi::default()).unwrap();
+    assert!(!report.has_floating_point);
+    assert!(!report.memory.uses_memory_grow);
+    assert_eq!(
+        report
+            .largest_functions
+            .iter()
+            .map(|function| function.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["internal_helper", "init"]
+    );
+
+    let json = serde_json::to_value(&report.largest_functions[1]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_value(WasmFunctionSize {
+            name: "init".to_string(),
+            size: report.largest_functions[1].size,
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn wasm_report_invalid_wasm_test() {
+    assert!(WasmReport::analyze("bad.wasm", &[0, 1, 2, 3], &ContractAbi::default()).is_err());
+}
@@ -0,0 +1,226 @@
+use dharitri_wasm::{
+    abi::{ContractAbi, StorageAbi, StorageKindAbi, TypeName},
+    storage::{
+        check_storage_layout,
+        mappers::{SingleValueMapper, StorageMapper},
+        StorageKey, StorageKeyDeclaration, StorageLayout,
+    },
+    types::{BigUint, ManagedAddress},
+};
+use dharitri_wasm_debug::{meta::storage_key_prefix_warnings, DebugApi};
+use typed_keys::TypedKeys;
+
+const MAIN_KEYS: &[StorageKeyDeclaration] = &[
+    StorageKeyDeclaration {
+        key: "balance",
+        mapper: true,
+        collision_message: "main balance",
+    },
+    StorageKeyDeclaration {
+        key: "counter",
+        mapper: false,
+        collision_message: "main counter",
+    },
+];
+
+const MODULE_KEYS: &[StorageKeyDeclaration] = &[
+    StorageKeyDeclaration {
+        key: "module:balance",
+        mapper: true,
+        collision_message: "module balance",
+    },
+    StorageKeyDeclaration {
+        key: "counter",
+        mapper: false,
+        collision_message: "module counter",
+    },
+];
+
+const COLLIDING_MODULE_KEYS: &[StorageKeyDeclaration] = &[StorageKeyDeclaration {
+    key: "balance",
+    mapper: false,
+    collision_message: "module balance collision",
+}];
+
+const MODULE_LAYOUT: StorageLayout = StorageLayout {
+    trait_path: "module::Module",
+    keys: MODULE_KEYS,
+    supertraits: &[],
+};
+
+const COLLIDING_MODULE_LAYOUT: StorageLayout = StorageLayout {
+    trait_path: "colliding::CollidingModule",
+    keys: COLLIDING_MODULE_KEYS,
+    supertraits: &[],
+};
+
+const MAIN_LAYOUT: StorageLayout = StorageLayout {
+    trait_path: "main::Main",
+    keys: MAIN_KEYS,
+    supertraits: &[&MODULE_LAYOUT],
+};
+
+// checked at compile time, just like in the generated contract code
+const _: () = check_storage_layout(&MAIN_LAYOUT);
+
+#[test]
+fn storage_layout_no_collision_test() {
+    // plain getters and setters in different modules can share keys
+    check_storage_layout(&MAIN_LAYOUT);
+}
+
+#[test]
+#[should_panic(expected = "module balance collision")]
+fn storage_layout_collision_test() {
+    check_storage_layout(&StorageLayout {
+        trait_path: "main::Main",
+        keys: MAIN_KEYS,
+        supertraits: &[&MODULE_LAYOUT, &COLLIDING_MODULE_LAYOUT],
+    });
+}
+
+#[test]
+#[should_panic(expected = "module balance collision")]
+fn storage_layout_nested_module_collision_test() {
+    // the colliding module is only extended by another module
+    check_storage_layout(&StorageLayout {
+        trait_path: "main::Main",
+        keys: MAIN_KEYS,
+        supertraits: &[
+            &MODULE_LAYOUT,
+            &StorageLayout {
+                trait_path: "intermediate::IntermediateModule",
+                keys: &[],
+                supertraits: &[&COLLIDING_MODULE_LAYOUT],
+            },
+        ],
+    });
+}
+
+#[test]
+fn storage_layout_shared_module_test() {
+    // a module extended both directly and through another module does not collide with itself
+    check_storage_layout(&StorageLayout {
+        trait_path: "main::Main",
+        keys: MAIN_KEYS,
+        supertraits: &[
+            &MODULE_LAYOUT,
+            &StorageLayout {
+                trait_path: "intermediate::IntermediateModule",
+                keys: &[],
+                supertraits: &[&MODULE_LAYOUT],
+            },
+        ],
+    });
+}
+
+#[test]
+#[should_panic(expected = "storage key `__reentrancy_lock__` is reserved")]
+fn storage_layout_reserved_key_test() {
+    check_storage_layout(&StorageLayout {
+        trait_path: "main::Main",
+        keys: &[StorageKeyDeclaration {
+            key: "__reentrancy_lock__",
+            mapper: false,
+            collision_message: "main lock",
+        }],
+        supertraits: &[],
+    });
+}
+
+mod typed_keys {
+    dharitri_wasm::imports!();
+    dharitri_wasm::derive_imports!();
+
+    #[derive(TopEncode, NestedEncode, TypeAbi)]
+    pub struct Position {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[dharitri_wasm::contract]
+    pub trait TypedKeys {
+        #[init]
+        fn init(&self) {}
+
+        #[storage_mapper("cell")]
+        fn cell(&self, position: &Position) -> SingleValueMapper<u32>;
+
+        #[storage_mapper("pair")]
+        fn pair(&self, pair: (u32, u64)) -> SingleValueMapper<u32>;
+    }
+}
+
+fn raw_mapper(key: &[u8]) -> SingleValueMapper<DebugApi, u32> {
+    SingleValueMapper::new(StorageKey::new(key))
+}
+
+#[test]
+fn storage_struct_and_tuple_keys_test() {
+    // key arguments are nested-encoded after the base key, structs and tuples included
+    let _ = DebugApi::dummy();
+    let sc = typed_keys::contract_obj::<DebugApi>();
+
+    sc.cell(&typed_keys::Position { x: 1, y: 2 }).set(5u32);
+    assert_eq!(
+        raw_mapper(b"cell\x00\x00\x00\x01\x00\x00\x00\x02").get(),
+        5u32
+    );
+
+    sc.pair((3, 4)).set(7u32);
+    assert_eq!(
+        raw_mapper(b"pair\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x04").get(),
+        7u32
+    );
+}
+
+fn storage(
+    name: &'static str,
+    key: &'static str,
+    mapper: Option<&'static str>,
+    key_args: &[&'static str],
+) -> StorageAbi {
+    let mut storage_abi = StorageAbi {
+        docs: &[],
+        name,
+        key,
+        kind: if mapper.is_some() {
+            StorageKindAbi::Mapper
+        } else {
+            StorageKindAbi::Get
+        },
+        mapper,
+        key_args: Vec::new(),
+        value_type: TypeName::new(),
+    };
+    for arg_name in key_args {
+        storage_abi.add_key_arg::<ManagedAddress<DebugApi>>(arg_name);
+    }
+    storage_abi.add_value_type::<BigUint<DebugApi>>();
+    storage_abi
+}
+
+#[test]
+fn storage_key_prefix_warnings_test() {
+    let mut abi = ContractAbi::default();
+    abi.storage
+        .push(storage("balance", "balance", None, &["address"]));
+    abi.storage
+        .push(storage("balance_of", "balanceOf", None, &[]));
+    abi.storage
+        .push(storage("total", "total", Some("SingleValueMapper"), &[]));
+    abi.storage
+        .push(storage("total_supply", "totalSupply", None, &[]));
+    abi.storage
+        .push(storage("users", "users", Some("SetMapper"), &[]));
+    abi.storage
+        .push(storage("users_count", "users_count", None, &[]));
+
+    assert_eq!(
+        storage_key_prefix_warnings(&abi),
+        vec![
+            "storage key \"balance\" (`balance`) is a prefix of storage key \"balanceOf\" (`balance_of`), their keys can overlap once key arguments are appended".to_string(),
+            "storage key \"users\" (`users`) is a prefix of storage key \"users_count\" (`users_count`), their keys can overlap once key arguments are appended".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,177 @@
+use dharitri_wasm::{
+    abi::{ContractAbi, EventAbi, EventInputAbi},
+    types::heap::Address,
+};
+use dharitri_wasm_debug::tx_mock::TxLog;
+
+fn input(arg_name: &'static str, type_name: &str, indexed: bool) -> EventInputAbi {
+    EventInputAbi {
+        arg_name,
+        type_name: type_name.to_string(),
+        indexed,
+        since: None,
+    }
+}
+
+fn abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.events.push(EventAbi {
+        docs: &[],
+        identifier: "multi_topics",
+        version: None,
+        inputs: vec![
+            input("pair", "multi<u32,bytes>", true),
+            input("counted", "counted-variadic<u8>", true),
+            input("opt", "optional<u64>", true),
+            input("data", "bytes", false),
+        ],
+    });
+    abi.events.push(EventAbi {
+        docs: &[],
+        identifier: "variadic_topics",
+        version: None,
+        inputs: vec![
+            input("first", "u32", true),
+            input("rest", "variadic<u32>", true),
+        ],
+    });
+    abi.events.push(EventAbi {
+        docs: &[],
+        identifier: "versioned",
+        version: Some(2),
+        inputs: vec![
+            input("first", "u32", true),
+            input("amount", "BigUint", false),
+            input("note", "bytes", false),
+            EventInputAbi {
+                since: Some(2),
+                ..input("fee", "BigUint", false)
+            },
+        ],
+    });
+    abi
+}
+
+fn log(topics: Vec<&[u8]>, data: &[u8]) -> TxLog {
+    TxLog {
+        address: Address::zero(),
+        endpoint: b"endpoint".to_vec(),
+        topics: topics.into_iter().map(|topic| topic.to_vec()).collect(),
+        data: data.to_vec(),
+    }
+}
+
+#[test]
+fn decode_multi_topics_test() {
+    let event = log(
+        vec![b"multi_topics", &[1], b"abc", &[2], &[7], &[8], &[9]],
+        b"data",
+    )
+    .decode(&abi())
+    .unwrap();
+    assert_eq!(event.identifier, "multi_topics");
+    assert_eq!(
+        event.field("pair").unwrap().raw,
+        vec![vec![1], b"abc".to_vec()]
+    );
+    assert_eq!(
+        event.field("counted").unwrap().raw,
+        vec![vec![2], vec![7], vec![8]]
+    );
+    assert_eq!(event.field("opt").unwrap().decode::<u64>(), Ok(9));
+    assert_eq!(event.field("data").unwrap().raw, vec![b"data".to_vec()]);
+    assert_eq!(
+        event.to_string(),
+        "multi_topics(pair: multi<u32,bytes> = [0x01,0x616263], counted: counted-variadic<u8> = [0x02,0x07,0x08], opt: optional<u64> = [0x09], data: bytes = [0x64617461])"
+    );
+
+    // the optional topic can be missing
+    let event = log(vec![b"multi_topics", &[1], b"abc", &[]], b"")
+        .decode(&abi())
+        .unwrap();
+    assert_eq!(event.field("counted").unwrap().raw, vec![Vec::<u8>::new()]);
+    assert!(event.field("opt").unwrap().raw.is_empty());
+}
+
+#[test]
+fn decode_variadic_topics_test() {
+    let event = log(vec![b"variadic_topics", &[1], &[2], &[3]], b"")
+        .decode(&abi())
+        .unwrap();
+    assert_eq!(event.field("first").unwrap().decode::<u32>(), Ok(1));
+    assert_eq!(event.field("rest").unwrap().raw, vec![vec![2], vec![3]]);
+}
+
+#[test]
+fn decode_errors_test() {
+    assert_eq!(
+        log(vec![b"unknown"], b"").decode(&abi()).unwrap_err(),
+        "unknown event `unknown`"
+    );
+    assert_eq!(
+        log(vec![], b"").decode(&abi()).unwrap_err(),
+        "log has no topics"
+    );
+    assert_eq!(
+        log(vec![b"multi_topics", &[1]], b"")
+            .decode(&abi())
+            .unwrap_err(),
+        "argument `pair`: too few topics"
+    );
+    assert_eq!(
+        log(vec![b"multi_topics", &[1], b"abc", &[1]], b"")
+            .decode(&abi())
+            .unwrap_err(),
+        "argument `counted`: too few topics"
+    );
+    assert_eq!(
+        log(vec![b"variadic_topics", &[1]], b"data")
+            .decode(&abi())
+            .unwrap_err(),
+        "event `variadic_topics` has unexpected data"
+    );
+}
+
+#[test]
+fn decode_structured_data_test() {
+    // version 2, then each field with a length prefix
+    let data = b"\x02\x00\x00\x00\x01\x05\x00\x00\x00\x03abc\x00\x00\x00\x01\x07";
+    let event = log(vec![b"versioned", &[1]], data).decode(&abi()).unwrap();
+    assert_eq!(event.field("amount").unwrap().raw, vec![vec![5]]);
+    assert_eq!(event.field("note").unwrap().raw, vec![b"abc".to_vec()]);
+    assert_eq!(event.field("fee").unwrap().decode::<u64>(), Ok(7));
+
+    // logged by version 1 of the contract, before the fee was added
+    let data = b"\x01\x00\x00\x00\x01\x05\x00\x00\x00\x03abc";
+    let event = log(vec![b"versioned", &[1]], data).decode(&abi()).unwrap();
+    assert_eq!(event.field("note").unwrap().raw, vec![b"abc".to_vec()]);
+    assert_eq!(event.field("fee").unwrap().decode::<u64>(), Ok(0));
+
+    // logged by a newer version of the contract, which added another field
+    let data =
+        b"\x03\x00\x00\x00\x01\x05\x00\x00\x00\x03abc\x00\x00\x00\x01\x07\x00\x00\x00\x01\x09";
+    let event = log(vec![b"versioned", &[1]], data).decode(&abi()).unwrap();
+    assert_eq!(event.field("fee").unwrap().decode::<u64>(), Ok(7));
+
+    let data = b"\x02\x00\x00\x00\x01\x05\x00\x00\x00\x03abc";
+    assert_eq!(
+        log(vec![b"versioned", &[1]], data)
+            .decode(&abi())
+            .unwrap_err(),
+        "argument `fee`: missing event data field"
+    );
+    let data =
+        b"\x02\x00\x00\x00\x01\x05\x00\x00\x00\x03abc\x00\x00\x00\x01\x07\x00\x00\x00\x01\x09";
+    assert_eq!(
+        log(vec![b"versioned", &[1]], data)
+            .decode(&abi())
+            .unwrap_err(),
+        "event `versioned`: unexpected event data fields"
+    );
+    assert_eq!(
+        log(vec![b"versioned", &[1]], b"")
+            .decode(&abi())
+            .unwrap_err(),
+        "invalid event data: missing event version"
+    );
+}
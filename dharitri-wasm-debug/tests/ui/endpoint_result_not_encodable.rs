@@ -0,0 +1,31 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+#[derive(TypeAbi)]
+pub struct NotEncodable {
+    pub value: u32,
+}
+
+#[dharitri_wasm::contract]
+pub trait EndpointResultNotEncodable {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn get_value(&self) -> NotEncodable {
+        NotEncodable { value: 1 }
+    }
+
+    #[endpoint]
+    fn try_get_value(&self) -> Result<NotEncodable, ValueError> {
+        Result::Err(ValueError::Missing)
+    }
+}
+
+#[derive(TypeAbi, ContractError)]
+pub enum ValueError {
+    #[error(code = 1, message = "missing value")]
+    Missing,
+}
+
+fn main() {}
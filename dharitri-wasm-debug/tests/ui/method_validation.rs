@@ -0,0 +1,31 @@
+dharitri_wasm::imports!();
+
+#[dharitri_wasm::contract]
+pub trait MethodValidation {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint(getCaller)]
+    fn get_caller_endpoint(&self) {}
+
+    #[nonzero_payment]
+    #[endpoint]
+    fn pay(&self) {}
+
+    #[endpoint]
+    fn not_a_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<u32>,
+        #[call_result] other_result: ManagedAsyncCallResult<u32>,
+    ) {
+        let _ = (result, other_result);
+    }
+
+    #[storage_mapper("value")]
+    fn value(&self) -> SingleValueMapper<u32>;
+
+    #[storage_get("value")]
+    fn get_value(&self) -> u32;
+}
+
+fn main() {}
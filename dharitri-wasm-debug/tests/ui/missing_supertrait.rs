@@ -0,0 +1,17 @@
+dharitri_wasm::imports!();
+
+#[dharitri_wasm::contract]
+pub trait MissingSupertrait {
+    #[init]
+    fn init(&self) {}
+
+    #[only_role("MINTER")]
+    #[endpoint]
+    fn mint(&self) {}
+
+    #[require_not_paused]
+    #[endpoint]
+    fn transfer(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+dharitri_wasm::imports!();
+
+#[dharitri_wasm::contract]
+pub trait PaymentArgsNotPayable {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn deposit(&self, #[payment_token] token: TokenIdentifier, #[payment_amount] amount: BigUint) {
+        let _ = (token, amount);
+    }
+
+    #[endpoint]
+    fn deposit_multi(&self, #[payment_multi] payments: ManagedVec<DctTokenPayment<Self::Api>>) {
+        let _ = payments;
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn deposit_twice(
+        &self,
+        #[payment_token] token: TokenIdentifier,
+        #[payment_token] other: TokenIdentifier,
+    ) {
+        let _ = (token, other);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+dharitri_wasm::imports!();
+
+#[dharitri_wasm::contract]
+pub trait VarArgsNotLast {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn sum(&self, values: MultiValueEncoded<BigUint>, factor: u32) -> BigUint {
+        let mut sum = BigUint::zero();
+        for value in values {
+            sum += value;
+        }
+        sum * factor
+    }
+
+    #[endpoint]
+    fn greet(&self, title: OptionalValue<ManagedBuffer>, name: ManagedBuffer) -> ManagedBuffer {
+        let _ = title;
+        name
+    }
+
+    #[endpoint]
+    fn optional_then_var_args(
+        &self,
+        first: OptionalValue<u32>,
+        rest: MultiValueEncoded<u32>,
+    ) -> u32 {
+        let _ = rest;
+        first.into_option().unwrap_or_default()
+    }
+}
+
+fn main() {}
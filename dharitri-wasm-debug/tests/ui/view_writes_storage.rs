@@ -0,0 +1,35 @@
+dharitri_wasm::imports!();
+
+#[dharitri_wasm::contract]
+pub trait ViewWritesStorage {
+    #[init]
+    fn init(&self) {}
+
+    #[view(getCounter)]
+    fn get_counter(&self) -> u64 {
+        self.counter().update(|counter| *counter += 1);
+        self.counter().get()
+    }
+
+    #[view(getAndReset)]
+    fn get_and_reset(&self) -> u64 {
+        let value = self.get_last_value();
+        if value > 0 {
+            self.set_last_value(0);
+        }
+        value
+    }
+
+    #[view(setLastValue)]
+    #[storage_set("lastValue")]
+    fn set_last_value(&self, value: u64);
+
+    #[storage_get("lastValue")]
+    fn get_last_value(&self) -> u64;
+
+    #[view(getCount)]
+    #[storage_mapper("counter")]
+    fn counter(&self) -> SingleValueMapper<u64>;
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+dharitri_wasm::imports!();
+
+/// Re-exports the access control module under the name used by this project.
+pub mod roles {
+    pub use dharitri_wasm_modules::access_control::{AccessControlModule as RolesModule, *};
+}
+
+/// Re-exports the pause module under the name used by this project.
+pub mod circuit_breaker {
+    pub use dharitri_wasm_modules::pause::{PauseModule as CircuitBreakerModule, *};
+}
+
+pub mod mint {
+    dharitri_wasm::imports!();
+
+    /// Only gets `AccessControlModule` through `RolesModule`.
+    #[dharitri_wasm::module]
+    pub trait MintModule: super::roles::RolesModule {
+        #[only_role("MINTER")]
+        #[endpoint]
+        fn mint(&self) {}
+    }
+}
+
+#[dharitri_wasm::contract]
+pub trait IndirectSupertrait:
+    mint::MintModule + roles::RolesModule + circuit_breaker::CircuitBreakerModule
+{
+    #[init]
+    fn init(&self) {}
+
+    #[only_role("ADMIN")]
+    #[endpoint]
+    fn configure(&self) {}
+
+    #[require_not_paused]
+    #[endpoint]
+    fn transfer(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,159 @@
+use super::{
+    format::{count_args, parse_format_string, FormatPartType},
+    generate::util::byte_str_literal,
+    parse::attributes::{extract_doc, extract_error_attribute},
+};
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// The variant fields, bound to local variables, in declaration order.
+fn variant_field_bindings(
+    variant: &syn::Variant,
+) -> (proc_macro2::TokenStream, Vec<proc_macro2::Ident>) {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Named(fields_named) => {
+            let names: Vec<proc_macro2::Ident> = fields_named
+                .named
+                .iter()
+                .map(|field| field.ident.clone().unwrap())
+                .collect();
+            (quote! { Self::#variant_ident { #(#names),* } }, names)
+        },
+        syn::Fields::Unnamed(fields_unnamed) => {
+            let names: Vec<proc_macro2::Ident> = (0..fields_unnamed.unnamed.len())
+                .map(|index| format_ident!("field_{}", index))
+                .collect();
+            (quote! { Self::#variant_ident ( #(#names),* ) }, names)
+        },
+        syn::Fields::Unit => (quote! { Self::#variant_ident }, Vec::new()),
+    }
+}
+
+/// Each `{}`-style placeholder in the message gets filled by the next field of the variant.
+fn message_snippets(
+    message: &syn::LitStr,
+    fields: &[proc_macro2::Ident],
+    variant_name: &str,
+) -> Vec<proc_macro2::TokenStream> {
+    let format_parts = parse_format_string(&quote! { #message }.to_string());
+    let num_placeholders = count_args(&format_parts);
+    assert!(
+        num_placeholders == fields.len(),
+        "contract error variant `{}` has {} fields, but its message has {} placeholders",
+        variant_name,
+        fields.len(),
+        num_placeholders
+    );
+
+    let mut fields_iter = fields.iter();
+    format_parts
+        .into_iter()
+        .map(|part| match part {
+            FormatPartType::StaticAscii(ascii_string) => {
+                let str_as_bytes = byte_str_literal(ascii_string.as_bytes());
+                quote! {
+                    dharitri_wasm::formatter::FormatBuffer::append_ascii(__buffer__, #str_as_bytes);
+                }
+            },
+            FormatPartType::Display => {
+                let field = fields_iter.next().unwrap();
+                quote! {
+                    dharitri_wasm::formatter::FormatBuffer::append_display(__buffer__, #field);
+                }
+            },
+            FormatPartType::LowerHex => {
+                let field = fields_iter.next().unwrap();
+                quote! {
+                    dharitri_wasm::formatter::FormatBuffer::append_lower_hex(__buffer__, #field);
+                }
+            },
+            FormatPartType::Codec => {
+                let field = fields_iter.next().unwrap();
+                quote! {
+                    dharitri_wasm::formatter::FormatBuffer::append_codec(__buffer__, #field);
+                }
+            },
+            FormatPartType::Bytes => {
+                let field = fields_iter.next().unwrap();
+                quote! {
+                    dharitri_wasm::formatter::FormatBuffer::append_binary(__buffer__, #field);
+                }
+            },
+        })
+        .collect()
+}
+
+pub fn contract_error_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let name_str = name.to_string();
+    let data_enum = match &ast.data {
+        syn::Data::Enum(data_enum) => data_enum,
+        _ => panic!("ContractError can only be derived for enums"),
+    };
+
+    let mut codes: Vec<u32> = Vec::new();
+    let mut code_arms = Vec::new();
+    let mut message_arms = Vec::new();
+    let mut error_abi_snippets = Vec::new();
+    for variant in data_enum.variants.iter() {
+        let variant_name = variant.ident.to_string();
+        let error_attr = extract_error_attribute(variant.attrs.as_slice(), &variant_name);
+        let code = error_attr.code;
+        assert!(
+            !codes.contains(&code),
+            "error code {} is used by more than one variant of `{}`",
+            code,
+            name_str
+        );
+        codes.push(code);
+
+        let (pattern, fields) = variant_field_bindings(variant);
+        let message_snippets = message_snippets(&error_attr.message, &fields, &variant_name);
+        let variant_ident = &variant.ident;
+        code_arms.push(quote! {
+            Self::#variant_ident { .. } => #code,
+        });
+        message_arms.push(quote! {
+            #pattern => {
+                #(#message_snippets)*
+            },
+        });
+
+        let variant_docs = extract_doc(variant.attrs.as_slice());
+        let message_str = error_attr.message.value();
+        error_abi_snippets.push(quote! {
+            dharitri_wasm::abi::ErrorAbi {
+                docs: &[ #(#variant_docs),* ],
+                error_type: #name_str,
+                code: #code,
+                name: #variant_name,
+                message: #message_str,
+            }
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let contract_error_impl = quote! {
+        impl #impl_generics dharitri_wasm::types::ContractError for #name #ty_generics #where_clause {
+            fn error_code(&self) -> u32 {
+                match self {
+                    #(#code_arms)*
+                }
+            }
+
+            fn append_message<F: dharitri_wasm::formatter::FormatBuffer>(&self, __buffer__: &mut F) {
+                match self {
+                    #(#message_arms)*
+                }
+            }
+
+            fn error_abis() -> dharitri_wasm::types::heap::Vec<dharitri_wasm::abi::ErrorAbi> {
+                let mut error_abis = dharitri_wasm::types::heap::Vec::new();
+                #(error_abis.push(#error_abi_snippets);)*
+                error_abis
+            }
+        }
+    };
+    contract_error_impl.into()
+}
@@ -4,7 +4,8 @@ use crate::{
         auto_impl::generate_auto_impls, auto_impl_proxy::generate_all_proxy_trait_imports,
         callback_gen::*, callback_proxies_gen::*, contract_gen::*,
         endpoints_mod_gen::generate_endpoints_mod,
-        function_selector::generate_function_selector_body, proxy_gen, supertrait_gen,
+        function_selector::generate_function_selector_body, interactor_gen, migration_gen,
+        proxy_gen, storage_layout_gen, supertrait_gen, typed_events_gen,
     },
     model::ContractTrait,
 };
@@ -21,6 +22,7 @@ pub fn contract_implementation(
     let method_impls = extract_method_impls(contract);
     let call_methods = generate_call_methods(contract);
     let auto_impl_defs = generate_auto_impl_defs(contract);
+    let run_migrations = migration_gen::generate_run_migrations(contract);
     let auto_impls = generate_auto_impls(contract);
     let endpoints_mod = generate_endpoints_mod(contract, is_contract_main);
     let function_selector_body = generate_function_selector_body(contract);
@@ -43,6 +45,8 @@ pub fn contract_implementation(
 
             #(#auto_impl_defs)*
 
+            #run_migrations
+
             #callbacks_def
         }
     };
@@ -86,6 +90,13 @@ pub fn contract_implementation(
 
     let abi_provider = abi_gen::generate_abi_provider(contract, is_contract_main);
 
+    let storage_layout_keys = storage_layout_gen::generate_storage_layout_keys(contract);
+    let storage_layout_check = if is_contract_main {
+        storage_layout_gen::generate_storage_layout_check()
+    } else {
+        quote! {}
+    };
+
     let module_traits_code = quote! {
         #main_definition
 
@@ -94,6 +105,10 @@ pub fn contract_implementation(
         #endpoint_wrappers
 
         #abi_provider
+
+        #storage_layout_keys
+
+        #storage_layout_check
     };
 
     let contract_object_def = snippets::contract_object_def();
@@ -101,7 +116,8 @@ pub fn contract_implementation(
     let impl_all_auto_impl = supertrait_gen::impl_all_auto_impl(contract.supertraits.as_slice());
     let impl_all_endpoint_wrappers =
         supertrait_gen::impl_all_endpoint_wrappers(contract.supertraits.as_slice());
-    let impl_callable_contract = snippets::impl_callable_contract();
+    let impl_callable_contract =
+        snippets::impl_callable_contract(contract.upgrade_method().is_some());
     let new_contract_object_fn = snippets::new_contract_object_fn();
 
     let contract_obj_code = quote! {
@@ -126,6 +142,15 @@ pub fn contract_implementation(
         quote! {}
     };
 
+    let interactor_trait = interactor_gen::interactor_trait(contract);
+    let interactor_obj_code = if is_contract_main {
+        interactor_gen::interactor_obj_code(contract)
+    } else {
+        quote! {}
+    };
+
+    let typed_events_mod = typed_events_gen::generate_typed_events_mod(contract);
+
     quote! {
         #module_traits_code
 
@@ -137,6 +162,12 @@ pub fn contract_implementation(
 
         #proxy_obj_code
 
+        #interactor_trait
+
+        #interactor_obj_code
+
+        #typed_events_mod
+
         #callback_proxies_obj
     }
 }
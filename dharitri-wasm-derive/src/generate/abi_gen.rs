@@ -1,15 +1,15 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointLocationMetadata, EndpointMutabilityMetadata, Method,
-    MethodImpl, PublicRole,
+    AutoImpl, ContractTrait, EndpointLocationMetadata, EndpointMetadata,
+    EndpointMutabilityMetadata, Method, MethodImpl, PublicRole,
 };
 
+/// Constructors have no `EndpointMetadata`, they get the defaults: no restrictions, mutable, main contract.
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
-    only_owner: bool,
-    mutability: EndpointMutabilityMetadata,
-    location: EndpointLocationMetadata,
+    module_name: &str,
+    endpoint_metadata: Option<&EndpointMetadata>,
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
     let payable_in_tokens = m.payable_metadata().abi_strings();
@@ -39,57 +39,100 @@ fn generate_endpoint_snippet(
         syn::ReturnType::Type(_, ty) => {
             let mut res_type = ty.clone();
             clear_all_type_lifetimes(&mut res_type);
+            let errors_snippet = match split_result_type(&res_type) {
+                Some((_, err_type)) => quote! {
+                    endpoint_abi.add_errors::<#err_type>();
+                    contract_abi.add_errors::<#err_type>();
+                    contract_abi.add_type_descriptions::<#err_type>();
+                },
+                None => quote! {},
+            };
             quote! {
                 endpoint_abi.add_output::<#res_type>(&[ #(#output_names),* ]);
                 contract_abi.add_type_descriptions::<#res_type>();
+                #errors_snippet
             }
         },
     };
-    let mutability_tokens = mutability.to_tokens();
-    let location_tokens = location.to_tokens();
+    let only_owner = matches!(endpoint_metadata, Some(metadata) if metadata.only_owner);
+    let only_role_tokens = match endpoint_metadata.and_then(|metadata| metadata.only_role.as_ref())
+    {
+        Some(role) => quote! { Some(#role) },
+        None => quote! { None },
+    };
+    let tags: &[String] = endpoint_metadata.map_or(&[], |metadata| metadata.tags.as_slice());
+    let guards = endpoint_metadata.map_or_else(Vec::new, endpoint_guard_strings);
+    let mutability_tokens = endpoint_metadata
+        .map_or(EndpointMutabilityMetadata::Mutable, |metadata| {
+            metadata.mutability.clone()
+        })
+        .to_tokens();
+    let location_tokens = endpoint_metadata
+        .map_or(EndpointLocationMetadata::MainContract, |metadata| {
+            metadata.location.clone()
+        })
+        .to_tokens();
 
     quote! {
         let mut endpoint_abi = dharitri_wasm::abi::EndpointAbi{
             docs: &[ #(#endpoint_docs),* ],
             name: #endpoint_name,
             only_owner: #only_owner,
+            only_role: #only_role_tokens,
+            tags: &[ #(#tags),* ],
+            guards: &[ #(#guards),* ],
+            module: #module_name,
             mutability: #mutability_tokens,
             location: #location_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: dharitri_wasm::types::heap::Vec::new(),
             outputs: dharitri_wasm::types::heap::Vec::new(),
+            errors: dharitri_wasm::types::heap::Vec::new(),
         };
         #(#input_snippets)*
         #output_snippet
     }
 }
 
+fn endpoint_guard_strings(endpoint_metadata: &EndpointMetadata) -> Vec<String> {
+    let mut guards: Vec<String> = endpoint_metadata
+        .guards
+        .iter()
+        .map(|guard| guard.abi_string())
+        .collect();
+    if endpoint_metadata.non_reentrant {
+        guards.push("non_reentrant".to_string());
+    }
+    guards
+}
+
 fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    let module_name = contract.trait_name.to_string();
     contract
         .methods
         .iter()
         .filter_map(|m| match &m.public_role {
             PublicRole::Init(_) => {
-                let endpoint_def = generate_endpoint_snippet(
-                    m,
-                    "init",
-                    false,
-                    EndpointMutabilityMetadata::Mutable,
-                    EndpointLocationMetadata::MainContract,
-                );
+                let endpoint_def = generate_endpoint_snippet(m, "init", &module_name, None);
                 Some(quote! {
                     #endpoint_def
                     contract_abi.constructors.push(endpoint_abi);
                 })
             },
+            PublicRole::Upgrade(_) => {
+                let endpoint_def = generate_endpoint_snippet(m, "upgrade", &module_name, None);
+                Some(quote! {
+                    #endpoint_def
+                    contract_abi.upgrade_constructors.push(endpoint_abi);
+                })
+            },
             PublicRole::Endpoint(endpoint_metadata) => {
                 let endpoint_name_str = endpoint_metadata.public_name.to_string();
                 let endpoint_def = generate_endpoint_snippet(
                     m,
                     &endpoint_name_str,
-                    endpoint_metadata.only_owner,
-                    endpoint_metadata.mutability.clone(),
-                    endpoint_metadata.location.clone(),
+                    &module_name,
+                    Some(endpoint_metadata),
                 );
                 Some(quote! {
                     #endpoint_def
@@ -101,8 +144,16 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
         .collect()
 }
 
-fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStream {
+fn generate_event_snippet(
+    m: &Method,
+    event_name: &str,
+    version: Option<u8>,
+) -> proc_macro2::TokenStream {
     let event_docs = &m.docs;
+    let version_snippet = match version {
+        Some(version) => quote! { core::option::Option::Some(#version) },
+        None => quote! { core::option::Option::None },
+    };
     let input_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
         .iter()
@@ -112,8 +163,16 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
             clear_all_type_lifetimes(&mut arg_type);
             let arg_name = &arg.pat;
             let arg_name_str = quote! { #arg_name }.to_string();
+            let add_input_snippet = match arg.metadata.event_since {
+                Some(since) if since > 1 => quote! {
+                    event_abi.add_data_input_since::<#arg_type>(#arg_name_str, #since);
+                },
+                _ => quote! {
+                    event_abi.add_input::<#arg_type>(#arg_name_str, #indexed);
+                },
+            };
             quote! {
-                event_abi.add_input::<#arg_type>(#arg_name_str, #indexed);
+                #add_input_snippet
                 contract_abi.add_type_descriptions::<#arg_type>();
             }
         })
@@ -123,6 +182,7 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
         let mut event_abi = dharitri_wasm::abi::EventAbi{
             docs: &[ #(#event_docs),* ],
             identifier: #event_name,
+            version: #version_snippet,
             inputs: dharitri_wasm::types::heap::Vec::new(),
         };
         #(#input_snippets)*
@@ -134,8 +194,12 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .methods
         .iter()
         .filter_map(|m| {
-            if let MethodImpl::Generated(AutoImpl::Event { identifier }) = &m.implementation {
-                let event_def = generate_event_snippet(m, identifier);
+            if let MethodImpl::Generated(AutoImpl::Event {
+                identifier,
+                version,
+            }) = &m.implementation
+            {
+                let event_def = generate_event_snippet(m, identifier, *version);
                 Some(quote! {
                     #event_def
                     contract_abi.events.push(event_abi);
@@ -147,6 +211,147 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// Skips the `Self::Api` generic argument, which is added to managed types and mappers during preprocessing.
+fn is_self_api(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        type_path.qself.is_none()
+            && type_path.path.segments.len() == 2
+            && type_path.path.segments[0].ident == "Self"
+            && type_path.path.segments[1].ident == "Api"
+    } else {
+        false
+    }
+}
+
+fn generic_type_args(arguments: &syn::PathArguments) -> Vec<&syn::Type> {
+    match arguments {
+        syn::PathArguments::AngleBracketed(angle_bracketed) => angle_bracketed
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) if !is_self_api(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    kind: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let method_name = m.name.to_string();
+
+    let (key_args, mapper, value_types) = match &m.implementation {
+        MethodImpl::Generated(AutoImpl::StorageSetter { .. }) => {
+            let (value_arg, key_args) = m
+                .method_args
+                .split_last()
+                .expect("setter must have at least one argument, for the value");
+            (key_args, None, vec![&value_arg.ty])
+        },
+        _ => {
+            let return_type = match &m.return_type {
+                syn::ReturnType::Type(_, ty) => ty.as_ref(),
+                syn::ReturnType::Default => panic!("getter should return some value"),
+            };
+            match (&m.implementation, return_type) {
+                (
+                    MethodImpl::Generated(AutoImpl::StorageMapper { .. }),
+                    syn::Type::Path(type_path),
+                ) => {
+                    let mapper_segment = type_path.path.segments.last().unwrap();
+                    (
+                        m.method_args.as_slice(),
+                        Some(mapper_segment.ident.to_string()),
+                        generic_type_args(&mapper_segment.arguments),
+                    )
+                },
+                _ => (m.method_args.as_slice(), None, vec![return_type]),
+            }
+        },
+    };
+
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let mut arg_type = arg.ty.clone();
+            clear_all_type_lifetimes(&mut arg_type);
+            let original_pat = &arg.original_pat;
+            let arg_name_str = quote! { #original_pat }.to_string();
+            quote! {
+                storage_abi.add_key_arg::<#arg_type>(#arg_name_str);
+                contract_abi.add_type_descriptions::<#arg_type>();
+            }
+        })
+        .collect();
+    let value_type_snippets: Vec<proc_macro2::TokenStream> = value_types
+        .into_iter()
+        .map(|value_type| {
+            let mut value_type = value_type.clone();
+            clear_all_type_lifetimes(&mut value_type);
+            quote! {
+                storage_abi.add_value_type::<#value_type>();
+                contract_abi.add_type_descriptions::<#value_type>();
+            }
+        })
+        .collect();
+    let mapper_tokens = match mapper {
+        Some(mapper) => quote! { Some(#mapper) },
+        None => quote! { None },
+    };
+
+    quote! {
+        let mut storage_abi = dharitri_wasm::abi::StorageAbi {
+            docs: &[ #(#storage_docs),* ],
+            name: #method_name,
+            key: #identifier,
+            kind: #kind,
+            mapper: #mapper_tokens,
+            key_args: dharitri_wasm::types::heap::Vec::new(),
+            value_type: dharitri_wasm::abi::TypeName::new(),
+        };
+        #(#key_arg_snippets)*
+        #(#value_type_snippets)*
+        contract_abi.storage.push(storage_abi);
+    }
+}
+
+/// `#[storage_is_empty]` and `#[storage_clear]` do not describe any value, so they are left out.
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(AutoImpl::StorageGetter { identifier }) => {
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    quote! { dharitri_wasm::abi::StorageKindAbi::Get },
+                ))
+            },
+            MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    quote! { dharitri_wasm::abi::StorageKindAbi::Set },
+                ))
+            },
+            MethodImpl::Generated(AutoImpl::StorageMapper { identifier }) => {
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    quote! { dharitri_wasm::abi::StorageKindAbi::Mapper },
+                ))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -177,7 +382,9 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
+    let schema_version = contract.latest_schema_version();
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
     } else {
@@ -197,13 +404,18 @@ fn generate_abi_method_body(
             docs: &[ #(#contract_docs),* ],
             name: #contract_name,
             constructors: dharitri_wasm::types::heap::Vec::new(),
+            upgrade_constructors: dharitri_wasm::types::heap::Vec::new(),
             endpoints: dharitri_wasm::types::heap::Vec::new(),
             events: dharitri_wasm::types::heap::Vec::new(),
+            errors: dharitri_wasm::types::heap::Vec::new(),
+            storage: dharitri_wasm::types::heap::Vec::new(),
             has_callback: #has_callbacks,
+            schema_version: #schema_version,
             type_descriptions: <dharitri_wasm::abi::TypeDescriptionContainerImpl as dharitri_wasm::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
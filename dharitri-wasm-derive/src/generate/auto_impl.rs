@@ -32,7 +32,10 @@ fn generate_auto_impl(m: &Method, auto_impl: &AutoImpl) -> proc_macro2::TokenStr
         AutoImpl::LegacyEvent { identifier } => {
             generate_legacy_event_impl(m, identifier.as_slice())
         },
-        AutoImpl::Event { identifier } => generate_event_impl(m, identifier),
+        AutoImpl::Event {
+            identifier,
+            version,
+        } => generate_event_impl(m, identifier, *version),
         AutoImpl::StorageGetter { identifier } => generate_getter_impl(m, identifier),
         AutoImpl::StorageSetter { identifier } => generate_setter_impl(m, identifier),
         AutoImpl::StorageMapper { identifier } => generate_mapper_impl(m, identifier),
@@ -1,18 +1,73 @@
-use super::{method_gen, util::*};
+use super::{convert_to_owned_type::convert_to_owned_type, method_gen, util::*};
 use crate::model::{Method, MethodArgument};
+use proc_macro2::Span;
 
-pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::TokenStream {
-    let mut data_arg: Option<&MethodArgument> = None;
-    let mut topic_args = Vec::<&MethodArgument>::new();
+/// A non-indexed event argument, together with the event version that introduced it.
+pub struct EventDataArg<'a> {
+    pub arg: &'a MethodArgument,
+    pub since: u8,
+}
+
+/// Splits the arguments of an `#[event]` into topics and data, checking the `#[since(N)]` attributes.
+pub fn split_event_args(
+    m: &Method,
+    version: Option<u8>,
+) -> (Vec<&MethodArgument>, Vec<EventDataArg<'_>>) {
+    let mut topic_args = Vec::new();
+    let mut data_args: Vec<EventDataArg<'_>> = Vec::new();
     for arg in &m.method_args {
         if arg.metadata.event_topic {
+            assert!(
+                arg.metadata.event_since.is_none(),
+                "`#[since]` is only allowed on non-indexed event arguments"
+            );
             topic_args.push(arg);
-        } else if data_arg.is_none() {
-            data_arg = Some(arg);
-        } else {
-            panic!("only 1 data argument allowed in event log");
+            continue;
         }
+
+        let since = match (arg.metadata.event_since, version) {
+            (None, _) => 1,
+            (Some(since), Some(version)) => {
+                assert!(
+                    since <= version,
+                    "event argument `since` must be between 1 and the event version ({})",
+                    version
+                );
+                since
+            },
+            (Some(_), None) => panic!(
+                "`#[since]` requires a versioned event, e.g. `#[event(\"identifier\", version = 2)]`"
+            ),
+        };
+        if let Some(previous) = data_args.last() {
+            assert!(
+                previous.since <= since,
+                "event arguments added in later versions must come after the older ones"
+            );
+        }
+        data_args.push(EventDataArg { arg, since });
+    }
+    (topic_args, data_args)
+}
+
+/// Several non-indexed arguments, or versioning, require the structured data encoding.
+pub fn has_structured_data(data_args: &[EventDataArg], version: Option<u8>) -> bool {
+    version.is_some() || data_args.len() > 1
+}
+
+fn option_u8_tokens(value: Option<u8>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { core::option::Option::Some(#value) },
+        None => quote! { core::option::Option::None },
     }
+}
+
+pub fn generate_event_impl(
+    m: &Method,
+    event_identifier: &str,
+    version: Option<u8>,
+) -> proc_macro2::TokenStream {
+    let (topic_args, data_args) = split_event_args(m, version);
 
     let topic_push_snippets: Vec<proc_macro2::TokenStream> = topic_args
         .iter()
@@ -23,8 +78,20 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
             }
         })
         .collect();
-    let data_buffer_snippet = if let Some(data_arg) = data_arg {
-        let data_pat = &data_arg.pat;
+    let data_buffer_snippet = if has_structured_data(data_args.as_slice(), version) {
+        let version_tokens = option_u8_tokens(version);
+        let field_snippets = data_args.iter().map(|data_arg| {
+            let data_pat = &data_arg.arg.pat;
+            quote! {
+                dharitri_wasm::log_util::serialize_event_data_field(&mut ___data_buffer___, #data_pat);
+            }
+        });
+        quote! {
+            let mut ___data_buffer___ = dharitri_wasm::log_util::event_data_accumulator::<Self::Api>(#version_tokens);
+            #(#field_snippets)*
+        }
+    } else if let Some(data_arg) = data_args.first() {
+        let data_pat = &data_arg.arg.pat;
         quote! {
             let ___data_buffer___ = dharitri_wasm::log_util::serialize_log_data(#data_pat);
         }
@@ -48,6 +115,76 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
     }
 }
 
+/// The name of the local variable holding a decoded event argument.
+pub fn event_decoded_arg_name(arg_index: usize) -> syn::Ident {
+    syn::Ident::new(&format!("___arg_{}___", arg_index), Span::call_site())
+}
+
+/// Decodes all event arguments from `topics` (identifier first) and `data`,
+/// into local variables named via `event_decoded_arg_name`, in declaration order.
+///
+/// Must be placed in a function returning `Result<_, DecodeError>`.
+pub fn generate_event_decode_snippet(
+    m: &Method,
+    event_identifier: &str,
+    version: Option<u8>,
+) -> proc_macro2::TokenStream {
+    let (_, data_args) = split_event_args(m, version);
+    let structured_data = has_structured_data(data_args.as_slice(), version);
+
+    let mut topic_decode_snippets = Vec::new();
+    let mut data_decode_snippets = Vec::new();
+    let mut data_args_iter = data_args.iter();
+    for (arg_index, arg) in m.method_args.iter().enumerate() {
+        let arg_name = event_decoded_arg_name(arg_index);
+        let owned_type = convert_to_owned_type(&arg.ty);
+        if arg.metadata.event_topic {
+            topic_decode_snippets.push(quote! {
+                let #arg_name = <#owned_type as dharitri_wasm::dharitri_codec::TopDecodeMulti>::multi_decode(
+                    &mut ___topics___,
+                )?;
+            });
+        } else if structured_data {
+            let since = data_args_iter.next().unwrap().since;
+            data_decode_snippets.push(quote! {
+                let #arg_name = ___data___.next_field::<#owned_type>(#since)?;
+            });
+        } else {
+            data_decode_snippets.push(quote! {
+                let #arg_name = <#owned_type as dharitri_wasm::dharitri_codec::TopDecode>::top_decode(data)?;
+            });
+        }
+    }
+
+    let data_decode_snippet = if structured_data {
+        let version_tokens = option_u8_tokens(version);
+        quote! {
+            let mut ___data___ = dharitri_wasm::types::InteractorEventData::new(data, #version_tokens)?;
+            #(#data_decode_snippets)*
+            ___data___.finish()?;
+        }
+    } else if data_decode_snippets.is_empty() {
+        quote! {
+            let _ = data;
+        }
+    } else {
+        quote! {
+            #(#data_decode_snippets)*
+        }
+    };
+    let event_identifier_literal = byte_str_slice_literal(event_identifier.as_bytes());
+
+    quote! {
+        let mut ___topics___ = dharitri_wasm::types::interactor_event_topics(topics, #event_identifier_literal)?;
+        #(#topic_decode_snippets)*
+        dharitri_wasm::dharitri_codec::TopDecodeMultiInput::assert_no_more_args(
+            &___topics___,
+            dharitri_wasm::dharitri_codec::DefaultErrorHandler,
+        )?;
+        #data_decode_snippet
+    }
+}
+
 /// Still only used in legacy event logs.
 fn generate_topic_conversion_code(
     topic_index: usize,
@@ -1,4 +1,4 @@
-use super::{method_call_gen::generate_call_method, method_gen};
+use super::{method_call_gen::generate_call_method, method_gen, migration_gen};
 use crate::model::{ContractTrait, MethodImpl, PublicRole};
 
 pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
@@ -24,7 +24,10 @@ pub fn generate_call_methods(contract_trait: &ContractTrait) -> Vec<proc_macro2:
         .methods
         .iter()
         .filter_map(|m| match &m.public_role {
-            PublicRole::Init(_init_metadata) => Some(generate_call_method(m)),
+            PublicRole::Init(_init_metadata) => {
+                Some(migration_gen::generate_init_call_method(contract_trait, m))
+            },
+            PublicRole::Upgrade(_upgrade_metadata) => Some(generate_call_method(m)),
             PublicRole::Endpoint(_endpoint_metadata) => Some(generate_call_method(m)),
             _ => None,
         })
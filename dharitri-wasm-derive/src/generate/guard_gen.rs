@@ -0,0 +1,47 @@
+use super::required_module_gen::generate_required_module_call;
+use crate::model::{EndpointGuard, Method, PublicRole};
+
+/// Guards are checked in declaration order, after the payable and ownership checks.
+///
+/// `#[require_not_paused]` relies on the `PauseModule` in `dharitri-wasm-modules`,
+/// so the trait declaring the endpoint needs to extend it, directly or through another module.
+pub fn generate_guard_snippets(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        let guard_snippets = endpoint_metadata
+            .guards
+            .iter()
+            .map(|guard| generate_guard_snippet(m, guard));
+        return quote! {
+            #(#guard_snippets)*
+        };
+    }
+    quote! {}
+}
+
+fn generate_guard_snippet(m: &Method, guard: &EndpointGuard) -> proc_macro2::TokenStream {
+    match guard {
+        EndpointGuard::RequireNotPaused => {
+            let is_paused = generate_required_module_call(
+                m,
+                "require_not_paused_requires_pause_module",
+                quote! { dharitri_wasm_modules::pause::PauseModule },
+                quote! { bool },
+                quote! { sc.is_paused() },
+            );
+            quote! {
+                dharitri_wasm::io::endpoint_guards::require_not_paused::<Self::Api>(#is_paused);
+            }
+        },
+        EndpointGuard::OnlyWhitelisted(mapper) => quote! {
+            dharitri_wasm::io::endpoint_guards::require_caller_whitelisted::<Self::Api>(
+                self.#mapper().contains(&self.blockchain().get_caller()),
+            );
+        },
+        EndpointGuard::NonzeroPayment => quote! {
+            dharitri_wasm::io::endpoint_guards::require_nonzero_payment::<Self::Api>();
+        },
+        EndpointGuard::BeforeTimestamp(storage) => quote! {
+            dharitri_wasm::io::endpoint_guards::require_before_timestamp::<Self::Api>(self.#storage().get());
+        },
+    }
+}
@@ -0,0 +1,144 @@
+use super::{
+    auto_impl_event::{event_decoded_arg_name, generate_event_decode_snippet},
+    convert_to_owned_type::convert_to_owned_type,
+    proxy_gen::{
+        generate_method_sig_with_return_type, generate_proxy_deploy_body,
+        generate_proxy_endpoint_body, method_result_type,
+    },
+    snippets, supertrait_gen,
+};
+use crate::model::{AutoImpl, ContractTrait, Method, MethodImpl, PublicRole};
+
+fn generate_interactor_endpoint(m: &Method, endpoint_name: String) -> proc_macro2::TokenStream {
+    let ret_tok = method_result_type(m);
+    let msig = generate_method_sig_with_return_type(
+        m,
+        quote! { dharitri_wasm::types::InteractorCall<#ret_tok> },
+    );
+    let body = generate_proxy_endpoint_body(m, endpoint_name);
+
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #[allow(clippy::type_complexity)]
+        #msig {
+            let ___contract_call___: dharitri_wasm::types::ContractCall<Self::Api, #ret_tok> = {
+                #body
+            };
+            dharitri_wasm::types::InteractorCall::from(___contract_call___)
+        }
+    }
+}
+
+fn generate_interactor_deploy(init_method: &Method) -> proc_macro2::TokenStream {
+    let ret_tok = method_result_type(init_method);
+    let msig = generate_method_sig_with_return_type(
+        init_method,
+        quote! { dharitri_wasm::types::InteractorDeploy<#ret_tok> },
+    );
+    let body = generate_proxy_deploy_body(init_method);
+
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #[allow(clippy::type_complexity)]
+        #msig {
+            let ___contract_deploy___: dharitri_wasm::types::ContractDeploy<Self::Api, #ret_tok> = {
+                #body
+            };
+            dharitri_wasm::types::InteractorDeploy::from(___contract_deploy___)
+        }
+    }
+}
+
+/// Decodes the event from the topics and data of a transaction log.
+///
+/// The decoder is named `decode_<event method name>`,
+/// and returns the event arguments, in the order in which they were declared.
+fn generate_event_decoder(
+    m: &Method,
+    event_identifier: &str,
+    version: Option<u8>,
+) -> proc_macro2::TokenStream {
+    let decoder_name = syn::Ident::new(&format!("decode_{}", m.name), m.name.span());
+    let arg_names: Vec<syn::Ident> = (0..m.method_args.len())
+        .map(event_decoded_arg_name)
+        .collect();
+    let arg_types: Vec<proc_macro2::TokenStream> = m
+        .method_args
+        .iter()
+        .map(|arg| convert_to_owned_type(&arg.ty))
+        .collect();
+
+    let (result_type, result_expr) = if arg_names.len() == 1 {
+        let arg_type = &arg_types[0];
+        let arg_name = &arg_names[0];
+        (quote! { #arg_type }, quote! { #arg_name })
+    } else {
+        (quote! { (#(#arg_types),*) }, quote! { (#(#arg_names),*) })
+    };
+    let decode_snippet = generate_event_decode_snippet(m, event_identifier, version);
+
+    quote! {
+        #[allow(clippy::type_complexity)]
+        fn #decoder_name(
+            &self,
+            topics: &[dharitri_wasm::types::heap::Vec<u8>],
+            data: &[u8],
+        ) -> Result<#result_type, dharitri_wasm::dharitri_codec::DecodeError> {
+            #decode_snippet
+            Result::Ok(#result_expr)
+        }
+    }
+}
+
+fn generate_interactor_methods(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract_trait
+        .methods
+        .iter()
+        .filter_map(|m| match (&m.public_role, &m.implementation) {
+            (PublicRole::Init(_), _) => Some(generate_interactor_deploy(m)),
+            (PublicRole::Endpoint(endpoint_metadata), _) => Some(generate_interactor_endpoint(
+                m,
+                endpoint_metadata.public_name.to_string(),
+            )),
+            (
+                _,
+                MethodImpl::Generated(AutoImpl::Event {
+                    identifier,
+                    version,
+                }),
+            ) => Some(generate_event_decoder(m, identifier.as_str(), *version)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The off-chain counterpart of the `ProxyTrait`.
+///
+/// Builds transactions instead of contract calls and decodes the events of the contract.
+/// Interactors never run on-chain, so they are left out of wasm builds.
+pub fn interactor_trait(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let interactor_supertrait_decl =
+        supertrait_gen::interactor_supertrait_decl(contract.supertraits.as_slice());
+    let interactor_methods = generate_interactor_methods(contract);
+    quote! {
+        #[cfg(not(target_arch = "wasm32"))]
+        pub trait InteractorTrait:
+            dharitri_wasm::contract_base::InteractorObjBase
+            + Sized
+            #(#interactor_supertrait_decl)*
+        {
+            #(#interactor_methods)*
+        }
+    }
+}
+
+pub fn interactor_obj_code(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let interactor_object_def = snippets::interactor_object_def();
+    let impl_all_interactor_traits =
+        supertrait_gen::impl_all_interactor_traits(contract.supertraits.as_slice());
+    quote! {
+        #interactor_object_def
+
+        #(#impl_all_interactor_traits)*
+    }
+}
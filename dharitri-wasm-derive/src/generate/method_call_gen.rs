@@ -1,11 +1,12 @@
 use super::{
-    convert_to_owned_type::*, method_gen::generate_arg_call_name, only_owner_gen::*,
-    payable_gen::*, util::*,
+    convert_to_owned_type::*, guard_gen::*, method_gen::generate_arg_call_name,
+    non_reentrant_gen::*, only_owner_gen::*, only_role_gen::*, payable_gen::*, util::*,
 };
 use crate::{
     generate::snippets,
     model::{Method, MethodArgument},
 };
+use syn::spanned::Spanned;
 
 pub fn generate_call_to_method_expr(m: &Method) -> proc_macro2::TokenStream {
     let fn_ident = &m.name;
@@ -86,10 +87,36 @@ pub fn generate_call_method_arg_load(m: &Method) -> proc_macro2::TokenStream {
     }
 }
 
+/// `#[call_result]` has no effect outside callbacks, the argument is loaded like any other.
+/// It used to be accepted silently, so it only produces a warning, via a deprecated item used at the span of the argument.
+fn generate_call_result_warnings(m: &Method) -> proc_macro2::TokenStream {
+    let warnings = m
+        .method_args
+        .iter()
+        .filter(|arg| arg.metadata.callback_call_result)
+        .map(|arg| {
+            quote_spanned! {arg.original_pat.span()=>
+                {
+                    #[deprecated(note = "`#[call_result]` has no effect outside `#[callback]` methods")]
+                    fn call_result_outside_callback() {}
+                    call_result_outside_callback();
+                }
+            }
+        });
+    quote! {
+        #(#warnings)*
+    }
+}
+
 pub fn generate_call_method_body(m: &Method) -> proc_macro2::TokenStream {
     let api_static_init = snippets::call_method_api_static_init();
+    let call_result_warnings = generate_call_result_warnings(m);
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
+    let guard_snippets = generate_guard_snippets(m);
+    let non_reentrant_enter_snippet = generate_non_reentrant_enter_snippet(m);
+    let non_reentrant_exit_snippet = generate_non_reentrant_exit_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
     let call = generate_call_to_method_expr(m);
@@ -97,10 +124,15 @@ pub fn generate_call_method_body(m: &Method) -> proc_macro2::TokenStream {
 
     quote! {
         #api_static_init
+        #call_result_warnings
         #payable_snippet
         #only_owner_snippet
+        #only_role_snippet
+        #guard_snippets
+        #non_reentrant_enter_snippet
         #arg_load
         #body_with_result
+        #non_reentrant_exit_snippet
     }
 }
 
@@ -112,11 +144,35 @@ pub fn generate_body_with_result(
         syn::ReturnType::Default => quote! {
             #mbody;
         },
-        syn::ReturnType::Type(_, _) => {
+        syn::ReturnType::Type(_, ty) if split_result_type(ty).is_some() => {
+            let (ok_type, _) = split_result_type(ty).unwrap();
+            let finish = generate_finish_result(ok_type);
+            quote! {
+                match #mbody {
+                    core::result::Result::Ok(result) => {
+                        #finish
+                    },
+                    core::result::Result::Err(err) => {
+                        dharitri_wasm::types::ContractError::signal_error::<Self::Api>(&err);
+                    },
+                }
+            }
+        },
+        syn::ReturnType::Type(_, ty) => {
+            let finish = generate_finish_result(ty);
             quote! {
                 let result = #mbody;
-                dharitri_wasm::io::finish_multi::<Self::Api, _>(&result);
+                #finish
             }
         },
     }
 }
+
+/// Spanned at the result type, so result types that cannot be encoded are reported there.
+///
+/// The type itself is inferred, since it can contain lifetimes of the endpoint method.
+fn generate_finish_result(result_type: &syn::Type) -> proc_macro2::TokenStream {
+    quote_spanned! {result_type.span()=>
+        dharitri_wasm::io::finish_endpoint_result::<Self::Api, _>(&result);
+    }
+}
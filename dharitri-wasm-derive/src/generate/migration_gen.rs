@@ -0,0 +1,90 @@
+use super::{
+    method_call_gen::{generate_call_method, generate_call_method_body},
+    util::*,
+};
+use crate::model::{ContractTrait, Method};
+
+/// The `init` endpoint of contracts with an `#[upgrade]` method.
+///
+/// The `#[upgrade]` method is also exported as the `upgrade` endpoint, which the VM calls on upgrade when present.
+/// VMs that call `init` on upgrade too are handled by checking the stored schema version,
+/// which only exists once the contract was deployed with migrations.
+/// Contracts deployed before that can only be migrated through the `upgrade` endpoint.
+pub fn generate_init_call_method(
+    contract: &ContractTrait,
+    init_method: &Method,
+) -> proc_macro2::TokenStream {
+    let upgrade_method = match contract.upgrade_method() {
+        Some(upgrade_method) => upgrade_method,
+        None => return generate_call_method(init_method),
+    };
+    let call_method_ident = generate_call_method_name(&init_method.name);
+    let call_upgrade_method_ident = generate_call_method_name(&upgrade_method.name);
+    let init_body = generate_call_method_body(init_method);
+    let latest_schema_version = contract.latest_schema_version();
+    quote! {
+        #[inline]
+        fn #call_method_ident (&self) {
+            <Self::Api as dharitri_wasm::api::VMApi>::init_static();
+            if dharitri_wasm::storage::migration::is_deployed::<Self::Api>() {
+                self.#call_upgrade_method_ident();
+                return;
+            }
+            #init_body
+            dharitri_wasm::storage::migration::init_schema_version::<Self::Api>(#latest_schema_version);
+        }
+    }
+}
+
+fn generate_migration_step_arm(version: u32, m: &Method) -> proc_macro2::TokenStream {
+    let fn_ident = &m.name;
+    let call = if m.method_args.is_empty() {
+        quote! { self.#fn_ident() }
+    } else {
+        quote! { self.#fn_ident(&mut ___cursor___) }
+    };
+    let status = match &m.return_type {
+        syn::ReturnType::Default => quote! {
+            {
+                #call;
+                dharitri_wasm::types::OperationCompletionStatus::Completed
+            }
+        },
+        syn::ReturnType::Type(_, _) => call,
+    };
+    quote! {
+        #version => #status,
+    }
+}
+
+/// Generates `run_migrations`, which runs the pending migration steps in order,
+/// until all are done or one of them gets interrupted to be resumed in a later call.
+pub fn generate_run_migrations(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let migration_steps = contract.migration_steps();
+    if migration_steps.is_empty() {
+        return quote! {};
+    }
+    let step_arms: Vec<proc_macro2::TokenStream> = migration_steps
+        .iter()
+        .map(|(version, m)| generate_migration_step_arm(*version, m))
+        .collect();
+    quote! {
+        /// Runs the pending `#[migration]` steps, in order.
+        /// Returns `interrupted` when running low on gas, in which case it needs to be called again.
+        fn run_migrations(&self) -> dharitri_wasm::types::OperationCompletionStatus {
+            let mut ___cursor___ = dharitri_wasm::storage::migration::MigrationCursor::<Self::Api>::new();
+            loop {
+                let ___next_version___ =
+                    dharitri_wasm::storage::migration::schema_version::<Self::Api>() + 1;
+                let ___status___ = match ___next_version___ {
+                    #(#step_arms)*
+                    _ => return dharitri_wasm::types::OperationCompletionStatus::Completed,
+                };
+                if ___status___.is_interrupted() {
+                    return ___status___;
+                }
+                dharitri_wasm::storage::migration::complete_migration_step::<Self::Api>(___next_version___);
+            }
+        }
+    }
+}
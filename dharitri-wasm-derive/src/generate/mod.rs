@@ -9,11 +9,19 @@ pub mod contract_gen;
 pub mod convert_to_owned_type;
 pub mod endpoints_mod_gen;
 pub mod function_selector;
+pub mod guard_gen;
+pub mod interactor_gen;
 pub mod method_call_gen;
 pub mod method_gen;
+pub mod migration_gen;
+pub mod non_reentrant_gen;
 pub mod only_owner_gen;
+pub mod only_role_gen;
 pub mod payable_gen;
 pub mod proxy_gen;
+pub mod required_module_gen;
 pub mod snippets;
+pub mod storage_layout_gen;
 pub mod supertrait_gen;
+pub mod typed_events_gen;
 pub mod util;
@@ -0,0 +1,29 @@
+use crate::model::{Method, PublicRole};
+
+fn is_non_reentrant(m: &Method) -> bool {
+    matches!(&m.public_role, PublicRole::Endpoint(endpoint_metadata) if endpoint_metadata.non_reentrant)
+}
+
+/// The lock is taken after all other checks, right before loading the arguments.
+pub fn generate_non_reentrant_enter_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if is_non_reentrant(m) {
+        quote! {
+            dharitri_wasm::io::endpoint_guards::enter_non_reentrant::<Self::Api>();
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Released once the results have been finished.
+///
+/// Endpoints ending in an async call never get here, the VM APIs release the lock before making the call.
+pub fn generate_non_reentrant_exit_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if is_non_reentrant(m) {
+        quote! {
+            dharitri_wasm::io::endpoint_guards::exit_non_reentrant::<Self::Api>();
+        }
+    } else {
+        quote! {}
+    }
+}
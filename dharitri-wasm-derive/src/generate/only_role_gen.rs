@@ -0,0 +1,23 @@
+use super::required_module_gen::generate_required_module_call;
+use crate::model::{Method, PublicRole};
+
+/// The check itself is provided by the `AccessControlModule` in `dharitri-wasm-modules`,
+/// so the trait declaring the endpoint needs to extend it, directly or through another module.
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role) = &endpoint_metadata.only_role {
+            let role_literal = proc_macro2::Literal::byte_string(role.as_bytes());
+            let require_role = generate_required_module_call(
+                m,
+                "only_role_requires_access_control_module",
+                quote! { dharitri_wasm_modules::access_control::AccessControlModule },
+                quote! { () },
+                quote! { sc.require_caller_has_role(#role_literal) },
+            );
+            return quote! {
+                #require_role;
+            };
+        }
+    }
+    quote! {}
+}
@@ -1,4 +1,4 @@
-use crate::model::{Method, MethodArgument, MethodPayableMetadata};
+use crate::model::{Method, MethodArgument, MethodPayableMetadata, PayableTransferLimits};
 
 pub fn generate_payable_snippet(m: &Method) -> proc_macro2::TokenStream {
     let call_value_init = call_value_init_snippet(m.payable_metadata());
@@ -39,9 +39,33 @@ fn call_value_init_snippet(mpm: MethodPayableMetadata) -> proc_macro2::TokenStre
                 dharitri_wasm::io::call_value_init::payable_any::<Self::Api>();
             }
         },
+        MethodPayableMetadata::TokenList(token_identifiers, limits) => {
+            let (max_transfers, distinct_tokens) = transfer_limits_snippets(limits);
+            quote! {
+                dharitri_wasm::io::call_value_init::payable_token_list::<Self::Api>(&[#(#token_identifiers),*], #max_transfers, #distinct_tokens);
+            }
+        },
+        MethodPayableMetadata::StorageTokens(mapper, limits) => {
+            let mapper_ident = syn::Ident::new(mapper.as_str(), proc_macro2::Span::call_site());
+            let (max_transfers, distinct_tokens) = transfer_limits_snippets(limits);
+            quote! {
+                dharitri_wasm::io::call_value_init::payable_storage_tokens::<Self::Api, _>(&self.#mapper_ident(), #max_transfers, #distinct_tokens);
+            }
+        },
     }
 }
 
+fn transfer_limits_snippets(
+    limits: &PayableTransferLimits,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let max_transfers = match limits.max_transfers {
+        Some(max_transfers) => quote! { #max_transfers },
+        None => quote! { usize::MAX },
+    };
+    let distinct_tokens = limits.distinct_tokens;
+    (max_transfers, quote! { #distinct_tokens })
+}
+
 fn opt_payment_arg_snippet(
     opt_arg: &Option<MethodArgument>,
     init_fn_name: proc_macro2::TokenStream,
@@ -44,23 +44,43 @@ pub fn proxy_arg_gen(
     args_decl
 }
 
+/// The original result type of the method, `()` if it returns nothing.
+///
+/// For `Result<T, E>` it is `T`, since errors are signalled and never returned to the caller.
+pub fn method_result_type(method: &Method) -> proc_macro2::TokenStream {
+    match &method.return_type {
+        syn::ReturnType::Default => quote! { () },
+        syn::ReturnType::Type(_, ty) => match split_result_type(ty) {
+            Some((ok_type, _)) => quote! { #ok_type },
+            None => quote! { #ty },
+        },
+    }
+}
+
 pub fn generate_proxy_method_sig(
     method: &Method,
     proxy_return_struct_path: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ret_tok = method_result_type(method);
+    generate_method_sig_with_return_type(
+        method,
+        quote! { #proxy_return_struct_path<Self::Api, #ret_tok> },
+    )
+}
+
+pub fn generate_method_sig_with_return_type(
+    method: &Method,
+    return_type: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let method_name = &method.name;
     let mut generics = method.generics.clone();
     let generics_where = &method.generics.where_clause;
     let arg_decl = proxy_arg_gen(&method.method_args, &mut generics);
-    let ret_tok = match &method.return_type {
-        syn::ReturnType::Default => quote! { () },
-        syn::ReturnType::Type(_, ty) => quote! { #ty },
-    };
     let result = quote! {
         fn #method_name #generics (
             &mut self,
             #(#arg_decl),*
-        ) -> #proxy_return_struct_path<Self::Api, #ret_tok>
+        ) -> #return_type
         #generics_where
     };
     result
@@ -68,7 +88,21 @@ pub fn generate_proxy_method_sig(
 
 pub fn generate_proxy_endpoint(m: &Method, endpoint_name: String) -> proc_macro2::TokenStream {
     let msig = generate_proxy_method_sig(m, quote! { dharitri_wasm::types::ContractCall });
+    let body = generate_proxy_endpoint_body(m, endpoint_name);
 
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #[allow(clippy::type_complexity)]
+        #msig {
+            #body
+        }
+    }
+}
+
+/// Builds the `ContractCall`, expects `self` to provide `extract_address`.
+///
+/// Shared by the proxies and the off-chain interactors.
+pub fn generate_proxy_endpoint_body(m: &Method, endpoint_name: String) -> proc_macro2::TokenStream {
     let mut token_count = 0;
     let mut token_expr =
         quote! { dharitri_wasm::types::MoaxOrDctTokenIdentifier::<Self::Api>::moax() };
@@ -155,30 +189,38 @@ pub fn generate_proxy_endpoint(m: &Method, endpoint_name: String) -> proc_macro2
 
     let endpoint_name_literal = byte_str_slice_literal(endpoint_name.as_bytes());
 
-    let sig = quote! {
-        #[allow(clippy::too_many_arguments)]
-        #[allow(clippy::type_complexity)]
-        #msig {
-            let ___address___ = self.extract_address();
-            let mut ___contract_call___ = dharitri_wasm::types::new_contract_call(
-                ___address___,
-                #endpoint_name_literal,
-                ManagedVec::<Self::Api, DctTokenPayment<Self::Api>>::new(),
-            );
-            #single_payment_snippet
-            #multiple_payment_snippet
-            #(#arg_push_snippets)*
-            ___contract_call___
-        }
-    };
-
-    sig
+    quote! {
+        let ___address___ = self.extract_address();
+        let mut ___contract_call___ = dharitri_wasm::types::new_contract_call(
+            ___address___,
+            #endpoint_name_literal,
+            ManagedVec::<Self::Api, DctTokenPayment<Self::Api>>::new(),
+        );
+        #single_payment_snippet
+        #multiple_payment_snippet
+        #(#arg_push_snippets)*
+        ___contract_call___
+    }
 }
 
 pub fn generate_proxy_deploy(init_method: &Method) -> proc_macro2::TokenStream {
     let msig =
         generate_proxy_method_sig(init_method, quote! { dharitri_wasm::types::ContractDeploy });
+    let body = generate_proxy_deploy_body(init_method);
 
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #[allow(clippy::type_complexity)]
+        #msig {
+            #body
+        }
+    }
+}
+
+/// Builds the `ContractDeploy`, expects `self` to provide `extract_opt_address`.
+///
+/// Shared by the proxies and the off-chain interactors.
+pub fn generate_proxy_deploy_body(init_method: &Method) -> proc_macro2::TokenStream {
     let mut payment_count = 0;
     let mut multi_count = 0;
     let mut token_count = 0;
@@ -228,20 +270,14 @@ pub fn generate_proxy_deploy(init_method: &Method) -> proc_macro2::TokenStream {
     assert!(token_count == 0, "No DCT payment allowed in #[init]");
     assert!(nonce_count == 0, "No SFT/NFT payment allowed in #[init]");
 
-    let sig = quote! {
-        #[allow(clippy::too_many_arguments)]
-        #[allow(clippy::type_complexity)]
-        #msig {
-            let ___opt_address___ = self.extract_opt_address();
-            let mut ___contract_deploy___ = dharitri_wasm::types::new_contract_deploy(
-                ___opt_address___,
-            );
-            #(#arg_push_snippets)*
-            ___contract_deploy___
-        }
-    };
-
-    sig
+    quote! {
+        let ___opt_address___ = self.extract_opt_address();
+        let mut ___contract_deploy___ = dharitri_wasm::types::new_contract_deploy(
+            ___opt_address___,
+        );
+        #(#arg_push_snippets)*
+        ___contract_deploy___
+    }
 }
 
 pub fn generate_method_impl(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
@@ -0,0 +1,25 @@
+use crate::model::Method;
+
+/// Some attributes generate calls into framework modules, which fail with unclear errors
+/// deep inside the generated code when the module is missing.
+///
+/// The call goes through a function bound by the module trait, so rustc reports a missing module once,
+/// at the method name, and also resolves modules extended indirectly, through another supertrait.
+/// `module_call` is a call on `sc`, the contract.
+pub fn generate_required_module_call(
+    m: &Method,
+    function_name: &str,
+    module_path: proc_macro2::TokenStream,
+    result_type: proc_macro2::TokenStream,
+    module_call: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let function_ident = syn::Ident::new(function_name, m.name.span());
+    quote_spanned! {m.name.span()=>
+        {
+            fn #function_ident<T: ?Sized + #module_path>(sc: &T) -> #result_type {
+                #module_call
+            }
+            #function_ident(self)
+        }
+    }
+}
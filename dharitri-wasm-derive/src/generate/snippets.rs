@@ -60,7 +60,7 @@ pub fn impl_auto_impl() -> proc_macro2::TokenStream {
     }
 }
 
-pub fn impl_callable_contract() -> proc_macro2::TokenStream {
+pub fn impl_callable_contract(has_upgrade_constructor: bool) -> proc_macro2::TokenStream {
     quote! {
         impl<A> dharitri_wasm::contract_base::CallableContract for ContractObj<A>
         where
@@ -70,6 +70,10 @@ pub fn impl_callable_contract() -> proc_macro2::TokenStream {
                 EndpointWrappers::call(self, fn_name)
             }
 
+            fn has_upgrade_constructor(&self) -> bool {
+                #has_upgrade_constructor
+            }
+
             fn clone_obj(&self) -> dharitri_wasm::types::heap::Box<dyn dharitri_wasm::contract_base::CallableContract> {
                 dharitri_wasm::types::heap::Box::new(ContractObj::<A> {
                     _phantom: core::marker::PhantomData,
@@ -121,6 +125,57 @@ pub fn proxy_object_def() -> proc_macro2::TokenStream {
     }
 }
 
+pub fn interactor_object_def() -> proc_macro2::TokenStream {
+    quote! {
+        #[cfg(not(target_arch = "wasm32"))]
+        pub struct Interactor<A>
+        where
+            A: dharitri_wasm::api::VMApi + 'static,
+        {
+            pub address: Option<dharitri_wasm::types::heap::Address>,
+            _phantom: core::marker::PhantomData<A>,
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        impl<A> dharitri_wasm::contract_base::InteractorObjBase for Interactor<A>
+        where
+            A: dharitri_wasm::api::VMApi + 'static,
+        {
+            type Api = A;
+
+            fn new_interactor_obj() -> Self {
+                Interactor {
+                    address: None,
+                    _phantom: core::marker::PhantomData,
+                }
+            }
+
+            fn contract(mut self, address: dharitri_wasm::types::heap::Address) -> Self {
+                self.address = Some(address);
+                self
+            }
+
+            fn extract_opt_address(
+                &mut self,
+            ) -> dharitri_wasm::types::ManagedOption<
+                Self::Api,
+                dharitri_wasm::types::ManagedAddress<Self::Api>,
+            > {
+                match &self.address {
+                    Some(address) => dharitri_wasm::types::ManagedOption::some(
+                        dharitri_wasm::types::ManagedAddress::from_address(address),
+                    ),
+                    None => dharitri_wasm::types::ManagedOption::none(),
+                }
+            }
+
+            fn extract_address(&mut self) -> dharitri_wasm::types::ManagedAddress<Self::Api> {
+                self.extract_opt_address().unwrap_or_sc_panic(dharitri_wasm::err_msg::RECIPIENT_ADDRESS_NOT_SET)
+            }
+        }
+    }
+}
+
 pub fn callback_proxy_object_def() -> proc_macro2::TokenStream {
     quote! {
         pub struct CallbackProxyObj<A>
@@ -0,0 +1,54 @@
+use crate::model::ContractTrait;
+
+/// Lists the distinct storage keys of the contract or module, along with the layouts of its supertraits,
+/// so that the main contract can check them against the keys of all its modules.
+pub fn generate_storage_layout_keys(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let trait_name = contract.trait_name.to_string();
+    let mut keys: Vec<&String> = Vec::new();
+    let mut declarations = Vec::new();
+    for m in &contract.methods {
+        if let Some(key) = m.storage_identifier() {
+            if keys.contains(&key) {
+                continue;
+            }
+            keys.push(key);
+            let mapper = contract
+                .methods
+                .iter()
+                .any(|other| other.storage_identifier() == Some(key) && other.is_storage_mapper());
+            let collision_message = format!(
+                "storage key \"{}\" of `{}::{}` is also used by another module of the contract, consider adding a `storage_prefix` to one of the modules",
+                key, trait_name, m.name
+            );
+            declarations.push(quote! {
+                dharitri_wasm::storage::StorageKeyDeclaration {
+                    key: #key,
+                    mapper: #mapper,
+                    collision_message: #collision_message,
+                }
+            });
+        }
+    }
+    let supertrait_layouts = contract.supertraits.iter().map(|supertrait| {
+        let module_path = &supertrait.module_path;
+        quote! { &#module_path STORAGE_LAYOUT }
+    });
+    quote! {
+        pub const STORAGE_LAYOUT_KEYS: &[dharitri_wasm::storage::StorageKeyDeclaration] = &[
+            #(#declarations),*
+        ];
+
+        pub const STORAGE_LAYOUT: dharitri_wasm::storage::StorageLayout = dharitri_wasm::storage::StorageLayout {
+            trait_path: concat!(module_path!(), "::", #trait_name),
+            keys: STORAGE_LAYOUT_KEYS,
+            supertraits: &[ #(#supertrait_layouts),* ],
+        };
+    }
+}
+
+/// Only generated for the main contract, the modules are reached through its supertraits, recursively.
+pub fn generate_storage_layout_check() -> proc_macro2::TokenStream {
+    quote! {
+        const _: () = dharitri_wasm::storage::check_storage_layout(&STORAGE_LAYOUT);
+    }
+}
@@ -45,6 +45,18 @@ pub fn proxy_supertrait_decl(supertraits: &[Supertrait]) -> Vec<proc_macro2::Tok
         .collect()
 }
 
+pub fn interactor_supertrait_decl(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
+    supertraits
+        .iter()
+        .map(|supertrait| {
+            let module_path = &supertrait.module_path;
+            quote! {
+                + #module_path InteractorTrait
+            }
+        })
+        .collect()
+}
+
 fn impl_auto_impl(module_path: &ModulePath) -> proc_macro2::TokenStream {
     quote! {
         impl<A> #module_path AutoImpl for ContractObj<A>
@@ -148,3 +160,21 @@ pub fn impl_all_proxy_traits(supertraits: &[Supertrait]) -> Vec<proc_macro2::Tok
 
     implementations
 }
+
+fn impl_interactor_trait(module_path: &ModulePath) -> proc_macro2::TokenStream {
+    quote! {
+        #[cfg(not(target_arch = "wasm32"))]
+        impl<A> #module_path InteractorTrait for Interactor<A> where A: dharitri_wasm::api::VMApi {}
+    }
+}
+
+pub fn impl_all_interactor_traits(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
+    let mut implementations: Vec<proc_macro2::TokenStream> = supertraits
+        .iter()
+        .map(|supertrait| impl_interactor_trait(&supertrait.module_path))
+        .collect();
+
+    implementations.push(impl_interactor_trait(&self_module_path()));
+
+    implementations
+}
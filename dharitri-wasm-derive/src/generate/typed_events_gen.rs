@@ -0,0 +1,160 @@
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+
+use super::{
+    auto_impl_event::{event_decoded_arg_name, generate_event_decode_snippet},
+    convert_to_owned_type::convert_to_owned_type,
+};
+use crate::model::{AutoImpl, ContractTrait, Method, MethodImpl};
+
+const EVENT_API_GENERIC: &str = "M";
+
+/// `deposit_event` -> `DepositEvent`.
+fn event_struct_name(m: &Method) -> Ident {
+    let camel_case: String = m
+        .name
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            core::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect();
+    Ident::new(&camel_case, m.name.span())
+}
+
+fn event_field_name(pat: &syn::Pat, arg_index: usize) -> Ident {
+    match pat {
+        syn::Pat::Ident(pat_ident) => {
+            let name = pat_ident.ident.to_string();
+            Ident::new(name.trim_start_matches('_'), pat_ident.ident.span())
+        },
+        _ => Ident::new(&format!("field_{}", arg_index), Span::call_site()),
+    }
+}
+
+/// The generated structs do not belong to a contract, so `Self::Api` is replaced with their own generic.
+///
+/// Sets `replaced` if any replacement took place.
+fn replace_self_api(tokens: TokenStream, replaced: &mut bool) -> TokenStream {
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut result = Vec::with_capacity(trees.len());
+    let mut index = 0;
+    while index < trees.len() {
+        if is_self_api_at(&trees[index..]) {
+            result.push(TokenTree::Ident(Ident::new(
+                EVENT_API_GENERIC,
+                Span::call_site(),
+            )));
+            *replaced = true;
+            index += 4;
+            continue;
+        }
+        match &trees[index] {
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    replace_self_api(group.stream(), replaced),
+                );
+                new_group.set_span(group.span());
+                result.push(TokenTree::Group(new_group));
+            },
+            other => result.push(other.clone()),
+        }
+        index += 1;
+    }
+    result.into_iter().collect()
+}
+
+fn is_self_api_at(trees: &[TokenTree]) -> bool {
+    matches!(
+        trees,
+        [TokenTree::Ident(self_ident), TokenTree::Punct(colon1), TokenTree::Punct(colon2), TokenTree::Ident(api_ident), ..]
+            if self_ident == "Self" && colon1.as_char() == ':' && colon2.as_char() == ':' && api_ident == "Api"
+    )
+}
+
+fn generate_event_struct(m: &Method, event_identifier: &str, version: Option<u8>) -> TokenStream {
+    let struct_name = event_struct_name(m);
+    let docs = &m.docs;
+    let mut is_generic = false;
+    let field_names: Vec<Ident> = m
+        .method_args
+        .iter()
+        .enumerate()
+        .map(|(arg_index, arg)| event_field_name(&arg.pat, arg_index))
+        .collect();
+    let field_types: Vec<TokenStream> = m
+        .method_args
+        .iter()
+        .map(|arg| replace_self_api(convert_to_owned_type(&arg.ty), &mut is_generic))
+        .collect();
+    let decoded_arg_names: Vec<Ident> = (0..m.method_args.len())
+        .map(event_decoded_arg_name)
+        .collect();
+    let decode_snippet = replace_self_api(
+        generate_event_decode_snippet(m, event_identifier, version),
+        &mut is_generic,
+    );
+
+    let api_generic = Ident::new(EVENT_API_GENERIC, Span::call_site());
+    let (generics_decl, generics_use) = if is_generic {
+        (
+            quote! { <#api_generic: dharitri_wasm::api::ManagedTypeApi> },
+            quote! { <#api_generic> },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    quote! {
+        #(#[doc = #docs])*
+        pub struct #struct_name #generics_decl {
+            #(pub #field_names: #field_types,)*
+        }
+
+        impl #generics_decl dharitri_wasm::types::ContractEvent for #struct_name #generics_use {
+            const IDENTIFIER: &'static str = #event_identifier;
+
+            fn decode_event(
+                topics: &[dharitri_wasm::types::heap::Vec<u8>],
+                data: &[u8],
+            ) -> Result<Self, dharitri_wasm::dharitri_codec::DecodeError> {
+                #decode_snippet
+                Result::Ok(#struct_name {
+                    #(#field_names: #decoded_arg_names,)*
+                })
+            }
+        }
+    }
+}
+
+/// One struct per `#[event]`, named after the event method in camel case, with one public field per argument.
+///
+/// They implement `ContractEvent`, so the testing framework can decode logs into them.
+pub fn generate_typed_events_mod(contract: &ContractTrait) -> TokenStream {
+    let event_structs: Vec<TokenStream> = contract
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(AutoImpl::Event {
+                identifier,
+                version,
+            }) => Some(generate_event_struct(m, identifier.as_str(), *version)),
+            _ => None,
+        })
+        .collect();
+    if event_structs.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        pub mod typed_events {
+            #![allow(unused_imports)]
+            use super::*;
+
+            #(#event_structs)*
+        }
+    }
+}
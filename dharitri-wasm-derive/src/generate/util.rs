@@ -80,3 +80,32 @@ pub fn clear_all_type_lifetimes(ty: &mut syn::Type) {
         _ => {},
     }
 }
+
+/// Splits `Result<T, E>` into `T` and `E`.
+///
+/// Endpoints returning a `Result` produce `T` and signal `E` as a contract error.
+pub fn split_result_type(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    let type_path = match ty {
+        syn::Type::Path(type_path) => type_path,
+        _ => return None,
+    };
+    let last_segment = type_path.path.segments.last()?;
+    if last_segment.ident != "Result" {
+        return None;
+    }
+    let type_args: Vec<&syn::Type> = match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(angle_bracketed) => angle_bracketed
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+    match type_args.as_slice() {
+        [ok_type, err_type] => Some((ok_type, err_type)),
+        _ => None,
+    }
+}
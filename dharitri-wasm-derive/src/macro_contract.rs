@@ -12,7 +12,9 @@ pub fn process_contract(
     let proc_input = &parse_macro_input!(new_input as syn::ItemTrait);
 
     let contract = parse_contract_trait(args_input, proc_input);
-    validate_contract(&contract);
+    if let Err(err) = validate_contract(&contract) {
+        return err.to_compile_error().into();
+    }
 
     let contract_impl = contract_implementation(&contract, true);
 
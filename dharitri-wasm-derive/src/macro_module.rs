@@ -1,6 +1,8 @@
 use super::contract_impl::contract_implementation;
 use crate::{
-    parse::parse_contract_trait, preprocessing::trait_preprocessing, validate::validate_contract,
+    parse::parse_contract_trait,
+    preprocessing::trait_preprocessing,
+    validate::{validate_contract, validate_module_migrations},
 };
 
 pub fn process_module(
@@ -12,7 +14,9 @@ pub fn process_module(
     let proc_input = &parse_macro_input!(new_input as syn::ItemTrait);
 
     let contract = parse_contract_trait(args_input, proc_input);
-    validate_contract(&contract);
+    if let Err(err) = validate_contract(&contract).and(validate_module_migrations(&contract)) {
+        return err.to_compile_error().into();
+    }
 
     let contract_impl = contract_implementation(&contract, false);
 
@@ -1,5 +1,5 @@
 use crate::{
-    generate::{contract_gen::*, proxy_gen, supertrait_gen},
+    generate::{contract_gen::*, interactor_gen, proxy_gen, supertrait_gen, typed_events_gen},
     model::ContractTrait,
     parse::parse_contract_trait,
     preprocessing::trait_preprocessing,
@@ -15,7 +15,9 @@ pub fn process_proxy(
     let proc_input = parse_macro_input!(new_input as syn::ItemTrait);
 
     let contract = parse_contract_trait(args_input, &proc_input);
-    validate_contract(&contract);
+    if let Err(err) = validate_contract(&contract) {
+        return err.to_compile_error().into();
+    }
 
     let proxy_impl = proxy_implementation(&contract, true);
 
@@ -51,11 +53,26 @@ pub fn proxy_implementation(
         quote! {}
     };
 
+    let interactor_trait = interactor_gen::interactor_trait(contract);
+    let interactor_obj_code = if is_contract_main {
+        interactor_gen::interactor_obj_code(contract)
+    } else {
+        quote! {}
+    };
+
+    let typed_events_mod = typed_events_gen::generate_typed_events_mod(contract);
+
     quote! {
         #main_definition
 
         #proxy_trait
 
         #proxy_obj_code
+
+        #interactor_trait
+
+        #interactor_obj_code
+
+        #typed_events_mod
     }
 }
@@ -15,6 +15,8 @@ pub struct ArgMetadata {
     pub payment: ArgPaymentMetadata,
     pub callback_call_result: bool,
     pub event_topic: bool,
+    /// Set via `#[since(N)]`, on non-indexed event arguments added in later versions of the event.
+    pub event_since: Option<u8>,
 }
 
 impl Default for ArgMetadata {
@@ -23,6 +25,7 @@ impl Default for ArgMetadata {
             payment: ArgPaymentMetadata::NotPayment,
             callback_call_result: false,
             event_topic: false,
+            event_since: None,
         }
     }
 }
@@ -57,4 +60,7 @@ impl MethodArgument {
 #[derive(Clone, Debug, Default)]
 pub struct TraitProperties {
     pub only_owner: bool,
+
+    /// Set via `#[dharitri_wasm::module(storage_prefix = "...")]`, prepended to all storage keys of the trait.
+    pub storage_prefix: Option<String>,
 }
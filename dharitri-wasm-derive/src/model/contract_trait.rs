@@ -23,4 +23,33 @@ impl ContractTrait {
             .filter(|m| matches!(m.public_role, PublicRole::Callback(_)))
             .count()
     }
+
+    /// The method annotated with `#[upgrade]`, if any.
+    pub fn upgrade_method(&self) -> Option<&Method> {
+        self.methods
+            .iter()
+            .find(|m| matches!(m.public_role, PublicRole::Upgrade(_)))
+    }
+
+    /// The `#[migration]` steps, together with their versions, in the order in which they need to run.
+    pub fn migration_steps(&self) -> Vec<(u32, &Method)> {
+        let mut steps: Vec<(u32, &Method)> = self
+            .methods
+            .iter()
+            .filter_map(|m| match &m.public_role {
+                PublicRole::Migration(migration_metadata) => Some((migration_metadata.version, m)),
+                _ => None,
+            })
+            .collect();
+        steps.sort_by_key(|(version, _)| *version);
+        steps
+    }
+
+    /// The schema version reached after all migration steps, 0 if there are none.
+    pub fn latest_schema_version(&self) -> u32 {
+        self.migration_steps()
+            .last()
+            .map(|(version, _)| *version)
+            .unwrap_or(0)
+    }
 }
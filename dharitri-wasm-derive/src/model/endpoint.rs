@@ -1,4 +1,6 @@
-use super::{EndpointLocationMetadata, EndpointMutabilityMetadata, MethodPayableMetadata};
+use super::{
+    EndpointGuard, EndpointLocationMetadata, EndpointMutabilityMetadata, MethodPayableMetadata,
+};
 
 #[derive(Clone, Debug)]
 pub struct InitMetadata {
@@ -10,10 +12,20 @@ pub struct EndpointMetadata {
     pub public_name: syn::Ident,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_role: Option<String>,
+    pub tags: Vec<String>,
+    pub guards: Vec<EndpointGuard>,
+    pub non_reentrant: bool,
     pub mutability: EndpointMutabilityMetadata,
     pub location: EndpointLocationMetadata,
 }
 
+#[derive(Clone, Debug)]
+pub struct MigrationMetadata {
+    /// The schema version reached once the migration step completes.
+    pub version: u32,
+}
+
 #[derive(Clone, Debug)]
 pub struct CallbackMetadata {
     pub callback_name: syn::Ident,
@@ -25,6 +37,13 @@ pub enum PublicRole {
     /// The smart contract constructor. There can be only one.
     Init(InitMetadata),
 
+    /// Exported as `upgrade`, called on upgrade instead of the constructor.
+    /// There can be at most one.
+    Upgrade(InitMetadata),
+
+    /// A storage migration step, run by the generated `run_migrations` method.
+    Migration(MigrationMetadata),
+
     /// Means it gets a smart contract function generated for it
     Endpoint(EndpointMetadata),
 
@@ -0,0 +1,26 @@
+/// Checks generated before the endpoint body, declared via attributes such as `#[require_not_paused]`.
+#[derive(Clone, Debug)]
+pub enum EndpointGuard {
+    /// `#[require_not_paused]`, relies on the `PauseModule`.
+    RequireNotPaused,
+
+    /// `#[only_whitelisted(mapper)]`, the caller must be contained in the given mapper.
+    OnlyWhitelisted(syn::Ident),
+
+    /// `#[nonzero_payment]`
+    NonzeroPayment,
+
+    /// `#[before_timestamp(storage)]`, the block timestamp must be before the deadline held by a `SingleValueMapper<u64>`.
+    BeforeTimestamp(syn::Ident),
+}
+
+impl EndpointGuard {
+    pub fn abi_string(&self) -> String {
+        match self {
+            EndpointGuard::RequireNotPaused => "require_not_paused".to_string(),
+            EndpointGuard::OnlyWhitelisted(mapper) => format!("only_whitelisted({})", mapper),
+            EndpointGuard::NonzeroPayment => "nonzero_payment".to_string(),
+            EndpointGuard::BeforeTimestamp(storage) => format!("before_timestamp({})", storage),
+        }
+    }
+}
@@ -3,15 +3,55 @@ use super::*;
 /// Indicates how smart contract method implementations will be auto-generated based on their annotations.
 #[derive(Clone, Debug)]
 pub enum AutoImpl {
-    LegacyEvent { identifier: Vec<u8> },
-    Event { identifier: String },
-    StorageGetter { identifier: String },
-    StorageSetter { identifier: String },
-    StorageMapper { identifier: String },
-    StorageIsEmpty { identifier: String },
-    StorageClear { identifier: String },
+    LegacyEvent {
+        identifier: Vec<u8>,
+    },
+    Event {
+        identifier: String,
+        version: Option<u8>,
+    },
+    StorageGetter {
+        identifier: String,
+    },
+    StorageSetter {
+        identifier: String,
+    },
+    StorageMapper {
+        identifier: String,
+    },
+    StorageIsEmpty {
+        identifier: String,
+    },
+    StorageClear {
+        identifier: String,
+    },
     ProxyGetter,
 }
+impl AutoImpl {
+    /// The storage key of storage accessors and mappers, `None` for the other auto-implemented methods.
+    pub fn storage_identifier(&self) -> Option<&String> {
+        match self {
+            AutoImpl::StorageGetter { identifier }
+            | AutoImpl::StorageSetter { identifier }
+            | AutoImpl::StorageMapper { identifier }
+            | AutoImpl::StorageIsEmpty { identifier }
+            | AutoImpl::StorageClear { identifier } => Some(identifier),
+            _ => None,
+        }
+    }
+
+    pub fn storage_identifier_mut(&mut self) -> Option<&mut String> {
+        match self {
+            AutoImpl::StorageGetter { identifier }
+            | AutoImpl::StorageSetter { identifier }
+            | AutoImpl::StorageMapper { identifier }
+            | AutoImpl::StorageIsEmpty { identifier }
+            | AutoImpl::StorageClear { identifier } => Some(identifier),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum MethodImpl {
     /// Implementation auto-generated by the framework. There can (obviously) be only one per method.
@@ -46,6 +86,20 @@ pub struct Method {
 }
 
 impl Method {
+    pub fn storage_identifier(&self) -> Option<&String> {
+        match &self.implementation {
+            MethodImpl::Generated(auto_impl) => auto_impl.storage_identifier(),
+            _ => None,
+        }
+    }
+
+    pub fn is_storage_mapper(&self) -> bool {
+        matches!(
+            self.implementation,
+            MethodImpl::Generated(AutoImpl::StorageMapper { .. })
+        )
+    }
+
     pub fn payment_token_arg(&self) -> Option<MethodArgument> {
         self.method_args
             .iter()
@@ -76,19 +130,23 @@ impl Method {
 
     pub fn is_payable(&self) -> bool {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.is_payable(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.is_payable()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.is_payable(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => true,
-            PublicRole::Private => false,
+            PublicRole::Migration(_) | PublicRole::Private => false,
         }
     }
 
     pub fn payable_metadata(&self) -> MethodPayableMetadata {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.clone(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.clone()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.clone(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => MethodPayableMetadata::AnyToken,
-            PublicRole::Private => MethodPayableMetadata::NotPayable,
+            PublicRole::Migration(_) | PublicRole::Private => MethodPayableMetadata::NotPayable,
         }
     }
 }
@@ -3,6 +3,7 @@ mod contract_trait;
 mod endpoint;
 mod endpoint_location;
 mod endpoint_mutability_metadata;
+mod guard;
 mod method;
 mod payable;
 mod supertrait;
@@ -12,6 +13,7 @@ pub use contract_trait::*;
 pub use endpoint::*;
 pub use endpoint_location::*;
 pub use endpoint_mutability_metadata::*;
+pub use guard::*;
 pub use method::*;
 pub use payable::*;
 pub use supertrait::*;
@@ -6,6 +6,19 @@ pub enum MethodPayableMetadata {
     Moax,
     SingleDctToken(String),
     AnyToken,
+
+    /// `#[payable("TOKEN-A", "TOKEN-B")]`, "MOAX" can also be part of the list.
+    TokenList(Vec<String>, PayableTransferLimits),
+
+    /// `#[payable(storage = "mapper_method")]`, the accepted tokens are read from storage.
+    StorageTokens(String, PayableTransferLimits),
+}
+
+/// Restrictions on the DCT transfers accepted by token list and storage-driven payable endpoints.
+#[derive(Clone, Debug, Default)]
+pub struct PayableTransferLimits {
+    pub max_transfers: Option<usize>,
+    pub distinct_tokens: bool,
 }
 
 impl MethodPayableMetadata {
@@ -14,10 +27,13 @@ impl MethodPayableMetadata {
     }
 
     pub fn no_dct(&self) -> bool {
-        matches!(
-            self,
-            MethodPayableMetadata::NotPayable | MethodPayableMetadata::Moax
-        )
+        match self {
+            MethodPayableMetadata::NotPayable | MethodPayableMetadata::Moax => true,
+            MethodPayableMetadata::TokenList(tokens, _) => {
+                tokens.iter().all(|token| token == "MOAX")
+            },
+            _ => false,
+        }
     }
 
     pub fn abi_strings(&self) -> Vec<String> {
@@ -26,6 +42,8 @@ impl MethodPayableMetadata {
             MethodPayableMetadata::Moax => vec!["MOAX".to_string()],
             MethodPayableMetadata::SingleDctToken(s) => vec![s.clone()],
             MethodPayableMetadata::AnyToken => vec!["*".to_string()],
+            MethodPayableMetadata::TokenList(tokens, _) => tokens.clone(),
+            MethodPayableMetadata::StorageTokens(mapper, _) => vec![format!("storage:{}", mapper)],
         }
     }
 }
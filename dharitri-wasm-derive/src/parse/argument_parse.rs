@@ -83,6 +83,7 @@ fn process_arg_attribute(attr: &syn::Attribute, arg_metadata: &mut ArgMetadata)
         || process_payment_multi_attribute(attr, arg_metadata)
         || process_callback_result_attribute(attr, arg_metadata)
         || process_event_topic_attribute(attr, arg_metadata)
+        || process_event_since_attribute(attr, arg_metadata)
 }
 
 fn check_no_other_payment_attr(arg_metadata: &ArgMetadata) {
@@ -143,3 +144,11 @@ fn process_event_topic_attribute(attr: &syn::Attribute, arg_metadata: &mut ArgMe
     }
     has_attr
 }
+
+fn process_event_since_attribute(attr: &syn::Attribute, arg_metadata: &mut ArgMetadata) -> bool {
+    is_event_since(attr)
+        .map(|since| {
+            arg_metadata.event_since = Some(since);
+        })
+        .is_some()
+}
@@ -1,5 +1,12 @@
 pub(super) static ATTR_PAYABLE: &str = "payable";
 pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
+pub(super) static ATTR_ONLY_ROLE: &str = "only_role";
+pub(super) static ATTR_TAG: &str = "tag";
+pub(super) static ATTR_REQUIRE_NOT_PAUSED: &str = "require_not_paused";
+pub(super) static ATTR_ONLY_WHITELISTED: &str = "only_whitelisted";
+pub(super) static ATTR_NONZERO_PAYMENT: &str = "nonzero_payment";
+pub(super) static ATTR_BEFORE_TIMESTAMP: &str = "before_timestamp";
+pub(super) static ATTR_NON_REENTRANT: &str = "non_reentrant";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
@@ -7,11 +14,16 @@ pub(super) static ATTR_PAYMENT_TOKEN: &str = "payment_token";
 pub(super) static ATTR_PAYMENT_NONCE: &str = "payment_nonce";
 pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_INIT: &str = "init";
+pub(super) static ATTR_UPGRADE: &str = "upgrade";
+pub(super) static ATTR_MIGRATION: &str = "migration";
+pub(super) static ATTR_MIGRATION_VERSION: &str = "version";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_EXTERNAL_VIEW: &str = "external_view";
 pub(super) static ATTR_LEGACY_EVENT: &str = "legacy_event";
 pub(super) static ATTR_EVENT: &str = "event";
 pub(super) static ATTR_EVENT_INDEXED: &str = "indexed";
+pub(super) static ATTR_EVENT_VERSION: &str = "version";
+pub(super) static ATTR_EVENT_SINCE: &str = "since";
 pub(super) static ATTR_ENDPOINT: &str = "endpoint";
 pub(super) static ATTR_CALLBACK_DECL: &str = "callback";
 pub(super) static ATTR_CALLBACK_RAW_DECL: &str = "callback_raw";
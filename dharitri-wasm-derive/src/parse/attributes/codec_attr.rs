@@ -0,0 +1,131 @@
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
+static ATTR_CODEC: &str = "codec";
+static CODEC_ARG_VERSION: &str = "version";
+static CODEC_ARG_SINCE: &str = "since";
+static CODEC_ARG_SKIP: &str = "skip";
+static CODEC_ARG_WITH: &str = "with";
+static CODEC_ARG_TRANSPARENT: &str = "transparent";
+
+/// The `name = value` entries of `#[codec(...)]` attributes.
+///
+/// The codec derives validate these attributes, here we only look for the ones that show up in the ABI.
+fn codec_args(attrs: &[syn::Attribute]) -> Vec<(syn::Ident, Option<syn::Expr>)> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_CODEC))
+        .flat_map(|attr| {
+            attr.parse_args_with(|input: ParseStream| {
+                Punctuated::<CodecArg, Token![,]>::parse_terminated(input)
+            })
+            .unwrap_or_else(|err| panic!("malformed codec attribute: {}", err))
+        })
+        .map(|arg| (arg.name, arg.value))
+        .collect()
+}
+
+struct CodecArg {
+    name: syn::Ident,
+    value: Option<syn::Expr>,
+}
+
+impl syn::parse::Parse for CodecArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(CodecArg { name, value })
+    }
+}
+
+fn has_codec_flag(attrs: &[syn::Attribute], arg_name: &str) -> bool {
+    codec_args(attrs).iter().any(|(name, _)| name == arg_name)
+}
+
+fn int_lit_u8(expr: &syn::Expr, what: &str) -> u8 {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int
+            .base10_parse()
+            .unwrap_or_else(|_| panic!("{} must fit in a u8", what)),
+        _ => panic!("{} must be an integer literal", what),
+    }
+}
+
+fn codec_arg_u8(attrs: &[syn::Attribute], arg_name: &str) -> Option<u8> {
+    codec_args(attrs)
+        .into_iter()
+        .find(|(name, _)| name == arg_name)
+        .map(|(name, value)| match value {
+            Some(value) => int_lit_u8(&value, format!("codec `{}`", name).as_str()),
+            None => panic!("codec `{}` expects a value", name),
+        })
+}
+
+/// `#[codec(version = N)]` on a type.
+pub fn extract_codec_version(attrs: &[syn::Attribute]) -> Option<u8> {
+    codec_arg_u8(attrs, CODEC_ARG_VERSION)
+}
+
+/// `#[codec(since = N)]` on a field, only relevant if the field was not part of the first version.
+pub fn extract_codec_since(attrs: &[syn::Attribute]) -> Option<u8> {
+    codec_arg_u8(attrs, CODEC_ARG_SINCE).filter(|since| *since > 1)
+}
+
+/// `#[codec(skip)]` fields are not encoded, so they are left out of the ABI.
+pub fn is_codec_skip(attrs: &[syn::Attribute]) -> bool {
+    has_codec_flag(attrs, CODEC_ARG_SKIP)
+}
+
+/// `#[codec(transparent)]` newtypes are encoded exactly like their only field.
+pub fn is_codec_transparent(attrs: &[syn::Attribute]) -> bool {
+    has_codec_flag(attrs, CODEC_ARG_TRANSPARENT)
+}
+
+/// The module given in `#[codec(with = "module")]`.
+///
+/// The ABI describes such fields via the `AbiType` declared in that module.
+pub fn extract_codec_with(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    codec_args(attrs)
+        .into_iter()
+        .find(|(name, _)| name == CODEC_ARG_WITH)
+        .map(|(name, value)| match value {
+            Some(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) => lit_str
+                .parse()
+                .unwrap_or_else(|_| panic!("codec `{}` expects a module path", name)),
+            _ => panic!("codec `{}` expects a string literal", name),
+        })
+}
+
+/// The encoded enum discriminants, honoring the explicit ones (`Variant = 5`).
+///
+/// Proc-macro crates cannot share code, so this is a copy of `variant_discriminants` in `dharitri-codec-derive`,
+/// keep the two identical, so the ABI always reports the discriminants used on the wire.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants: Vec<u8> = Vec::with_capacity(data_enum.variants.len());
+    for variant in data_enum.variants.iter() {
+        let discriminant = match (&variant.discriminant, discriminants.last()) {
+            (Some((_, expr)), _) => int_lit_u8(expr, "enum discriminant"),
+            (None, Some(previous)) => previous
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("enum discriminant must fit in a u8")),
+            (None, None) => 0,
+        };
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate enum discriminant {}",
+            discriminant
+        );
+        discriminants.push(discriminant);
+    }
+    discriminants
+}
@@ -4,10 +4,51 @@ pub fn is_init(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_INIT)
 }
 
+pub fn is_upgrade(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_UPGRADE)
+}
+
+/// Retrieves the version from `#[migration(version = 2)]`.
+pub fn is_migration(attr: &syn::Attribute) -> Option<u32> {
+    let first_seg = attr.path.segments.first()?;
+    if first_seg.ident != ATTR_MIGRATION {
+        return None;
+    }
+    let name_value = attr
+        .parse_args::<syn::MetaNameValue>()
+        .unwrap_or_else(|_| panic!("expected `#[migration(version = <n>)]`"));
+    assert!(
+        name_value.path.is_ident(ATTR_MIGRATION_VERSION),
+        "expected `#[migration(version = <n>)]`"
+    );
+    match &name_value.lit {
+        syn::Lit::Int(lit_int) => Some(
+            lit_int
+                .base10_parse()
+                .expect("migration `version` must be a number"),
+        ),
+        _ => panic!("migration `version` must be a number"),
+    }
+}
+
 pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
 
+pub fn is_non_reentrant(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NON_REENTRANT)
+}
+
+/// Retrieves the role name from `#[only_role("ROLE")]`.
+pub fn is_only_role(attr: &syn::Attribute) -> Option<String> {
+    is_attr_one_string_arg(attr, ATTR_ONLY_ROLE)
+}
+
+/// Retrieves the tag from `#[tag("TAG")]`.
+pub fn is_tag(attr: &syn::Attribute) -> Option<String> {
+    is_attr_one_string_arg(attr, ATTR_TAG)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -0,0 +1,89 @@
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
+static ATTR_ERROR: &str = "error";
+static ERROR_ARG_CODE: &str = "code";
+static ERROR_ARG_MESSAGE: &str = "message";
+
+/// Contents of the `#[error(code = N, message = "...")]` attribute of a contract error variant.
+pub struct ErrorAttribute {
+    pub code: u32,
+    /// The message literal, including the quotes, as expected by `parse_format_string`.
+    pub message: syn::LitStr,
+}
+
+struct ErrorArg {
+    name: syn::Ident,
+    value: syn::Lit,
+}
+
+impl syn::parse::Parse for ErrorArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(ErrorArg { name, value })
+    }
+}
+
+/// Every contract error variant must have exactly one `#[error(...)]` attribute.
+pub fn extract_error_attribute(attrs: &[syn::Attribute], variant_name: &str) -> ErrorAttribute {
+    let mut error_attrs = attrs.iter().filter(|attr| attr.path.is_ident(ATTR_ERROR));
+    let attr = error_attrs.next().unwrap_or_else(|| {
+        panic!(
+            "contract error variant `{}` requires an #[error(code = ..., message = \"...\")] attribute",
+            variant_name
+        )
+    });
+    assert!(
+        error_attrs.next().is_none(),
+        "contract error variant `{}` can only have one #[error] attribute",
+        variant_name
+    );
+
+    let args = attr
+        .parse_args_with(|input: ParseStream| {
+            Punctuated::<ErrorArg, Token![,]>::parse_terminated(input)
+        })
+        .unwrap_or_else(|err| panic!("malformed error attribute: {}", err));
+
+    let mut code = None;
+    let mut message = None;
+    for arg in args {
+        match arg.value {
+            syn::Lit::Int(lit_int) if arg.name == ERROR_ARG_CODE => {
+                let value = lit_int
+                    .base10_parse()
+                    .unwrap_or_else(|_| panic!("error `code` must fit in a u32"));
+                assert!(
+                    code.replace(value).is_none(),
+                    "error `code` specified twice"
+                );
+            },
+            syn::Lit::Str(lit_str) if arg.name == ERROR_ARG_MESSAGE => {
+                assert!(
+                    message.replace(lit_str).is_none(),
+                    "error `message` specified twice"
+                );
+            },
+            _ => panic!(
+                "unexpected error argument `{}`, expected `code = <integer>` or `message = \"...\"`",
+                arg.name
+            ),
+        }
+    }
+
+    ErrorAttribute {
+        code: code.unwrap_or_else(|| {
+            panic!(
+                "contract error variant `{}` is missing the `code`",
+                variant_name
+            )
+        }),
+        message: message.unwrap_or_else(|| {
+            panic!(
+                "contract error variant `{}` is missing the `message`",
+                variant_name
+            )
+        }),
+    }
+}
@@ -1,15 +1,60 @@
 use super::{attr_names::*, util::*};
+use syn::{parse::ParseStream, Token};
 
 pub struct EventAttribute {
     pub identifier: String,
+    pub version: Option<u8>,
 }
 
 impl EventAttribute {
+    /// Parses `#[event("identifier")]` or `#[event("identifier", version = N)]`.
     pub fn parse(attr: &syn::Attribute) -> Option<Self> {
-        is_attr_one_string_arg(attr, ATTR_EVENT).map(|arg_str| EventAttribute {
-            identifier: arg_str,
-        })
+        let first_seg = attr.path.segments.first()?;
+        if first_seg.ident != ATTR_EVENT {
+            return None;
+        }
+        let event_attr = attr
+            .parse_args_with(|input: ParseStream| {
+                let identifier = input.parse::<syn::LitStr>()?.value();
+                let mut version = None;
+                if input.parse::<Option<Token![,]>>()?.is_some() {
+                    let name = input.parse::<syn::Ident>()?;
+                    if name != ATTR_EVENT_VERSION {
+                        return Err(syn::Error::new(name.span(), "expected `version`"));
+                    }
+                    input.parse::<Token![=]>()?;
+                    version = Some(input.parse::<syn::LitInt>()?.base10_parse::<u8>()?);
+                }
+                Ok(EventAttribute {
+                    identifier,
+                    version,
+                })
+            })
+            .unwrap_or_else(|err| {
+                panic!(
+                    "expected `#[event(\"identifier\")]` or `#[event(\"identifier\", version = <n>)]`: {}",
+                    err
+                )
+            });
+        if let Some(version) = event_attr.version {
+            assert!(version >= 1, "event `version` must be at least 1");
+        }
+        Some(event_attr)
+    }
+}
+
+/// Retrieves the version from `#[since(2)]`, on event arguments added in later event versions.
+pub fn is_event_since(attr: &syn::Attribute) -> Option<u8> {
+    let first_seg = attr.path.segments.first()?;
+    if first_seg.ident != ATTR_EVENT_SINCE {
+        return None;
     }
+    let since = attr
+        .parse_args::<syn::LitInt>()
+        .and_then(|lit_int| lit_int.base10_parse::<u8>())
+        .unwrap_or_else(|_| panic!("expected `#[since(<event version>)]`"));
+    assert!(since >= 1, "event argument `since` must be at least 1");
+    Some(since)
 }
 
 pub struct LegacyEventAttribute {
@@ -0,0 +1,30 @@
+use super::{attr_names::*, util::*};
+
+pub fn is_require_not_paused(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_REQUIRE_NOT_PAUSED)
+}
+
+pub fn is_nonzero_payment(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NONZERO_PAYMENT)
+}
+
+/// Retrieves the mapper method name from `#[only_whitelisted(mapper)]`.
+pub fn is_only_whitelisted(attr: &syn::Attribute) -> Option<syn::Ident> {
+    guard_method_name_arg(attr, ATTR_ONLY_WHITELISTED)
+}
+
+/// Retrieves the storage method name from `#[before_timestamp(storage)]`.
+pub fn is_before_timestamp(attr: &syn::Attribute) -> Option<syn::Ident> {
+    guard_method_name_arg(attr, ATTR_BEFORE_TIMESTAMP)
+}
+
+fn guard_method_name_arg(attr: &syn::Attribute, attr_name: &str) -> Option<syn::Ident> {
+    match is_attr_with_one_opt_token_tree_arg(attr, attr_name) {
+        None => None,
+        Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(ident),
+        _ => panic!(
+            "`#[{}]` expects the name of a storage method as argument",
+            attr_name
+        ),
+    }
+}
@@ -1,8 +1,11 @@
 mod argument_attr;
 mod attr_names;
+mod codec_attr;
 mod doc_attr;
 mod endpoint_attr;
+mod error_attr;
 mod event_attr;
+mod guard_attr;
 mod payable_attr;
 mod storage_attr;
 mod trait_argument_prop;
@@ -10,9 +13,12 @@ mod trait_prop_names;
 mod util;
 
 pub use argument_attr::*;
+pub use codec_attr::*;
 pub use doc_attr::{extract_doc, OutputNameAttribute};
 pub use endpoint_attr::*;
+pub use error_attr::*;
 pub use event_attr::*;
+pub use guard_attr::*;
 pub use payable_attr::*;
 pub use storage_attr::*;
 pub use trait_argument_prop::*;
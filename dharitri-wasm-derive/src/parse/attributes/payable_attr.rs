@@ -1,16 +1,25 @@
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
 use super::attr_names::*;
 
+static PAYABLE_ARG_STORAGE: &str = "storage";
+static PAYABLE_ARG_MAX_TRANSFERS: &str = "max_transfers";
+static PAYABLE_ARG_DISTINCT_TOKENS: &str = "distinct_tokens";
+
+/// Contains the raw arguments of the `#[payable(...)]` attribute,
+/// e.g. `#[payable("TOKEN-A", "TOKEN-B", max_transfers = 2, distinct_tokens)]`.
 pub struct PayableAttribute {
-    pub identifier: Option<String>,
+    pub token_identifiers: Vec<String>,
+    pub storage: Option<String>,
+    pub max_transfers: Option<usize>,
+    pub distinct_tokens: bool,
 }
 
 impl PayableAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<PayableAttribute> {
         if let Some(first_seg) = attr.path.segments.first() {
             if first_seg.ident == ATTR_PAYABLE {
-                Some(PayableAttribute {
-                    identifier: extract_token_identifier(attr),
-                })
+                Some(parse_payable_args(attr))
             } else {
                 None
             }
@@ -20,38 +29,68 @@ impl PayableAttribute {
     }
 }
 
-/// Current implementation only works with 1 token name.
-/// Might be extended in the future.
-fn extract_token_identifier(attr: &syn::Attribute) -> Option<String> {
-    let mut iter = attr.clone().tokens.into_iter();
-    let result_str = match iter.next() {
-        Some(proc_macro2::TokenTree::Group(group)) => {
-            assert!(
-                group.delimiter() == proc_macro2::Delimiter::Parenthesis,
-                "payable token name must be specified in parantheses"
-            );
-            let mut iter2 = group.stream().into_iter();
-            match iter2.next() {
-                Some(proc_macro2::TokenTree::Literal(lit)) => {
-                    let str_val = lit.to_string();
-                    assert!(
-                        str_val.starts_with('\"') && str_val.ends_with('\"'),
-                        "string literal expected as attribute argument"
-                    );
-                    let substr = &str_val[1..str_val.len() - 1];
-                    Some(substr.to_string())
-                },
-                _ => panic!("literal expected as event identifier"),
-            }
-        },
-        None => None,
-        _ => panic!("unexpected payable attribute format"),
-    };
+enum PayableArg {
+    TokenIdentifier(syn::LitStr),
+    Option(syn::Ident, Option<syn::Lit>),
+}
 
-    assert!(
-        iter.next().is_none(),
-        "event too many tokens in event attribute"
-    );
+impl syn::parse::Parse for PayableArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::LitStr) {
+            return Ok(PayableArg::TokenIdentifier(input.parse()?));
+        }
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(PayableArg::Option(name, value))
+    }
+}
 
-    result_str
+fn parse_payable_args(attr: &syn::Attribute) -> PayableAttribute {
+    let mut result = PayableAttribute {
+        token_identifiers: Vec::new(),
+        storage: None,
+        max_transfers: None,
+        distinct_tokens: false,
+    };
+    if attr.tokens.is_empty() {
+        return result;
+    }
+
+    let args = attr
+        .parse_args_with(|input: ParseStream| {
+            Punctuated::<PayableArg, Token![,]>::parse_terminated(input)
+        })
+        .unwrap_or_else(|err| panic!("unexpected payable attribute format: {}", err));
+    for arg in args {
+        match arg {
+            PayableArg::TokenIdentifier(lit_str) => result.token_identifiers.push(lit_str.value()),
+            PayableArg::Option(name, Some(syn::Lit::Str(lit_str)))
+                if name == PAYABLE_ARG_STORAGE =>
+            {
+                result.storage = Some(lit_str.value());
+            },
+            PayableArg::Option(name, Some(syn::Lit::Int(lit_int)))
+                if name == PAYABLE_ARG_MAX_TRANSFERS =>
+            {
+                result.max_transfers = Some(
+                    lit_int
+                        .base10_parse()
+                        .expect("payable `max_transfers` must be a number"),
+                );
+            },
+            PayableArg::Option(name, None) if name == PAYABLE_ARG_DISTINCT_TOKENS => {
+                result.distinct_tokens = true;
+            },
+            PayableArg::Option(name, _) => panic!(
+                "unexpected payable argument `{}`, expected token identifiers, `storage = \"<mapper>\"`, `max_transfers = <n>` or `distinct_tokens`",
+                name
+            ),
+        }
+    }
+    result
 }
@@ -1,9 +1,9 @@
 use super::{
-    attributes::extract_doc, method_parse::process_method, parse_util::validate_attribute_args,
+    attributes::extract_doc, method_parse::process_method, parse_util::process_attribute_args,
     supertrait_parse::parse_supertrait,
 };
 use crate::{
-    model::{ContractTrait, Method, Supertrait, TraitProperties},
+    model::{ContractTrait, Method, MethodImpl, Supertrait, TraitProperties},
     parse::process_trait_arguments,
 };
 
@@ -11,11 +11,10 @@ pub fn parse_contract_trait(
     args: syn::AttributeArgs,
     contract_trait: &syn::ItemTrait,
 ) -> ContractTrait {
-    validate_attribute_args(args);
-
     let docs = extract_doc(contract_trait.attrs.as_slice());
 
     let mut trait_attributes = TraitProperties::default();
+    process_attribute_args(args, &mut trait_attributes);
     let mut unprocessed_attributes = Vec::new();
     process_trait_arguments(
         contract_trait.attrs.as_slice(),
@@ -29,7 +28,7 @@ pub fn parse_contract_trait(
         .map(parse_supertrait)
         .collect();
 
-    let methods: Vec<Method> = contract_trait
+    let mut methods: Vec<Method> = contract_trait
         .items
         .iter()
         .map(|itm| match itm {
@@ -37,6 +36,9 @@ pub fn parse_contract_trait(
             _ => panic!("Only methods allowed in contract traits"),
         })
         .collect();
+    if let Some(storage_prefix) = &trait_attributes.storage_prefix {
+        apply_storage_prefix(methods.as_mut_slice(), storage_prefix);
+    }
 
     ContractTrait {
         docs,
@@ -48,3 +50,13 @@ pub fn parse_contract_trait(
         trait_attributes,
     }
 }
+
+fn apply_storage_prefix(methods: &mut [Method], storage_prefix: &str) {
+    for m in methods {
+        if let MethodImpl::Generated(auto_impl) = &mut m.implementation {
+            if let Some(identifier) = auto_impl.storage_identifier_mut() {
+                *identifier = format!("{}{}", storage_prefix, identifier);
+            }
+        }
+    }
+}
@@ -1,19 +1,20 @@
 use crate::model::{
     CallbackMetadata, EndpointLocationMetadata, EndpointMetadata, EndpointMutabilityMetadata,
-    InitMetadata, Method, PublicRole,
+    InitMetadata, Method, MigrationMetadata, PublicRole,
 };
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_owner, CallbackAttribute, EndpointAttribute,
-        ExternalViewAttribute, OutputNameAttribute, ViewAttribute,
+        is_callback_raw, is_init, is_migration, is_non_reentrant, is_only_owner, is_only_role,
+        is_tag, is_upgrade, CallbackAttribute, EndpointAttribute, ExternalViewAttribute,
+        OutputNameAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
 
 fn check_single_role(method: &Method) {
     assert!(matches!(method.public_role, PublicRole::Private),
-		"Can only annotate with one of the following arguments: `#[init]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
+		"Can only annotate with one of the following arguments: `#[init]`, `#[upgrade]`, `#[migration]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
 	);
 }
 
@@ -33,6 +34,31 @@ pub fn process_init_attribute(
     }
 }
 
+pub fn process_upgrade_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
+    if is_upgrade(attr) {
+        check_single_role(&*method);
+        method.public_role = PublicRole::Upgrade(InitMetadata {
+            payable: pass_1_data.payable.clone(),
+        });
+        true
+    } else {
+        false
+    }
+}
+
+pub fn process_migration_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+    is_migration(attr)
+        .map(|version| {
+            check_single_role(&*method);
+            method.public_role = PublicRole::Migration(MigrationMetadata { version });
+        })
+        .is_some()
+}
+
 pub fn process_only_owner_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -44,6 +70,45 @@ pub fn process_only_owner_attribute(
     is_only_owner
 }
 
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_non_reentrant = is_non_reentrant(attr);
+    if is_non_reentrant {
+        pass_1_data.non_reentrant = true;
+    }
+    is_non_reentrant
+}
+
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(role) = is_only_role(attr) {
+        assert!(
+            pass_1_data.only_role.is_none(),
+            "Only one `#[only_role]` attribute allowed per endpoint"
+        );
+        pass_1_data.only_role = Some(role);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn process_tag_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(tag) = is_tag(attr) {
+        pass_1_data.tags.push(tag);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -60,6 +125,10 @@ pub fn process_endpoint_attribute(
                 public_name: endpoint_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
+                tags: pass_1_data.tags.clone(),
+                guards: pass_1_data.guards.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Mutable,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -83,6 +152,10 @@ pub fn process_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
+                tags: pass_1_data.tags.clone(),
+                guards: pass_1_data.guards.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -106,6 +179,10 @@ pub fn process_external_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
+                tags: pass_1_data.tags.clone(),
+                guards: pass_1_data.guards.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::ViewContract,
             });
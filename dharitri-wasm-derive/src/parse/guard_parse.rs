@@ -0,0 +1,35 @@
+use super::{
+    attributes::{
+        is_before_timestamp, is_nonzero_payment, is_only_whitelisted, is_require_not_paused,
+    },
+    MethodAttributesPass1,
+};
+use crate::model::EndpointGuard;
+
+pub fn process_guard_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let guard = if is_require_not_paused(attr) {
+        EndpointGuard::RequireNotPaused
+    } else if is_nonzero_payment(attr) {
+        EndpointGuard::NonzeroPayment
+    } else if let Some(mapper) = is_only_whitelisted(attr) {
+        EndpointGuard::OnlyWhitelisted(mapper)
+    } else if let Some(storage) = is_before_timestamp(attr) {
+        EndpointGuard::BeforeTimestamp(storage)
+    } else {
+        return false;
+    };
+    assert!(
+        !pass_1_data
+            .guards
+            .iter()
+            .any(|existing| existing.abi_string() == guard.abi_string()),
+        "Duplicate guard `{}` on method `{}`",
+        guard.abi_string(),
+        pass_1_data.method_name
+    );
+    pass_1_data.guards.push(guard);
+    true
+}
@@ -1,4 +1,7 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties};
+use crate::model::{
+    EndpointGuard, EndpointMutabilityMetadata, Method, MethodImpl, MethodPayableMetadata,
+    PublicRole, TraitProperties,
+};
 
 use super::{
     attributes::extract_doc,
@@ -9,14 +12,20 @@ use super::{
         process_storage_set_attribute,
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
-    process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_only_owner_attribute, process_output_names_attribute, process_payable_attribute,
+    process_endpoint_attribute, process_external_view_attribute, process_guard_attribute,
+    process_init_attribute, process_migration_attribute, process_non_reentrant_attribute,
+    process_only_owner_attribute, process_only_role_attribute, process_output_names_attribute,
+    process_payable_attribute, process_tag_attribute, process_upgrade_attribute,
     process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_role: Option<String>,
+    pub tags: Vec<String>,
+    pub guards: Vec<EndpointGuard>,
+    pub non_reentrant: bool,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -32,6 +41,10 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         method_name: m.sig.ident.to_string(),
         payable: MethodPayableMetadata::NotPayable,
         only_owner: trait_attributes.only_owner,
+        only_role: None,
+        tags: Vec::new(),
+        guards: Vec::new(),
+        non_reentrant: false,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -58,6 +71,26 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         &first_pass_data,
         &mut method,
     );
+    assert!(
+        first_pass_data.guards.is_empty() || matches!(method.public_role, PublicRole::Endpoint(_)),
+        "Guard attributes are only allowed on endpoints and views. Method name: {}",
+        first_pass_data.method_name
+    );
+    assert!(
+        first_pass_data.tags.is_empty() || matches!(method.public_role, PublicRole::Endpoint(_)),
+        "`#[tag]` is only allowed on endpoints and views. Method name: {}",
+        first_pass_data.method_name
+    );
+    let is_mutable_endpoint = matches!(
+        &method.public_role,
+        PublicRole::Endpoint(endpoint_metadata)
+            if matches!(endpoint_metadata.mutability, EndpointMutabilityMetadata::Mutable)
+    );
+    assert!(
+        !first_pass_data.non_reentrant || is_mutable_endpoint,
+        "`#[non_reentrant]` is only allowed on endpoints, not on views. Method name: {}",
+        first_pass_data.method_name
+    );
 
     method
 }
@@ -81,6 +114,10 @@ fn process_attribute_first_pass(
 ) -> bool {
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
+        || process_tag_attribute(attr, first_pass_data)
+        || process_guard_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -102,6 +139,8 @@ fn process_attribute_second_pass(
     method: &mut Method,
 ) -> bool {
     process_init_attribute(attr, first_pass_data, method)
+        || process_upgrade_attribute(attr, first_pass_data, method)
+        || process_migration_attribute(attr, method)
         || process_endpoint_attribute(attr, first_pass_data, method)
         || process_view_attribute(attr, first_pass_data, method)
         || process_external_view_attribute(attr, first_pass_data, method)
@@ -1,6 +1,31 @@
-pub fn validate_attribute_args(args: syn::AttributeArgs) {
-    assert!(
-        args.is_empty(),
-        "No arguments expected in contract, module or proxy annotation."
-    );
+use crate::model::TraitProperties;
+
+static ARG_STORAGE_PREFIX: &str = "storage_prefix";
+
+/// The contract, module or proxy annotation only accepts `storage_prefix = "..."`.
+pub fn process_attribute_args(args: syn::AttributeArgs, trait_attributes: &mut TraitProperties) {
+    for arg in args {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(ARG_STORAGE_PREFIX) =>
+            {
+                assert!(
+                    trait_attributes.storage_prefix.is_none(),
+                    "Only one `storage_prefix` argument allowed."
+                );
+                let storage_prefix = match &name_value.lit {
+                    syn::Lit::Str(lit_str) => lit_str.value(),
+                    _ => panic!("`storage_prefix` expects a string literal."),
+                };
+                assert!(
+                    !storage_prefix.is_empty(),
+                    "`storage_prefix` cannot be empty."
+                );
+                trait_attributes.storage_prefix = Some(storage_prefix);
+            },
+            _ => panic!(
+                "Unexpected argument in contract, module or proxy annotation. Only `storage_prefix = \"...\"` is allowed."
+            ),
+        }
+    }
 }
@@ -1,26 +1,69 @@
 use super::{attributes::PayableAttribute, MethodAttributesPass1};
-use crate::model::MethodPayableMetadata;
+use crate::model::{MethodPayableMetadata, PayableTransferLimits};
 
 pub fn process_payable_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
 ) -> bool {
-    PayableAttribute::parse(attr).map(|payable_attr| {
-		if let Some(identifier) = payable_attr.identifier {
-			pass_1_data.payable = parse_payable_identifier(identifier.as_str());
-		} else {
-			panic!(
-				"Endpoint `payable` attribute requires one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"MOAX\")]`. Method name: {}",
-				&pass_1_data.method_name);
-		}
-	}).is_some()
+    PayableAttribute::parse(attr)
+        .map(|payable_attr| {
+            pass_1_data.payable =
+                parse_payable_attribute(payable_attr, pass_1_data.method_name.as_str());
+        })
+        .is_some()
+}
+
+fn parse_payable_attribute(
+    payable_attr: PayableAttribute,
+    method_name: &str,
+) -> MethodPayableMetadata {
+    let limits = PayableTransferLimits {
+        max_transfers: payable_attr.max_transfers,
+        distinct_tokens: payable_attr.distinct_tokens,
+    };
+    let has_limits = limits.max_transfers.is_some() || limits.distinct_tokens;
+    let mut token_identifiers = payable_attr.token_identifiers;
+
+    if let Some(mapper) = payable_attr.storage {
+        assert!(
+            token_identifiers.is_empty(),
+            "Endpoint `payable` attribute cannot combine `storage` with token identifiers. Method name: {}",
+            method_name
+        );
+        assert!(
+            !mapper.is_empty(),
+            "empty storage mapper name not allowed in #[payable] attribute"
+        );
+        return MethodPayableMetadata::StorageTokens(mapper, limits);
+    }
+
+    for identifier in token_identifiers.iter() {
+        assert!(
+            !identifier.is_empty(),
+            "empty token name not allowed in #[payable] attribute"
+        );
+    }
+    match token_identifiers.len() {
+        0 => panic!(
+            "Endpoint `payable` attribute requires at least one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"MOAX\")]`. Method name: {}",
+            method_name
+        ),
+        1 if !has_limits => parse_payable_identifier(token_identifiers.pop().unwrap().as_str()),
+        _ => {
+            assert!(
+                !token_identifiers.iter().any(|identifier| identifier == "*"),
+                "`*` cannot be combined with other tokens or transfer limits in #[payable] attribute. Method name: {}",
+                method_name
+            );
+            MethodPayableMetadata::TokenList(token_identifiers, limits)
+        },
+    }
 }
 
 fn parse_payable_identifier(identifier: &str) -> MethodPayableMetadata {
     match identifier {
         "MOAX" => MethodPayableMetadata::Moax,
         "*" => MethodPayableMetadata::AnyToken,
-        "" => panic!("empty token name not allowed in #[payable] attribute"),
         _ => MethodPayableMetadata::SingleDctToken(identifier.to_string()),
     }
 }
@@ -1,25 +1,46 @@
-use super::parse::attributes::extract_doc;
+use super::parse::attributes::{
+    extract_codec_since, extract_codec_version, extract_codec_with, extract_doc, is_codec_skip,
+    is_codec_transparent, variant_discriminants,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    if is_codec_skip(field.attrs.as_slice()) {
+        return quote! {};
+    }
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
         ident.to_string()
     } else {
         index.to_string()
     };
-    let field_ty = &field.ty;
+    let field_ty = match extract_codec_with(field.attrs.as_slice()) {
+        Some(with) => quote! { #with::AbiType },
+        None => {
+            let ty = &field.ty;
+            quote! { #ty }
+        },
+    };
+    let field_since = option_u8_snippet(extract_codec_since(field.attrs.as_slice()));
     quote! {
         field_descriptions.push(dharitri_wasm::abi::StructFieldDescription {
             docs: &[ #(#field_docs),* ],
             name: #field_name_str,
             field_type: <#field_ty>::type_name(),
+            since: #field_since,
         });
         <#field_ty>::provide_type_descriptions(accumulator);
     }
 }
 
+fn option_u8_snippet(value: Option<u8>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { core::option::Option::Some(#value) },
+        None => quote! { core::option::Option::None },
+    }
+}
+
 fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     match fields {
         syn::Fields::Named(fields_named) => fields_named
@@ -38,8 +59,24 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     }
 }
 
+/// The type of the only encoded field of a `#[codec(transparent)]` struct.
+fn transparent_field_type(ast: &syn::DeriveInput) -> Option<&syn::Type> {
+    if !is_codec_transparent(ast.attrs.as_slice()) {
+        return None;
+    }
+    match &ast.data {
+        syn::Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .find(|field| !is_codec_skip(field.attrs.as_slice()))
+            .map(|field| &field.ty),
+        _ => None,
+    }
+}
+
 pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
+    let type_version = option_u8_snippet(extract_codec_version(ast.attrs.as_slice()));
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let struct_field_snippets = fields_snippets(&data_struct.fields);
@@ -55,6 +92,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                             dharitri_wasm::abi::TypeDescription {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
+                                version: #type_version,
                                 contents: dharitri_wasm::abi::TypeContents::Struct(field_descriptions),
                             },
                         );
@@ -66,8 +104,9 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .zip(variant_discriminants(data_enum))
+                .map(|(variant, discriminant)| {
+                    let discriminant = discriminant as usize;
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,7 +115,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(dharitri_wasm::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
@@ -95,6 +134,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                             dharitri_wasm::abi::TypeDescription {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
+                                version: #type_version,
                                 contents: dharitri_wasm::abi::TypeContents::Enum(variant_descriptions),
                             },
                         );
@@ -108,13 +148,28 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
     let name_str = name.to_string();
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
-    let type_abi_impl = quote! {
-        impl #impl_generics dharitri_wasm::abi::TypeAbi for #name #ty_generics #where_clause {
-            fn type_name() -> dharitri_wasm::abi::TypeName {
-                #name_str.into()
+    let type_abi_impl = if let Some(transparent_ty) = transparent_field_type(ast) {
+        // encoded exactly like the field, so it also shows up as such in the ABI
+        quote! {
+            impl #impl_generics dharitri_wasm::abi::TypeAbi for #name #ty_generics #where_clause {
+                fn type_name() -> dharitri_wasm::abi::TypeName {
+                    <#transparent_ty>::type_name()
+                }
+
+                fn provide_type_descriptions<TDC: dharitri_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
+                    <#transparent_ty>::provide_type_descriptions(accumulator);
+                }
             }
+        }
+    } else {
+        quote! {
+            impl #impl_generics dharitri_wasm::abi::TypeAbi for #name #ty_generics #where_clause {
+                fn type_name() -> dharitri_wasm::abi::TypeName {
+                    #name_str.into()
+                }
 
-            #type_description_impl
+                #type_description_impl
+            }
         }
     };
     type_abi_impl.into()
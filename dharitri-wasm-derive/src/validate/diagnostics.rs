@@ -0,0 +1,32 @@
+use core::fmt::Display;
+use proc_macro2::Span;
+
+/// Collects all the problems found while validating a contract, so they can be reported together,
+/// each at the span of the code that caused it.
+#[derive(Default)]
+pub struct Diagnostics {
+    errors: Option<syn::Error>,
+}
+
+impl Diagnostics {
+    pub fn error<M: Display>(&mut self, span: Span, message: M) {
+        let error = syn::Error::new(span, message);
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    pub fn check<M: Display>(&mut self, condition: bool, span: Span, message: M) {
+        if !condition {
+            self.error(span, message);
+        }
+    }
+
+    pub fn into_result(self) -> Result<(), syn::Error> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,4 +1,14 @@
+mod diagnostics;
 mod reserved;
+mod validate_arg_order;
 mod validate_method;
+mod validate_migration;
+mod validate_storage;
+mod validate_view;
 
+pub use diagnostics::Diagnostics;
+pub use validate_arg_order::*;
 pub use validate_method::*;
+pub use validate_migration::*;
+pub use validate_storage::*;
+pub use validate_view::*;
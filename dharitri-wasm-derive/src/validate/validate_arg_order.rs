@@ -0,0 +1,106 @@
+use super::Diagnostics;
+use crate::{
+    generate::util::pat_string,
+    model::{Method, MethodArgument, PublicRole},
+};
+use syn::spanned::Spanned;
+
+/// Multi-value types that consume all the remaining arguments.
+const VAR_ARGS_TYPES: &[&str] = &[
+    "MultiValueEncoded",
+    "ManagedVarArgs",
+    "ManagedMultiResultVec",
+    "MultiValueManagedVec",
+    "ManagedVarArgsEager",
+    "ManagedMultiResultVecEager",
+    "MultiValueVec",
+    "MultiArgVec",
+    "VarArgs",
+    "MultiResultVec",
+    "IgnoreValue",
+    "IgnoreVarArgs",
+];
+
+/// Multi-value types that can be missing, but only at the end of the arguments.
+const OPTIONAL_TYPES: &[&str] = &["OptionalValue", "OptionalArg", "OptionalResult"];
+
+enum ArgKind {
+    Single,
+    Optional,
+    VarArgs,
+}
+
+/// Only looks at the last path segment, aliases defined in contracts are not recognized.
+fn arg_kind(arg: &MethodArgument) -> (ArgKind, String) {
+    if let syn::Type::Path(type_path) = &arg.ty {
+        if let Some(last_segment) = type_path.path.segments.last() {
+            let type_name = last_segment.ident.to_string();
+            if VAR_ARGS_TYPES.contains(&type_name.as_str()) {
+                return (ArgKind::VarArgs, type_name);
+            }
+            if OPTIONAL_TYPES.contains(&type_name.as_str()) {
+                return (ArgKind::Optional, type_name);
+            }
+        }
+    }
+    (ArgKind::Single, String::new())
+}
+
+fn has_loaded_args(m: &Method) -> bool {
+    matches!(
+        m.public_role,
+        PublicRole::Init(_)
+            | PublicRole::Upgrade(_)
+            | PublicRole::Endpoint(_)
+            | PublicRole::Callback(_)
+    )
+}
+
+/// Arguments are deserialized in order, so var-args can only come last,
+/// and optional arguments can only be followed by other optional arguments or var-args.
+pub fn validate_arg_order(m: &Method, diagnostics: &mut Diagnostics) {
+    if !has_loaded_args(m) {
+        return;
+    }
+    let mut var_args: Option<(&MethodArgument, String)> = None;
+    let mut optional_arg: Option<&MethodArgument> = None;
+    for arg in &m.method_args {
+        if !arg.is_endpoint_arg() || arg.metadata.callback_call_result {
+            continue;
+        }
+        if let Some((var_args_arg, var_args_type)) = &var_args {
+            diagnostics.error(
+                var_args_arg.ty.span(),
+                format!(
+                    "`{}` consumes all the remaining arguments, so it must be the last argument (method: `{}`), move `{}` before it",
+                    var_args_type,
+                    m.name,
+                    arg_name(arg)
+                ),
+            );
+            break;
+        }
+        match arg_kind(arg) {
+            (ArgKind::VarArgs, type_name) => var_args = Some((arg, type_name)),
+            (ArgKind::Optional, _) => optional_arg = Some(arg),
+            (ArgKind::Single, _) => {
+                if let Some(optional_arg) = optional_arg {
+                    diagnostics.error(
+                        arg.ty.span(),
+                        format!(
+                            "`{}` cannot follow the optional argument `{}` (method: `{}`), optional arguments can only be followed by other optional arguments or var-args",
+                            arg_name(arg),
+                            arg_name(optional_arg),
+                            m.name
+                        ),
+                    );
+                    break;
+                }
+            },
+        }
+    }
+}
+
+fn arg_name(arg: &MethodArgument) -> String {
+    pat_string(&arg.original_pat)
+}
@@ -1,115 +1,142 @@
-use super::reserved;
-use crate::model::{ArgPaymentMetadata, ContractTrait, Method, PublicRole};
+use super::{reserved, Diagnostics};
+use crate::model::{
+    ArgPaymentMetadata, ContractTrait, EndpointGuard, Method, MethodArgument, PublicRole,
+};
+use syn::spanned::Spanned;
 
 const INIT_ENDPOINT_NAME: &str = "init";
 
-/// TODO: make it work with Result instead of panic
-pub fn validate_contract(contract_trait: &ContractTrait) {
+/// Checks the whole contract, module or proxy trait,
+/// reporting all problems found, each at the span of the code causing it.
+pub fn validate_contract(contract_trait: &ContractTrait) -> Result<(), syn::Error> {
+    let mut diagnostics = Diagnostics::default();
     for m in &contract_trait.methods {
-        validate_method(m);
+        validate_method(m, &mut diagnostics);
     }
+    super::validate_views(contract_trait, &mut diagnostics);
+    super::validate_storage_keys(contract_trait, &mut diagnostics);
+    super::validate_migrations(contract_trait, &mut diagnostics);
+    diagnostics.into_result()
 }
 
-pub fn validate_method(m: &Method) {
-    validate_method_name(m);
-    validate_payment_args(m);
-    validate_callback_call_result_arg(m);
+pub fn validate_method(m: &Method, diagnostics: &mut Diagnostics) {
+    validate_method_name(m, diagnostics);
+    validate_payment_args(m, diagnostics);
+    validate_callback_call_result_arg(m, diagnostics);
+    validate_guards(m, diagnostics);
+    super::validate_arg_order(m, diagnostics);
 }
 
-fn validate_method_name(m: &Method) {
+fn validate_method_name(m: &Method, diagnostics: &mut Diagnostics) {
     if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
         let endpoint_name_str = endpoint_metadata.public_name.to_string();
-        assert!(
+        diagnostics.check(
             endpoint_name_str != INIT_ENDPOINT_NAME,
-            "Cannot declare endpoint with name 'init'. Use #[init] instead."
+            endpoint_metadata.public_name.span(),
+            "Cannot declare endpoint with name 'init'. Use #[init] instead.",
+        );
+        diagnostics.check(
+            !reserved::is_reserved(endpoint_name_str.as_str()),
+            endpoint_metadata.public_name.span(),
+            format!(
+                "Cannot declare endpoint with name '{}', because that name is reserved by the Arwen API.",
+                endpoint_name_str
+            ),
         );
-        assert!(!reserved::is_reserved(endpoint_name_str.as_str()), "Cannot declare endpoint with name '{}', because that name is reserved by the Arwen API.", endpoint_name_str);
     }
 }
 
-fn validate_payment_args(m: &Method) {
-    let num_payment_amount = m
-        .method_args
-        .iter()
-        .filter(|&arg| matches!(arg.metadata.payment, ArgPaymentMetadata::PaymentAmount))
-        .count();
-    let num_payment_token = m
-        .method_args
-        .iter()
-        .filter(|&arg| matches!(arg.metadata.payment, ArgPaymentMetadata::PaymentToken))
-        .count();
-    let num_payment_nonce = m
-        .method_args
-        .iter()
-        .filter(|&arg| matches!(arg.metadata.payment, ArgPaymentMetadata::PaymentNonce))
-        .count();
-    let num_payment_multi = m
-        .method_args
-        .iter()
-        .filter(|&arg| matches!(arg.metadata.payment, ArgPaymentMetadata::PaymentMulti))
-        .count();
-    assert!(
-        num_payment_amount <= 1,
-        "only one `#[payment]` argument allowed (method: `{}`)",
-        m.name
-    );
-    assert!(
-        num_payment_token <= 1,
-        "only one `#[payment_token]` argument allowed (method: `{}`)",
-        m.name
-    );
-    assert!(
-        num_payment_nonce <= 1,
-        "only one `#[payment_nonce]` argument allowed (method: `{}`)",
-        m.name
-    );
-    assert!(
-        num_payment_multi <= 1,
-        "only one `#[payment_multi]` argument allowed (method: `{}`)",
-        m.name
-    );
-    if !m.is_payable() {
-        assert!(num_payment_amount == 0, "`#[payment]` only allowed in payable endpoints, payable init or callbacks (method: `{}`)", m.name);
+fn validate_guards(m: &Method, diagnostics: &mut Diagnostics) {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        let has_nonzero_payment = endpoint_metadata
+            .guards
+            .iter()
+            .any(|guard| matches!(guard, EndpointGuard::NonzeroPayment));
+        diagnostics.check(
+            !has_nonzero_payment || m.is_payable(),
+            m.name.span(),
+            format!(
+                "`#[nonzero_payment]` only allowed in payable endpoints (method: `{}`), add `#[payable(\"*\")]` or restrict the accepted token",
+                m.name
+            ),
+        );
+    }
+}
+
+fn payment_attr_name(payment: &ArgPaymentMetadata) -> Option<&'static str> {
+    match payment {
+        ArgPaymentMetadata::NotPayment => None,
+        ArgPaymentMetadata::PaymentAmount => Some("payment"),
+        ArgPaymentMetadata::PaymentToken => Some("payment_token"),
+        ArgPaymentMetadata::PaymentNonce => Some("payment_nonce"),
+        ArgPaymentMetadata::PaymentMulti => Some("payment_multi"),
+    }
+}
 
-        assert!(num_payment_token == 0, "`#[payment_token]` only allowed in payable endpoints, payable init or callbacks (method: `{}`)", m.name);
+fn validate_payment_args(m: &Method, diagnostics: &mut Diagnostics) {
+    let mut seen_payment_attrs: Vec<&'static str> = Vec::new();
+    for arg in &m.method_args {
+        let attr_name = match payment_attr_name(&arg.metadata.payment) {
+            Some(attr_name) => attr_name,
+            None => continue,
+        };
+        let arg_span = arg.original_pat.span();
+        if seen_payment_attrs.contains(&attr_name) {
+            diagnostics.error(
+                arg_span,
+                format!(
+                    "only one `#[{}]` argument allowed (method: `{}`)",
+                    attr_name, m.name
+                ),
+            );
+        }
+        seen_payment_attrs.push(attr_name);
+        diagnostics.check(
+            m.is_payable(),
+            arg_span,
+            format!(
+                "`#[{}]` only allowed in payable endpoints, payable init or callbacks (method: `{}`), add `#[payable(\"*\")]` to the method, or remove the attribute",
+                attr_name, m.name
+            ),
+        );
     }
-    if let PublicRole::Init(init_metadata) = &m.public_role {
-        assert!(
+    if let PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) = &m.public_role {
+        diagnostics.check(
             init_metadata.payable.no_dct(),
-            "only MOAX payments currently allowed in constructors"
+            m.name.span(),
+            "only MOAX payments currently allowed in constructors",
         );
     }
-    validate_payment_args_not_reference(m);
+    validate_payment_args_not_reference(m, diagnostics);
 }
 
-pub fn validate_payment_args_not_reference(m: &Method) {
+pub fn validate_payment_args_not_reference(m: &Method, diagnostics: &mut Diagnostics) {
     if let Some(payment_arg) = m.payment_amount_arg() {
         match &payment_arg.ty {
             syn::Type::Path(_) => {},
-            syn::Type::Reference(_) => {
-                panic!("The payment argument is expected to be an owned BigUint, references are not allowed.");
-            },
-            _ => panic!("Unsupported payment argument type"),
+            syn::Type::Reference(_) => diagnostics.error(
+                payment_arg.ty.span(),
+                "The payment argument is expected to be an owned BigUint, references are not allowed.",
+            ),
+            _ => diagnostics.error(payment_arg.ty.span(), "Unsupported payment argument type"),
         }
     }
 }
 
-fn validate_callback_call_result_arg(m: &Method) {
-    let num_call_result = m
+fn validate_callback_call_result_arg(m: &Method, diagnostics: &mut Diagnostics) {
+    let call_result_args: Vec<&MethodArgument> = m
         .method_args
         .iter()
         .filter(|&arg| arg.metadata.callback_call_result)
-        .count();
+        .collect();
 
-    if matches!(&m.public_role, PublicRole::Callback(_)) {
-        assert!(
-            num_call_result <= 1,
+    // a single `#[call_result]` outside callbacks only gets a warning, in the generated code
+    if let Some(extra_arg) = call_result_args.get(1) {
+        let message = if matches!(&m.public_role, PublicRole::Callback(_)) {
             "only one `#[call_result]` argument allowed"
-        );
-    } else {
-        assert!(
-            num_call_result <= 1,
+        } else {
             "`#[call_result]` argument only allowed in `#[callback]` methods"
-        );
+        };
+        diagnostics.error(extra_arg.original_pat.span(), message);
     }
 }
@@ -0,0 +1,72 @@
+use super::Diagnostics;
+use crate::model::{ContractTrait, PublicRole};
+
+/// Migration steps need to run in order, starting from version 1, and only start on upgrade.
+pub fn validate_migrations(contract_trait: &ContractTrait, diagnostics: &mut Diagnostics) {
+    let upgrade_methods: Vec<_> = contract_trait
+        .methods
+        .iter()
+        .filter(|m| matches!(m.public_role, PublicRole::Upgrade(_)))
+        .collect();
+    for extra_upgrade_method in upgrade_methods.iter().skip(1) {
+        diagnostics.error(
+            extra_upgrade_method.name.span(),
+            "Only one `#[upgrade]` method allowed per contract.",
+        );
+    }
+
+    let migration_steps = contract_trait.migration_steps();
+    if migration_steps.is_empty() {
+        return;
+    }
+    diagnostics.check(
+        !upgrade_methods.is_empty(),
+        migration_steps[0].1.name.span(),
+        "Contracts with `#[migration]` steps also need an `#[upgrade]` method, otherwise the constructor would run again on upgrade."
+    );
+    for (index, (version, m)) in migration_steps.iter().enumerate() {
+        diagnostics.check(
+            *version as usize == index + 1,
+            m.name.span(),
+            format!(
+                "Migration versions must start from 1 and have no gaps or duplicates (method: `{}`, version {}).",
+                m.name, version
+            ),
+        );
+        diagnostics.check(
+            m.method_args.len() <= 1,
+            m.name.span(),
+            format!(
+                "Migration steps can only take the migration cursor as argument (method: `{}`).",
+                m.name
+            ),
+        );
+        diagnostics.check(
+            m.method_args.is_empty() || !matches!(m.return_type, syn::ReturnType::Default),
+            m.name.span(),
+            format!(
+                "Migration steps that take the migration cursor need to return its `OperationCompletionStatus` (method: `{}`).",
+                m.name
+            ),
+        );
+    }
+}
+
+/// The upgrade and the migrations run from the `init` endpoint, which is only generated in the main contract.
+pub fn validate_module_migrations(contract_trait: &ContractTrait) -> Result<(), syn::Error> {
+    let mut diagnostics = Diagnostics::default();
+    for m in &contract_trait.methods {
+        diagnostics.check(
+            !matches!(
+                m.public_role,
+                PublicRole::Upgrade(_) | PublicRole::Migration(_)
+            ),
+            m.name.span(),
+            format!(
+                "`#[upgrade]` and `#[migration]` are only allowed in the main contract (method: `{}`).",
+                m.name
+            ),
+        );
+    }
+    diagnostics.into_result()
+}
@@ -0,0 +1,27 @@
+use super::Diagnostics;
+use crate::model::ContractTrait;
+
+/// Getters, setters and the other storage accessors can share a key,
+/// but each storage mapper manages its keys on its own, so it needs a key of its own.
+pub fn validate_storage_keys(contract_trait: &ContractTrait, diagnostics: &mut Diagnostics) {
+    let mapper_methods = contract_trait
+        .methods
+        .iter()
+        .filter(|m| m.is_storage_mapper());
+    for mapper_method in mapper_methods {
+        let key = mapper_method.storage_identifier().unwrap();
+        if let Some(other) = contract_trait
+            .methods
+            .iter()
+            .find(|m| m.name != mapper_method.name && m.storage_identifier() == Some(key))
+        {
+            diagnostics.error(
+                mapper_method.name.span(),
+                format!(
+                    "Storage key \"{}\" is used by both `{}` and `{}`. Storage mappers cannot share their key with other storage methods.",
+                    key, mapper_method.name, other.name
+                ),
+            );
+        }
+    }
+}
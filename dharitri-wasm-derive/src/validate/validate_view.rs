@@ -0,0 +1,161 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+
+use super::Diagnostics;
+use crate::model::{
+    AutoImpl, ContractTrait, EndpointLocationMetadata, EndpointMutabilityMetadata, Method,
+    MethodImpl, PublicRole,
+};
+
+/// Storage mapper methods that change storage.
+const MAPPER_WRITE_METHODS: &[&str] = &[
+    "set",
+    "set_if_empty",
+    "update",
+    "clear",
+    "take",
+    "push",
+    "push_back",
+    "push_front",
+    "pop_back",
+    "pop_front",
+    "insert",
+    "remove",
+    "swap_remove",
+    "extend",
+];
+
+/// External views are left out, they live in a separate contract, where storage writes fail at runtime.
+fn is_view(m: &Method) -> bool {
+    matches!(
+        &m.public_role,
+        PublicRole::Endpoint(endpoint_metadata)
+            if matches!(endpoint_metadata.mutability, EndpointMutabilityMetadata::Readonly)
+                && matches!(endpoint_metadata.location, EndpointLocationMetadata::MainContract)
+    )
+}
+
+fn is_storage_write(m: &Method) -> bool {
+    matches!(
+        m.implementation,
+        MethodImpl::Generated(AutoImpl::StorageSetter { .. })
+            | MethodImpl::Generated(AutoImpl::StorageClear { .. })
+    )
+}
+
+/// Views are executed as queries, where storage changes are not allowed.
+///
+/// Only catches writes done directly in the view body, via the storage setters and mappers of the same trait,
+/// e.g. `self.set_value(..)` or `self.value().set(..)`.
+/// The body is scanned token by token, without any type information, so this is a best-effort check:
+/// - writes via helper methods, or via setters and mappers declared in other modules, are not detected;
+/// - neither are writes via a mapper bound to a local variable first, e.g. `let m = self.value(); m.set(..)`,
+///   or via code generated by macros called in the body;
+/// - mapper writes are recognized by method name only, see `MAPPER_WRITE_METHODS`,
+///   so custom mappers with other write methods are not covered.
+///
+/// Views that get past it still fail at runtime, when the VM rejects the storage write.
+pub fn validate_views(contract_trait: &ContractTrait, diagnostics: &mut Diagnostics) {
+    let setter_names: Vec<String> = contract_trait
+        .methods
+        .iter()
+        .filter(|m| is_storage_write(m))
+        .map(|m| m.name.to_string())
+        .collect();
+    let mapper_names: Vec<String> = contract_trait
+        .methods
+        .iter()
+        .filter(|m| m.is_storage_mapper())
+        .map(|m| m.name.to_string())
+        .collect();
+
+    for m in contract_trait.methods.iter().filter(|m| is_view(m)) {
+        if is_storage_write(m) {
+            diagnostics.error(
+                m.name.span(),
+                format!(
+                    "`{}` writes to storage, so it cannot be a view, use `#[endpoint]` instead",
+                    m.name
+                ),
+            );
+            continue;
+        }
+        if let MethodImpl::Explicit(body) = &m.implementation {
+            let context = ViewBodyContext {
+                view: m,
+                setter_names: setter_names.as_slice(),
+                mapper_names: mapper_names.as_slice(),
+            };
+            context.check_tokens(body.to_token_stream(), diagnostics);
+        }
+    }
+}
+
+struct ViewBodyContext<'a> {
+    view: &'a Method,
+    setter_names: &'a [String],
+    mapper_names: &'a [String],
+}
+
+impl<'a> ViewBodyContext<'a> {
+    fn check_tokens(&self, tokens: TokenStream, diagnostics: &mut Diagnostics) {
+        let trees: Vec<TokenTree> = tokens.into_iter().collect();
+        for (index, tree) in trees.iter().enumerate() {
+            if let TokenTree::Group(group) = tree {
+                self.check_tokens(group.stream(), diagnostics);
+                continue;
+            }
+            let method_ident = match self_method_call_at(&trees[index..]) {
+                Some(method_ident) => method_ident,
+                None => continue,
+            };
+            let method_name = method_ident.to_string();
+            if self.setter_names.contains(&method_name) {
+                self.report(method_ident, &method_name, diagnostics);
+            } else if self.mapper_names.contains(&method_name) {
+                // self . mapper ( ) . write_method
+                if let Some(TokenTree::Ident(write_ident)) = chained_method_at(&trees[index + 3..])
+                {
+                    if MAPPER_WRITE_METHODS.contains(&write_ident.to_string().as_str()) {
+                        let description = format!("{}().{}", method_name, write_ident);
+                        self.report(write_ident, &description, diagnostics);
+                    }
+                }
+            }
+        }
+    }
+
+    fn report(&self, ident: &proc_macro2::Ident, description: &str, diagnostics: &mut Diagnostics) {
+        diagnostics.error(
+            ident.span(),
+            format!(
+                "view `{}` writes to storage via `{}`, but views cannot change storage, use `#[endpoint]` instead",
+                self.view.name, description
+            ),
+        );
+    }
+}
+
+/// Matches `self . method`, returns `method`.
+fn self_method_call_at(trees: &[TokenTree]) -> Option<&proc_macro2::Ident> {
+    match trees {
+        [TokenTree::Ident(self_ident), TokenTree::Punct(dot), TokenTree::Ident(method_ident), ..]
+            if self_ident == "self" && dot.as_char() == '.' =>
+        {
+            Some(method_ident)
+        },
+        _ => None,
+    }
+}
+
+/// Matches `( .. ) . method`, returns `method`.
+fn chained_method_at(trees: &[TokenTree]) -> Option<&TokenTree> {
+    match trees {
+        [TokenTree::Group(_), TokenTree::Punct(dot), method_ident @ TokenTree::Ident(_), ..]
+            if dot.as_char() == '.' =>
+        {
+            Some(method_ident)
+        },
+        _ => None,
+    }
+}
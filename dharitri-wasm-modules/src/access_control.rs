@@ -0,0 +1,159 @@
+dharitri_wasm::imports!();
+
+/// The role that administers all roles, unless configured otherwise via `setRoleAdmin`.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard smart contract module that, when added to a smart contract, offers role-based access control.
+///
+/// Roles are arbitrary names, each with its own set of member addresses.
+/// Every role has an admin role, whose members can grant and revoke it.
+/// By default, that is the `DEFAULT_ADMIN` role, which is given to the first admin on init.
+///
+/// Endpoints can be restricted to the members of a role with the `#[only_role("ROLE")]` attribute,
+/// as long as the trait declaring them extends this module.
+///
+/// It offers:
+/// * endpoints where role admins grant/revoke roles, and where members renounce their own roles
+/// * an endpoint where role admins change the admin role
+/// * views for checking roles and listing role members
+/// * events for all changes
+///
+#[dharitri_wasm::module]
+pub trait AccessControlModule {
+    /// Gives the `DEFAULT_ADMIN` role to `admin`.
+    /// Usually called from the main contract's `#[init]` function.
+    fn init_access_control_module(&self, admin: &ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.grant_role_unchecked(&ManagedBuffer::from(DEFAULT_ADMIN_ROLE), admin, &caller);
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_role_admin(&role, &caller);
+
+        self.grant_role_unchecked(&role, &address, &caller);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_role_admin(&role, &caller);
+
+        self.revoke_role_unchecked(&role, &address, &caller);
+    }
+
+    /// Removes the role from the caller, e.g. when the address is compromised.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(&role, &caller),
+            "Caller does not have the role"
+        );
+
+        self.revoke_role_unchecked(&role, &caller, &caller);
+    }
+
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_role_admin(&role, &caller);
+
+        let previous_admin_role = self.get_role_admin(&role);
+        self.role_admin(&role).set(&admin_role);
+
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    /// Called by the code generated for `#[only_role]`.
+    fn require_caller_has_role(&self, role: &[u8]) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(&ManagedBuffer::from(role), &caller),
+            "Endpoint can only be called by role members"
+        );
+    }
+
+    fn require_role_admin(&self, role: &ManagedBuffer, address: &ManagedAddress) {
+        let admin_role = self.get_role_admin(role);
+        require!(
+            self.has_role(&admin_role, address),
+            "Caller is not an admin of the role"
+        );
+    }
+
+    fn grant_role_unchecked(
+        &self,
+        role: &ManagedBuffer,
+        address: &ManagedAddress,
+        sender: &ManagedAddress,
+    ) {
+        if self.role_members(role).insert(address.clone()) {
+            self.role_granted_event(role, address, sender);
+        }
+    }
+
+    fn revoke_role_unchecked(
+        &self,
+        role: &ManagedBuffer,
+        address: &ManagedAddress,
+        sender: &ManagedAddress,
+    ) {
+        if self.role_members(role).swap_remove(address) {
+            self.role_revoked_event(role, address, sender);
+        }
+    }
+
+    // views
+
+    #[view(hasRole)]
+    fn has_role(&self, role: &ManagedBuffer, address: &ManagedAddress) -> bool {
+        self.role_members(role).contains(address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: &ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(role);
+        if admin_role_mapper.is_empty() {
+            ManagedBuffer::from(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    // storage
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("access_control_module:roleMembers")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control_module:roleAdmin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // events
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
@@ -0,0 +1,33 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point_math},
+    utils::structs::CurveArguments,
+};
+
+/// Price of the token at supply `x`: `initial_price * e^(x / scale)`.
+///
+/// The price grows by a factor of `e` every `scale` tokens.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct ExponentialFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for ExponentialFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        // integral is initial_price * scale * (e^((x + n) / scale) - e^(x / scale))
+        let token_end = token_start + amount;
+        let exp_end =
+            fixed_point_math::exp(&fixed_point_math::fixed_ratio(&token_end, &self.scale));
+        let exp_start =
+            fixed_point_math::exp(&fixed_point_math::fixed_ratio(token_start, &self.scale));
+        &self.initial_price * &self.scale * (exp_end - exp_start) / fixed_point_math::FP_ONE
+    }
+}
@@ -0,0 +1,100 @@
+//! Unsigned fixed-point arithmetic with 18 decimals, used by the non-polynomial curves.
+//!
+//! Only `BigUint` operations are involved, so these curves work on any VM version.
+//! Both `exp` and `ln` reduce their argument by powers of 2 and then sum a short series,
+//! the result being accurate to a few units in the last (18th) decimal.
+
+dharitri_wasm::imports!();
+
+/// `1.0` as a fixed-point number.
+pub const FP_ONE: u64 = 1_000_000_000_000_000_000;
+
+/// `ln(2)` as a fixed-point number.
+const LN_2: u64 = 693_147_180_559_945_309;
+
+/// Largest power of 2 `exp` scales its result by, i.e. arguments up to `256 * ln(2) ~ 177.4`.
+///
+/// Bounds the size of the result, which would otherwise grow exponentially with the argument.
+const MAX_EXP_SHIFT: u64 = 256;
+
+/// `e^(-x)` is below the fixed-point resolution of `1e-18` for any `x > 41.45`,
+/// so `exp_neg` returns 0 once the integer part of `x` reaches this.
+const EXP_NEG_ZERO_THRESHOLD: u64 = 42;
+
+/// The series stop earlier, as soon as the terms become 0.
+const MAX_SERIES_TERMS: u64 = 40;
+
+/// Converts an integer to fixed-point.
+pub fn to_fixed<M: ManagedTypeApi>(value: &BigUint<M>) -> BigUint<M> {
+    value * FP_ONE
+}
+
+/// `numerator / denominator` as a fixed-point number.
+pub fn fixed_ratio<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+) -> BigUint<M> {
+    &to_fixed(numerator) / denominator
+}
+
+/// `e^x`, for a fixed-point `x`.
+///
+/// Signals an error for arguments above `256 * ln(2)`.
+pub fn exp<M: ManagedTypeApi>(x: &BigUint<M>) -> BigUint<M> {
+    // x = k * ln(2) + r, 0 <= r < ln(2)
+    let k = x / LN_2;
+    let r = x - &(&k * LN_2);
+    let k = k
+        .to_u64()
+        .filter(|k| *k <= MAX_EXP_SHIFT)
+        .unwrap_or_else(|| M::error_api_impl().signal_error(b"exponential argument too large"));
+
+    let mut term = BigUint::from(FP_ONE);
+    let mut sum = BigUint::from(FP_ONE);
+    for i in 1..=MAX_SERIES_TERMS {
+        term = &(&term * &r) / FP_ONE / i;
+        if term == 0 {
+            break;
+        }
+        sum += &term;
+    }
+
+    sum << (k as usize)
+}
+
+/// `e^(-x)`, for a fixed-point `x`.
+///
+/// Large arguments yield 0, since the result is too small to represent.
+pub fn exp_neg<M: ManagedTypeApi>(x: &BigUint<M>) -> BigUint<M> {
+    if x / FP_ONE >= EXP_NEG_ZERO_THRESHOLD {
+        return BigUint::zero();
+    }
+    BigUint::from(FP_ONE) * FP_ONE / exp(x)
+}
+
+/// `ln(x)`, for a fixed-point `x >= 1`.
+pub fn ln<M: ManagedTypeApi>(x: &BigUint<M>) -> BigUint<M> {
+    let one = BigUint::from(FP_ONE);
+    if x < &one {
+        M::error_api_impl().signal_error(b"logarithm argument below 1");
+    }
+
+    // x = mantissa * 2^k, 1 <= mantissa < 2
+    let k = (x / FP_ONE).log2();
+    let mantissa = x >> (k as usize);
+
+    // ln(m) = 2 * atanh(z), z = (m - 1) / (m + 1) < 1/3
+    let z = &(&(&mantissa - &one) * FP_ONE) / &(&mantissa + &one);
+    let z_squared = &(&z * &z) / FP_ONE;
+    let mut power = z.clone();
+    let mut sum = z;
+    for i in 1..MAX_SERIES_TERMS {
+        power = &(&power * &z_squared) / FP_ONE;
+        if power == 0 {
+            break;
+        }
+        sum += &power / (2 * i + 1);
+    }
+
+    sum * 2u64 + BigUint::from(LN_2) * k as u64
+}
@@ -0,0 +1,43 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point_math},
+    utils::structs::CurveArguments,
+};
+
+/// Price of the token at supply `x`: `initial_price + coefficient * ln(1 + x / scale)`.
+///
+/// Early buyers get a steep price increase, which flattens out as the supply grows.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct LogarithmicFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub coefficient: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> LogarithmicFunction<M> {
+    /// `(1 + u) * ln(1 + u) - u`, the antiderivative of `ln(1 + u)`, in fixed-point.
+    fn antiderivative(&self, supply: &BigUint<M>) -> BigUint<M> {
+        let u = fixed_point_math::fixed_ratio(supply, &self.scale);
+        let one_plus_u = &u + fixed_point_math::FP_ONE;
+        let product = &one_plus_u * &fixed_point_math::ln(&one_plus_u) / fixed_point_math::FP_ONE;
+        product - u
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for LogarithmicFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        let token_end = token_start + amount;
+        let variable_part = &self.coefficient
+            * &self.scale
+            * (self.antiderivative(&token_end) - self.antiderivative(token_start))
+            / fixed_point_math::FP_ONE;
+        &self.initial_price * amount + variable_part
+    }
+}
@@ -1,2 +1,9 @@
 pub mod curve_function;
+pub mod exponential_function;
+pub mod fixed_point_math;
 pub mod linear_function;
+pub mod logarithmic_function;
+pub mod piecewise_linear_function;
+pub mod power_function;
+pub mod reserve_ratio_function;
+pub mod sigmoid_function;
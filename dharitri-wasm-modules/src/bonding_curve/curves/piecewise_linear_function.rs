@@ -0,0 +1,79 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, linear_function::LinearFunction},
+    utils::structs::CurveArguments,
+};
+
+/// A linear segment of a `PiecewiseLinearFunction`,
+/// applying from `start_supply` until the start of the next segment.
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone,
+)]
+pub struct LinearSegment<M: ManagedTypeApi> {
+    pub start_supply: BigUint<M>,
+    pub initial_price: BigUint<M>,
+    pub linear_coefficient: BigUint<M>,
+}
+
+/// Sequence of linear segments, sorted by `start_supply`, the first one starting at 0.
+///
+/// Within a segment, the price at supply `x` is
+/// `initial_price + linear_coefficient * (x - start_supply)`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct PiecewiseLinearFunction<M: ManagedTypeApi> {
+    pub segments: ManagedVec<M, LinearSegment<M>>,
+}
+
+impl<M: ManagedTypeApi> PiecewiseLinearFunction<M> {
+    pub fn is_valid(&self) -> bool {
+        if self.segments.is_empty() || self.segments.get(0).start_supply != 0 {
+            return false;
+        }
+        for i in 1..self.segments.len() {
+            if self.segments.get(i).start_supply <= self.segments.get(i - 1).start_supply {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PiecewiseLinearFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        let token_end = token_start + amount;
+        let mut price = BigUint::zero();
+        for (index, segment) in self.segments.iter().enumerate() {
+            if segment.start_supply >= token_end {
+                break;
+            }
+            let range_start = core::cmp::max(token_start.clone(), segment.start_supply.clone());
+            let range_end = match self.segments.try_get(index + 1) {
+                Some(next_segment) if next_segment.start_supply < token_end => {
+                    next_segment.start_supply
+                },
+                _ => token_end.clone(),
+            };
+            if range_start >= range_end {
+                continue;
+            }
+
+            let linear_function = LinearFunction {
+                initial_price: segment.initial_price,
+                linear_coefficient: segment.linear_coefficient,
+            };
+            price += linear_function.calculate_price(
+                &(&range_start - &segment.start_supply),
+                &(&range_end - &range_start),
+                arguments,
+            );
+        }
+        price
+    }
+}
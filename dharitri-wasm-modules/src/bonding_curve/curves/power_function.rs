@@ -0,0 +1,33 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// Price of the token at supply `x`: `initial_price + coefficient * x^exponent / denominator`.
+///
+/// The denominator allows for fractional coefficients,
+/// or for scaling down the supply when the token has decimals.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct PowerFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub coefficient: BigUint<M>,
+    pub exponent: u32,
+    pub denominator: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PowerFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        // integral of c * x^k from x to x + n is c * ((x + n)^(k + 1) - x^(k + 1)) / (k + 1)
+        let next_exponent = self.exponent + 1;
+        let token_end = token_start + amount;
+        let power_difference = token_end.pow(next_exponent) - token_start.pow(next_exponent);
+        let variable_part =
+            &self.coefficient * &power_difference / (&self.denominator * next_exponent as u64);
+        &self.initial_price * amount + variable_part
+    }
+}
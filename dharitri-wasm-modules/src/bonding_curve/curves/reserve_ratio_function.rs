@@ -0,0 +1,57 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point_math},
+    utils::structs::CurveArguments,
+};
+
+/// Reserve ratio denominator, the ratio is expressed in parts per million.
+pub const MAX_RESERVE_RATIO: u32 = 1_000_000;
+
+/// Bancor-style curve, keeping the payment reserve at a constant ratio of the token market cap.
+///
+/// The curve starts from a virtual supply and a virtual reserve, which set the initial price:
+/// `virtual_reserve / (virtual_supply * reserve_ratio)`.
+/// The reserve then follows `R(s) = virtual_reserve * (s / virtual_supply)^(1 / reserve_ratio)`,
+/// where `s` is the virtual supply plus the tokens sold, and the price of a trade is the reserve difference.
+/// Buying and selling the same amount therefore cancel out, which makes this curve suited for the sell side.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct ReserveRatioFunction<M: ManagedTypeApi> {
+    /// Parts per million, at most `MAX_RESERVE_RATIO`.
+    pub reserve_ratio: u32,
+    pub virtual_supply: BigUint<M>,
+    pub virtual_reserve: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> ReserveRatioFunction<M> {
+    pub fn is_valid(&self) -> bool {
+        self.reserve_ratio > 0
+            && self.reserve_ratio <= MAX_RESERVE_RATIO
+            && self.virtual_supply > 0u64
+            && self.virtual_reserve > 0u64
+    }
+
+    /// `(s / virtual_supply)^(1 / reserve_ratio)`, in fixed-point.
+    fn reserve_factor(&self, tokens_sold: &BigUint<M>) -> BigUint<M> {
+        let supply = &self.virtual_supply + tokens_sold;
+        let ratio = fixed_point_math::fixed_ratio(&supply, &self.virtual_supply);
+        let exponent =
+            fixed_point_math::ln(&ratio) * MAX_RESERVE_RATIO as u64 / self.reserve_ratio as u64;
+        fixed_point_math::exp(&exponent)
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for ReserveRatioFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        let token_end = token_start + amount;
+        &self.virtual_reserve
+            * &(self.reserve_factor(&token_end) - self.reserve_factor(token_start))
+            / fixed_point_math::FP_ONE
+    }
+}
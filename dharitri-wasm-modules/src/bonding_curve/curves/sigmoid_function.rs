@@ -0,0 +1,49 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point_math},
+    utils::structs::CurveArguments,
+};
+
+/// Price of the token at supply `x`: `max_price / (1 + e^(-(x - midpoint) / scale))`.
+///
+/// The price starts close to 0, reaches half of `max_price` at `midpoint`
+/// and then approaches `max_price`. A bigger `scale` makes the transition smoother.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct SigmoidFunction<M: ManagedTypeApi> {
+    pub max_price: BigUint<M>,
+    pub midpoint: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> SigmoidFunction<M> {
+    /// `ln(1 + e^v)`, with `v = (x - midpoint) / scale`, the antiderivative of the sigmoid, in fixed-point.
+    fn antiderivative(&self, supply: &BigUint<M>) -> BigUint<M> {
+        if supply >= &self.midpoint {
+            // ln(1 + e^v) = v + ln(1 + e^(-v))
+            let v = fixed_point_math::fixed_ratio(&(supply - &self.midpoint), &self.scale);
+            let correction =
+                fixed_point_math::ln(&(fixed_point_math::exp_neg(&v) + fixed_point_math::FP_ONE));
+            v + correction
+        } else {
+            let v = fixed_point_math::fixed_ratio(&(&self.midpoint - supply), &self.scale);
+            fixed_point_math::ln(&(fixed_point_math::exp_neg(&v) + fixed_point_math::FP_ONE))
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for SigmoidFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        let token_end = token_start + amount;
+        &self.max_price
+            * &self.scale
+            * (self.antiderivative(&token_end) - self.antiderivative(token_start))
+            / fixed_point_math::FP_ONE
+    }
+}
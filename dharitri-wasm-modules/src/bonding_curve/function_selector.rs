@@ -2,21 +2,56 @@ dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
 use crate::bonding_curve::{
-    curves::{curve_function::CurveFunction, linear_function::LinearFunction},
+    curves::{
+        curve_function::CurveFunction, exponential_function::ExponentialFunction,
+        linear_function::LinearFunction, logarithmic_function::LogarithmicFunction,
+        piecewise_linear_function::PiecewiseLinearFunction, power_function::PowerFunction,
+        reserve_ratio_function::ReserveRatioFunction, sigmoid_function::SigmoidFunction,
+    },
     utils::structs::CurveArguments,
 };
 
+/// New functions are added at the end, so that the curves already in storage keep their encoding.
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
 pub enum FunctionSelector<M: ManagedTypeApi> {
     Linear(LinearFunction<M>),
     CustomExample(BigUint<M>),
     None,
+    Power(PowerFunction<M>),
+    Exponential(ExponentialFunction<M>),
+    Logarithmic(LogarithmicFunction<M>),
+    Sigmoid(SigmoidFunction<M>),
+    PiecewiseLinear(PiecewiseLinearFunction<M>),
+    ReserveRatio(ReserveRatioFunction<M>),
 }
 
 impl<M: ManagedTypeApi> FunctionSelector<M> {
     pub fn is_none(&self) -> bool {
         matches!(self, FunctionSelector::None)
     }
+
+    /// Checks the parameters that would otherwise make every price computation fail.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            FunctionSelector::Power(power_function) => power_function.denominator > 0u64,
+            FunctionSelector::Exponential(exponential_function) => {
+                exponential_function.scale > 0u64
+            },
+            FunctionSelector::Logarithmic(logarithmic_function) => {
+                logarithmic_function.scale > 0u64
+            },
+            FunctionSelector::Sigmoid(sigmoid_function) => sigmoid_function.scale > 0u64,
+            FunctionSelector::PiecewiseLinear(piecewise_linear_function) => {
+                piecewise_linear_function.is_valid()
+            },
+            FunctionSelector::ReserveRatio(reserve_ratio_function) => {
+                reserve_ratio_function.is_valid()
+            },
+            FunctionSelector::Linear(_)
+            | FunctionSelector::CustomExample(_)
+            | FunctionSelector::None => true,
+        }
+    }
 }
 
 impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
@@ -38,6 +73,34 @@ impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
             FunctionSelector::None => {
                 M::error_api_impl().signal_error(b"Bonding Curve function is not assiged")
             },
+            FunctionSelector::Power(power_function) => {
+                CurveFunction::calculate_price(power_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Exponential(exponential_function) => {
+                CurveFunction::calculate_price(exponential_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Logarithmic(logarithmic_function) => {
+                CurveFunction::calculate_price(logarithmic_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Sigmoid(sigmoid_function) => {
+                CurveFunction::calculate_price(sigmoid_function, token_start, amount, arguments)
+            },
+            FunctionSelector::PiecewiseLinear(piecewise_linear_function) => {
+                CurveFunction::calculate_price(
+                    piecewise_linear_function,
+                    token_start,
+                    amount,
+                    arguments,
+                )
+            },
+            FunctionSelector::ReserveRatio(reserve_ratio_function) => {
+                CurveFunction::calculate_price(
+                    reserve_ratio_function,
+                    token_start,
+                    amount,
+                    arguments,
+                )
+            },
         }
     }
 }
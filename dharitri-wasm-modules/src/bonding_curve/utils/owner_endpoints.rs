@@ -5,7 +5,7 @@ use crate::bonding_curve::{
     function_selector::FunctionSelector,
     utils::{
         events, storage,
-        structs::{BondingCurve, TokenOwnershipData},
+        structs::{BondingCurve, CurveSpread, TokenOwnershipData},
     },
 };
 
@@ -48,22 +48,55 @@ pub trait OwnerEndpointsModule: storage::StorageModule + events::EventsModule {
         function: FunctionSelector<Self::Api>,
         sell_availability: bool,
     ) {
+        self.require_token_seller(&identifier);
+        require!(function.is_valid(), "Invalid curve function parameters");
+
+        self.bonding_curve(&identifier).update(|bonding_curve| {
+            bonding_curve.curve = function;
+            bonding_curve.sell_availability = sell_availability
+        });
+    }
+
+    /// Sets a different curve for selling, e.g. a reserve ratio curve.
+    /// Passing `None` reverts to selling on the buy curve.
+    #[endpoint(setSellCurve)]
+    fn set_sell_curve(&self, identifier: TokenIdentifier, function: FunctionSelector<Self::Api>) {
+        self.require_token_seller(&identifier);
+        require!(function.is_valid(), "Invalid curve function parameters");
+
+        if function.is_none() {
+            self.sell_curve(&identifier).clear();
+        } else {
+            self.sell_curve(&identifier).set(&function);
+        }
+    }
+
+    /// Buy and sell spreads, in basis points.
+    #[endpoint(setSpread)]
+    fn set_spread(&self, identifier: TokenIdentifier, buy_spread: u64, sell_spread: u64) {
+        self.require_token_seller(&identifier);
+
+        let spread = CurveSpread {
+            buy_spread,
+            sell_spread,
+        };
+        require!(spread.is_valid(), "Invalid spread");
+        self.spread(&identifier).set(&spread);
+    }
+
+    fn require_token_seller(&self, identifier: &TokenIdentifier) {
         require!(
-            !self.token_details(&identifier).is_empty(),
+            !self.token_details(identifier).is_empty(),
             "Token is not issued yet!"
         );
 
         let caller = self.blockchain().get_caller();
 
-        let details = self.token_details(&identifier).get();
+        let details = self.token_details(identifier).get();
         require!(
             details.owner == caller,
             "The price function can only be set by the seller."
         );
-        self.bonding_curve(&identifier).update(|bonding_curve| {
-            bonding_curve.curve = function;
-            bonding_curve.sell_availability = sell_availability
-        });
     }
 
     #[endpoint(deposit)]
@@ -125,6 +158,8 @@ pub trait OwnerEndpointsModule: storage::StorageModule + events::EventsModule {
             }
             self.token_details(&token).clear();
             self.bonding_curve(&token).clear();
+            self.sell_curve(&token).clear();
+            self.spread(&token).clear();
         }
         self.owned_tokens(&caller).clear();
     }
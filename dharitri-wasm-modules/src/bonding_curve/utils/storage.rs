@@ -1,7 +1,9 @@
 dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
-use super::structs::{BondingCurve, TokenOwnershipData};
+use crate::bonding_curve::function_selector::FunctionSelector;
+
+use super::structs::{BondingCurve, CurveSpread, TokenOwnershipData};
 
 #[dharitri_wasm::module]
 pub trait StorageModule {
@@ -14,6 +16,14 @@ pub trait StorageModule {
     #[storage_mapper("bonding_curve")]
     fn bonding_curve(&self, token: &TokenIdentifier) -> SingleValueMapper<BondingCurve<Self::Api>>;
 
+    /// Curve used when selling, if different from the one used when buying.
+    #[storage_mapper("sell_curve")]
+    fn sell_curve(&self, token: &TokenIdentifier)
+        -> SingleValueMapper<FunctionSelector<Self::Api>>;
+
+    #[storage_mapper("spread")]
+    fn spread(&self, token: &TokenIdentifier) -> SingleValueMapper<CurveSpread>;
+
     #[storage_mapper("owned_tokens")]
     fn owned_tokens(&self, owner: &ManagedAddress) -> SetMapper<TokenIdentifier>;
 
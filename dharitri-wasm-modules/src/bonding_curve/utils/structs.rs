@@ -24,6 +24,35 @@ pub struct BondingCurve<M: ManagedTypeApi> {
     pub payment_amount: BigUint<M>,
 }
 
+/// Basis points denominator for the spread.
+pub const MAX_SPREAD: u64 = 10_000;
+
+/// Spread applied on top of the curve price, in basis points:
+/// buyers pay `buy_spread` more, sellers receive `sell_spread` less.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Default,
+)]
+pub struct CurveSpread {
+    pub buy_spread: u64,
+    pub sell_spread: u64,
+}
+
+impl CurveSpread {
+    pub fn is_valid(&self) -> bool {
+        self.buy_spread <= MAX_SPREAD && self.sell_spread <= MAX_SPREAD
+    }
+
+    pub fn apply_to_buy_price<M: ManagedTypeApi>(&self, price: BigUint<M>) -> BigUint<M> {
+        let spread_amount = &price * self.buy_spread / MAX_SPREAD;
+        price + spread_amount
+    }
+
+    pub fn apply_to_sell_price<M: ManagedTypeApi>(&self, price: BigUint<M>) -> BigUint<M> {
+        let spread_amount = &price * self.sell_spread / MAX_SPREAD;
+        price - spread_amount
+    }
+}
+
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
 pub struct TokenOwnershipData<M: ManagedTypeApi> {
     pub token_nonces: ManagedVec<M, u64>,
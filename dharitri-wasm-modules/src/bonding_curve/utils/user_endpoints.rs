@@ -4,7 +4,10 @@ dharitri_wasm::derive_imports!();
 use crate::bonding_curve::{
     curves::curve_function::CurveFunction,
     function_selector::FunctionSelector,
-    utils::{events, storage, structs::CurveArguments},
+    utils::{
+        events, storage,
+        structs::{CurveArguments, CurveSpread},
+    },
 };
 
 #[dharitri_wasm::module]
@@ -21,6 +24,7 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
                 "Selling is not available on this token"
             );
             let price = self.compute_sell_price(
+                &offered_token,
                 &bonding_curve.curve,
                 sell_amount.clone(),
                 bonding_curve.arguments.clone(),
@@ -64,6 +68,7 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
             .bonding_curve(&requested_token)
             .update(|bonding_curve| {
                 let price = self.compute_buy_price(
+                    &requested_token,
                     &bonding_curve.curve,
                     requested_amount.clone(),
                     bonding_curve.arguments.clone(),
@@ -149,7 +154,12 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
         self.check_token_exists(&identifier);
 
         let bonding_curve = self.bonding_curve(&identifier).get();
-        self.compute_buy_price(&bonding_curve.curve, amount, bonding_curve.arguments)
+        self.compute_buy_price(
+            &identifier,
+            &bonding_curve.curve,
+            amount,
+            bonding_curve.arguments,
+        )
     }
 
     #[view]
@@ -157,7 +167,12 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
         self.check_token_exists(&identifier);
 
         let bonding_curve = self.bonding_curve(&identifier).get();
-        self.compute_sell_price(&bonding_curve.curve, amount, bonding_curve.arguments)
+        self.compute_sell_price(
+            &identifier,
+            &bonding_curve.curve,
+            amount,
+            bonding_curve.arguments,
+        )
     }
 
     fn check_token_exists(&self, issued_token: &TokenIdentifier) {
@@ -194,7 +209,7 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
         let bonding_curve = self.bonding_curve(issued_token).get();
 
         require!(
-            bonding_curve.curve.is_none(),
+            !bonding_curve.curve.is_none(),
             "The token price was not set yet!"
         );
         require!(amount > &0, "Must pay more than 0 tokens!");
@@ -215,21 +230,39 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
 
     fn compute_buy_price(
         &self,
+        identifier: &TokenIdentifier,
         function_selector: &FunctionSelector<Self::Api>,
         amount: BigUint,
         arguments: CurveArguments<Self::Api>,
     ) -> BigUint {
         let token_start = arguments.first_token_available();
-        function_selector.calculate_price(&token_start, &amount, &arguments)
+        let price = function_selector.calculate_price(&token_start, &amount, &arguments);
+        self.get_spread(identifier).apply_to_buy_price(price)
     }
 
     fn compute_sell_price(
         &self,
+        identifier: &TokenIdentifier,
         function_selector: &FunctionSelector<Self::Api>,
         amount: BigUint,
         arguments: CurveArguments<Self::Api>,
     ) -> BigUint {
         let token_start = &arguments.first_token_available() - &amount;
-        function_selector.calculate_price(&token_start, &amount, &arguments)
+        let price = if self.sell_curve(identifier).is_empty() {
+            function_selector.calculate_price(&token_start, &amount, &arguments)
+        } else {
+            self.sell_curve(identifier)
+                .get()
+                .calculate_price(&token_start, &amount, &arguments)
+        };
+        self.get_spread(identifier).apply_to_sell_price(price)
+    }
+
+    fn get_spread(&self, identifier: &TokenIdentifier) -> CurveSpread {
+        if self.spread(identifier).is_empty() {
+            CurveSpread::default()
+        } else {
+            self.spread(identifier).get()
+        }
     }
 }
@@ -1,5 +1,8 @@
 dharitri_wasm::imports!();
 
+/// The quorum percentage is expressed with 2 decimals, i.e. `10_000` is 100%.
+pub const FULL_QUORUM_PERCENTAGE: u64 = 10_000;
+
 /// # Dharitri smart contract module - Governance
 ///
 /// This is a standard smart contract module, that when added to a smart contract offers governance features:
@@ -7,11 +10,15 @@ dharitri_wasm::imports!();
 /// - voting/downvoting a certain proposal
 /// - after a voting period, either putting the action in a queue (if it reached quorum), or canceling
 ///
-/// Voting can only be done by depositing a certain token, decided upon first time setup.  
+/// Voting power comes from depositing a certain token, decided upon first time setup.
+/// It can be delegated, and is measured when the proposal is created.
 ///
 /// The module provides the following configurable parameters:  
-/// - `quorum` - the minimum number of (`votes` minus `downvotes`) at the end of voting period  
-/// - `minTokenBalanceForProposing` - Minimum numbers of tokens the proposer has to deposit. These automatically count as `votes` as well  
+/// - `quorum` - the minimum number of (`votes` plus `abstain votes`) at the end of voting period  
+/// - `quorumPercentage` - optionally, the same minimum as a share of `governanceTokenSupply`, `10_000` being 100%.
+///   The stricter of the two applies, and it is fixed when the proposal is created.  
+/// - `governanceTokenSupply` - the total supply of the governance token, the base of `quorumPercentage`  
+/// - `minTokenBalanceForProposing` - Minimum voting power needed for proposing  
 /// - `maxActionsPerProposal` - Maximum number of actions (transfers and/or smart contract calls) that a proposal may have  
 /// - `votingDelayInBlocks` - Number of blocks to wait after a block is proposed before being able to vote/downvote that proposal
 /// - `votingPeriodInBlocks` - Number of blocks the voting period lasts (voting delay does not count towards this)  
@@ -21,8 +28,10 @@ dharitri_wasm::imports!();
 /// - `proposalCreated` - triggers when a proposal is created. Also provoides all the relevant information, like proposer, actions etc.  
 /// - `voteCast` - user voted on a proposal  
 /// - `downvoteCast` - user downvoted a proposal  
+/// - `abstainVoteCast` - user abstained, only counting towards the quorum  
 /// - `proposalCanceled`, `proposalQueued` and `proposalExecuted` - provides the ID of the specific proposal  
 /// - `userDeposit` - a user deposited some tokens needed for a future payable action  
+/// - `governanceTokensDeposited`, `governanceTokensWithdrawn` and `delegateChanged` - changes in voting power  
 ///
 /// Please note that although the main contract can modify the module's storage directly, it is not recommended to do so,
 /// as that defeats the whole purpose of having governance. These parameters should only be modified through actions.
@@ -59,6 +68,17 @@ pub trait GovernanceConfigurablePropertiesModule {
         );
     }
 
+    /// Optional, on top of `init_governance_module`.
+    /// Without it, only the absolute `quorum` applies.
+    fn init_governance_quorum_percentage(
+        &self,
+        quorum_percentage: u64,
+        governance_token_supply: BigUint,
+    ) {
+        self.try_change_quorum_percentage(quorum_percentage);
+        self.governance_token_supply().set(&governance_token_supply);
+    }
+
     // endpoints - these can only be called by the SC itself.
     // i.e. only by proposing and executing an action with the SC as dest and the respective func name
 
@@ -69,6 +89,21 @@ pub trait GovernanceConfigurablePropertiesModule {
         self.try_change_quorum(new_value);
     }
 
+    #[endpoint(changeQuorumPercentage)]
+    fn change_quorum_percentage(&self, new_value: u64) {
+        self.require_caller_self();
+
+        self.try_change_quorum_percentage(new_value);
+    }
+
+    /// Needs to follow the mints and burns of the governance token, for `quorumPercentage` to stay accurate.
+    #[endpoint(changeGovernanceTokenSupply)]
+    fn change_governance_token_supply(&self, new_value: BigUint) {
+        self.require_caller_self();
+
+        self.governance_token_supply().set(&new_value);
+    }
+
     #[endpoint(changeMinTokenBalanceForProposing)]
     fn change_min_token_balance_for_proposing(&self, new_value: BigUint) {
         self.require_caller_self();
@@ -115,6 +150,25 @@ pub trait GovernanceConfigurablePropertiesModule {
         self.quorum().set(&new_value);
     }
 
+    /// 0 disables the percentage quorum.
+    fn try_change_quorum_percentage(&self, new_value: u64) {
+        require!(
+            new_value <= FULL_QUORUM_PERCENTAGE,
+            "Quorum percentage can't be over 100%"
+        );
+
+        self.quorum_percentage().set(new_value);
+    }
+
+    /// The quorum for proposals created now.
+    fn current_quorum(&self) -> BigUint {
+        let quorum = self.quorum().get();
+        let percentage_quorum = self.governance_token_supply().get()
+            * self.quorum_percentage().get()
+            / FULL_QUORUM_PERCENTAGE;
+        core::cmp::max(quorum, percentage_quorum)
+    }
+
     fn try_change_min_token_balance_for_proposing(&self, new_value: BigUint) {
         require!(
             new_value != 0,
@@ -160,6 +214,14 @@ pub trait GovernanceConfigurablePropertiesModule {
     #[storage_mapper("governance:quorum")]
     fn quorum(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getQuorumPercentage)]
+    #[storage_mapper("governance:quorumPercentage")]
+    fn quorum_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getGovernanceTokenSupply)]
+    #[storage_mapper("governance:governanceTokenSupply")]
+    fn governance_token_supply(&self) -> SingleValueMapper<BigUint>;
+
     #[view(getMinTokenBalanceForProposing)]
     #[storage_mapper("governance:minTokenBalanceForProposing")]
     fn min_token_balance_for_proposing(&self) -> SingleValueMapper<BigUint>;
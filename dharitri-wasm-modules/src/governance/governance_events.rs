@@ -30,11 +30,24 @@ pub trait GovernanceEventsModule {
         nr_downvotes: &BigUint,
     );
 
+    #[event("abstainVoteCast")]
+    fn abstain_vote_cast_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: usize,
+        nr_votes: &BigUint,
+    );
+
     #[event("proposalCanceled")]
     fn proposal_canceled_event(&self, #[indexed] proposal_id: usize);
 
     #[event("proposalQueued")]
-    fn proposal_queued_event(&self, #[indexed] proposal_id: usize, #[indexed] queued_block: u64);
+    fn proposal_queued_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        #[indexed] queued_block: u64,
+        #[indexed] execution_block: u64,
+    );
 
     #[event("proposalExecuted")]
     fn proposal_executed_event(&self, #[indexed] proposal_id: usize);
@@ -45,4 +58,26 @@ pub trait GovernanceEventsModule {
         #[indexed] address: &ManagedAddress,
         payments: &ManagedVec<DctTokenPayment<Self::Api>>,
     );
+
+    #[event("governanceTokensDeposited")]
+    fn governance_tokens_deposited_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("governanceTokensWithdrawn")]
+    fn governance_tokens_withdrawn_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("delegateChanged")]
+    fn delegate_changed_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] previous_delegatee: &ManagedAddress,
+        #[indexed] new_delegatee: &ManagedAddress,
+    );
 }
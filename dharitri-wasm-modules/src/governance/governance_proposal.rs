@@ -21,6 +21,13 @@ pub enum GovernanceProposalStatus {
     Queued,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Eq, Clone, Copy)]
+pub enum VoteType {
+    UpVote,
+    DownVote,
+    AbstainVote,
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct GovernanceAction<M: ManagedTypeApi> {
     pub gas_limit: u64,
@@ -0,0 +1,229 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use dharitri_wasm::api::StorageMapperApi;
+
+use super::{governance_configurable, governance_events};
+
+/// Voting power of an address, starting from a certain block.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct VotingPowerCheckpoint<M: ManagedTypeApi> {
+    pub block_nonce: u64,
+    pub voting_power: BigUint<M>,
+}
+
+/// Keeps track of the voting power of each address.
+///
+/// Governance tokens are deposited in the contract, and count as voting power
+/// for the depositor or for the address they delegated to.
+/// Every change is recorded as a checkpoint, so that proposals can use
+/// the voting power from the moment they were created, regardless of later transfers.
+#[dharitri_wasm::module]
+pub trait GovernanceVotingPowerModule:
+    governance_configurable::GovernanceConfigurablePropertiesModule
+    + governance_events::GovernanceEventsModule
+{
+    // endpoints
+
+    /// Deposit governance tokens, to be used as voting power.
+    /// They can be withdrawn at any time, without affecting the votes already cast.
+    #[payable("*")]
+    #[endpoint(depositGovernanceTokens)]
+    fn deposit_governance_tokens(&self) {
+        let payment = self.require_payment_token_governance_token();
+        let caller = self.blockchain().get_caller();
+
+        self.deposited_tokens(&caller)
+            .update(|deposited| *deposited += &payment.amount);
+
+        let delegatee = self.get_delegatee(&caller);
+        self.increase_voting_power(&delegatee, &payment.amount);
+        self.update_total_voting_power(|total| *total += &payment.amount);
+
+        self.governance_tokens_deposited_event(&caller, &payment.amount);
+    }
+
+    #[endpoint(withdrawDepositedTokens)]
+    fn withdraw_deposited_tokens(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let deposited_mapper = self.deposited_tokens(&caller);
+        let deposited = deposited_mapper.get();
+        require!(
+            amount > 0 && amount <= deposited,
+            "Invalid amount of governance tokens to withdraw"
+        );
+
+        deposited_mapper.set(&(deposited - &amount));
+
+        let delegatee = self.get_delegatee(&caller);
+        self.decrease_voting_power(&delegatee, &amount);
+        self.update_total_voting_power(|total| *total -= &amount);
+
+        let governance_token_id = self.governance_token_id().get();
+        self.send()
+            .direct_dct(&caller, &governance_token_id, 0, &amount);
+
+        self.governance_tokens_withdrawn_event(&caller, &amount);
+    }
+
+    /// Moves the voting power of the caller's deposit to another address.
+    /// Delegating to oneself reverts to the default behaviour.
+    #[endpoint]
+    fn delegate(&self, delegatee: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let previous_delegatee = self.get_delegatee(&caller);
+        require!(
+            previous_delegatee != delegatee,
+            "Already delegated to this address"
+        );
+
+        if delegatee == caller {
+            self.delegatee(&caller).clear();
+        } else {
+            self.delegatee(&caller).set(&delegatee);
+        }
+
+        let deposited = self.deposited_tokens(&caller).get();
+        if deposited > 0 {
+            self.decrease_voting_power(&previous_delegatee, &deposited);
+            self.increase_voting_power(&delegatee, &deposited);
+        }
+
+        self.delegate_changed_event(&caller, &previous_delegatee, &delegatee);
+    }
+
+    // views
+
+    /// The address that votes with the caller's deposit. By default, the depositor itself.
+    #[view(getDelegatee)]
+    fn get_delegatee(&self, address: &ManagedAddress) -> ManagedAddress {
+        let delegatee_mapper = self.delegatee(address);
+        if delegatee_mapper.is_empty() {
+            address.clone()
+        } else {
+            delegatee_mapper.get()
+        }
+    }
+
+    #[view(getVotingPower)]
+    fn get_voting_power(&self, address: &ManagedAddress) -> BigUint {
+        last_checkpoint_value(&self.voting_power_checkpoints(address))
+    }
+
+    /// The voting power at the start of the given block,
+    /// i.e. without any of the changes made during that block.
+    #[view(getVotingPowerAtBlock)]
+    fn get_voting_power_at_block(&self, address: &ManagedAddress, block_nonce: u64) -> BigUint {
+        checkpoint_value_at_block(&self.voting_power_checkpoints(address), block_nonce)
+    }
+
+    #[view(getTotalVotingPower)]
+    fn get_total_voting_power(&self) -> BigUint {
+        last_checkpoint_value(&self.total_voting_power_checkpoints())
+    }
+
+    #[view(getTotalVotingPowerAtBlock)]
+    fn get_total_voting_power_at_block(&self, block_nonce: u64) -> BigUint {
+        checkpoint_value_at_block(&self.total_voting_power_checkpoints(), block_nonce)
+    }
+
+    // private
+
+    fn require_payment_token_governance_token(&self) -> DctTokenPayment {
+        let payment = self.call_value().single_dct();
+        require!(
+            payment.token_identifier == self.governance_token_id().get(),
+            "Only Governance token accepted as payment"
+        );
+        payment
+    }
+
+    fn increase_voting_power(&self, address: &ManagedAddress, amount: &BigUint) {
+        let mut checkpoints = self.voting_power_checkpoints(address);
+        let new_value = last_checkpoint_value(&checkpoints) + amount;
+        self.write_checkpoint(&mut checkpoints, new_value);
+    }
+
+    fn decrease_voting_power(&self, address: &ManagedAddress, amount: &BigUint) {
+        let mut checkpoints = self.voting_power_checkpoints(address);
+        let new_value = last_checkpoint_value(&checkpoints) - amount;
+        self.write_checkpoint(&mut checkpoints, new_value);
+    }
+
+    fn update_total_voting_power<F: FnOnce(&mut BigUint)>(&self, f: F) {
+        let mut checkpoints = self.total_voting_power_checkpoints();
+        let mut new_value = last_checkpoint_value(&checkpoints);
+        f(&mut new_value);
+        self.write_checkpoint(&mut checkpoints, new_value);
+    }
+
+    /// Several changes in the same block only produce one checkpoint.
+    fn write_checkpoint(
+        &self,
+        checkpoints: &mut VecMapper<VotingPowerCheckpoint<Self::Api>>,
+        voting_power: BigUint,
+    ) {
+        let checkpoint = VotingPowerCheckpoint {
+            block_nonce: self.blockchain().get_block_nonce(),
+            voting_power,
+        };
+
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).block_nonce == checkpoint.block_nonce {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            let _ = checkpoints.push(&checkpoint);
+        }
+    }
+
+    // storage
+
+    #[view(getDepositedTokens)]
+    #[storage_mapper("governance:depositedTokens")]
+    fn deposited_tokens(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("governance:delegatee")]
+    fn delegatee(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    /// Ordered by block nonce.
+    #[storage_mapper("governance:votingPowerCheckpoints")]
+    fn voting_power_checkpoints(
+        &self,
+        address: &ManagedAddress,
+    ) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
+
+    #[storage_mapper("governance:totalVotingPowerCheckpoints")]
+    fn total_voting_power_checkpoints(&self) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
+}
+
+fn last_checkpoint_value<SA: StorageMapperApi>(
+    checkpoints: &VecMapper<SA, VotingPowerCheckpoint<SA>>,
+) -> BigUint<SA> {
+    if checkpoints.is_empty() {
+        BigUint::zero()
+    } else {
+        checkpoints.get(checkpoints.len()).voting_power
+    }
+}
+
+/// Binary search for the last checkpoint before `block_nonce`.
+fn checkpoint_value_at_block<SA: StorageMapperApi>(
+    checkpoints: &VecMapper<SA, VotingPowerCheckpoint<SA>>,
+    block_nonce: u64,
+) -> BigUint<SA> {
+    let mut result = BigUint::zero();
+    let mut low = 1;
+    let mut high = checkpoints.len();
+    while low <= high {
+        let mid = (low + high) / 2;
+        let checkpoint = checkpoints.get(mid);
+        if checkpoint.block_nonce < block_nonce {
+            result = checkpoint.voting_power;
+            low = mid + 1;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    result
+}
@@ -3,6 +3,7 @@ dharitri_wasm::imports!();
 pub mod governance_configurable;
 pub mod governance_events;
 pub mod governance_proposal;
+pub mod governance_voting_power;
 
 use governance_proposal::*;
 
@@ -12,6 +13,7 @@ const MAX_GAS_LIMIT_PER_BLOCK: u64 = 1_500_000_000;
 pub trait GovernanceModule:
     governance_configurable::GovernanceConfigurablePropertiesModule
     + governance_events::GovernanceEventsModule
+    + governance_voting_power::GovernanceVotingPowerModule
 {
     // endpoints
 
@@ -27,7 +29,9 @@ pub trait GovernanceModule:
         self.user_deposit_event(&caller, &payments);
     }
 
-    // Used to withdraw the tokens after the action was executed or cancelled
+    /// Used to withdraw the tokens locked by proposing, voting or downvoting
+    /// before voting power was introduced, once the proposal was executed or cancelled.
+    /// Deposited voting power is withdrawn through `withdrawDepositedTokens`.
     #[endpoint(withdrawGovernanceTokens)]
     fn withdraw_governance_tokens(&self, proposal_id: usize) {
         self.require_valid_proposal_id(proposal_id);
@@ -65,18 +69,21 @@ pub trait GovernanceModule:
     ///     - endpoint to be called on the destination
     ///     - a vector of arguments for the endpoint, in the form of ManagedVec<ManagedBuffer>
     ///
+    /// The proposer needs a voting power of at least `min_token_balance_for_proposing`.
+    /// The voting power of all addresses, the proposer included, is measured at the start of the proposal creation block.
+    ///
     /// Returns the ID of the newly created proposal.
-    #[payable("*")]
     #[endpoint]
     fn propose(
         &self,
         description: ManagedBuffer,
         actions: MultiValueEncoded<GovernanceActionAsMultiArg<Self::Api>>,
     ) -> usize {
-        let payment = self.require_payment_token_governance_token();
-
+        let proposer = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
         require!(
-            payment.amount >= self.min_token_balance_for_proposing().get(),
+            self.get_voting_power_at_block(&proposer, current_block)
+                >= self.min_token_balance_for_proposing().get(),
             "Not enough tokens for proposing action"
         );
         require!(!actions.is_empty(), "Proposal has no actions");
@@ -109,8 +116,6 @@ pub trait GovernanceModule:
             "Actions require too much gas to be executed"
         );
 
-        let proposer = self.blockchain().get_caller();
-        let current_block = self.blockchain().get_block_nonce();
         let proposal_id = self.proposals().len() + 1;
 
         self.proposal_created_event(
@@ -122,8 +127,7 @@ pub trait GovernanceModule:
         );
 
         self.proposal_start_block(proposal_id).set(&current_block);
-        self.total_votes(proposal_id).set(&payment.amount);
-        self.votes(proposal_id, &proposer).set(&payment.amount);
+        self.proposal_quorum(proposal_id).set(self.current_quorum());
 
         let proposal = GovernanceProposal {
             proposer,
@@ -135,52 +139,31 @@ pub trait GovernanceModule:
         proposal_id
     }
 
-    /// Vote on a proposal by depositing any amount of governance tokens
-    /// These tokens will be locked until the proposal is executed or cancelled.
-    #[payable("*")]
+    /// Vote on a proposal, with the voting power from the proposal creation block.
     #[endpoint]
     fn vote(&self, proposal_id: usize) {
-        let payment = self.require_payment_token_governance_token();
-        self.require_valid_proposal_id(proposal_id);
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
-
-        let voter = self.blockchain().get_caller();
-        self.vote_cast_event(&voter, proposal_id, &payment.amount);
-
-        self.total_votes(proposal_id)
-            .update(|total_votes| *total_votes += &payment.amount);
-        self.votes(proposal_id, &voter)
-            .update(|nr_votes| *nr_votes += &payment.amount);
+        self.cast_vote(proposal_id, VoteType::UpVote);
     }
 
-    /// Downvote a proposal by depositing any amount of governance tokens.
-    /// These tokens will be locked until the proposal is executed or cancelled.
-    #[payable("*")]
+    /// Vote against a proposal, with the voting power from the proposal creation block.
     #[endpoint]
     fn downvote(&self, proposal_id: usize) {
-        let payment = self.require_payment_token_governance_token();
-        self.require_valid_proposal_id(proposal_id);
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
-
-        let downvoter = self.blockchain().get_caller();
-        self.downvote_cast_event(&downvoter, proposal_id, &payment.amount);
+        self.cast_vote(proposal_id, VoteType::DownVote);
+    }
 
-        self.total_downvotes(proposal_id)
-            .update(|total_downvotes| *total_downvotes += &payment.amount);
-        self.downvotes(proposal_id, &downvoter)
-            .update(|nr_downvotes| *nr_downvotes += &payment.amount);
+    /// Counts towards the quorum, but neither for nor against the proposal.
+    #[endpoint]
+    fn abstain(&self, proposal_id: usize) {
+        self.cast_vote(proposal_id, VoteType::AbstainVote);
     }
 
     /// Queue a proposal for execution.
     /// This can be done only if the proposal has reached the quorum.
     /// A proposal is considered successful and ready for queing if
-    /// total_votes - total_downvotes >= quorum
+    /// total_votes + total_abstain_votes >= quorum, and total_votes > total_downvotes.
+    ///
+    /// The proposal can be executed after `lock_time_after_voting_ends_in_blocks`.
+    /// Later changes to that parameter do not affect queued proposals.
     #[endpoint]
     fn queue(&self, proposal_id: usize) {
         require!(
@@ -189,14 +172,17 @@ pub trait GovernanceModule:
         );
 
         let current_block = self.blockchain().get_block_nonce();
+        let lock_blocks = self.lock_time_after_voting_ends_in_blocks().get();
+        let execution_block = current_block + lock_blocks;
         self.proposal_queue_block(proposal_id).set(&current_block);
+        self.proposal_execution_block(proposal_id)
+            .set(execution_block);
 
-        self.proposal_queued_event(proposal_id, current_block);
+        self.proposal_queued_event(proposal_id, current_block, execution_block);
     }
 
     /// Execute a previously queued proposal.
-    /// This will clear the proposal and unlock the governance tokens.
-    /// Said tokens can then be withdrawn and used to vote/downvote other proposals.
+    /// This will clear the proposal.
     #[endpoint]
     fn execute(&self, proposal_id: usize) {
         require!(
@@ -205,13 +191,10 @@ pub trait GovernanceModule:
         );
 
         let current_block = self.blockchain().get_block_nonce();
-        let lock_blocks = self.lock_time_after_voting_ends_in_blocks().get();
-
-        let lock_start = self.proposal_queue_block(proposal_id).get();
-        let lock_end = lock_start + lock_blocks;
+        let execution_block = self.get_proposal_execution_block(proposal_id);
 
         require!(
-            current_block >= lock_end,
+            current_block >= execution_block,
             "Proposal is in timelock status. Try again later"
         );
 
@@ -247,7 +230,7 @@ pub trait GovernanceModule:
     }
 
     /// Cancel a proposed action. This can be done:
-    /// - by the proposer, at any time
+    /// - by the proposer, at any time before execution
     /// - by anyone, if the proposal was defeated
     #[endpoint]
     fn cancel(&self, proposal_id: usize) {
@@ -255,19 +238,16 @@ pub trait GovernanceModule:
             GovernanceProposalStatus::None => {
                 sc_panic!("Proposal does not exist");
             },
-            GovernanceProposalStatus::Pending => {
+            GovernanceProposalStatus::Defeated => {},
+            _ => {
                 let proposal = self.proposals().get(proposal_id);
                 let caller = self.blockchain().get_caller();
 
                 require!(
                     caller == proposal.proposer,
-                    "Only original proposer may cancel a pending proposal"
+                    "Only original proposer may cancel a proposal before it is defeated"
                 );
             },
-            GovernanceProposalStatus::Defeated => {},
-            _ => {
-                sc_panic!("Action may not be cancelled");
-            },
         }
 
         self.clear_proposal(proposal_id);
@@ -305,15 +285,32 @@ pub trait GovernanceModule:
 
         let total_votes = self.total_votes(proposal_id).get();
         let total_downvotes = self.total_downvotes(proposal_id).get();
-        let quorum = self.quorum().get();
+        let total_abstain_votes = self.total_abstain_votes(proposal_id).get();
+        let quorum = self.get_proposal_quorum(proposal_id);
 
-        if total_votes > total_downvotes && total_votes - total_downvotes >= quorum {
+        if &total_votes + &total_abstain_votes >= quorum && total_votes > total_downvotes {
             GovernanceProposalStatus::Succeeded
         } else {
             GovernanceProposalStatus::Defeated
         }
     }
 
+    /// Minimum number of votes plus abstain votes needed for the proposal to pass,
+    /// as set when the proposal was created.
+    #[view(getProposalQuorum)]
+    fn get_proposal_quorum(&self, proposal_id: usize) -> BigUint {
+        if !self.proposal_exists(proposal_id) {
+            return BigUint::zero();
+        }
+
+        let proposal_quorum_mapper = self.proposal_quorum(proposal_id);
+        if proposal_quorum_mapper.is_empty() {
+            self.quorum().get()
+        } else {
+            proposal_quorum_mapper.get()
+        }
+    }
+
     #[view(getProposer)]
     fn get_proposer(&self, proposal_id: usize) -> OptionalValue<ManagedAddress> {
         if !self.proposal_exists(proposal_id) {
@@ -353,13 +350,50 @@ pub trait GovernanceModule:
 
     // private
 
-    fn require_payment_token_governance_token(&self) -> DctTokenPayment {
-        let payment = self.call_value().single_dct();
+    /// Proposals queued before the execution block was stored use the current lock time.
+    fn get_proposal_execution_block(&self, proposal_id: usize) -> u64 {
+        let execution_block_mapper = self.proposal_execution_block(proposal_id);
+        if execution_block_mapper.is_empty() {
+            self.proposal_queue_block(proposal_id).get()
+                + self.lock_time_after_voting_ends_in_blocks().get()
+        } else {
+            execution_block_mapper.get()
+        }
+    }
+
+    fn cast_vote(&self, proposal_id: usize, vote_type: VoteType) {
+        self.require_valid_proposal_id(proposal_id);
         require!(
-            payment.token_identifier == self.governance_token_id().get(),
-            "Only Governance token accepted as payment"
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            "Proposal is not active"
         );
-        payment
+
+        let voter = self.blockchain().get_caller();
+        let has_voted_mapper = self.has_voted(proposal_id, &voter);
+        require!(!has_voted_mapper.get(), "Already voted for this proposal");
+
+        let proposal_block = self.proposal_start_block(proposal_id).get();
+        let voting_power = self.get_voting_power_at_block(&voter, proposal_block);
+        require!(voting_power > 0, "No voting power");
+
+        has_voted_mapper.set(true);
+        match vote_type {
+            VoteType::UpVote => {
+                self.total_votes(proposal_id)
+                    .update(|total_votes| *total_votes += &voting_power);
+                self.vote_cast_event(&voter, proposal_id, &voting_power);
+            },
+            VoteType::DownVote => {
+                self.total_downvotes(proposal_id)
+                    .update(|total_downvotes| *total_downvotes += &voting_power);
+                self.downvote_cast_event(&voter, proposal_id, &voting_power);
+            },
+            VoteType::AbstainVote => {
+                self.total_abstain_votes(proposal_id)
+                    .update(|total_abstain_votes| *total_abstain_votes += &voting_power);
+                self.abstain_vote_cast_event(&voter, proposal_id, &voting_power);
+            },
+        }
     }
 
     fn require_valid_proposal_id(&self, proposal_id: usize) {
@@ -389,15 +423,20 @@ pub trait GovernanceModule:
         total
     }
 
-    /// specific votes/downvotes are not cleared,
-    /// as they're used for reclaim tokens logic and cleared one by one
+    /// The `has_voted` flags are not cleared, as they can be arbitrarily many.
+    /// Proposal IDs are never reused, so they do not get in the way.
+    /// Specific votes/downvotes are not cleared either,
+    /// as they're used for reclaim tokens logic and cleared one by one.
     fn clear_proposal(&self, proposal_id: usize) {
         self.proposals().clear_entry(proposal_id);
         self.proposal_start_block(proposal_id).clear();
         self.proposal_queue_block(proposal_id).clear();
+        self.proposal_execution_block(proposal_id).clear();
+        self.proposal_quorum(proposal_id).clear();
 
         self.total_votes(proposal_id).clear();
         self.total_downvotes(proposal_id).clear();
+        self.total_abstain_votes(proposal_id).clear();
     }
 
     // storage - general
@@ -412,9 +451,15 @@ pub trait GovernanceModule:
     #[storage_mapper("governance:proposalQueueBlock")]
     fn proposal_queue_block(&self, proposal_id: usize) -> SingleValueMapper<u64>;
 
+    #[view(getProposalExecutionBlock)]
+    #[storage_mapper("governance:proposalExecutionBlock")]
+    fn proposal_execution_block(&self, proposal_id: usize) -> SingleValueMapper<u64>;
+
+    /// Tokens locked by voting before voting power was introduced.
     #[storage_mapper("governance:votes")]
     fn votes(&self, proposal_id: usize, voter: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Tokens locked by downvoting before voting power was introduced.
     #[storage_mapper("governance:downvotes")]
     fn downvotes(
         &self,
@@ -422,6 +467,14 @@ pub trait GovernanceModule:
         downvoter: &ManagedAddress,
     ) -> SingleValueMapper<BigUint>;
 
+    /// Missing for proposals created before the quorum was fixed at creation.
+    #[storage_mapper("governance:proposalQuorum")]
+    fn proposal_quorum(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
+    #[view(hasVoted)]
+    #[storage_mapper("governance:hasVoted")]
+    fn has_voted(&self, proposal_id: usize, voter: &ManagedAddress) -> SingleValueMapper<bool>;
+
     #[view(getTotalVotes)]
     #[storage_mapper("governance:totalVotes")]
     fn total_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
@@ -429,4 +482,8 @@ pub trait GovernanceModule:
     #[view(getTotalDownvotes)]
     #[storage_mapper("governance:totalDownvotes")]
     fn total_downvotes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalAbstainVotes)]
+    #[storage_mapper("governance:totalAbstainVotes")]
+    fn total_abstain_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
 }
@@ -1,12 +1,14 @@
 #![no_std]
 
+pub mod access_control;
+pub mod dct;
 pub mod default_issue_callbacks;
 pub mod dns;
-pub mod dct;
 pub mod features;
 pub mod pause;
 pub mod staking;
 pub mod transfer_role_proxy;
+pub mod vesting;
 
 // TODO: remove alloc feature from the following, after they have been cleaned
 
@@ -0,0 +1,214 @@
+dharitri_wasm::imports!();
+
+pub mod vesting_events;
+pub mod vesting_schedule;
+
+use vesting_schedule::{VestingGrant, VestingSchedule};
+
+static INVALID_GRANT_ID_ERR_MSG: &[u8] = b"Invalid grant ID";
+
+/// Standard smart contract module that, when added to a smart contract, offers token vesting.
+///
+/// The owner locks MOAX or any DCT for a beneficiary, following one of the schedules
+/// in `VestingSchedule`. The beneficiary claims the unlocked part whenever they want.
+///
+/// It offers:
+/// * endpoints where the owner creates linear, cliff and periodic grants
+/// * an endpoint where the owner revokes a revocable grant, getting back the part not yet vested
+/// * an endpoint where the beneficiary claims the vested tokens
+/// * views for the vested and claimable amounts at any timestamp
+///
+#[dharitri_wasm::module]
+pub trait VestingModule: vesting_events::VestingEventsModule {
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createLinearGrant)]
+    fn create_linear_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        start_timestamp: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> u64 {
+        let payment = self.call_value().moax_or_single_dct();
+        let schedule = VestingSchedule::Linear {
+            start_timestamp,
+            duration,
+        };
+        self.create_grant(beneficiary, payment, schedule, revocable)
+    }
+
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createCliffGrant)]
+    fn create_cliff_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> u64 {
+        let payment = self.call_value().moax_or_single_dct();
+        let schedule = VestingSchedule::Cliff {
+            start_timestamp,
+            cliff_duration,
+            duration,
+        };
+        self.create_grant(beneficiary, payment, schedule, revocable)
+    }
+
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createPeriodicGrant)]
+    fn create_periodic_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        start_timestamp: u64,
+        period: u64,
+        nr_periods: u64,
+        revocable: bool,
+    ) -> u64 {
+        let payment = self.call_value().moax_or_single_dct();
+        let schedule = VestingSchedule::Periodic {
+            start_timestamp,
+            period,
+            nr_periods,
+        };
+        self.create_grant(beneficiary, payment, schedule, revocable)
+    }
+
+    /// Stops the vesting of a revocable grant.
+    /// What was vested so far can still be claimed, the rest is sent back to the caller.
+    #[only_owner]
+    #[endpoint(revokeGrant)]
+    fn revoke_grant(&self, grant_id: u64) -> BigUint {
+        let grant_mapper = self.grant(grant_id);
+        require!(!grant_mapper.is_empty(), INVALID_GRANT_ID_ERR_MSG);
+
+        let mut grant = grant_mapper.get();
+        require!(grant.revocable, "Grant is not revocable");
+        require!(!grant.is_revoked(), "Grant already revoked");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let returned_amount = &grant.total_amount - &grant.vested_amount(current_timestamp);
+        grant.revoked_timestamp = Some(current_timestamp);
+        grant_mapper.set(&grant);
+
+        if returned_amount > 0 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct(
+                &caller,
+                &grant.token_identifier,
+                grant.token_nonce,
+                &returned_amount,
+            );
+        }
+
+        self.grant_revoked_event(grant_id, &grant.beneficiary, &returned_amount);
+
+        returned_amount
+    }
+
+    /// Sends the beneficiary everything vested and not yet claimed from the grant.
+    #[endpoint]
+    fn claim(&self, grant_id: u64) -> BigUint {
+        let grant_mapper = self.grant(grant_id);
+        require!(!grant_mapper.is_empty(), INVALID_GRANT_ID_ERR_MSG);
+
+        let mut grant = grant_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == grant.beneficiary,
+            "Only the beneficiary can claim"
+        );
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let claimable_amount = grant.claimable_amount(current_timestamp);
+        require!(claimable_amount > 0, "Nothing to claim");
+
+        grant.claimed_amount += &claimable_amount;
+        grant_mapper.set(&grant);
+
+        self.send().direct(
+            &caller,
+            &grant.token_identifier,
+            grant.token_nonce,
+            &claimable_amount,
+        );
+
+        self.vested_tokens_claimed_event(grant_id, &caller, &claimable_amount);
+
+        claimable_amount
+    }
+
+    /// Locks `payment` for `beneficiary`. Can be called directly by contracts
+    /// that create grants from their own logic, using tokens they already hold.
+    fn create_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        payment: MoaxOrDctTokenPayment<Self::Api>,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> u64 {
+        require!(payment.amount > 0, "Cannot create an empty grant");
+        require!(schedule.is_valid(), "Invalid vesting schedule");
+
+        let grant_id = self.last_grant_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let grant = VestingGrant {
+            beneficiary,
+            token_identifier: payment.token_identifier,
+            token_nonce: payment.token_nonce,
+            total_amount: payment.amount,
+            claimed_amount: BigUint::zero(),
+            schedule,
+            revocable,
+            revoked_timestamp: None,
+        };
+
+        self.grant(grant_id).set(&grant);
+        let _ = self
+            .beneficiary_grant_ids(&grant.beneficiary)
+            .insert(grant_id);
+
+        self.grant_created_event(grant_id, &grant.beneficiary, &grant);
+
+        grant_id
+    }
+
+    // views
+
+    #[view(getGrant)]
+    fn get_grant(&self, grant_id: u64) -> VestingGrant<Self::Api> {
+        let grant_mapper = self.grant(grant_id);
+        require!(!grant_mapper.is_empty(), INVALID_GRANT_ID_ERR_MSG);
+
+        grant_mapper.get()
+    }
+
+    #[view(getVestedAmount)]
+    fn get_vested_amount(&self, grant_id: u64, timestamp: u64) -> BigUint {
+        self.get_grant(grant_id).vested_amount(timestamp)
+    }
+
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, grant_id: u64, timestamp: u64) -> BigUint {
+        self.get_grant(grant_id).claimable_amount(timestamp)
+    }
+
+    // storage
+
+    #[view(getLastGrantId)]
+    #[storage_mapper("vesting_module:lastGrantId")]
+    fn last_grant_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("vesting_module:grant")]
+    fn grant(&self, grant_id: u64) -> SingleValueMapper<VestingGrant<Self::Api>>;
+
+    #[view(getBeneficiaryGrantIds)]
+    #[storage_mapper("vesting_module:beneficiaryGrantIds")]
+    fn beneficiary_grant_ids(&self, beneficiary: &ManagedAddress) -> UnorderedSetMapper<u64>;
+}
@@ -0,0 +1,30 @@
+dharitri_wasm::imports!();
+
+use super::vesting_schedule::VestingGrant;
+
+#[dharitri_wasm::module]
+pub trait VestingEventsModule {
+    #[event("grantCreated")]
+    fn grant_created_event(
+        &self,
+        #[indexed] grant_id: u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        grant: &VestingGrant<Self::Api>,
+    );
+
+    #[event("vestedTokensClaimed")]
+    fn vested_tokens_claimed_event(
+        &self,
+        #[indexed] grant_id: u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("grantRevoked")]
+    fn grant_revoked_event(
+        &self,
+        #[indexed] grant_id: u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        returned_amount: &BigUint,
+    );
+}
@@ -0,0 +1,166 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+/// Describes how the granted amount unlocks over time.
+///
+/// All timestamps are in seconds, as returned by `get_block_timestamp`.
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug,
+)]
+pub enum VestingSchedule {
+    /// Unlocks continuously, from `start_timestamp` to `start_timestamp + duration`.
+    Linear { start_timestamp: u64, duration: u64 },
+
+    /// Nothing unlocks before `start_timestamp + cliff_duration`.
+    /// After the cliff, everything accrued linearly since `start_timestamp` becomes available,
+    /// the rest following linearly until `start_timestamp + duration`.
+    Cliff {
+        start_timestamp: u64,
+        cliff_duration: u64,
+        duration: u64,
+    },
+
+    /// Unlocks `1 / nr_periods` of the total at the end of each period.
+    Periodic {
+        start_timestamp: u64,
+        period: u64,
+        nr_periods: u64,
+    },
+}
+
+impl VestingSchedule {
+    /// Rejects schedules that would never unlock or would divide by zero.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            VestingSchedule::Linear { duration, .. } => *duration > 0,
+            VestingSchedule::Cliff {
+                cliff_duration,
+                duration,
+                ..
+            } => *duration > 0 && cliff_duration <= duration,
+            VestingSchedule::Periodic {
+                period, nr_periods, ..
+            } => *period > 0 && *nr_periods > 0,
+        }
+    }
+
+    /// The timestamp from which the whole amount is unlocked.
+    pub fn end_timestamp(&self) -> u64 {
+        match self {
+            VestingSchedule::Linear {
+                start_timestamp,
+                duration,
+            }
+            | VestingSchedule::Cliff {
+                start_timestamp,
+                duration,
+                ..
+            } => start_timestamp.saturating_add(*duration),
+            VestingSchedule::Periodic {
+                start_timestamp,
+                period,
+                nr_periods,
+            } => start_timestamp.saturating_add(period.saturating_mul(*nr_periods)),
+        }
+    }
+
+    /// How much of `total_amount` is unlocked at `timestamp`, rounded down.
+    pub fn vested_amount<M: ManagedTypeApi>(
+        &self,
+        total_amount: &BigUint<M>,
+        timestamp: u64,
+    ) -> BigUint<M> {
+        if timestamp >= self.end_timestamp() {
+            return total_amount.clone();
+        }
+
+        match self {
+            VestingSchedule::Linear {
+                start_timestamp,
+                duration,
+            } => linear_amount(total_amount, *start_timestamp, *duration, timestamp),
+            VestingSchedule::Cliff {
+                start_timestamp,
+                cliff_duration,
+                duration,
+            } => {
+                if timestamp < start_timestamp.saturating_add(*cliff_duration) {
+                    BigUint::zero()
+                } else {
+                    linear_amount(total_amount, *start_timestamp, *duration, timestamp)
+                }
+            },
+            VestingSchedule::Periodic {
+                start_timestamp,
+                period,
+                nr_periods,
+            } => {
+                if timestamp < *start_timestamp {
+                    return BigUint::zero();
+                }
+
+                let elapsed_periods = (timestamp - start_timestamp) / period;
+                total_amount * elapsed_periods / *nr_periods
+            },
+        }
+    }
+}
+
+fn linear_amount<M: ManagedTypeApi>(
+    total_amount: &BigUint<M>,
+    start_timestamp: u64,
+    duration: u64,
+    timestamp: u64,
+) -> BigUint<M> {
+    if timestamp <= start_timestamp {
+        return BigUint::zero();
+    }
+
+    let elapsed = timestamp - start_timestamp;
+    total_amount * elapsed / duration
+}
+
+/// Tokens locked for a beneficiary.
+///
+/// The funds are deposited when the grant is created,
+/// so claiming never depends on the contract balance being topped up.
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug,
+)]
+pub struct VestingGrant<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub token_identifier: MoaxOrDctTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub schedule: VestingSchedule,
+    pub revocable: bool,
+    /// Vesting stops at this timestamp if the grant was revoked.
+    pub revoked_timestamp: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> VestingGrant<M> {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_timestamp.is_some()
+    }
+
+    /// Amount unlocked at `timestamp`, including what was already claimed.
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        let effective_timestamp = match self.revoked_timestamp {
+            Some(revoked_timestamp) => core::cmp::min(timestamp, revoked_timestamp),
+            None => timestamp,
+        };
+        self.schedule
+            .vested_amount(&self.total_amount, effective_timestamp)
+    }
+
+    /// Amount that the beneficiary can withdraw at `timestamp`.
+    pub fn claimable_amount(&self, timestamp: u64) -> BigUint<M> {
+        let vested_amount = self.vested_amount(timestamp);
+        if vested_amount > self.claimed_amount {
+            vested_amount - &self.claimed_amount
+        } else {
+            BigUint::zero()
+        }
+    }
+}
@@ -28,6 +28,12 @@ extern "C" {
     #[cfg(not(feature = "ei-unmanaged-node"))]
     fn managedCaller(resultHandle: i32);
 
+    fn getOriginalCallerAddr(resultOffset: *mut u8);
+    #[cfg(not(feature = "ei-unmanaged-node"))]
+    fn managedGetOriginalCallerAddr(resultHandle: i32);
+
+    fn getCallDepth() -> i32;
+
     fn getShardOfAddress(address_ptr: *const u8) -> i32;
     fn isSmartContract(address_ptr: *const u8) -> i32;
 
@@ -203,6 +209,28 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn get_original_caller_legacy(&self) -> Address {
+        unsafe {
+            let mut res = Address::zero();
+            getOriginalCallerAddr(res.as_mut_ptr());
+            res
+        }
+    }
+
+    #[inline]
+    #[cfg(not(feature = "ei-unmanaged-node"))]
+    fn load_original_caller_managed(&self, dest: Handle) {
+        unsafe {
+            managedGetOriginalCallerAddr(dest);
+        }
+    }
+
+    #[inline]
+    fn get_call_depth(&self) -> u32 {
+        unsafe { getCallDepth() as u32 }
+    }
+
     #[inline]
     fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
         unsafe { getShardOfAddress(address.as_ref().as_ptr()) as u32 }
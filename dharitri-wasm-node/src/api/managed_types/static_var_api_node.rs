@@ -11,6 +11,7 @@ static mut NEXT_HANDLE: i32 = const_handles::NEW_HANDLE_START_FROM;
 static mut NUM_ARGUMENTS: i32 = 0;
 static mut CALL_VALUE_MOAX_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
 static mut CALL_VALUE_MULTI_DCT_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
+static mut NON_REENTRANT_LOCK_HELD: bool = false;
 
 // The compiler seems to enjoy inlining this method no matter how many times it shows up.
 // Hence the rather drastic directive.
@@ -78,4 +79,14 @@ impl StaticVarApiImpl for VmApiImpl {
     fn get_call_value_multi_dct_handle(&self) -> Handle {
         unsafe { CALL_VALUE_MULTI_DCT_HANDLE }
     }
+
+    fn set_non_reentrant_lock_held(&self, held: bool) {
+        unsafe {
+            NON_REENTRANT_LOCK_HELD = held;
+        }
+    }
+
+    fn is_non_reentrant_lock_held(&self) -> bool {
+        unsafe { NON_REENTRANT_LOCK_HELD }
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
 use alloc::vec::Vec;
 use dharitri_wasm::{
     api::{const_handles, ManagedTypeApi, SendApi, SendApiImpl, StaticVarApiImpl},
+    io::endpoint_guards::release_non_reentrant_before_async_call,
     types::{
         heap::{Address, ArgBuffer, BoxedBytes},
         managed_vec_from_slice_of_boxed_bytes, BigUint, CodeMetadata, DctTokenPayment,
@@ -524,6 +525,7 @@ impl SendApiImpl for VmApiImpl {
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> ! {
+        release_non_reentrant_before_async_call::<VmApiImpl>();
         unsafe {
             managedAsyncCall(
                 to.get_raw_handle(),
@@ -541,6 +543,7 @@ impl SendApiImpl for VmApiImpl {
         endpoint_name: &BoxedBytes,
         arg_buffer: &ArgBuffer,
     ) -> ! {
+        release_non_reentrant_before_async_call::<VmApiImpl>();
         unsafe {
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
             let call_data =
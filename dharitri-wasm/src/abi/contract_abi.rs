@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::ContractError;
 use alloc::{string::String, vec::Vec};
 
 #[derive(Debug, Default, Clone)]
@@ -7,9 +8,19 @@ pub struct ContractAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub constructors: Vec<EndpointAbi>,
+
+    /// Called instead of the constructor on upgrade, see `#[upgrade]`.
+    pub upgrade_constructors: Vec<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+
+    /// All errors that endpoints can return, see `ContractError`.
+    pub errors: Vec<ErrorAbi>,
+    pub storage: Vec<StorageAbi>,
     pub has_callback: bool,
+
+    /// The storage schema version reached after all `#[migration]` steps, 0 if there are none.
+    pub schema_version: u32,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
 
@@ -17,29 +28,22 @@ impl ContractAbi {
     pub fn coalesce(&mut self, other: Self) {
         self.constructors
             .extend_from_slice(other.constructors.as_slice());
+        self.upgrade_constructors
+            .extend_from_slice(other.upgrade_constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
+        self.events.extend_from_slice(other.events.as_slice());
+        for error_abi in other.errors {
+            self.add_error_abi(error_abi);
+        }
+        self.storage.extend_from_slice(other.storage.as_slice());
         self.has_callback |= other.has_callback;
+        self.schema_version = self.schema_version.max(other.schema_version);
         self.type_descriptions.insert_all(&other.type_descriptions);
     }
 
     #[must_use]
     pub fn main_contract(&self) -> ContractAbi {
-        ContractAbi {
-            build_info: self.build_info.clone(),
-            docs: self.docs,
-            name: self.name,
-            constructors: self.constructors.clone(),
-            endpoints: self
-                .endpoints
-                .clone()
-                .iter()
-                .filter(|endpoint| endpoint.location == EndpointLocationAbi::MainContract)
-                .cloned()
-                .collect(),
-            events: self.events.clone(),
-            has_callback: self.has_callback,
-            type_descriptions: self.type_descriptions.clone(),
-        }
+        self.select_endpoints(EndpointLocationAbi::MainContract, |_| true)
     }
 
     pub fn location_exists(&self, location: EndpointLocationAbi) -> bool {
@@ -51,20 +55,43 @@ impl ContractAbi {
 
     #[must_use]
     pub fn secondary_contract(&self, location: EndpointLocationAbi) -> ContractAbi {
+        self.select_endpoints(location, |_| true)
+    }
+
+    /// Keeps the endpoints at the given location that also pass the filter.
+    ///
+    /// Only contracts in the main location get the constructor and the callback.
+    #[must_use]
+    pub fn select_endpoints<F>(&self, location: EndpointLocationAbi, filter: F) -> ContractAbi
+    where
+        F: Fn(&EndpointAbi) -> bool,
+    {
+        let is_main = location == EndpointLocationAbi::MainContract;
         ContractAbi {
             build_info: self.build_info.clone(),
             docs: self.docs,
             name: self.name,
-            constructors: Vec::new(),
+            constructors: if is_main {
+                self.constructors.clone()
+            } else {
+                Vec::new()
+            },
+            upgrade_constructors: if is_main {
+                self.upgrade_constructors.clone()
+            } else {
+                Vec::new()
+            },
             endpoints: self
                 .endpoints
-                .clone()
                 .iter()
-                .filter(|endpoint| endpoint.location == location)
+                .filter(|endpoint| endpoint.location == location && filter(endpoint))
                 .cloned()
                 .collect(),
             events: self.events.clone(),
-            has_callback: false,
+            errors: self.errors.clone(),
+            storage: self.storage.clone(),
+            has_callback: is_main && self.has_callback,
+            schema_version: self.schema_version,
             type_descriptions: self.type_descriptions.clone(),
         }
     }
@@ -75,6 +102,19 @@ impl ContractAbi {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
 
+    /// Errors declared by the same type are only added once, even if several endpoints return it.
+    pub fn add_errors<E: ContractError>(&mut self) {
+        for error_abi in E::error_abis() {
+            self.add_error_abi(error_abi);
+        }
+    }
+
+    fn add_error_abi(&mut self, error_abi: ErrorAbi) {
+        if !self.errors.contains(&error_abi) {
+            self.errors.push(error_abi);
+        }
+    }
+
     /// Contract main crate name.
     pub fn get_crate_name(&self) -> &str {
         self.build_info.contract_crate.name
@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::ContractError;
 use alloc::vec::Vec;
 
 #[derive(Clone, Debug)]
@@ -35,11 +36,20 @@ pub struct EndpointAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub only_owner: bool,
+    pub only_role: Option<&'static str>,
+    /// Set via `#[tag("...")]`, used for selecting endpoints in multi-output builds.
+    pub tags: &'static [&'static str],
+    /// Checks performed before the endpoint body, e.g. `require_not_paused` or `only_whitelisted(mapper)`.
+    pub guards: &'static [&'static str],
+    /// Name of the trait (contract or module) that declares the endpoint.
+    pub module: &'static str,
     pub mutability: EndpointMutabilityAbi,
     pub location: EndpointLocationAbi,
     pub payable_in_tokens: &'static [&'static str],
     pub inputs: Vec<InputAbi>,
     pub outputs: OutputAbis,
+    /// Codes of the errors the endpoint can return, described in the contract ABI.
+    pub errors: Vec<u32>,
 }
 
 impl EndpointAbi {
@@ -55,4 +65,12 @@ impl EndpointAbi {
         self.outputs
             .extend_from_slice(T::output_abis(output_names).as_slice());
     }
+
+    pub fn add_errors<E: ContractError>(&mut self) {
+        for error_abi in E::error_abis() {
+            if !self.errors.contains(&error_abi.code) {
+                self.errors.push(error_abi.code);
+            }
+        }
+    }
 }
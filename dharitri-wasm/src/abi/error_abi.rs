@@ -0,0 +1,12 @@
+/// Describes one of the errors an endpoint can return, see `ContractError`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorAbi {
+    pub docs: &'static [&'static str],
+    /// Name of the error type, usually an enum.
+    pub error_type: &'static str,
+    pub code: u32,
+    /// Name of the variant.
+    pub name: &'static str,
+    /// The message template, as declared, i.e. with `{}` placeholders for the variant fields.
+    pub message: &'static str,
+}
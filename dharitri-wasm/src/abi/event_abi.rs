@@ -6,12 +6,16 @@ pub struct EventInputAbi {
     pub arg_name: &'static str,
     pub type_name: TypeName,
     pub indexed: bool,
+    /// The event version that introduced the argument, only set for arguments added after the first version.
+    pub since: Option<u8>,
 }
 
 #[derive(Clone, Debug)]
 pub struct EventAbi {
     pub docs: &'static [&'static str],
     pub identifier: &'static str,
+    /// Set via `#[event("identifier", version = N)]`.
+    pub version: Option<u8>,
     pub inputs: Vec<EventInputAbi>,
 }
 
@@ -21,6 +25,25 @@ impl EventAbi {
             arg_name,
             type_name: T::type_name(),
             indexed,
+            since: None,
         });
     }
+
+    /// Non-indexed argument, added in a later version of the event, via `#[since(N)]`.
+    pub fn add_data_input_since<T: TypeAbi>(&mut self, arg_name: &'static str, since: u8) {
+        self.inputs.push(EventInputAbi {
+            arg_name,
+            type_name: T::type_name(),
+            indexed: false,
+            since: Some(since),
+        });
+    }
+
+    /// Events with several non-indexed arguments, as well as versioned events,
+    /// encode their data as a list of length-prefixed arguments, see `InteractorEventData`.
+    ///
+    /// Otherwise, the data is just the top-encoded non-indexed argument, if there is one.
+    pub fn has_structured_data(&self) -> bool {
+        self.version.is_some() || self.inputs.iter().filter(|input| !input.indexed).count() > 1
+    }
 }
@@ -0,0 +1,53 @@
+use super::*;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StorageKindAbi {
+    /// `#[storage_get]`
+    Get,
+    /// `#[storage_set]`
+    Set,
+    /// `#[storage_mapper]`
+    Mapper,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes a storage declaration, so that contract state can be read directly by key.
+///
+/// Key and value types are described like endpoint inputs, via `TypeAbi`.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+    /// Name of the storage method.
+    pub name: &'static str,
+    /// The base key. Key arguments are appended to it, nested-encoded.
+    pub key: &'static str,
+    pub kind: StorageKindAbi,
+    /// Mapper type name, e.g. `SingleValueMapper`. Only for `#[storage_mapper]`.
+    pub mapper: Option<&'static str>,
+    pub key_args: Vec<StorageKeyArgAbi>,
+    /// Value type for `#[storage_get]` and `#[storage_set]`,
+    /// the mapper type arguments for `#[storage_mapper]`, comma-separated.
+    pub value_type: TypeName,
+}
+
+impl StorageAbi {
+    pub fn add_key_arg<T: TypeAbi>(&mut self, arg_name: &'static str) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name,
+            type_name: T::type_name(),
+        });
+    }
+
+    pub fn add_value_type<T: TypeAbi>(&mut self) {
+        if !self.value_type.is_empty() {
+            self.value_type.push_str(", ");
+        }
+        self.value_type.push_str(T::type_name().as_str());
+    }
+}
@@ -4,6 +4,9 @@ use alloc::{string::String, vec::Vec};
 pub struct TypeDescription {
     pub docs: &'static [&'static str],
     pub name: String,
+
+    /// The latest encoding version of a struct, declared with `#[codec(version = N)]`.
+    pub version: Option<u8>,
     pub contents: TypeContents,
 }
 
@@ -15,6 +18,7 @@ impl TypeDescription {
     pub const PLACEHOLDER: TypeDescription = TypeDescription {
         docs: &[],
         name: String::new(),
+        version: None,
         contents: TypeContents::NotSpecified,
     };
 }
@@ -45,4 +49,7 @@ pub struct StructFieldDescription {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub field_type: String,
+
+    /// The struct version that added the field, `None` for the fields of the first version.
+    pub since: Option<u8>,
 }
@@ -35,6 +35,17 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
         self.mb_overwrite(dest, self.get_owner_address_legacy().as_bytes())
     }
 
+    /// The sender of the transaction that started the current call stack.
+    fn get_original_caller_legacy(&self) -> Address;
+
+    fn load_original_caller_managed(&self, dest: Handle) {
+        self.mb_overwrite(dest, self.get_original_caller_legacy().as_bytes())
+    }
+
+    /// The number of synchronous calls the current execution is nested in.
+    /// It is 0 for the contract called directly by the transaction.
+    fn get_call_depth(&self) -> u32;
+
     fn get_shard_of_address_legacy(&self, address: &Address) -> u32;
 
     fn get_shard_of_address(&self, address_handle: Handle) -> u32 {
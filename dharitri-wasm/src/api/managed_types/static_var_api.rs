@@ -31,4 +31,10 @@ pub trait StaticVarApiImpl {
     fn set_call_value_multi_dct_handle(&self, handle: Handle);
 
     fn get_call_value_multi_dct_handle(&self) -> Handle;
+
+    /// Set while the current execution holds the `#[non_reentrant]` lock,
+    /// so that it can be released before an async call ends the execution.
+    fn set_non_reentrant_lock_held(&self, held: bool);
+
+    fn is_non_reentrant_lock_held(&self) -> bool;
 }
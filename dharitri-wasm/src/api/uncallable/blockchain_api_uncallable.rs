@@ -27,6 +27,14 @@ impl BlockchainApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn get_original_caller_legacy(&self) -> Address {
+        unreachable!()
+    }
+
+    fn get_call_depth(&self) -> u32 {
+        unreachable!()
+    }
+
     fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
         unreachable!()
     }
@@ -56,4 +56,12 @@ impl StaticVarApiImpl for UncallableApi {
     fn get_call_value_multi_dct_handle(&self) -> Handle {
         unreachable!()
     }
+
+    fn set_non_reentrant_lock_held(&self, _held: bool) {
+        unreachable!()
+    }
+
+    fn is_non_reentrant_lock_held(&self) -> bool {
+        unreachable!()
+    }
 }
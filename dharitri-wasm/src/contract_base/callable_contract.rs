@@ -6,6 +6,12 @@ use crate::api::VMApi;
 pub trait CallableContract {
     fn call(&self, fn_name: &[u8]) -> bool;
 
+    /// Whether the contract has an `#[upgrade]` method, exported as `upgrade`.
+    /// The debugger calls it on upgrade instead of `init`, when present.
+    fn has_upgrade_constructor(&self) -> bool {
+        false
+    }
+
     fn clone_obj(&self) -> Box<dyn CallableContract>;
 }
 
@@ -0,0 +1,31 @@
+use crate::{
+    api::VMApi,
+    types::{heap::Address, ManagedAddress, ManagedOption},
+};
+
+/// Base of the generated `Interactor` objects, the off-chain counterparts of the contract proxies.
+///
+/// The target address is kept as a heap `Address`, so the interactor is not tied to any API context.
+pub trait InteractorObjBase {
+    type Api: VMApi;
+
+    #[doc(hidden)]
+    fn new_interactor_obj() -> Self;
+
+    /// Specify the target contract to call.
+    /// For deploys, only taken into account when building upgrade transactions.
+    #[must_use]
+    fn contract(self, address: Address) -> Self;
+
+    /// Converts the address contained in the interactor to a managed address.
+    ///
+    /// Will just return `ManagedOption::none()` if no address was specified.
+    #[doc(hidden)]
+    fn extract_opt_address(&mut self) -> ManagedOption<Self::Api, ManagedAddress<Self::Api>>;
+
+    /// Converts the address contained in the interactor to a managed address.
+    ///
+    /// Will crash if no address was specified.
+    #[doc(hidden)]
+    fn extract_address(&mut self) -> ManagedAddress<Self::Api>;
+}
@@ -1,6 +1,8 @@
 mod callable_contract;
 mod contract_abi_provider;
 mod contract_base_trait;
+#[cfg(not(target_arch = "wasm32"))]
+mod interactor_obj_base;
 mod proxy_obj_base;
 mod proxy_obj_callback_base;
 mod wrappers;
@@ -8,6 +10,8 @@ mod wrappers;
 pub use callable_contract::{CallableContract, CallableContractBuilder};
 pub use contract_abi_provider::ContractAbiProvider;
 pub use contract_base_trait::ContractBase;
+#[cfg(not(target_arch = "wasm32"))]
+pub use interactor_obj_base::InteractorObjBase;
 pub use proxy_obj_base::ProxyObjBase;
 pub use proxy_obj_callback_base::CallbackProxyObjBase;
 pub use wrappers::*;
@@ -75,6 +75,34 @@ where
         ManagedAddress::from_raw_handle(handle)
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn get_original_caller_legacy(&self) -> crate::types::Address {
+        A::blockchain_api_impl().get_original_caller_legacy()
+    }
+
+    /// The sender of the transaction, as opposed to `get_caller`,
+    /// which gives the contract that called this one when the call is nested.
+    #[inline]
+    pub fn get_original_caller(&self) -> ManagedAddress<A> {
+        let handle = A::static_var_api_impl().next_handle();
+        A::blockchain_api_impl().load_original_caller_managed(handle);
+        ManagedAddress::from_raw_handle(handle)
+    }
+
+    /// The number of synchronous calls (`execute_on_dest_context`, `execute_on_same_context`, etc.)
+    /// the current execution is nested in. 0 when the contract was called directly by the transaction.
+    #[inline]
+    pub fn get_call_depth(&self) -> u32 {
+        A::blockchain_api_impl().get_call_depth()
+    }
+
+    /// True when the contract was called by another contract, within the same transaction.
+    #[inline]
+    pub fn is_nested_call(&self) -> bool {
+        self.get_call_depth() > 0
+    }
+
     pub fn check_caller_is_owner(&self) {
         if self.get_owner_address() != self.get_caller() {
             A::error_api_impl().signal_error(b"Endpoint can only be called by owner");
@@ -3,10 +3,19 @@ pub const NON_PAYABLE_FUNC_DCT: &str = "function does not accept DCT payment";
 pub const BAD_TOKEN_PROVIDED: &str = "bad call value token provided";
 pub const SINGLE_DCT_EXPECTED: &str = "function expects single DCT payment";
 pub const TOO_MANY_DCT_TRANSFERS: &str = "too many DCT transfers";
+pub const DCT_PAYMENT_EXPECTED: &str = "function expects DCT payment";
+pub const DUPLICATE_DCT_TOKEN: &str = "same DCT token provided more than once";
 pub const DCT_INVALID_TOKEN_INDEX: &str = "invalid token index";
 pub const INCORRECT_NUM_DCT_TRANSFERS: &str = "incorrect number of DCT transfers";
+pub const ZERO_PAYMENT: &str = "payment amount must not be zero";
 pub static FUNGIBLE_TOKEN_EXPECTED_ERR_MSG: &str = "fungible DCT token expected";
 
+pub const CONTRACT_PAUSED: &str = "contract is paused";
+pub const CALLER_NOT_WHITELISTED: &str = "caller is not whitelisted";
+pub const DEADLINE_NOT_SET: &str = "deadline not set";
+pub const DEADLINE_PASSED: &str = "deadline has passed";
+pub const REENTRANT_CALL: &str = "reentrant call";
+
 pub const ARG_WRONG_NUMBER: &str = "wrong number of arguments";
 pub const ARG_ASYNC_WRONG_NUMBER: &[u8] = b"wrong number of arguments provided to async call";
 pub const ARG_ASYNC_RETURN_WRONG_NUMBER: &[u8] =
@@ -40,6 +49,7 @@ pub const EXPONENT_IS_POSITIVE: &[u8] = b"exponent must be negative";
 pub const NUMBER_IS_NOT_NORMAL: &[u8] =
     b"number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &[u8] = b"values are not comparable";
+pub const BIG_FLOAT_EXP_OVERFLOW: &[u8] = b"big float exponential out of range";
 
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
 pub const DESERIALIZATION_NOT_32_BYTES: &str =
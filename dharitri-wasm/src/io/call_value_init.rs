@@ -1,11 +1,15 @@
 use crate::{
     api::{
         const_handles, CallValueApi, CallValueApiImpl, ErrorApi, ErrorApiImpl, ManagedBufferApi,
-        ManagedTypeApi,
+        ManagedTypeApi, StorageMapperApi,
     },
     contract_base::CallValueWrapper,
     err_msg,
-    types::{BigUint, MoaxOrDctTokenIdentifier, DctTokenPayment, ManagedType, ManagedVec},
+    storage::mappers::AcceptedPaymentTokens,
+    types::{
+        BigUint, DctTokenPayment, ManagedBuffer, ManagedType, ManagedVec, MoaxOrDctTokenIdentifier,
+        TokenIdentifier,
+    },
 };
 
 /// Called initially in the generated code whenever no payable annotation is provided.
@@ -56,6 +60,80 @@ where
     }
 }
 
+/// Called initially in the generated code whenever a list of tokens is provided,
+/// e.g. `#[payable("TOKEN-A", "TOKEN-B")]`.
+///
+/// "MOAX" in the list means MOAX payments are also accepted.
+pub fn payable_token_list<A>(accepted_tokens: &[&str], max_transfers: usize, distinct_tokens: bool)
+where
+    A: CallValueApi + ManagedTypeApi + ErrorApi,
+{
+    let accepts_moax = accepted_tokens
+        .iter()
+        .any(|token| token.as_bytes() == MoaxOrDctTokenIdentifier::<A>::MOAX_REPRESENTATION);
+    check_payment_tokens::<A, _>(
+        accepts_moax,
+        |token_identifier| {
+            accepted_tokens
+                .iter()
+                .any(|token| token_identifier.as_managed_buffer() == &ManagedBuffer::from(*token))
+        },
+        max_transfers,
+        distinct_tokens,
+    );
+}
+
+/// Called initially in the generated code whenever `#[payable(storage = "<mapper>")]` annotation is provided.
+pub fn payable_storage_tokens<A, M>(
+    accepted_tokens: &M,
+    max_transfers: usize,
+    distinct_tokens: bool,
+) where
+    A: CallValueApi + StorageMapperApi,
+    M: AcceptedPaymentTokens<A>,
+{
+    check_payment_tokens::<A, _>(
+        accepted_tokens.accepts_moax(),
+        |token_identifier| accepted_tokens.accepts_dct(token_identifier),
+        max_transfers,
+        distinct_tokens,
+    );
+}
+
+fn check_payment_tokens<A, F>(
+    accepts_moax: bool,
+    accepts_dct: F,
+    max_transfers: usize,
+    distinct_tokens: bool,
+) where
+    A: CallValueApi + ManagedTypeApi + ErrorApi,
+    F: Fn(&TokenIdentifier<A>) -> bool,
+{
+    let transfers = CallValueWrapper::<A>::new().all_dct_transfers();
+    if transfers.is_empty() {
+        if !accepts_moax {
+            A::error_api_impl().signal_error(err_msg::DCT_PAYMENT_EXPECTED.as_bytes());
+        }
+        return;
+    }
+    if transfers.len() > max_transfers {
+        A::error_api_impl().signal_error(err_msg::TOO_MANY_DCT_TRANSFERS.as_bytes());
+    }
+    for (index, transfer) in transfers.iter().enumerate() {
+        if !accepts_dct(&transfer.token_identifier) {
+            A::error_api_impl().signal_error(err_msg::BAD_TOKEN_PROVIDED.as_bytes());
+        }
+        if distinct_tokens
+            && transfers
+                .iter()
+                .take(index)
+                .any(|previous| previous.token_identifier == transfer.token_identifier)
+        {
+            A::error_api_impl().signal_error(err_msg::DUPLICATE_DCT_TOKEN.as_bytes());
+        }
+    }
+}
+
 /// Initializes an argument annotated with `#[payment_amount]` or `#[payment]`.
 pub fn arg_payment_amount<A>() -> BigUint<A>
 where
@@ -0,0 +1,112 @@
+use crate::{
+    api::{
+        BlockchainApi, BlockchainApiImpl, CallValueApi, ErrorApi, ErrorApiImpl, ManagedTypeApi,
+        StaticVarApiImpl, StorageMapperApi,
+    },
+    contract_base::CallValueWrapper,
+    err_msg,
+    storage::{storage_clear, storage_get_len, storage_set, StorageKey},
+    types::ManagedType,
+};
+
+/// Storage key of the flag set while a `#[non_reentrant]` endpoint is executing.
+/// It is shared by all the `#[non_reentrant]` endpoints of the contract.
+/// Reserved, contracts and modules cannot declare storage under the same key.
+pub const REENTRANCY_LOCK_KEY: &str = "__reentrancy_lock__";
+
+/// Called in the generated code whenever the `#[require_not_paused]` annotation is provided.
+///
+/// The flag comes from the `PauseModule`, which the contract needs to extend.
+pub fn require_not_paused<A>(paused: bool)
+where
+    A: ErrorApi,
+{
+    if paused {
+        A::error_api_impl().signal_error(err_msg::CONTRACT_PAUSED.as_bytes());
+    }
+}
+
+/// Called in the generated code whenever the `#[only_whitelisted(mapper)]` annotation is provided.
+pub fn require_caller_whitelisted<A>(whitelisted: bool)
+where
+    A: ErrorApi,
+{
+    if !whitelisted {
+        A::error_api_impl().signal_error(err_msg::CALLER_NOT_WHITELISTED.as_bytes());
+    }
+}
+
+/// Called in the generated code whenever the `#[nonzero_payment]` annotation is provided.
+///
+/// Requires either a non-zero MOAX payment, or at least one DCT transfer, all with non-zero amounts.
+pub fn require_nonzero_payment<A>()
+where
+    A: CallValueApi + ManagedTypeApi + ErrorApi,
+{
+    let call_value = CallValueWrapper::<A>::new();
+    let transfers = call_value.all_dct_transfers();
+    let nonzero = if transfers.is_empty() {
+        call_value.moax_value() > 0u32
+    } else {
+        transfers.iter().all(|transfer| transfer.amount > 0u32)
+    };
+    if !nonzero {
+        A::error_api_impl().signal_error(err_msg::ZERO_PAYMENT.as_bytes());
+    }
+}
+
+/// Called in the generated code whenever the `#[before_timestamp(storage)]` annotation is provided.
+///
+/// A deadline of 0 is the same as empty storage, so it means the deadline was never set.
+pub fn require_before_timestamp<A>(deadline: u64)
+where
+    A: BlockchainApi + ErrorApi,
+{
+    if deadline == 0 {
+        A::error_api_impl().signal_error(err_msg::DEADLINE_NOT_SET.as_bytes());
+    }
+    if A::blockchain_api_impl().get_block_timestamp() >= deadline {
+        A::error_api_impl().signal_error(err_msg::DEADLINE_PASSED.as_bytes());
+    }
+}
+
+/// Called in the generated code at the start of `#[non_reentrant]` endpoints, after all other checks.
+///
+/// Fails if another `#[non_reentrant]` endpoint of the same contract is still executing further down the call stack,
+/// e.g. when a contract it called synchronously calls back into it.
+pub fn enter_non_reentrant<A>()
+where
+    A: StorageMapperApi,
+{
+    let lock_key = StorageKey::<A>::new(REENTRANCY_LOCK_KEY.as_bytes());
+    if storage_get_len(lock_key.as_ref()) > 0 {
+        A::error_api_impl().signal_error(err_msg::REENTRANT_CALL.as_bytes());
+    }
+    storage_set(lock_key.as_ref(), &true);
+    A::static_var_api_impl().set_non_reentrant_lock_held(true);
+}
+
+/// Called in the generated code once the body of a `#[non_reentrant]` endpoint has finished.
+///
+/// There is no need to release the lock when the endpoint fails, since the storage changes get reverted anyway.
+pub fn exit_non_reentrant<A>()
+where
+    A: StorageMapperApi,
+{
+    storage_clear(StorageKey::<A>::new(REENTRANCY_LOCK_KEY.as_bytes()).as_ref());
+    A::static_var_api_impl().set_non_reentrant_lock_held(false);
+}
+
+/// Called by the VM APIs right before an async call, which ends the execution without returning,
+/// so the exit code of a `#[non_reentrant]` endpoint never gets to run.
+///
+/// Only releases the lock if the current execution took it.
+/// An unguarded endpoint called back while the lock is held must leave it alone.
+pub fn release_non_reentrant_before_async_call<A>()
+where
+    A: StorageMapperApi,
+{
+    if A::static_var_api_impl().is_non_reentrant_lock_held() {
+        exit_non_reentrant::<A>();
+    }
+}
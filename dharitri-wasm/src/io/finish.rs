@@ -23,6 +23,23 @@ where
     let Ok(()) = item.multi_encode_or_handle_err(&mut output, h);
 }
 
+/// Implemented by all types that endpoints can return, which are exactly the `TopEncodeMulti` types.
+///
+/// The contract macros require it on endpoint result types, at the span of the result type,
+/// so invalid result types get reported where they are declared.
+pub trait EndpointResult: TopEncodeMulti {}
+
+impl<T> EndpointResult for T where T: TopEncodeMulti {}
+
+/// Finishes the result of an endpoint, called from the generated endpoint wrappers.
+pub fn finish_endpoint_result<FA, T>(item: &T)
+where
+    FA: ManagedTypeApi + EndpointFinishApi,
+    T: EndpointResult,
+{
+    finish_multi::<FA, T>(item);
+}
+
 #[derive(Clone)]
 pub struct ApiOutputAdapter<FA>
 where
@@ -6,6 +6,7 @@ mod arg_loader_single;
 mod arg_nested_tuple;
 mod bytes_arg_loader;
 pub mod call_value_init;
+pub mod endpoint_guards;
 mod finish;
 mod managed_result_arg_loader;
 mod signal_error;
@@ -1,4 +1,4 @@
-use dharitri_codec::{TopEncode, TopEncodeMulti};
+use dharitri_codec::{NestedEncode, TopEncode, TopEncodeMulti};
 
 use crate::{
     api::{ErrorApi, LogApi, LogApiImpl, ManagedTypeApi},
@@ -40,6 +40,33 @@ where
     data_buffer
 }
 
+/// Starts the data of an event with structured data, see `InteractorEventData`.
+///
+/// Versioned events start with the version byte.
+pub fn event_data_accumulator<A>(version: Option<u8>) -> ManagedBuffer<A>
+where
+    A: ErrorApi + ManagedTypeApi,
+{
+    let mut accumulator = ManagedBuffer::new();
+    if let Some(version) = version {
+        accumulator.append_bytes(&[version]);
+    }
+    accumulator
+}
+
+/// Appends an argument to the data of an event with structured data: top-encoded, with a length prefix.
+pub fn serialize_event_data_field<A, T>(accumulator: &mut ManagedBuffer<A>, field: T)
+where
+    A: ErrorApi + ManagedTypeApi,
+    T: TopEncode,
+{
+    let field_buffer = serialize_log_data::<T, A>(field);
+    let Ok(()) = field_buffer.dep_encode_or_handle_err(
+        accumulator,
+        ExitCodecErrorHandler::<A>::from(err_msg::LOG_DATA_ENCODE_ERROR),
+    );
+}
+
 pub fn write_log<A>(topics: &ManagedVec<A, ManagedBuffer<A>>, data: &ManagedBuffer<A>)
 where
     A: LogApi + ManagedTypeApi,
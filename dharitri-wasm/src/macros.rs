@@ -26,7 +26,7 @@ macro_rules! imports {
             non_zero_usize,
             non_zero_util::*,
             require, require_old, sc_error, sc_format, sc_panic, sc_print,
-            storage::mappers::*,
+            storage::{mappers::*, migration::MigrationCursor},
             types::{
                 SCResult::{Err, Ok},
                 *,
@@ -40,7 +40,7 @@ macro_rules! imports {
 macro_rules! derive_imports {
     () => {
         use dharitri_wasm::{
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{ContractError, ManagedVecItem, TypeAbi},
             dharitri_codec,
             dharitri_codec::dharitri_codec_derive::{
                 NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
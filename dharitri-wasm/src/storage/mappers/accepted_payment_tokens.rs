@@ -0,0 +1,66 @@
+use super::{SetMapper, SingleValueMapper, UnorderedSetMapper, WhitelistMapper};
+use crate::{
+    api::StorageMapperApi,
+    types::{MoaxOrDctTokenIdentifier, TokenIdentifier},
+};
+
+/// Storage mappers that can hold the tokens accepted by an endpoint marked `#[payable(storage = "...")]`.
+pub trait AcceptedPaymentTokens<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_moax(&self) -> bool {
+        false
+    }
+
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool;
+}
+
+impl<SA> AcceptedPaymentTokens<SA> for SingleValueMapper<SA, TokenIdentifier<SA>>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool {
+        !self.is_empty() && &self.get() == token_identifier
+    }
+}
+
+impl<SA> AcceptedPaymentTokens<SA> for SingleValueMapper<SA, MoaxOrDctTokenIdentifier<SA>>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_moax(&self) -> bool {
+        !self.is_empty() && self.get().is_moax()
+    }
+
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool {
+        !self.is_empty() && &self.get() == token_identifier
+    }
+}
+
+impl<SA> AcceptedPaymentTokens<SA> for SetMapper<SA, TokenIdentifier<SA>>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool {
+        self.contains(token_identifier)
+    }
+}
+
+impl<SA> AcceptedPaymentTokens<SA> for UnorderedSetMapper<SA, TokenIdentifier<SA>>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool {
+        self.contains(token_identifier)
+    }
+}
+
+impl<SA> AcceptedPaymentTokens<SA> for WhitelistMapper<SA, TokenIdentifier<SA>>
+where
+    SA: StorageMapperApi,
+{
+    fn accepts_dct(&self, token_identifier: &TokenIdentifier<SA>) -> bool {
+        self.contains(token_identifier)
+    }
+}
@@ -74,7 +74,7 @@ where
         storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
     }
 
-    fn set_mapped_value(&self, key: &K, value: &V) {
+    pub(crate) fn set_mapped_value(&self, key: &K, value: &V) {
         storage_set(
             self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
             &value,
@@ -149,6 +149,12 @@ where
         self.keys_set.iter()
     }
 
+    /// An iterator visiting the keys in the same order as `keys`, starting at the given key.
+    /// It is empty if the key is not in the map.
+    pub fn keys_from(&self, k: &K) -> Keys<'_, SA, K> {
+        self.keys_set.iter_from(k)
+    }
+
     /// An iterator visiting all values in arbitrary order.
     /// The iterator element type is `&'a V`.
     pub fn values(&self) -> Values<SA, K, V> {
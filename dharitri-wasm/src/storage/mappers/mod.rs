@@ -1,3 +1,4 @@
+mod accepted_payment_tokens;
 mod bi_di_mapper;
 mod fungible_token_mapper;
 mod linked_list_mapper;
@@ -16,6 +17,7 @@ mod user_mapper;
 mod vec_mapper;
 mod whitelist_mapper;
 
+pub use accepted_payment_tokens::AcceptedPaymentTokens;
 pub use bi_di_mapper::BiDiMapper;
 pub use fungible_token_mapper::FungibleTokenMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
@@ -332,6 +332,14 @@ where
         Iter::new(self)
     }
 
+    /// Provides a forward iterator, starting from the node with the given id.
+    pub(crate) fn iter_from_node_id(&self, node_id: u32) -> Iter<'_, SA, T> {
+        Iter {
+            node_id,
+            queue: self,
+        }
+    }
+
     /// Runs several checks in order to verify that both forwards and backwards iteration
     /// yields the same node entries and that the number of items in the queue is correct.
     /// Used for unit testing.
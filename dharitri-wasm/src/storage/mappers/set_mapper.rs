@@ -144,6 +144,12 @@ where
         self.queue_mapper.iter()
     }
 
+    /// An iterator starting at the given value and visiting the rest of the set in the same order as `iter`.
+    /// It is empty if the value is not in the set.
+    pub fn iter_from(&self, value: &T) -> Iter<'_, SA, T> {
+        self.queue_mapper.iter_from_node_id(self.get_node_id(value))
+    }
+
     /// Checks the internal consistency of the collection. Used for unit tests.
     pub fn check_internal_consistency(&self) -> bool {
         self.queue_mapper.check_internal_consistency()
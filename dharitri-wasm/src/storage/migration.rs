@@ -0,0 +1,208 @@
+use crate::{
+    api::{BlockchainApi, BlockchainApiImpl, StorageMapperApi},
+    storage::{
+        mappers::{MapMapper, VecMapper},
+        storage_clear, storage_get, storage_get_len, storage_set, StorageKey,
+    },
+    types::{ManagedType, OperationCompletionStatus},
+};
+use dharitri_codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
+
+/// Storage key of the schema version, i.e. the version of the last migration step that completed.
+pub static SCHEMA_VERSION_KEY: &[u8] = b"__schema_version__";
+
+/// Storage key of the position reached by a migration step that was interrupted, so it can be resumed.
+pub static MIGRATION_CURSOR_KEY: &[u8] = b"__migration_cursor__";
+
+/// Migration steps stop processing items when the gas left falls below this limit, unless configured otherwise.
+pub const DEFAULT_MIGRATION_GAS_RESERVE: u64 = 5_000_000;
+
+/// The version of the last migration step that completed. 0 if no migration ever ran.
+pub fn schema_version<A>() -> u32
+where
+    A: StorageMapperApi,
+{
+    let key = StorageKey::<A>::new(SCHEMA_VERSION_KEY);
+    if storage_get_len(key.as_ref()) == 0 {
+        return 0;
+    }
+    u32::from_be_bytes(storage_get(key.as_ref()))
+}
+
+/// Always stored on 4 bytes, so that version 0 can be told apart from a contract that was never deployed.
+fn set_schema_version<A>(version: u32)
+where
+    A: StorageMapperApi,
+{
+    storage_set(
+        StorageKey::<A>::new(SCHEMA_VERSION_KEY).as_ref(),
+        &version.to_be_bytes(),
+    );
+}
+
+/// Called in the generated code, after the constructor of contracts that declare migrations or an `#[upgrade]` method.
+///
+/// A freshly deployed contract starts with the latest storage layout, so there is nothing to migrate.
+pub fn init_schema_version<A>(latest_version: u32)
+where
+    A: StorageMapperApi,
+{
+    set_schema_version::<A>(latest_version);
+}
+
+/// Called in the generated code to decide whether `init` was called on deploy or on upgrade.
+///
+/// The schema version is written on deploy, so it can only be missing when the contract was never deployed,
+/// or when it was deployed by a version of the contract that did not declare migrations or an `#[upgrade]` method.
+/// Such contracts get upgraded through the `upgrade` endpoint, where the missing schema version reads as 0,
+/// so all migration steps run.
+pub fn is_deployed<A>() -> bool
+where
+    A: StorageMapperApi,
+{
+    storage_get_len(StorageKey::<A>::new(SCHEMA_VERSION_KEY).as_ref()) > 0
+}
+
+/// Called in the generated `run_migrations` method, once a migration step has completed.
+pub fn complete_migration_step<A>(version: u32)
+where
+    A: StorageMapperApi,
+{
+    set_schema_version::<A>(version);
+    storage_clear(StorageKey::<A>::new(MIGRATION_CURSOR_KEY).as_ref());
+}
+
+/// Passed to the `#[migration]` steps that need to process large collections over several calls.
+///
+/// It keeps track of the position reached in the collection, and of the limits of the current call:
+/// the gas reserve and, optionally, the number of items that can be processed in one call.
+/// A step can migrate a single collection with the cursor, larger migrations should be split into several steps.
+/// The collection should not otherwise change while a migration step is in progress.
+pub struct MigrationCursor<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    cursor_key: StorageKey<A>,
+    gas_reserve: u64,
+    max_items: Option<usize>,
+    processed_items: usize,
+}
+
+impl<A> Default for MigrationCursor<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    fn default() -> Self {
+        MigrationCursor {
+            cursor_key: StorageKey::new(MIGRATION_CURSOR_KEY),
+            gas_reserve: DEFAULT_MIGRATION_GAS_RESERVE,
+            max_items: None,
+            processed_items: 0,
+        }
+    }
+}
+
+impl<A> MigrationCursor<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processing stops when the gas left falls below this limit.
+    /// It needs to cover processing one more item, as well as saving the cursor.
+    pub fn set_gas_reserve(&mut self, gas_reserve: u64) {
+        self.gas_reserve = gas_reserve;
+    }
+
+    /// Limits the number of items processed in one call, across all migration steps.
+    pub fn set_max_items(&mut self, max_items: usize) {
+        self.max_items = Some(max_items);
+    }
+
+    /// The number of items processed so far in the current call.
+    pub fn processed_items(&self) -> usize {
+        self.processed_items
+    }
+
+    /// Checks both the item limit and the gas left.
+    pub fn can_process_item(&self) -> bool {
+        if let Some(max_items) = self.max_items {
+            if self.processed_items >= max_items {
+                return false;
+            }
+        }
+        A::blockchain_api_impl().get_gas_left() >= self.gas_reserve
+    }
+
+    fn load_position<T: TopDecode>(&self) -> Option<T> {
+        if storage_get_len(self.cursor_key.as_ref()) == 0 {
+            None
+        } else {
+            Some(storage_get(self.cursor_key.as_ref()))
+        }
+    }
+
+    fn save_position<T: TopEncode>(&self, position: &T) {
+        storage_set(self.cursor_key.as_ref(), position);
+    }
+
+    /// Rewrites all items of a `VecMapper`, resuming from where the previous call stopped.
+    ///
+    /// Items are decoded with the current item type, so older encodings need to be readable by it,
+    /// e.g. by using `#[codec(version = ...)]`.
+    pub fn migrate_vec_mapper<T, F>(
+        &mut self,
+        mapper: &VecMapper<A, T>,
+        mut migrate_item: F,
+    ) -> OperationCompletionStatus
+    where
+        T: TopEncode + TopDecode,
+        F: FnMut(T) -> T,
+    {
+        let mut index: usize = self.load_position().unwrap_or(1);
+        while index <= mapper.len() {
+            if !self.can_process_item() {
+                self.save_position(&index);
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+            let item = migrate_item(mapper.get(index));
+            mapper.set(index, &item);
+            self.processed_items += 1;
+            index += 1;
+        }
+        OperationCompletionStatus::Completed
+    }
+
+    /// Rewrites all values of a `MapMapper`, resuming from where the previous call stopped.
+    ///
+    /// Values are decoded with the current value type, so older encodings need to be readable by it.
+    pub fn migrate_map_mapper<K, V, F>(
+        &mut self,
+        mapper: &MapMapper<A, K, V>,
+        mut migrate_value: F,
+    ) -> OperationCompletionStatus
+    where
+        K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+        V: TopEncode + TopDecode,
+        F: FnMut(&K, V) -> V,
+    {
+        let keys = match self.load_position::<K>() {
+            Some(next_key) => mapper.keys_from(&next_key),
+            None => mapper.keys(),
+        };
+        for key in keys {
+            if !self.can_process_item() {
+                self.save_position(&key);
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+            if let Some(value) = mapper.get(&key) {
+                let migrated = migrate_value(&key, value);
+                mapper.set_mapped_value(&key, &migrated);
+            }
+            self.processed_items += 1;
+        }
+        OperationCompletionStatus::Completed
+    }
+}
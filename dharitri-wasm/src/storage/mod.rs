@@ -1,9 +1,12 @@
 pub mod mappers;
+pub mod migration;
 pub mod protected_keys;
 mod storage_get;
 mod storage_key;
+mod storage_layout;
 mod storage_set;
 
 pub use storage_get::*;
 pub use storage_key::*;
+pub use storage_layout::*;
 pub use storage_set::*;
@@ -0,0 +1,98 @@
+/// A storage key declared by a contract or module, via `#[storage_get]`, `#[storage_set]`, `#[storage_mapper]` etc.
+///
+/// Generated by the contract macros, in order to detect storage key collisions at compile time.
+pub struct StorageKeyDeclaration {
+    /// The base key, including the module storage prefix, if any.
+    pub key: &'static str,
+
+    /// Keys of storage mappers cannot be shared, while plain getters and setters
+    /// from different modules can access the same key.
+    pub mapper: bool,
+
+    /// The error shown when another contract or module declares the same key.
+    pub collision_message: &'static str,
+}
+
+/// Keys written by the framework itself, in the generated code.
+const RESERVED_KEYS: &[StorageKeyDeclaration] = &[StorageKeyDeclaration {
+    key: crate::io::endpoint_guards::REENTRANCY_LOCK_KEY,
+    mapper: true,
+    collision_message:
+        "storage key `__reentrancy_lock__` is reserved for `#[non_reentrant]` endpoints",
+}];
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The storage keys of a contract or module, together with the layouts of all its supertraits.
+///
+/// Key arguments, whatever their type (structs and tuples included), are nested-encoded and appended to the base key,
+/// so only the base keys need to be compared.
+pub struct StorageLayout {
+    /// Full path of the trait, so that modules reached through several supertraits are only compared once.
+    pub trait_path: &'static str,
+    pub keys: &'static [StorageKeyDeclaration],
+    pub supertraits: &'static [&'static StorageLayout],
+}
+
+const fn check_keys(keys: &[StorageKeyDeclaration], other_keys: &[StorageKeyDeclaration]) {
+    let mut i = 0;
+    while i < keys.len() {
+        let mut j = 0;
+        while j < other_keys.len() {
+            let declaration = &keys[i];
+            let other_declaration = &other_keys[j];
+            if (declaration.mapper || other_declaration.mapper)
+                && str_eq(declaration.key, other_declaration.key)
+            {
+                panic!("{}", other_declaration.collision_message);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Compares the keys of one trait against the keys of every other trait in the tree.
+const fn check_against_tree(layout: &StorageLayout, tree: &StorageLayout) {
+    if !str_eq(layout.trait_path, tree.trait_path) {
+        check_keys(layout.keys, tree.keys);
+    }
+    let mut i = 0;
+    while i < tree.supertraits.len() {
+        check_against_tree(layout, tree.supertraits[i]);
+        i += 1;
+    }
+}
+
+const fn check_subtree(layout: &StorageLayout, root: &StorageLayout) {
+    check_keys(layout.keys, RESERVED_KEYS);
+    check_against_tree(layout, root);
+    let mut i = 0;
+    while i < layout.supertraits.len() {
+        check_subtree(layout.supertraits[i], root);
+        i += 1;
+    }
+}
+
+/// Checks that no storage mapper key of a contract or module is also declared by another module of the same contract,
+/// including the modules that are only extended indirectly, by other modules.
+/// The keys reserved by the framework cannot be declared at all.
+///
+/// Called in a `const` context by the generated contract code, so collisions become compilation errors.
+pub const fn check_storage_layout(layout: &StorageLayout) {
+    check_subtree(layout, layout);
+}
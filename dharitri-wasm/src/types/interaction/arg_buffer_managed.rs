@@ -143,7 +143,6 @@ where
         self
     }
 
-    #[cfg(feature = "alloc")]
     pub fn to_raw_args_vec(&self) -> Vec<Vec<u8>> {
         let mut v = Vec::new();
         for item in self.data.into_iter() {
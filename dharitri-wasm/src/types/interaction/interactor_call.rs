@@ -0,0 +1,197 @@
+use core::marker::PhantomData;
+
+use dharitri_codec::{
+    top_encode_to_vec_u8_or_panic, CodecFrom, DecodeError, DefaultErrorHandler, TopDecodeMulti,
+    TopDecodeMultiInput, TopEncodeMulti,
+};
+
+use crate::{
+    api::{
+        CallTypeApi, DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME,
+        DCT_TRANSFER_FUNC_NAME,
+    },
+    hex_call_data::HexCallDataSerializer,
+    types::heap::{Address, BoxedBytes, Vec},
+};
+use alloc::string::String;
+
+use super::ContractCall;
+
+/// Same as in `ContractCall`, `u64::MAX` means that no gas limit was given.
+const UNSPECIFIED_GAS_LIMIT: u64 = u64::MAX;
+
+/// A token transfer attached to an off-chain call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InteractorPayment {
+    pub token_identifier: BoxedBytes,
+    pub nonce: u64,
+
+    /// Big endian, unsigned.
+    pub amount: BoxedBytes,
+}
+
+/// A transaction, ready to be signed and sent by an off-chain client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InteractorTransaction {
+    pub receiver: Address,
+
+    /// Big endian, unsigned.
+    pub moax_value: BoxedBytes,
+    pub data: String,
+    pub gas_limit: Option<u64>,
+}
+
+/// Off-chain counterpart of `ContractCall`.
+///
+/// Holds no managed types, so it can outlive the API context it was created in.
+/// It is produced by the generated `InteractorTrait` methods,
+/// and can be converted into a transaction, or executed in the Rust testing framework.
+#[must_use]
+pub struct InteractorCall<OriginalResult> {
+    pub to: Address,
+
+    /// Big endian, unsigned.
+    pub moax_payment: BoxedBytes,
+    pub payments: Vec<InteractorPayment>,
+    pub endpoint_name: BoxedBytes,
+    pub arg_buffer: Vec<Vec<u8>>,
+    pub gas_limit: Option<u64>,
+    _return_type: PhantomData<OriginalResult>,
+}
+
+impl<SA, OriginalResult> From<ContractCall<SA, OriginalResult>> for InteractorCall<OriginalResult>
+where
+    SA: CallTypeApi + 'static,
+{
+    fn from(contract_call: ContractCall<SA, OriginalResult>) -> Self {
+        let payments = contract_call
+            .payments
+            .iter()
+            .map(|payment| InteractorPayment {
+                token_identifier: payment.token_identifier.to_boxed_bytes(),
+                nonce: payment.token_nonce,
+                amount: payment.amount.to_bytes_be(),
+            })
+            .collect();
+        let gas_limit = if contract_call.explicit_gas_limit == UNSPECIFIED_GAS_LIMIT {
+            None
+        } else {
+            Some(contract_call.explicit_gas_limit)
+        };
+        InteractorCall {
+            to: contract_call.to.to_address(),
+            moax_payment: contract_call.moax_payment.to_bytes_be(),
+            payments,
+            endpoint_name: contract_call.endpoint_name.to_boxed_bytes(),
+            arg_buffer: contract_call.arg_buffer.to_raw_args_vec(),
+            gas_limit,
+            _return_type: PhantomData,
+        }
+    }
+}
+
+#[allow(clippy::return_self_not_must_use)]
+impl<OriginalResult> InteractorCall<OriginalResult> {
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Builds the transaction that performs the call, sent by `sender`.
+    ///
+    /// Token payments are wrapped in the appropriate built-in function call,
+    /// same as on-chain calls made via `ContractCall`.
+    pub fn to_transaction(&self, sender: &Address) -> InteractorTransaction {
+        let (receiver, serializer) = match self.payments.len() {
+            0 => (
+                self.to.clone(),
+                self.endpoint_call_data(HexCallDataSerializer::new(self.endpoint_name.as_slice())),
+            ),
+            1 => self.single_transfer_call_data(sender, &self.payments[0]),
+            _ => self.multi_transfer_call_data(sender),
+        };
+        let moax_value = if self.payments.is_empty() {
+            self.moax_payment.clone()
+        } else {
+            BoxedBytes::empty()
+        };
+        InteractorTransaction {
+            receiver,
+            moax_value,
+            data: String::from_utf8(serializer.into_vec()).unwrap(),
+            gas_limit: self.gas_limit,
+        }
+    }
+
+    fn single_transfer_call_data(
+        &self,
+        sender: &Address,
+        payment: &InteractorPayment,
+    ) -> (Address, HexCallDataSerializer) {
+        if payment.nonce == 0 {
+            // fungible DCT
+            let mut serializer = HexCallDataSerializer::new(DCT_TRANSFER_FUNC_NAME);
+            serializer.push_argument_bytes(payment.token_identifier.as_slice());
+            serializer.push_argument_bytes(payment.amount.as_slice());
+            (self.to.clone(), self.endpoint_call_data(serializer))
+        } else {
+            // NFT or SFT, the transfer is a call to self
+            let mut serializer = HexCallDataSerializer::new(DCT_NFT_TRANSFER_FUNC_NAME);
+            serializer.push_argument_bytes(payment.token_identifier.as_slice());
+            serializer
+                .push_argument_bytes(top_encode_to_vec_u8_or_panic(&payment.nonce).as_slice());
+            serializer.push_argument_bytes(payment.amount.as_slice());
+            serializer.push_argument_bytes(self.to.as_bytes());
+            (sender.clone(), self.endpoint_call_data(serializer))
+        }
+    }
+
+    fn multi_transfer_call_data(&self, sender: &Address) -> (Address, HexCallDataSerializer) {
+        let mut serializer = HexCallDataSerializer::new(DCT_MULTI_TRANSFER_FUNC_NAME);
+        serializer.push_argument_bytes(self.to.as_bytes());
+        serializer
+            .push_argument_bytes(top_encode_to_vec_u8_or_panic(&self.payments.len()).as_slice());
+        for payment in &self.payments {
+            serializer.push_argument_bytes(payment.token_identifier.as_slice());
+            serializer
+                .push_argument_bytes(top_encode_to_vec_u8_or_panic(&payment.nonce).as_slice());
+            serializer.push_argument_bytes(payment.amount.as_slice());
+        }
+        (sender.clone(), self.endpoint_call_data(serializer))
+    }
+
+    /// Appends the endpoint name and arguments.
+    /// Not needed when there is no transfer, since the serializer already starts with the endpoint name.
+    fn endpoint_call_data(&self, mut serializer: HexCallDataSerializer) -> HexCallDataSerializer {
+        if !self.payments.is_empty() {
+            serializer.push_argument_bytes(self.endpoint_name.as_slice());
+        }
+        for arg in &self.arg_buffer {
+            serializer.push_argument_bytes(arg.as_slice());
+        }
+        serializer
+    }
+
+    /// Decodes the raw results of the call, as returned by the blockchain.
+    pub fn decode_results<RequestedResult>(
+        &self,
+        raw_results: Vec<Vec<u8>>,
+    ) -> Result<RequestedResult, DecodeError>
+    where
+        OriginalResult: TopEncodeMulti,
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        decode_raw_results(raw_results)
+    }
+}
+
+pub(super) fn decode_raw_results<RequestedResult>(
+    mut raw_results: Vec<Vec<u8>>,
+) -> Result<RequestedResult, DecodeError>
+where
+    RequestedResult: TopDecodeMulti,
+{
+    let result = RequestedResult::multi_decode(&mut raw_results)?;
+    raw_results.assert_no_more_args(DefaultErrorHandler)?;
+    Ok(result)
+}
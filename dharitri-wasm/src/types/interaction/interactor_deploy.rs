@@ -0,0 +1,136 @@
+use core::marker::PhantomData;
+
+use dharitri_codec::{CodecFrom, DecodeError, TopEncodeMulti};
+
+use crate::{
+    api::CallTypeApi,
+    hex_call_data::HexCallDataSerializer,
+    types::{
+        heap::{Address, BoxedBytes, Vec},
+        CodeMetadata,
+    },
+};
+use alloc::string::String;
+
+use super::{interactor_call::decode_raw_results, ContractDeploy, InteractorTransaction};
+
+/// Same as in `ContractDeploy`, `u64::MAX` means that no gas limit was given.
+const UNSPECIFIED_GAS_LIMIT: u64 = u64::MAX;
+
+/// The VM that runs wasm contracts, first argument after the code in deploy transactions.
+const VM_TYPE_WASM: &[u8] = &[0x05, 0x00];
+
+const UPGRADE_CONTRACT_FUNC_NAME: &[u8] = b"upgradeContract";
+
+/// Off-chain counterpart of `ContractDeploy`.
+///
+/// Like `InteractorCall`, it holds no managed types.
+/// The contract code is only needed when building the transaction.
+#[must_use]
+pub struct InteractorDeploy<OriginalResult> {
+    /// Only used for upgrades.
+    pub to: Option<Address>,
+
+    /// Big endian, unsigned.
+    pub moax_payment: BoxedBytes,
+    pub arg_buffer: Vec<Vec<u8>>,
+    pub gas_limit: Option<u64>,
+    _return_type: PhantomData<OriginalResult>,
+}
+
+impl<SA, OriginalResult> From<ContractDeploy<SA, OriginalResult>>
+    for InteractorDeploy<OriginalResult>
+where
+    SA: CallTypeApi + 'static,
+{
+    fn from(contract_deploy: ContractDeploy<SA, OriginalResult>) -> Self {
+        let gas_limit = if contract_deploy.explicit_gas_limit == UNSPECIFIED_GAS_LIMIT {
+            None
+        } else {
+            Some(contract_deploy.explicit_gas_limit)
+        };
+        InteractorDeploy {
+            to: contract_deploy
+                .to
+                .into_option()
+                .map(|address| address.to_address()),
+            moax_payment: contract_deploy.moax_payment.to_bytes_be(),
+            arg_buffer: contract_deploy.arg_buffer.to_raw_args_vec(),
+            gas_limit,
+            _return_type: PhantomData,
+        }
+    }
+}
+
+#[allow(clippy::return_self_not_must_use)]
+impl<OriginalResult> InteractorDeploy<OriginalResult> {
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Deploy transactions are sent to the zero address.
+    pub fn to_deploy_transaction(
+        &self,
+        code: &[u8],
+        code_metadata: CodeMetadata,
+    ) -> InteractorTransaction {
+        let mut serializer = HexCallDataSerializer::new(b"");
+        serializer.push_argument_bytes(code);
+        serializer.push_argument_bytes(VM_TYPE_WASM);
+        serializer = self.push_metadata_and_args(serializer, code_metadata);
+        // the serializer always starts with a separator after the (here empty) function name
+        let data = String::from_utf8(serializer.into_vec()).unwrap();
+        InteractorTransaction {
+            receiver: Address::zero(),
+            moax_value: self.moax_payment.clone(),
+            data: String::from(&data[1..]),
+            gas_limit: self.gas_limit,
+        }
+    }
+
+    /// Upgrades the contract given via `contract`, when creating the interactor.
+    pub fn to_upgrade_transaction(
+        &self,
+        code: &[u8],
+        code_metadata: CodeMetadata,
+    ) -> InteractorTransaction {
+        let receiver = self
+            .to
+            .clone()
+            .expect("no contract address specified for the upgrade");
+        let mut serializer = HexCallDataSerializer::new(UPGRADE_CONTRACT_FUNC_NAME);
+        serializer.push_argument_bytes(code);
+        serializer = self.push_metadata_and_args(serializer, code_metadata);
+        InteractorTransaction {
+            receiver,
+            moax_value: self.moax_payment.clone(),
+            data: String::from_utf8(serializer.into_vec()).unwrap(),
+            gas_limit: self.gas_limit,
+        }
+    }
+
+    fn push_metadata_and_args(
+        &self,
+        mut serializer: HexCallDataSerializer,
+        code_metadata: CodeMetadata,
+    ) -> HexCallDataSerializer {
+        serializer.push_argument_bytes(&code_metadata.to_byte_array()[..]);
+        for arg in &self.arg_buffer {
+            serializer.push_argument_bytes(arg.as_slice());
+        }
+        serializer
+    }
+
+    /// Decodes the raw results of the constructor.
+    pub fn decode_results<RequestedResult>(
+        &self,
+        raw_results: Vec<Vec<u8>>,
+    ) -> Result<RequestedResult, DecodeError>
+    where
+        OriginalResult: TopEncodeMulti,
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        decode_raw_results(raw_results)
+    }
+}
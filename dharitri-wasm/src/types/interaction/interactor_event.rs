@@ -0,0 +1,98 @@
+use dharitri_codec::{DecodeError, NestedDecode, TopDecode};
+
+use crate::types::heap::Vec;
+
+const WRONG_EVENT_IDENTIFIER: &str = "wrong event identifier";
+const MISSING_EVENT_VERSION: &str = "missing event version";
+const MISSING_EVENT_DATA_FIELD: &str = "missing event data field";
+const UNEXPECTED_EVENT_DATA_FIELDS: &str = "unexpected event data fields";
+
+/// Used by the generated event decoders of the `InteractorTrait`.
+///
+/// Checks that the first topic is the expected event identifier,
+/// and returns the other topics, from which the indexed arguments get decoded.
+pub fn interactor_event_topics(
+    topics: &[Vec<u8>],
+    identifier: &[u8],
+) -> Result<Vec<Vec<u8>>, DecodeError> {
+    match topics.split_first() {
+        Some((first, rest)) if first.as_slice() == identifier => Ok(rest.to_vec()),
+        _ => Err(DecodeError::from(WRONG_EVENT_IDENTIFIER)),
+    }
+}
+
+/// The data of an event with structured data, i.e. with several non-indexed arguments, or versioned.
+///
+/// Versioned events start with the version byte. Each argument follows, top-encoded, with a length prefix.
+/// Arguments added in versions newer than that of the log are decoded from empty bytes, i.e. they get default values.
+pub struct InteractorEventData {
+    version: u8,
+    latest_version: u8,
+    fields: Vec<Vec<u8>>,
+    next_index: usize,
+}
+
+impl InteractorEventData {
+    /// `latest_version` is the version declared by the event, `None` if it is not versioned.
+    pub fn new(data: &[u8], latest_version: Option<u8>) -> Result<Self, DecodeError> {
+        let (version, mut remaining) = match latest_version {
+            Some(_) => {
+                let (version, rest) = data
+                    .split_first()
+                    .ok_or(DecodeError::from(MISSING_EVENT_VERSION))?;
+                (*version, rest)
+            },
+            None => (1, data),
+        };
+        let mut fields = Vec::new();
+        while !remaining.is_empty() {
+            fields.push(Vec::<u8>::dep_decode(&mut remaining)?);
+        }
+        Ok(InteractorEventData {
+            version,
+            latest_version: latest_version.unwrap_or(1),
+            fields,
+            next_index: 0,
+        })
+    }
+
+    /// The version of the contract that emitted the log.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// The top-encoded next argument, empty if it was added after the version of the log.
+    pub fn next_raw_field(&mut self, since: u8) -> Result<Vec<u8>, DecodeError> {
+        if since > self.version {
+            return Ok(Vec::new());
+        }
+        let field = self
+            .fields
+            .get(self.next_index)
+            .ok_or(DecodeError::from(MISSING_EVENT_DATA_FIELD))?;
+        self.next_index += 1;
+        Ok(field.clone())
+    }
+
+    pub fn next_field<T: TopDecode>(&mut self, since: u8) -> Result<T, DecodeError> {
+        T::top_decode(self.next_raw_field(since)?)
+    }
+
+    /// Logs from newer versions of the contract are allowed to contain arguments unknown to the decoder.
+    pub fn finish(self) -> Result<(), DecodeError> {
+        if self.version <= self.latest_version && self.next_index < self.fields.len() {
+            return Err(DecodeError::from(UNEXPECTED_EVENT_DATA_FIELDS));
+        }
+        Ok(())
+    }
+}
+
+/// Implemented by the structs generated for each `#[event]`, in the `typed_events` module of the contract.
+///
+/// Allows decoding logs into typed values, e.g. in tests.
+pub trait ContractEvent: Sized {
+    const IDENTIFIER: &'static str;
+
+    /// Decodes the event from the topics (identifier first) and data of a log.
+    fn decode_event(topics: &[Vec<u8>], data: &[u8]) -> Result<Self, DecodeError>;
+}
@@ -4,6 +4,11 @@ mod callback_closure;
 mod callback_selector_result;
 mod contract_call;
 mod contract_deploy;
+#[cfg(not(target_arch = "wasm32"))]
+mod interactor_call;
+#[cfg(not(target_arch = "wasm32"))]
+mod interactor_deploy;
+mod interactor_event;
 
 pub use arg_buffer_managed::ManagedArgBuffer;
 pub use async_call::AsyncCall;
@@ -11,3 +16,8 @@ pub use callback_closure::{new_callback_call, CallbackClosure, CallbackClosureFo
 pub use callback_selector_result::CallbackSelectorResult;
 pub use contract_call::{new_contract_call, ContractCall};
 pub use contract_deploy::{new_contract_deploy, ContractDeploy};
+#[cfg(not(target_arch = "wasm32"))]
+pub use interactor_call::{InteractorCall, InteractorPayment, InteractorTransaction};
+#[cfg(not(target_arch = "wasm32"))]
+pub use interactor_deploy::InteractorDeploy;
+pub use interactor_event::{interactor_event_topics, ContractEvent, InteractorEventData};
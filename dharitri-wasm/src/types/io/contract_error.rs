@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use crate::{
+    abi::{ErrorAbi, TypeAbi},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    formatter::FormatBuffer,
+    types::{ManagedBufferCachedBuilder, ManagedType},
+};
+
+/// Error messages signalled by contract errors start with this prefix, followed by the error code and `: `,
+/// e.g. `E3: not enough funds`.
+pub const CONTRACT_ERROR_PREFIX: &[u8] = b"E";
+
+const CONTRACT_ERROR_CODE_SEPARATOR: &[u8] = b": ";
+
+/// The longest possible message prefix, `E4294967295: `.
+pub const CONTRACT_ERROR_MAX_PREFIX_LEN: usize = 13;
+
+/// Error types that can be returned by endpoints, as `Result<T, E>`.
+///
+/// Usually derived on enums, with `#[derive(ContractError)]`,
+/// each variant getting a code and a message via `#[error(code = 1, message = "...")]`.
+/// The message can have placeholders, filled in by the variant fields, in order.
+/// Since the VM only accepts a message, the error code is signalled as the beginning of the message,
+/// callers and callbacks can retrieve it with `parse_contract_error_code`.
+pub trait ContractError: TypeAbi {
+    fn error_code(&self) -> u32;
+
+    /// Appends the formatted error message, without the code.
+    fn append_message<F: FormatBuffer>(&self, buffer: &mut F);
+
+    /// Describes all the errors of the type, for the ABI.
+    fn error_abis() -> Vec<ErrorAbi>;
+
+    /// Called in the generated code when an endpoint returns an error. Stops execution.
+    fn signal_error<M: ManagedTypeApi>(&self) -> ! {
+        let mut buffer = ManagedBufferCachedBuilder::<M>::new_from_slice(CONTRACT_ERROR_PREFIX);
+        buffer.append_display(&self.error_code());
+        buffer.append_ascii(CONTRACT_ERROR_CODE_SEPARATOR);
+        self.append_message(&mut buffer);
+        M::error_api_impl().signal_error_from_buffer(buffer.into_managed_buffer().get_raw_handle())
+    }
+}
+
+/// Retrieves the error code from a message signalled by a `ContractError`,
+/// together with the position where the actual message starts.
+///
+/// Returns `None` for any other error message.
+pub fn parse_contract_error_code(message: &[u8]) -> Option<(u32, usize)> {
+    let digits = message.strip_prefix(CONTRACT_ERROR_PREFIX)?;
+    let num_digits = digits.iter().take_while(|b| b.is_ascii_digit()).count();
+    if num_digits == 0 || !digits[num_digits..].starts_with(CONTRACT_ERROR_CODE_SEPARATOR) {
+        return None;
+    }
+    let mut code = 0u32;
+    for digit in &digits[..num_digits] {
+        code = code.checked_mul(10)?.checked_add((digit - b'0') as u32)?;
+    }
+    let message_start =
+        CONTRACT_ERROR_PREFIX.len() + num_digits + CONTRACT_ERROR_CODE_SEPARATOR.len();
+    Some((code, message_start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_contract_error_code() {
+        assert_eq!(
+            parse_contract_error_code(b"E3: not enough funds"),
+            Some((3, 4))
+        );
+        assert_eq!(
+            parse_contract_error_code(b"E4294967295: "),
+            Some((u32::MAX, 13))
+        );
+        assert_eq!(parse_contract_error_code(b"E4294967296: overflow"), None);
+        assert_eq!(parse_contract_error_code(b"E: no code"), None);
+        assert_eq!(parse_contract_error_code(b"E12 missing separator"), None);
+        assert_eq!(
+            parse_contract_error_code(b"Endpoint can only be called by owner"),
+            None
+        );
+        assert_eq!(parse_contract_error_code(b""), None);
+    }
+}
@@ -198,6 +198,55 @@ impl<M: ManagedTypeApi> BigFloat<M> {
     }
 }
 
+impl<M: ManagedTypeApi> BigFloat<M> {
+    /// Largest integer less than or equal to the value.
+    pub fn floor(&self) -> BigInt<M> {
+        let result = M::static_var_api_impl().next_handle();
+        M::managed_type_impl().bf_floor(result, self.handle);
+        BigInt::from_raw_handle(result)
+    }
+
+    /// Smallest integer greater than or equal to the value.
+    pub fn ceil(&self) -> BigInt<M> {
+        let result = M::static_var_api_impl().next_handle();
+        M::managed_type_impl().bf_ceil(result, self.handle);
+        BigInt::from_raw_handle(result)
+    }
+
+    /// Integer part of the value, rounding towards zero.
+    pub fn trunc(&self) -> BigInt<M> {
+        let result = M::static_var_api_impl().next_handle();
+        M::managed_type_impl().bf_trunc(result, self.handle);
+        BigInt::from_raw_handle(result)
+    }
+
+    /// Nearest integer to the value, rounding half-way cases away from zero.
+    pub fn round(&self) -> BigInt<M> {
+        // the fractional part is computed exactly, unlike `self + 1/2`,
+        // which can round up to the next integer when the precision runs out
+        let truncated = self.trunc();
+        let fraction = self - &BigFloat::from_big_int(&truncated);
+        let half = BigFloat::from_frac(1, 2);
+        if fraction >= half {
+            truncated + BigInt::from(1i64)
+        } else if fraction <= half.neg() {
+            truncated - BigInt::from(1i64)
+        } else {
+            truncated
+        }
+    }
+
+    /// Rounds the value to `decimals` decimal places, half-way cases away from zero.
+    ///
+    /// The result is exact only up to the precision of the underlying representation,
+    /// since most decimal fractions have no exact binary form.
+    pub fn round_to_decimals(&self, decimals: u32) -> Self {
+        let denominator = BigFloat::from(10i64).pow(decimals);
+        let rounded = BigFloat::from_big_int(&(self * &denominator).round());
+        rounded / denominator
+    }
+}
+
 impl<M: ManagedTypeApi> Clone for BigFloat<M> {
     fn clone(&self) -> Self {
         let new_handle = M::static_var_api_impl().next_handle();
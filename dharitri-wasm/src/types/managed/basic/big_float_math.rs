@@ -0,0 +1,137 @@
+//! Transcendental functions on `BigFloat`.
+//!
+//! The VM offers no hooks for these, so they are computed from the basic arithmetic hooks,
+//! which makes them behave the same in the VM and in the debug API.
+//!
+//! Both `ln` and `exp` first reduce the argument to a small interval
+//! and then sum a fixed number of series terms.
+//! The truncation error of the series is below `1e-20` relative to the result,
+//! so the overall error is dominated by the rounding of the underlying arithmetic:
+//! a 53-bit mantissa in the VM and `f64` in the debug API,
+//! i.e. a relative error of a few units of `1e-16` for moderately sized arguments.
+//! For `exp`, the relative error additionally grows linearly with the magnitude of the argument,
+//! since the reduction step subtracts a multiple of `ln(2)`.
+
+use crate::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    types::BigUint,
+};
+
+use super::BigFloat;
+
+/// `ln` sums terms of `2 * atanh(y)`, with `0 <= y < 1/3`.
+/// The remainder after 20 terms is below `2 * (1/3)^41 / 36 < 2e-21`.
+const LN_SERIES_TERMS: u32 = 20;
+
+/// `ln(2) = 0.6931471805599453094...`, more digits than either representation can hold.
+const LN_2_SIGNIFICAND: i64 = 6_931_471_805_599_453_094;
+const LN_2_EXPONENT: i32 = -19;
+
+/// `ln(10) = 2.302585092994045684...`
+const LN_10_SIGNIFICAND: i64 = 2_302_585_092_994_045_684;
+const LN_10_EXPONENT: i32 = -18;
+
+/// `exp` sums Taylor terms of `e^r`, with `|r| <= ln(2) / 2 < 0.35`.
+/// The remainder after 18 terms is below `0.35^19 / 19! * e^0.35 < 3e-26`.
+const EXP_SERIES_TERMS: u32 = 18;
+
+impl<M: ManagedTypeApi> BigFloat<M> {
+    /// Natural logarithm.
+    ///
+    /// Returns `None` if the value is not strictly positive.
+    pub fn ln(&self) -> Option<Self> {
+        if *self <= 0 {
+            return None;
+        }
+
+        // bring the mantissa into [1, 2): self = mantissa * 2^exponent
+        let one = BigFloat::from(1i64);
+        let mut exponent = self.binary_exponent();
+        let mut mantissa = self * &power_of_two(-exponent);
+        // only reachable when rounding moved the reciprocal in `binary_exponent` onto a power of 2
+        if mantissa < one {
+            mantissa *= &BigFloat::from(2i64);
+            exponent -= 1;
+        }
+
+        // ln(m) = 2 * atanh((m - 1) / (m + 1))
+        let y = &(&mantissa - &one) / &(&mantissa + &one);
+        let mut result = ln_series(&y);
+        if exponent != 0 {
+            result += &Self::ln_2() * &BigFloat::from(exponent);
+        }
+        Some(result)
+    }
+
+    /// Base 10 logarithm.
+    ///
+    /// Returns `None` if the value is not strictly positive.
+    pub fn log10(&self) -> Option<Self> {
+        let ln_10 = BigFloat::from_sci(LN_10_SIGNIFICAND, LN_10_EXPONENT);
+        Some(self.ln()? / ln_10)
+    }
+
+    /// Exponential function, `e^self`.
+    ///
+    /// Signals an error if the binary exponent of the result does not fit in an `i32`.
+    ///
+    /// The debug API computes in `f64`, which runs out of range much earlier:
+    /// there the result overflows to infinity, or underflows to zero, once `|self|` exceeds about 709.
+    pub fn exp(&self) -> Self {
+        // self = k * ln(2) + r, with |r| <= ln(2) / 2
+        let ln_2 = Self::ln_2();
+        let k = (self / &ln_2)
+            .round()
+            .to_i64()
+            .filter(|k| k.unsigned_abs() <= i32::MAX as u64)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::BIG_FLOAT_EXP_OVERFLOW));
+        let r = self - &(&ln_2 * &BigFloat::from(k));
+
+        let mut term = BigFloat::from(1i64);
+        let mut sum = BigFloat::from(1i64);
+        for i in 1..=EXP_SERIES_TERMS {
+            term = &(&term * &r) / &BigFloat::from(i as i64);
+            sum += &term;
+        }
+
+        sum * power_of_two(k)
+    }
+
+    fn ln_2() -> Self {
+        BigFloat::from_sci(LN_2_SIGNIFICAND, LN_2_EXPONENT)
+    }
+
+    /// `floor(log2(self))`, for strictly positive values.
+    fn binary_exponent(&self) -> i64 {
+        let one = BigFloat::from(1i64);
+        if *self >= one {
+            return self.trunc().magnitude().log2() as i64;
+        }
+        // self in [2^-k, 2^-(k-1)) => 1/self in (2^(k-1), 2^k] => ceil(1/self) - 1 in [2^(k-1), 2^k)
+        let reciprocal = (&one / self).ceil().magnitude() - BigUint::from(1u32);
+        -(reciprocal.log2() as i64) - 1
+    }
+}
+
+/// `2^exponent`, exact.
+fn power_of_two<M: ManagedTypeApi>(exponent: i64) -> BigFloat<M> {
+    let power = BigFloat::from(2i64).pow(exponent.unsigned_abs() as u32);
+    if exponent < 0 {
+        BigFloat::from(1i64) / power
+    } else {
+        power
+    }
+}
+
+/// `2 * atanh(y) = 2 * (y + y^3/3 + y^5/5 + ...)`
+fn ln_series<M: ManagedTypeApi>(y: &BigFloat<M>) -> BigFloat<M> {
+    let y_squared = y * y;
+    let mut power = y.clone();
+    let mut sum = y.clone();
+    for i in 1..LN_SERIES_TERMS {
+        power *= &y_squared;
+        sum += &power / &BigFloat::from((2 * i + 1) as i64);
+    }
+    sum * BigFloat::from(2i64)
+}
@@ -19,6 +19,8 @@ mod big_float;
 #[cfg(feature = "big-float")]
 mod big_float_cmp;
 #[cfg(feature = "big-float")]
+mod big_float_math;
+#[cfg(feature = "big-float")]
 mod big_float_operators;
 #[cfg(feature = "big-float")]
 pub use big_float::BigFloat;
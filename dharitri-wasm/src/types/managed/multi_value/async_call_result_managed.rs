@@ -1,7 +1,7 @@
 use crate::{
     abi::{TypeAbi, TypeName},
     api::ManagedTypeApi,
-    types::ManagedBuffer,
+    types::{parse_contract_error_code, ManagedBuffer, CONTRACT_ERROR_MAX_PREFIX_LEN},
 };
 use dharitri_codec::{
     DecodeErrorHandler, EncodeErrorHandler, TopDecodeMulti, TopDecodeMultiInput, TopEncodeMulti,
@@ -19,6 +19,31 @@ where
     pub err_msg: ManagedBuffer<M>,
 }
 
+impl<M> ManagedAsyncCallError<M>
+where
+    M: ManagedTypeApi,
+{
+    fn parse_contract_error(&self) -> Option<(u32, usize)> {
+        let prefix_len = core::cmp::min(self.err_msg.len(), CONTRACT_ERROR_MAX_PREFIX_LEN);
+        let mut prefix = [0u8; CONTRACT_ERROR_MAX_PREFIX_LEN];
+        self.err_msg.load_slice(0, &mut prefix[..prefix_len]).ok()?;
+        parse_contract_error_code(&prefix[..prefix_len])
+    }
+
+    /// The code of the error, if the called endpoint returned a `ContractError`.
+    pub fn contract_error_code(&self) -> Option<u32> {
+        self.parse_contract_error().map(|(code, _)| code)
+    }
+
+    /// The formatted message of the error, without the code prefix,
+    /// if the called endpoint returned a `ContractError`.
+    pub fn contract_error_message(&self) -> Option<ManagedBuffer<M>> {
+        let (_, message_start) = self.parse_contract_error()?;
+        self.err_msg
+            .copy_slice(message_start, self.err_msg.len() - message_start)
+    }
+}
+
 pub enum ManagedAsyncCallResult<M, T>
 where
     M: ManagedTypeApi,
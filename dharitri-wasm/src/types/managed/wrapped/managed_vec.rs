@@ -144,7 +144,8 @@ where
             return None;
         }
 
-        let mut result_uninit = core::mem::MaybeUninit::<T::Ref<'_>>::uninit_array();
+        let mut result_uninit: [core::mem::MaybeUninit<T::Ref<'_>>; N] =
+            [const { core::mem::MaybeUninit::uninit() }; N];
         for (index, value) in self.iter().enumerate() {
             result_uninit[index].write(value);
         }
@@ -0,0 +1,195 @@
+use std::{fs, path::Path};
+
+use dharitri_wasm_debug::abi_json::{
+    ConstructorAbiJson, ContractAbiJson, EventAbiJson, InputAbiJson, OutputAbiJson,
+};
+use serde_json::{Map, Value};
+
+use crate::{AbiType, CodecError, TypeCodec};
+
+/// The name under which the constructor is looked up, since it has no name in the ABI.
+pub const CONSTRUCTOR_NAME: &str = "init";
+
+/// The name of the endpoint the `#[upgrade]` method is exported as, which is where its arguments go.
+pub const UPGRADE_CONSTRUCTOR_NAME: &str = "upgrade";
+
+/// Encodes endpoint arguments and decodes results and events of a contract, based only on its ABI.
+pub struct AbiCodec {
+    pub abi: ContractAbiJson,
+}
+
+impl AbiCodec {
+    pub fn new(abi: ContractAbiJson) -> Self {
+        AbiCodec { abi }
+    }
+
+    pub fn from_json_str(json: &str) -> Result<Self, CodecError> {
+        let abi = serde_json::from_str(json)
+            .map_err(|err| CodecError::new(format!("invalid ABI: {}", err)))?;
+        Ok(AbiCodec::new(abi))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CodecError> {
+        let json = fs::read_to_string(path.as_ref()).map_err(|err| {
+            CodecError::new(format!(
+                "could not read {}: {}",
+                path.as_ref().display(),
+                err
+            ))
+        })?;
+        Self::from_json_str(json.as_str())
+    }
+
+    pub fn type_codec(&self) -> TypeCodec<'_> {
+        TypeCodec::new(&self.abi.types)
+    }
+
+    fn constructor(&self, endpoint_name: &str) -> Option<&ConstructorAbiJson> {
+        match endpoint_name {
+            CONSTRUCTOR_NAME => self.abi.constructor.as_ref(),
+            UPGRADE_CONSTRUCTOR_NAME => self.abi.upgrade_constructor.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn endpoint_inputs(&self, endpoint_name: &str) -> Result<&[InputAbiJson], CodecError> {
+        if let Some(constructor) = self.constructor(endpoint_name) {
+            return Ok(constructor.inputs.as_slice());
+        }
+        self.abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .map(|endpoint| endpoint.inputs.as_slice())
+            .ok_or_else(|| CodecError::new(format!("unknown endpoint `{}`", endpoint_name)))
+    }
+
+    fn endpoint_outputs(&self, endpoint_name: &str) -> Result<&[OutputAbiJson], CodecError> {
+        if let Some(constructor) = self.constructor(endpoint_name) {
+            return Ok(constructor.outputs.as_slice());
+        }
+        self.abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .map(|endpoint| endpoint.outputs.as_slice())
+            .ok_or_else(|| CodecError::new(format!("unknown endpoint `{}`", endpoint_name)))
+    }
+
+    fn event(&self, identifier: &str) -> Result<&EventAbiJson, CodecError> {
+        self.abi
+            .events
+            .iter()
+            .find(|event| event.identifier == identifier)
+            .ok_or_else(|| CodecError::new(format!("unknown event `{}`", identifier)))
+    }
+
+    /// Top-encodes a single value of the given ABI type.
+    pub fn encode_value(&self, type_name: &str, value: &Value) -> Result<Vec<u8>, CodecError> {
+        self.type_codec()
+            .top_encode(&AbiType::parse(type_name)?, value)
+    }
+
+    /// Top-decodes a single value of the given ABI type.
+    pub fn decode_value(&self, type_name: &str, bytes: &[u8]) -> Result<Value, CodecError> {
+        self.type_codec()
+            .top_decode(&AbiType::parse(type_name)?, bytes)
+    }
+
+    /// Expects a JSON array with one item per declared input, multi-value inputs included.
+    /// Trailing `optional<...>` inputs can be left out.
+    pub fn encode_args(
+        &self,
+        endpoint_name: &str,
+        args: &Value,
+    ) -> Result<Vec<Vec<u8>>, CodecError> {
+        let inputs = self.endpoint_inputs(endpoint_name)?;
+        let values = args
+            .as_array()
+            .ok_or_else(|| CodecError::new("endpoint arguments must be a JSON array"))?;
+        if values.len() > inputs.len() {
+            return Err(CodecError::new(format!(
+                "`{}` takes {} arguments, got {}",
+                endpoint_name,
+                inputs.len(),
+                values.len()
+            )));
+        }
+
+        let type_codec = self.type_codec();
+        let mut encoded = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let value = values.get(index).unwrap_or(&Value::Null);
+            let input_type = AbiType::parse(input.type_name.as_str())?;
+            type_codec
+                .multi_encode(&input_type, value, &mut encoded)
+                .map_err(|err| {
+                    CodecError::new(format!("argument `{}`: {}", input.arg_name, err))
+                })?;
+        }
+        Ok(encoded)
+    }
+
+    /// Transaction data of an endpoint call, e.g. `add@05`.
+    pub fn call_data(&self, endpoint_name: &str, args: &Value) -> Result<String, CodecError> {
+        let mut call_data = endpoint_name.to_string();
+        for arg in self.encode_args(endpoint_name, args)? {
+            call_data.push('@');
+            call_data.push_str(hex::encode(arg).as_str());
+        }
+        Ok(call_data)
+    }
+
+    /// Decodes the raw results of an endpoint call into a JSON array, one item per declared output.
+    pub fn decode_results(
+        &self,
+        endpoint_name: &str,
+        results: &[Vec<u8>],
+    ) -> Result<Value, CodecError> {
+        let type_codec = self.type_codec();
+        let mut remaining = results;
+        let mut values = Vec::new();
+        for output in self.endpoint_outputs(endpoint_name)? {
+            let output_type = AbiType::parse(output.type_name.as_str())?;
+            values.push(type_codec.multi_decode(&output_type, &mut remaining)?);
+        }
+        if !remaining.is_empty() {
+            return Err(CodecError::new("too many results"));
+        }
+        Ok(Value::Array(values))
+    }
+
+    /// Decodes a log entry, as found in transaction results.
+    ///
+    /// The first topic is the event identifier, followed by the indexed inputs.
+    /// The non-indexed input, if any, is in the data field.
+    /// Produces `{ "identifier": ..., "fields": { <input name>: <value>, ... } }`.
+    pub fn decode_event(&self, topics: &[Vec<u8>], data: &[u8]) -> Result<Value, CodecError> {
+        let (identifier_topic, mut remaining) = topics
+            .split_first()
+            .ok_or_else(|| CodecError::new("log has no topics"))?;
+        let identifier = String::from_utf8(identifier_topic.clone())
+            .map_err(|_| CodecError::new("invalid event identifier"))?;
+        let event = self.event(identifier.as_str())?;
+
+        let type_codec = self.type_codec();
+        let mut fields = Map::new();
+        for input in &event.inputs {
+            let input_type = AbiType::parse(input.type_name.as_str())?;
+            let value = if input.indexed.unwrap_or(false) {
+                type_codec.multi_decode(&input_type, &mut remaining)?
+            } else {
+                type_codec.top_decode(&input_type, data)?
+            };
+            fields.insert(input.arg_name.clone(), value);
+        }
+        if !remaining.is_empty() {
+            return Err(CodecError::new("too many topics"));
+        }
+
+        let mut decoded = Map::new();
+        decoded.insert("identifier".to_string(), Value::String(identifier));
+        decoded.insert("fields".to_string(), Value::Object(fields));
+        Ok(Value::Object(decoded))
+    }
+}
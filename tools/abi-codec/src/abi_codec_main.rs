@@ -0,0 +1,83 @@
+use std::{env, process};
+
+use abi_codec::{AbiCodec, CodecError};
+use serde_json::Value;
+
+const USAGE: &str = "usage:
+    abi-codec encode <abi.json> <endpoint> <json args array>
+    abi-codec decode <abi.json> <endpoint> [<hex result>...]
+    abi-codec encode-value <abi.json> <type> <json value>
+    abi-codec decode-value <abi.json> <type> <hex>
+    abi-codec decode-event <abi.json> <hex data> [<hex topic>...]";
+
+/// Examples how to run:
+/// `cargo run encode ../../contracts/examples/adder/output/adder.abi.json add '[5]'`
+/// `cargo run decode ../../contracts/examples/adder/output/adder.abi.json getSum 05`
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 4 {
+        println!("{}", USAGE);
+        process::exit(1);
+    }
+
+    match run(&args[1], &args[2], &args[3], &args[4..]) {
+        Ok(output) => println!("{}", output),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        },
+    }
+}
+
+fn parse_json(json: &str) -> Result<Value, CodecError> {
+    serde_json::from_str(json).map_err(|err| CodecError::new(format!("invalid JSON: {}", err)))
+}
+
+fn parse_hex(hex_str: &str) -> Result<Vec<u8>, CodecError> {
+    hex::decode(hex_str.trim_start_matches("0x"))
+        .map_err(|_| CodecError::new(format!("invalid hex `{}`", hex_str)))
+}
+
+fn parse_hex_list(hex_strs: &[String]) -> Result<Vec<Vec<u8>>, CodecError> {
+    hex_strs.iter().map(|hex_str| parse_hex(hex_str)).collect()
+}
+
+fn json_argument(rest: &[String]) -> Result<Value, CodecError> {
+    let json = rest
+        .first()
+        .ok_or_else(|| CodecError::new("missing JSON argument"))?;
+    parse_json(json)
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap()
+}
+
+fn run(command: &str, abi_path: &str, name: &str, rest: &[String]) -> Result<String, CodecError> {
+    let abi_codec = AbiCodec::load(abi_path)?;
+    match command {
+        "encode" => abi_codec.call_data(name, &json_argument(rest)?),
+        "decode" => {
+            let results = parse_hex_list(rest)?;
+            Ok(pretty(&abi_codec.decode_results(name, &results)?))
+        },
+        "encode-value" => {
+            let encoded = abi_codec.encode_value(name, &json_argument(rest)?)?;
+            Ok(hex::encode(encoded))
+        },
+        "decode-value" => {
+            let bytes = match rest.first() {
+                Some(hex_str) => parse_hex(hex_str)?,
+                None => Vec::new(),
+            };
+            Ok(pretty(&abi_codec.decode_value(name, &bytes)?))
+        },
+        "decode-event" => {
+            // events have no name argument, the identifier is the first topic
+            let data = parse_hex(name)?;
+            let topics = parse_hex_list(rest)?;
+            Ok(pretty(&abi_codec.decode_event(&topics, &data)?))
+        },
+        _ => Err(CodecError::new(USAGE)),
+    }
+}
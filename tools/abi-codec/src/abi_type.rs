@@ -0,0 +1,183 @@
+use crate::CodecError;
+
+/// A parsed ABI type name, e.g. `variadic<multi<Address,BigUint>>`.
+///
+/// Names that are not built into the framework are kept as `Custom`
+/// and resolved against the `types` section of the ABI when encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    Bool,
+    BigUint,
+    BigInt,
+    Bytes,
+    Utf8String,
+    Address,
+    H256,
+    TokenIdentifier,
+    MoaxOrDctTokenIdentifier,
+    CodeMetadata,
+    Option(Box<AbiType>),
+    List(Box<AbiType>),
+    Array(usize, Box<AbiType>),
+    Tuple(Vec<AbiType>),
+    Multi(Vec<AbiType>),
+    Variadic(Box<AbiType>),
+    CountedVariadic(Box<AbiType>),
+    Optional(Box<AbiType>),
+    Ignore,
+    Unit,
+    Custom(String),
+}
+
+impl AbiType {
+    pub fn parse(type_name: &str) -> Result<Self, CodecError> {
+        let mut parser = TypeNameParser {
+            input: type_name.as_bytes(),
+            position: 0,
+        };
+        let abi_type = parser.parse_type()?;
+        if parser.position != parser.input.len() {
+            return Err(CodecError::new(format!(
+                "unexpected characters in type name `{}`",
+                type_name
+            )));
+        }
+        Ok(abi_type)
+    }
+
+    /// Multi-value types take up a variable number of top-level arguments.
+    pub fn is_multi(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Multi(_)
+                | AbiType::Variadic(_)
+                | AbiType::CountedVariadic(_)
+                | AbiType::Optional(_)
+                | AbiType::Ignore
+                | AbiType::Unit
+        )
+    }
+
+    fn from_name_and_args(name: &str, mut args: Vec<AbiType>) -> Result<Self, CodecError> {
+        let single_arg = |args: &mut Vec<AbiType>| -> Result<Box<AbiType>, CodecError> {
+            if args.len() != 1 {
+                return Err(CodecError::new(format!(
+                    "`{}` expects exactly one type argument",
+                    name
+                )));
+            }
+            Ok(Box::new(args.remove(0)))
+        };
+
+        let abi_type = match name {
+            "Option" => AbiType::Option(single_arg(&mut args)?),
+            "List" => AbiType::List(single_arg(&mut args)?),
+            "tuple" => AbiType::Tuple(args),
+            "multi" => AbiType::Multi(args),
+            "variadic" => AbiType::Variadic(single_arg(&mut args)?),
+            "counted-variadic" => AbiType::CountedVariadic(single_arg(&mut args)?),
+            "optional" => AbiType::Optional(single_arg(&mut args)?),
+            _ if name.starts_with("array") && !args.is_empty() => {
+                let size = name["array".len()..]
+                    .parse::<usize>()
+                    .map_err(|_| CodecError::new(format!("invalid array type name `{}`", name)))?;
+                AbiType::Array(size, single_arg(&mut args)?)
+            },
+            _ if !args.is_empty() => {
+                return Err(CodecError::new(format!(
+                    "unsupported generic type `{}`",
+                    name
+                )))
+            },
+            "u8" => AbiType::U8,
+            "u16" => AbiType::U16,
+            "u32" | "usize" => AbiType::U32,
+            "u64" => AbiType::U64,
+            "i8" => AbiType::I8,
+            "i16" => AbiType::I16,
+            "i32" | "isize" => AbiType::I32,
+            "i64" => AbiType::I64,
+            "bool" => AbiType::Bool,
+            "BigUint" => AbiType::BigUint,
+            "BigInt" => AbiType::BigInt,
+            "bytes" => AbiType::Bytes,
+            "utf-8 string" => AbiType::Utf8String,
+            "Address" => AbiType::Address,
+            "H256" => AbiType::H256,
+            "TokenIdentifier" => AbiType::TokenIdentifier,
+            "MoaxOrDctTokenIdentifier" => AbiType::MoaxOrDctTokenIdentifier,
+            "CodeMetadata" => AbiType::CodeMetadata,
+            "ignore" => AbiType::Ignore,
+            "()" => AbiType::Unit,
+            other => AbiType::Custom(other.to_string()),
+        };
+        Ok(abi_type)
+    }
+}
+
+struct TypeNameParser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> TypeNameParser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).cloned()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.position += 1;
+        }
+    }
+
+    /// Reads up to the next `<`, `>` or `,`.
+    /// Spaces are allowed inside names, e.g. `utf-8 string`.
+    fn parse_name(&mut self) -> &'a str {
+        let start = self.position;
+        while let Some(c) = self.peek() {
+            if c == b'<' || c == b'>' || c == b',' {
+                break;
+            }
+            self.position += 1;
+        }
+        core::str::from_utf8(&self.input[start..self.position])
+            .unwrap()
+            .trim()
+    }
+
+    fn parse_type(&mut self) -> Result<AbiType, CodecError> {
+        self.skip_spaces();
+        let name = self.parse_name();
+        if name.is_empty() {
+            return Err(CodecError::new("empty type name"));
+        }
+
+        let mut args = Vec::new();
+        if self.peek() == Some(b'<') {
+            self.position += 1;
+            loop {
+                args.push(self.parse_type()?);
+                self.skip_spaces();
+                match self.peek() {
+                    Some(b',') => self.position += 1,
+                    Some(b'>') => {
+                        self.position += 1;
+                        break;
+                    },
+                    _ => return Err(CodecError::new("unterminated type argument list")),
+                }
+            }
+        }
+
+        AbiType::from_name_and_args(name, args)
+    }
+}
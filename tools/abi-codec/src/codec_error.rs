@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// Describes why a value could not be encoded or decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodecError {
+    pub message: String,
+}
+
+impl CodecError {
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        CodecError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message.as_str())
+    }
+}
+
+impl std::error::Error for CodecError {}
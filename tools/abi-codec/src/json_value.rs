@@ -0,0 +1,155 @@
+use bech32::{FromBase32, ToBase32, Variant};
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::Value;
+
+use crate::CodecError;
+
+pub const ADDRESS_HRP: &str = "moa";
+const ADDRESS_LEN: usize = 32;
+
+fn expected(what: &str, value: &Value) -> CodecError {
+    CodecError::new(format!("expected {}, got `{}`", what, value))
+}
+
+/// Accepts JSON numbers and decimal strings, since large `u64` values do not survive all JSON tooling.
+pub fn json_to_u64(value: &Value, type_name: &str, max: u64) -> Result<u64, CodecError> {
+    let number = match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(s) => s.parse::<u64>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| expected(type_name, value))?;
+    if number > max {
+        return Err(CodecError::new(format!(
+            "{} out of range for {}",
+            number, type_name
+        )));
+    }
+    Ok(number)
+}
+
+pub fn json_to_i64(value: &Value, type_name: &str, min: i64, max: i64) -> Result<i64, CodecError> {
+    let number = match value {
+        Value::Number(number) => number.as_i64(),
+        Value::String(s) => s.parse::<i64>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| expected(type_name, value))?;
+    if number < min || number > max {
+        return Err(CodecError::new(format!(
+            "{} out of range for {}",
+            number, type_name
+        )));
+    }
+    Ok(number)
+}
+
+pub fn json_to_bool(value: &Value) -> Result<bool, CodecError> {
+    value.as_bool().ok_or_else(|| expected("bool", value))
+}
+
+fn json_to_decimal_string(value: &Value, type_name: &str) -> Result<String, CodecError> {
+    match value {
+        Value::Number(number) if number.is_u64() || number.is_i64() => Ok(number.to_string()),
+        Value::String(s) => Ok(s.clone()),
+        _ => Err(expected(type_name, value)),
+    }
+}
+
+/// Minimal big endian representation, zero is encoded as no bytes, same as the VM big ints.
+pub fn json_to_big_uint_bytes(value: &Value) -> Result<Vec<u8>, CodecError> {
+    let s = json_to_decimal_string(value, "BigUint")?;
+    let big_uint = s
+        .parse::<BigUint>()
+        .map_err(|_| expected("BigUint", value))?;
+    if big_uint.bits() == 0 {
+        Ok(Vec::new())
+    } else {
+        Ok(big_uint.to_bytes_be())
+    }
+}
+
+/// Minimal two's complement representation, zero is encoded as no bytes, same as the VM big ints.
+pub fn json_to_big_int_bytes(value: &Value) -> Result<Vec<u8>, CodecError> {
+    let s = json_to_decimal_string(value, "BigInt")?;
+    let big_int = s.parse::<BigInt>().map_err(|_| expected("BigInt", value))?;
+    if big_int.sign() == Sign::NoSign {
+        Ok(Vec::new())
+    } else {
+        Ok(big_int.to_signed_bytes_be())
+    }
+}
+
+pub fn big_uint_bytes_to_json(bytes: &[u8]) -> Value {
+    Value::String(BigUint::from_bytes_be(bytes).to_string())
+}
+
+pub fn big_int_bytes_to_json(bytes: &[u8]) -> Value {
+    Value::String(BigInt::from_signed_bytes_be(bytes).to_string())
+}
+
+fn hex_to_bytes(hex_str: &str, value: &Value) -> Result<Vec<u8>, CodecError> {
+    hex::decode(hex_str).map_err(|_| expected("hex string", value))
+}
+
+/// `"0x..."` is interpreted as hex, any other string as UTF-8 text.
+pub fn json_to_bytes(value: &Value) -> Result<Vec<u8>, CodecError> {
+    match value {
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex_str) => hex_to_bytes(hex_str, value),
+            None => Ok(s.as_bytes().to_vec()),
+        },
+        _ => Err(expected("bytes", value)),
+    }
+}
+
+pub fn bytes_to_json(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+pub fn json_to_string(value: &Value, type_name: &str) -> Result<Vec<u8>, CodecError> {
+    value
+        .as_str()
+        .map(|s| s.as_bytes().to_vec())
+        .ok_or_else(|| expected(type_name, value))
+}
+
+pub fn string_to_json(bytes: &[u8]) -> Result<Value, CodecError> {
+    String::from_utf8(bytes.to_vec())
+        .map(Value::String)
+        .map_err(|_| CodecError::new("invalid UTF-8 string"))
+}
+
+pub fn json_to_h256(value: &Value) -> Result<Vec<u8>, CodecError> {
+    let bytes = match value.as_str().and_then(|s| s.strip_prefix("0x")) {
+        Some(hex_str) => hex_to_bytes(hex_str, value)?,
+        None => return Err(expected("0x-prefixed hex string", value)),
+    };
+    if bytes.len() != ADDRESS_LEN {
+        return Err(CodecError::new(format!(
+            "expected {} bytes, got {}",
+            ADDRESS_LEN,
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Accepts both bech32 (`moa1...`) and 0x-prefixed hex addresses.
+pub fn json_to_address(value: &Value) -> Result<Vec<u8>, CodecError> {
+    let s = value.as_str().ok_or_else(|| expected("address", value))?;
+    if s.starts_with("0x") {
+        return json_to_h256(value);
+    }
+
+    let (hrp, data, _) = bech32::decode(s).map_err(|_| expected("bech32 address", value))?;
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|_| expected("bech32 address", value))?;
+    if hrp != ADDRESS_HRP || bytes.len() != ADDRESS_LEN {
+        return Err(expected("bech32 address", value));
+    }
+    Ok(bytes)
+}
+
+pub fn address_to_json(bytes: &[u8]) -> Value {
+    Value::String(bech32::encode(ADDRESS_HRP, bytes.to_base32(), Variant::Bech32).unwrap())
+}
@@ -0,0 +1,25 @@
+//! Encodes and decodes contract arguments, results and events based on an `.abi.json` file,
+//! without compiling the contract crate.
+//!
+//! Values are represented as `serde_json::Value`:
+//! - integers as JSON numbers (decimal strings also accepted), big integers as decimal strings;
+//! - `bytes` and `H256` as `"0x..."` hex strings (plain strings are also accepted as UTF-8 bytes);
+//! - addresses as bech32 strings (`"0x..."` hex also accepted);
+//! - token identifiers and `utf-8 string` as strings;
+//! - `Option<T>` as `null` or the value, lists, arrays and tuples as JSON arrays;
+//! - structs as objects keyed by field name;
+//! - enum variants as their name, or `{ "name": ..., "fields": { ... } }` if they have fields;
+//! - `multi<...>`, `variadic<...>` and `counted-variadic<...>` as JSON arrays, `optional<T>` as `null` or the value.
+
+mod abi_codec;
+mod abi_type;
+mod codec_error;
+mod json_value;
+mod multi_codec;
+mod type_codec;
+
+pub use abi_codec::{AbiCodec, CONSTRUCTOR_NAME, UPGRADE_CONSTRUCTOR_NAME};
+pub use abi_type::AbiType;
+pub use codec_error::CodecError;
+pub use json_value::ADDRESS_HRP;
+pub use type_codec::TypeCodec;
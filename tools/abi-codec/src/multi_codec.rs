@@ -0,0 +1,114 @@
+use serde_json::Value;
+
+use crate::{AbiType, CodecError, TypeCodec};
+
+impl<'a> TypeCodec<'a> {
+    /// Encodes a value that can span several top-level arguments, appending them to `args`.
+    ///
+    /// `multi<...>` values are JSON arrays with one item per part,
+    /// `variadic<T>` and `counted-variadic<T>` are arrays of any length,
+    /// `optional<T>` is either `null` or the value itself.
+    pub fn multi_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+        args: &mut Vec<Vec<u8>>,
+    ) -> Result<(), CodecError> {
+        match abi_type {
+            AbiType::Multi(part_types) => {
+                let parts = multi_items(value)?;
+                if parts.len() != part_types.len() {
+                    return Err(CodecError::new(format!(
+                        "expected {} multi-value parts, got {}",
+                        part_types.len(),
+                        parts.len()
+                    )));
+                }
+                for (part_type, part) in part_types.iter().zip(parts.iter()) {
+                    self.multi_encode(part_type, part, args)?;
+                }
+            },
+            AbiType::Variadic(item_type) => {
+                for item in multi_items(value)? {
+                    self.multi_encode(item_type, item, args)?;
+                }
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let items = multi_items(value)?;
+                args.push(self.top_encode(&AbiType::U32, &Value::from(items.len()))?);
+                for item in items {
+                    self.multi_encode(item_type, item, args)?;
+                }
+            },
+            AbiType::Optional(item_type) => {
+                if !value.is_null() {
+                    self.multi_encode(item_type, value, args)?;
+                }
+            },
+            AbiType::Ignore | AbiType::Unit => {},
+            _ => args.push(self.top_encode(abi_type, value)?),
+        }
+        Ok(())
+    }
+
+    /// Decodes a value that can span several top-level arguments, consuming them from the front of `args`.
+    pub fn multi_decode(
+        &self,
+        abi_type: &AbiType,
+        args: &mut &[Vec<u8>],
+    ) -> Result<Value, CodecError> {
+        let value = match abi_type {
+            AbiType::Multi(part_types) => {
+                let mut parts = Vec::with_capacity(part_types.len());
+                for part_type in part_types {
+                    parts.push(self.multi_decode(part_type, args)?);
+                }
+                Value::Array(parts)
+            },
+            AbiType::Variadic(item_type) => {
+                let mut items = Vec::new();
+                while !args.is_empty() {
+                    items.push(self.multi_decode(item_type, args)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let count = self
+                    .multi_decode(&AbiType::U32, args)?
+                    .as_u64()
+                    .unwrap_or_default();
+                let mut items = Vec::new();
+                for _ in 0..count {
+                    items.push(self.multi_decode(item_type, args)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Optional(item_type) => {
+                if args.is_empty() {
+                    Value::Null
+                } else {
+                    self.multi_decode(item_type, args)?
+                }
+            },
+            AbiType::Ignore => {
+                *args = &[];
+                Value::Null
+            },
+            AbiType::Unit => Value::Null,
+            _ => {
+                let (arg, rest) = args
+                    .split_first()
+                    .ok_or_else(|| CodecError::new("too few arguments"))?;
+                *args = rest;
+                self.top_decode(abi_type, arg.as_slice())?
+            },
+        };
+        Ok(value)
+    }
+}
+
+fn multi_items(value: &Value) -> Result<&Vec<Value>, CodecError> {
+    value
+        .as_array()
+        .ok_or_else(|| CodecError::new(format!("expected array, got `{}`", value)))
+}
@@ -0,0 +1,534 @@
+use std::collections::BTreeMap;
+
+use dharitri_codec::{
+    top_encode_to_vec_u8, DecodeError, EncodeError, NestedDecode, NestedEncode, TopDecode,
+};
+use dharitri_wasm_debug::abi_json::{
+    EnumVariantDescriptionJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+use serde_json::{Map, Value};
+
+use crate::{json_value::*, AbiType, CodecError};
+
+const ADDRESS_LEN: usize = 32;
+
+impl From<EncodeError> for CodecError {
+    fn from(err: EncodeError) -> Self {
+        CodecError::new(err.message_str())
+    }
+}
+
+impl From<DecodeError> for CodecError {
+    fn from(err: DecodeError) -> Self {
+        CodecError::new(err.message_str())
+    }
+}
+
+/// Encodes and decodes single values, resolving custom types from the ABI `types` section.
+///
+/// Primitive values go through `dharitri-codec` itself,
+/// so the results are identical to what the contract produces and expects.
+pub struct TypeCodec<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+macro_rules! nested_encode_unsigned {
+    ($ty:ty, $value:expr, $dest:expr, $name:expr) => {
+        (json_to_u64($value, $name, <$ty>::MAX as u64)? as $ty).dep_encode($dest)?
+    };
+}
+
+macro_rules! nested_encode_signed {
+    ($ty:ty, $value:expr, $dest:expr, $name:expr) => {
+        (json_to_i64($value, $name, <$ty>::MIN as i64, <$ty>::MAX as i64)? as $ty)
+            .dep_encode($dest)?
+    };
+}
+
+macro_rules! top_encode_unsigned {
+    ($ty:ty, $value:expr, $name:expr) => {
+        top_encode_to_vec_u8(&(json_to_u64($value, $name, <$ty>::MAX as u64)? as $ty))?
+    };
+}
+
+macro_rules! top_encode_signed {
+    ($ty:ty, $value:expr, $name:expr) => {
+        top_encode_to_vec_u8(
+            &(json_to_i64($value, $name, <$ty>::MIN as i64, <$ty>::MAX as i64)? as $ty),
+        )?
+    };
+}
+
+fn multi_value_error(abi_type: &AbiType) -> CodecError {
+    CodecError::new(format!(
+        "{:?} is a multi-value type, only allowed as top-level argument or result",
+        abi_type
+    ))
+}
+
+fn array_items(value: &Value, expected_len: Option<usize>) -> Result<&Vec<Value>, CodecError> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| CodecError::new(format!("expected array, got `{}`", value)))?;
+    if let Some(len) = expected_len {
+        if items.len() != len {
+            return Err(CodecError::new(format!(
+                "expected {} items, got {}",
+                len,
+                items.len()
+            )));
+        }
+    }
+    Ok(items)
+}
+
+fn read_fixed(input: &mut &[u8], len: usize) -> Result<Vec<u8>, CodecError> {
+    if input.len() < len {
+        return Err(DecodeError::INPUT_TOO_SHORT.into());
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes.to_vec())
+}
+
+fn check_depleted(input: &[u8]) -> Result<(), CodecError> {
+    if input.is_empty() {
+        Ok(())
+    } else {
+        Err(DecodeError::INPUT_TOO_LONG.into())
+    }
+}
+
+impl<'a> TypeCodec<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        TypeCodec { types }
+    }
+
+    fn type_description(&self, name: &str) -> Result<&'a TypeDescriptionJson, CodecError> {
+        self.types
+            .get(name)
+            .ok_or_else(|| CodecError::new(format!("unknown type `{}`", name)))
+    }
+
+    fn find_variant(
+        type_description: &TypeDescriptionJson,
+        predicate: impl Fn(&EnumVariantDescriptionJson) -> bool,
+    ) -> Option<&EnumVariantDescriptionJson> {
+        type_description
+            .variants
+            .iter()
+            .find(|variant| predicate(variant))
+    }
+
+    /// Fieldless variants are written as a plain string, the others as `{ "name": ..., "fields": { ... } }`.
+    fn variant_from_json<'t>(
+        type_name: &str,
+        type_description: &'t TypeDescriptionJson,
+        value: &'t Value,
+    ) -> Result<(&'t EnumVariantDescriptionJson, Option<&'t Value>), CodecError> {
+        let (variant_name, fields) = match value {
+            Value::String(name) => (name.as_str(), None),
+            Value::Object(object) => (
+                object.get("name").and_then(Value::as_str).unwrap_or(""),
+                object.get("fields"),
+            ),
+            _ => {
+                return Err(CodecError::new(format!(
+                    "expected variant of `{}`, got `{}`",
+                    type_name, value
+                )))
+            },
+        };
+        let variant = Self::find_variant(type_description, |variant| variant.name == variant_name)
+            .ok_or_else(|| {
+                CodecError::new(format!(
+                    "unknown variant `{}` of `{}`",
+                    variant_name, type_name
+                ))
+            })?;
+        Ok((variant, fields))
+    }
+
+    fn variant_to_json(variant: &EnumVariantDescriptionJson, fields: Option<Value>) -> Value {
+        match fields {
+            None => Value::String(variant.name.clone()),
+            Some(fields) => {
+                let mut object = Map::new();
+                object.insert("name".to_string(), Value::String(variant.name.clone()));
+                object.insert("fields".to_string(), fields);
+                Value::Object(object)
+            },
+        }
+    }
+
+    fn discriminant_u8(variant: &EnumVariantDescriptionJson) -> Result<u8, CodecError> {
+        if variant.discriminant > u8::MAX as usize {
+            return Err(CodecError::new(format!(
+                "discriminant of variant `{}` does not fit in a byte",
+                variant.name
+            )));
+        }
+        Ok(variant.discriminant as u8)
+    }
+
+    fn nested_encode_fields(
+        &self,
+        fields: &[StructFieldDescriptionJson],
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), CodecError> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| CodecError::new(format!("expected object, got `{}`", value)))?;
+        for field in fields {
+            let field_value = object
+                .get(&field.name)
+                .ok_or_else(|| CodecError::new(format!("missing field `{}`", field.name)))?;
+            let field_type = AbiType::parse(field.field_type.as_str())?;
+            self.nested_encode(&field_type, field_value, dest)?;
+        }
+        Ok(())
+    }
+
+    fn nested_decode_fields(
+        &self,
+        fields: &[StructFieldDescriptionJson],
+        input: &mut &[u8],
+    ) -> Result<Value, CodecError> {
+        let mut object = Map::new();
+        for field in fields {
+            if field.since.is_some() && input.is_empty() {
+                // encoded by an older version of a versioned struct
+                break;
+            }
+            let field_type = AbiType::parse(field.field_type.as_str())?;
+            object.insert(field.name.clone(), self.nested_decode(&field_type, input)?);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn nested_encode_custom(
+        &self,
+        type_name: &str,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), CodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            "struct" => self.nested_encode_fields(&type_description.fields, value, dest),
+            "enum" => {
+                let (variant, fields) =
+                    Self::variant_from_json(type_name, type_description, value)?;
+                dest.push(Self::discriminant_u8(variant)?);
+                if variant.fields.is_empty() {
+                    Ok(())
+                } else {
+                    let fields = fields.ok_or_else(|| {
+                        CodecError::new(format!("missing fields of variant `{}`", variant.name))
+                    })?;
+                    self.nested_encode_fields(&variant.fields, fields, dest)
+                }
+            },
+            other => Err(CodecError::new(format!(
+                "cannot encode type `{}` of kind `{}`",
+                type_name, other
+            ))),
+        }
+    }
+
+    fn nested_decode_custom(
+        &self,
+        type_name: &str,
+        input: &mut &[u8],
+    ) -> Result<Value, CodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            "struct" => self.nested_decode_fields(&type_description.fields, input),
+            "enum" => {
+                let discriminant = u8::dep_decode(input)? as usize;
+                let variant = Self::find_variant(type_description, |variant| {
+                    variant.discriminant == discriminant
+                })
+                .ok_or(DecodeError::INVALID_VALUE)?;
+                if variant.fields.is_empty() {
+                    Ok(Self::variant_to_json(variant, None))
+                } else {
+                    let fields = self.nested_decode_fields(&variant.fields, input)?;
+                    Ok(Self::variant_to_json(variant, Some(fields)))
+                }
+            },
+            other => Err(CodecError::new(format!(
+                "cannot decode type `{}` of kind `{}`",
+                type_name, other
+            ))),
+        }
+    }
+
+    /// Appends the nested encoding of a value, as found inside structs, lists, etc.
+    pub fn nested_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), CodecError> {
+        match abi_type {
+            AbiType::U8 => nested_encode_unsigned!(u8, value, dest, "u8"),
+            AbiType::U16 => nested_encode_unsigned!(u16, value, dest, "u16"),
+            AbiType::U32 => nested_encode_unsigned!(u32, value, dest, "u32"),
+            AbiType::U64 => nested_encode_unsigned!(u64, value, dest, "u64"),
+            AbiType::I8 => nested_encode_signed!(i8, value, dest, "i8"),
+            AbiType::I16 => nested_encode_signed!(i16, value, dest, "i16"),
+            AbiType::I32 => nested_encode_signed!(i32, value, dest, "i32"),
+            AbiType::I64 => nested_encode_signed!(i64, value, dest, "i64"),
+            AbiType::Bool => json_to_bool(value)?.dep_encode(dest)?,
+            AbiType::BigUint => json_to_big_uint_bytes(value)?.dep_encode(dest)?,
+            AbiType::BigInt => json_to_big_int_bytes(value)?.dep_encode(dest)?,
+            AbiType::Bytes => json_to_bytes(value)?.dep_encode(dest)?,
+            AbiType::Utf8String => json_to_string(value, "utf-8 string")?.dep_encode(dest)?,
+            AbiType::TokenIdentifier | AbiType::MoaxOrDctTokenIdentifier => {
+                json_to_string(value, "token identifier")?.dep_encode(dest)?
+            },
+            AbiType::Address => dest.extend_from_slice(&json_to_address(value)?),
+            AbiType::H256 => dest.extend_from_slice(&json_to_h256(value)?),
+            AbiType::CodeMetadata => nested_encode_unsigned!(u16, value, dest, "CodeMetadata"),
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    dest.push(0);
+                } else {
+                    dest.push(1);
+                    self.nested_encode(item_type, value, dest)?;
+                }
+            },
+            AbiType::List(item_type) => {
+                let items = array_items(value, None)?;
+                items.len().dep_encode(dest)?;
+                for item in items {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+            },
+            AbiType::Array(len, item_type) => {
+                for item in array_items(value, Some(*len))? {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+            },
+            AbiType::Tuple(item_types) => {
+                let items = array_items(value, Some(item_types.len()))?;
+                for (item_type, item) in item_types.iter().zip(items.iter()) {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+            },
+            AbiType::Unit => {},
+            AbiType::Custom(type_name) => self.nested_encode_custom(type_name, value, dest)?,
+            AbiType::Multi(_)
+            | AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Ignore => return Err(multi_value_error(abi_type)),
+        }
+        Ok(())
+    }
+
+    /// Decodes one nested value from the front of the input, advancing it.
+    pub fn nested_decode(
+        &self,
+        abi_type: &AbiType,
+        input: &mut &[u8],
+    ) -> Result<Value, CodecError> {
+        let value = match abi_type {
+            AbiType::U8 => Value::from(u8::dep_decode(input)?),
+            AbiType::U16 => Value::from(u16::dep_decode(input)?),
+            AbiType::U32 => Value::from(u32::dep_decode(input)?),
+            AbiType::U64 => Value::from(u64::dep_decode(input)?),
+            AbiType::I8 => Value::from(i8::dep_decode(input)?),
+            AbiType::I16 => Value::from(i16::dep_decode(input)?),
+            AbiType::I32 => Value::from(i32::dep_decode(input)?),
+            AbiType::I64 => Value::from(i64::dep_decode(input)?),
+            AbiType::Bool => Value::from(bool::dep_decode(input)?),
+            AbiType::BigUint => big_uint_bytes_to_json(&Vec::<u8>::dep_decode(input)?),
+            AbiType::BigInt => big_int_bytes_to_json(&Vec::<u8>::dep_decode(input)?),
+            AbiType::Bytes => bytes_to_json(&Vec::<u8>::dep_decode(input)?),
+            AbiType::Utf8String | AbiType::TokenIdentifier | AbiType::MoaxOrDctTokenIdentifier => {
+                string_to_json(&Vec::<u8>::dep_decode(input)?)?
+            },
+            AbiType::Address => address_to_json(&read_fixed(input, ADDRESS_LEN)?),
+            AbiType::H256 => bytes_to_json(&read_fixed(input, ADDRESS_LEN)?),
+            AbiType::CodeMetadata => Value::from(u16::dep_decode(input)?),
+            AbiType::Option(item_type) => match u8::dep_decode(input)? {
+                0 => Value::Null,
+                1 => self.nested_decode(item_type, input)?,
+                _ => return Err(DecodeError::INVALID_VALUE.into()),
+            },
+            AbiType::List(item_type) => {
+                let len = usize::dep_decode(input)?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.nested_decode(item_type, input)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Array(len, item_type) => {
+                let mut items = Vec::with_capacity(*len);
+                for _ in 0..*len {
+                    items.push(self.nested_decode(item_type, input)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Tuple(item_types) => {
+                let mut items = Vec::with_capacity(item_types.len());
+                for item_type in item_types {
+                    items.push(self.nested_decode(item_type, input)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Unit => Value::Null,
+            AbiType::Custom(type_name) => self.nested_decode_custom(type_name, input)?,
+            AbiType::Multi(_)
+            | AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Ignore => return Err(multi_value_error(abi_type)),
+        };
+        Ok(value)
+    }
+
+    fn nested_encode_to_vec(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+    ) -> Result<Vec<u8>, CodecError> {
+        let mut dest = Vec::new();
+        self.nested_encode(abi_type, value, &mut dest)?;
+        Ok(dest)
+    }
+
+    fn nested_decode_all(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<Value, CodecError> {
+        let mut input = bytes;
+        let value = self.nested_decode(abi_type, &mut input)?;
+        check_depleted(input)?;
+        Ok(value)
+    }
+
+    /// Encodes a value as a single argument, result, storage value or event topic.
+    pub fn top_encode(&self, abi_type: &AbiType, value: &Value) -> Result<Vec<u8>, CodecError> {
+        let encoded = match abi_type {
+            AbiType::U8 => top_encode_unsigned!(u8, value, "u8"),
+            AbiType::U16 => top_encode_unsigned!(u16, value, "u16"),
+            AbiType::U32 => top_encode_unsigned!(u32, value, "u32"),
+            AbiType::U64 => top_encode_unsigned!(u64, value, "u64"),
+            AbiType::I8 => top_encode_signed!(i8, value, "i8"),
+            AbiType::I16 => top_encode_signed!(i16, value, "i16"),
+            AbiType::I32 => top_encode_signed!(i32, value, "i32"),
+            AbiType::I64 => top_encode_signed!(i64, value, "i64"),
+            AbiType::Bool => top_encode_to_vec_u8(&json_to_bool(value)?)?,
+            AbiType::BigUint => json_to_big_uint_bytes(value)?,
+            AbiType::BigInt => json_to_big_int_bytes(value)?,
+            AbiType::Bytes => json_to_bytes(value)?,
+            AbiType::Utf8String => json_to_string(value, "utf-8 string")?,
+            AbiType::TokenIdentifier | AbiType::MoaxOrDctTokenIdentifier => {
+                json_to_string(value, "token identifier")?
+            },
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    Vec::new()
+                } else {
+                    let mut dest = vec![1u8];
+                    self.nested_encode(item_type, value, &mut dest)?;
+                    dest
+                }
+            },
+            AbiType::List(item_type) => {
+                let mut dest = Vec::new();
+                for item in array_items(value, None)? {
+                    self.nested_encode(item_type, item, &mut dest)?;
+                }
+                dest
+            },
+            AbiType::Custom(type_name) => {
+                // fieldless variants are top-encoded as u8, even in enums that also have variants with fields
+                let type_description = self.type_description(type_name)?;
+                let fieldless_variant = if type_description.content_type == "enum" {
+                    let (variant, _) = Self::variant_from_json(type_name, type_description, value)?;
+                    Some(variant).filter(|variant| variant.fields.is_empty())
+                } else {
+                    None
+                };
+                match fieldless_variant {
+                    Some(variant) => top_encode_to_vec_u8(&Self::discriminant_u8(variant)?)?,
+                    None => self.nested_encode_to_vec(abi_type, value)?,
+                }
+            },
+            AbiType::Address
+            | AbiType::H256
+            | AbiType::CodeMetadata
+            | AbiType::Array(..)
+            | AbiType::Tuple(_) => self.nested_encode_to_vec(abi_type, value)?,
+            AbiType::Multi(_)
+            | AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Ignore
+            | AbiType::Unit => return Err(multi_value_error(abi_type)),
+        };
+        Ok(encoded)
+    }
+
+    /// Decodes a single argument, result, storage value or event topic.
+    pub fn top_decode(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<Value, CodecError> {
+        let value = match abi_type {
+            AbiType::U8 => Value::from(u8::top_decode(bytes)?),
+            AbiType::U16 => Value::from(u16::top_decode(bytes)?),
+            AbiType::U32 => Value::from(u32::top_decode(bytes)?),
+            AbiType::U64 => Value::from(u64::top_decode(bytes)?),
+            AbiType::I8 => Value::from(i8::top_decode(bytes)?),
+            AbiType::I16 => Value::from(i16::top_decode(bytes)?),
+            AbiType::I32 => Value::from(i32::top_decode(bytes)?),
+            AbiType::I64 => Value::from(i64::top_decode(bytes)?),
+            AbiType::Bool => Value::from(bool::top_decode(bytes)?),
+            AbiType::BigUint => big_uint_bytes_to_json(bytes),
+            AbiType::BigInt => big_int_bytes_to_json(bytes),
+            AbiType::Bytes => bytes_to_json(bytes),
+            AbiType::Utf8String | AbiType::TokenIdentifier | AbiType::MoaxOrDctTokenIdentifier => {
+                string_to_json(bytes)?
+            },
+            AbiType::Option(item_type) => match bytes.split_first() {
+                None => Value::Null,
+                Some((1, rest)) => self.nested_decode_all(item_type, rest)?,
+                Some(_) => return Err(DecodeError::INVALID_VALUE.into()),
+            },
+            AbiType::List(item_type) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.nested_decode(item_type, &mut input)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Custom(type_name) => {
+                // the fieldless variant with discriminant 0 is top-encoded as empty bytes,
+                // in every enum, the same as the `auto_default` of the codec derives
+                let type_description = self.type_description(type_name)?;
+                if type_description.content_type == "enum" && bytes.is_empty() {
+                    let variant = Self::find_variant(type_description, |variant| {
+                        variant.discriminant == 0 && variant.fields.is_empty()
+                    })
+                    .ok_or(DecodeError::INPUT_TOO_SHORT)?;
+                    Self::variant_to_json(variant, None)
+                } else {
+                    self.nested_decode_all(abi_type, bytes)?
+                }
+            },
+            AbiType::Address
+            | AbiType::H256
+            | AbiType::CodeMetadata
+            | AbiType::Array(..)
+            | AbiType::Tuple(_) => self.nested_decode_all(abi_type, bytes)?,
+            AbiType::Multi(_)
+            | AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Ignore
+            | AbiType::Unit => return Err(multi_value_error(abi_type)),
+        };
+        Ok(value)
+    }
+}
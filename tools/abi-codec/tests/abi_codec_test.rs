@@ -0,0 +1,484 @@
+use abi_codec::{AbiCodec, AbiType};
+use dharitri_codec::{
+    dharitri_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    num_bigint::BigUint,
+    test_util::top_encode_to_vec_u8_or_panic,
+    TopDecode,
+};
+use serde_json::{json, Value};
+
+const SAMPLE_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "x.x.x-nightly",
+            "commitHash": "<commit hash here>",
+            "commitDate": "<commit date here>",
+            "channel": "Channel",
+            "short": "rustc <version> (<short hash> <date>)"
+        },
+        "contractCrate": {
+            "name": "sample",
+            "version": "0.0.0"
+        },
+        "framework": {
+            "name": "dharitri-wasm",
+            "version": "0.5.0"
+        }
+    },
+    "name": "Sample",
+    "constructor": {
+        "inputs": [
+            { "name": "initial", "type": "BigUint" }
+        ],
+        "outputs": []
+    },
+    "upgradeConstructor": {
+        "inputs": [
+            { "name": "new_owner", "type": "Address" },
+            { "name": "schema_version", "type": "u32" }
+        ],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "setItems",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "owner", "type": "Address" },
+                { "name": "items", "type": "variadic<multi<u32,Item>>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "setStatus",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "status", "type": "Status" },
+                { "name": "note", "type": "optional<utf-8 string>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getItem",
+            "mutability": "readonly",
+            "inputs": [
+                { "name": "id", "type": "u32" }
+            ],
+            "outputs": [
+                { "type": "Option<Item>" },
+                { "type": "counted-variadic<TokenIdentifier>", "multi_result": true }
+            ]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "item_set",
+            "inputs": [
+                { "name": "id", "type": "u32", "indexed": true },
+                { "name": "owner", "type": "Address", "indexed": true },
+                { "name": "item", "type": "Item" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Item": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "weight", "type": "i16" },
+                { "name": "tags", "type": "List<u64>" },
+                { "name": "limit", "type": "Option<u8>" }
+            ]
+        },
+        "Versioned": {
+            "type": "struct",
+            "version": 2,
+            "fields": [
+                { "name": "value", "type": "u32" },
+                { "name": "added", "type": "u8", "since": 2 }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 },
+                {
+                    "name": "Limited",
+                    "discriminant": 2,
+                    "fields": [
+                        { "name": "0", "type": "u32" },
+                        { "name": "1", "type": "bool" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+const OWNER_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+struct Item {
+    amount: BigUint,
+    weight: i16,
+    tags: Vec<u64>,
+    limit: Option<u8>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+enum Status {
+    Inactive,
+    Active,
+    Limited(u32, bool),
+}
+
+fn sample_codec() -> AbiCodec {
+    AbiCodec::from_json_str(SAMPLE_ABI).unwrap()
+}
+
+fn sample_item() -> (Item, Value) {
+    let item = Item {
+        amount: BigUint::from(1_000_000_000_000_000_000u64),
+        weight: -300,
+        tags: vec![1, u64::MAX],
+        limit: Some(5),
+    };
+    let json = json!({
+        "amount": "1000000000000000000",
+        "weight": -300,
+        "tags": [1, u64::MAX],
+        "limit": 5
+    });
+    (item, json)
+}
+
+#[test]
+fn abi_type_parse_test() {
+    assert_eq!(
+        AbiType::parse("variadic<multi<Address,List<BigUint>>>").unwrap(),
+        AbiType::Variadic(Box::new(AbiType::Multi(vec![
+            AbiType::Address,
+            AbiType::List(Box::new(AbiType::BigUint)),
+        ])))
+    );
+    assert_eq!(
+        AbiType::parse("array32<u8>").unwrap(),
+        AbiType::Array(32, Box::new(AbiType::U8))
+    );
+    assert_eq!(
+        AbiType::parse("Option<utf-8 string>").unwrap(),
+        AbiType::Option(Box::new(AbiType::Utf8String))
+    );
+    assert!(AbiType::parse("List<u8").is_err());
+    assert!(AbiType::parse("List<u8,u16>").is_err());
+}
+
+#[test]
+fn abi_type_parse_generated_abi_test() {
+    let abi_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../contracts/feature-tests/abi-tester/abi_tester_expected_main.abi.json"
+    );
+    let abi_codec = AbiCodec::load(abi_path).unwrap();
+    let mut type_names = Vec::new();
+    for endpoint in &abi_codec.abi.endpoints {
+        type_names.extend(endpoint.inputs.iter().map(|input| &input.type_name));
+        type_names.extend(endpoint.outputs.iter().map(|output| &output.type_name));
+    }
+    for type_description in abi_codec.abi.types.values() {
+        type_names.extend(
+            type_description
+                .fields
+                .iter()
+                .map(|field| &field.field_type),
+        );
+    }
+    for type_name in type_names {
+        if let AbiType::Custom(name) = AbiType::parse(type_name).unwrap() {
+            assert!(abi_codec.abi.types.contains_key(&name), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn abi_codec_numbers_test() {
+    let abi_codec = sample_codec();
+    assert_eq!(
+        abi_codec.encode_value("u64", &json!(0)).unwrap(),
+        Vec::<u8>::new()
+    );
+    assert_eq!(
+        abi_codec.encode_value("u32", &json!(256)).unwrap(),
+        vec![1, 0]
+    );
+    assert_eq!(
+        abi_codec.encode_value("usize", &json!("7")).unwrap(),
+        vec![7]
+    );
+    assert_eq!(
+        abi_codec.encode_value("i16", &json!(-1)).unwrap(),
+        vec![0xff]
+    );
+    assert_eq!(
+        abi_codec.encode_value("i32", &json!(128)).unwrap(),
+        vec![0, 0x80]
+    );
+    assert!(abi_codec.encode_value("u8", &json!(256)).is_err());
+    assert!(abi_codec.encode_value("u8", &json!(-1)).is_err());
+
+    assert_eq!(abi_codec.decode_value("i32", &[0xff]).unwrap(), json!(-1));
+    assert_eq!(abi_codec.decode_value("u16", &[]).unwrap(), json!(0));
+    assert!(abi_codec.decode_value("u8", &[1, 0]).is_err());
+
+    assert_eq!(
+        abi_codec.encode_value("BigUint", &json!("0")).unwrap(),
+        Vec::<u8>::new()
+    );
+    assert_eq!(
+        abi_codec.encode_value("BigInt", &json!(-128)).unwrap(),
+        vec![0x80]
+    );
+    assert_eq!(
+        abi_codec.decode_value("BigInt", &[0, 0x80]).unwrap(),
+        json!("128")
+    );
+    assert_eq!(
+        abi_codec.encode_value("bool", &json!(true)).unwrap(),
+        vec![1]
+    );
+    assert_eq!(
+        abi_codec.encode_value("bool", &json!(false)).unwrap(),
+        Vec::<u8>::new()
+    );
+}
+
+#[test]
+fn abi_codec_bytes_and_address_test() {
+    let abi_codec = sample_codec();
+    assert_eq!(
+        abi_codec.encode_value("bytes", &json!("abc")).unwrap(),
+        b"abc"
+    );
+    assert_eq!(
+        abi_codec.encode_value("bytes", &json!("0x0102")).unwrap(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        abi_codec.decode_value("bytes", &[1, 2]).unwrap(),
+        json!("0x0102")
+    );
+    assert_eq!(
+        abi_codec
+            .decode_value("TokenIdentifier", b"WMOAX-abcdef")
+            .unwrap(),
+        json!("WMOAX-abcdef")
+    );
+
+    let owner_bytes = hex::decode(OWNER_HEX).unwrap();
+    let owner_bech32 = abi_codec.decode_value("Address", &owner_bytes).unwrap();
+    assert!(owner_bech32.as_str().unwrap().starts_with("moa1"));
+    assert_eq!(
+        abi_codec.encode_value("Address", &owner_bech32).unwrap(),
+        owner_bytes
+    );
+    assert_eq!(
+        abi_codec
+            .encode_value("Address", &json!(format!("0x{}", OWNER_HEX)))
+            .unwrap(),
+        owner_bytes
+    );
+    assert!(abi_codec
+        .decode_value("Address", &owner_bytes[1..])
+        .is_err());
+}
+
+#[test]
+fn abi_codec_struct_matches_codec_test() {
+    let abi_codec = sample_codec();
+    let (item, item_json) = sample_item();
+    let expected = top_encode_to_vec_u8_or_panic(&item);
+
+    assert_eq!(
+        abi_codec.encode_value("Item", &item_json).unwrap(),
+        expected
+    );
+    assert_eq!(
+        abi_codec.decode_value("Item", &expected).unwrap(),
+        item_json
+    );
+
+    let expected_option = top_encode_to_vec_u8_or_panic(&Some(item));
+    assert_eq!(
+        abi_codec.encode_value("Option<Item>", &item_json).unwrap(),
+        expected_option
+    );
+    assert_eq!(
+        abi_codec.decode_value("Option<Item>", &[]).unwrap(),
+        Value::Null
+    );
+
+    let mut too_long = expected;
+    too_long.push(0);
+    assert!(abi_codec.decode_value("Item", &too_long).is_err());
+}
+
+#[test]
+fn abi_codec_versioned_struct_test() {
+    let abi_codec = sample_codec();
+    assert_eq!(
+        abi_codec
+            .decode_value("Versioned", &[0, 0, 0, 5, 1])
+            .unwrap(),
+        json!({ "value": 5, "added": 1 })
+    );
+
+    // fields added in later versions are left out of older encodings
+    assert_eq!(
+        abi_codec.decode_value("Versioned", &[0, 0, 0, 5]).unwrap(),
+        json!({ "value": 5 })
+    );
+    assert!(abi_codec.decode_value("Versioned", &[0, 0, 5]).is_err());
+}
+
+#[test]
+fn abi_codec_enum_matches_codec_test() {
+    let abi_codec = sample_codec();
+    let cases = [
+        (Status::Inactive, json!("Inactive")),
+        (Status::Active, json!("Active")),
+        (
+            Status::Limited(1000, true),
+            json!({ "name": "Limited", "fields": { "0": 1000, "1": true } }),
+        ),
+    ];
+    for (status, status_json) in cases.iter() {
+        let expected = top_encode_to_vec_u8_or_panic(status);
+        let encoded = abi_codec.encode_value("Status", status_json).unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(&Status::top_decode(encoded.as_slice()).unwrap(), status);
+        assert_eq!(
+            &abi_codec.decode_value("Status", &expected).unwrap(),
+            status_json
+        );
+        assert_eq!(
+            abi_codec
+                .encode_value("List<Status>", &json!([status_json]))
+                .unwrap(),
+            top_encode_to_vec_u8_or_panic(&vec![status])
+        );
+    }
+    assert_eq!(
+        abi_codec
+            .decode_value("Status", &[2, 0, 0, 0, 5, 0])
+            .unwrap(),
+        json!({ "name": "Limited", "fields": { "0": 5, "1": false } })
+    );
+    assert!(abi_codec.encode_value("Status", &json!("Unknown")).is_err());
+    assert!(abi_codec.decode_value("Status", &[3]).is_err());
+}
+
+#[test]
+fn abi_codec_call_data_test() {
+    let abi_codec = sample_codec();
+    let (item, item_json) = sample_item();
+    let owner = format!("0x{}", OWNER_HEX);
+
+    let call_data = abi_codec
+        .call_data(
+            "setItems",
+            &json!([owner, [[1, item_json], [2, item_json]]]),
+        )
+        .unwrap();
+    let item_hex = hex::encode(top_encode_to_vec_u8_or_panic(&item));
+    assert_eq!(
+        call_data,
+        format!("setItems@{}@01@{}@02@{}", OWNER_HEX, item_hex, item_hex)
+    );
+
+    // optional argument left out, or given explicitly
+    assert_eq!(
+        abi_codec
+            .call_data("setStatus", &json!(["Inactive"]))
+            .unwrap(),
+        "setStatus@"
+    );
+    assert_eq!(
+        abi_codec
+            .call_data("setStatus", &json!(["Active", "paid"]))
+            .unwrap(),
+        "setStatus@01@70616964"
+    );
+
+    assert_eq!(
+        abi_codec.call_data("init", &json!(["1000"])).unwrap(),
+        "init@03e8"
+    );
+    // the `#[upgrade]` method has its own signature
+    assert_eq!(
+        abi_codec
+            .call_data(
+                "upgrade",
+                &json!([
+                    "0x0000000000000000000000000000000000000000000000000000000000000001",
+                    2
+                ])
+            )
+            .unwrap(),
+        "upgrade@0000000000000000000000000000000000000000000000000000000000000001@02"
+    );
+    assert!(abi_codec.call_data("setStatus", &json!([1, 2, 3])).is_err());
+    assert!(abi_codec.call_data("unknown", &json!([])).is_err());
+}
+
+#[test]
+fn abi_codec_decode_results_test() {
+    let abi_codec = sample_codec();
+    let (item, item_json) = sample_item();
+
+    let results = vec![
+        top_encode_to_vec_u8_or_panic(&Some(item)),
+        vec![2],
+        b"WMOAX-abcdef".to_vec(),
+        b"MEX-123456".to_vec(),
+    ];
+    assert_eq!(
+        abi_codec.decode_results("getItem", &results).unwrap(),
+        json!([item_json, ["WMOAX-abcdef", "MEX-123456"]])
+    );
+
+    assert_eq!(
+        abi_codec
+            .decode_results("getItem", &[Vec::new(), Vec::new()])
+            .unwrap(),
+        json!([null, []])
+    );
+    assert!(abi_codec
+        .decode_results("getItem", &[Vec::new(), Vec::new(), Vec::new()])
+        .is_err());
+}
+
+#[test]
+fn abi_codec_decode_event_test() {
+    let abi_codec = sample_codec();
+    let (item, item_json) = sample_item();
+    let owner_bytes = hex::decode(OWNER_HEX).unwrap();
+    let owner_bech32 = abi_codec.decode_value("Address", &owner_bytes).unwrap();
+
+    let topics = vec![b"item_set".to_vec(), vec![7], owner_bytes];
+    let data = top_encode_to_vec_u8_or_panic(&item);
+    assert_eq!(
+        abi_codec.decode_event(&topics, &data).unwrap(),
+        json!({
+            "identifier": "item_set",
+            "fields": {
+                "id": 7,
+                "owner": owner_bech32,
+                "item": item_json
+            }
+        })
+    );
+
+    assert!(abi_codec.decode_event(&[b"unknown".to_vec()], &[]).is_err());
+}
@@ -66,6 +66,10 @@ impl CmdBuilder {
         println!("{}", self.cmd);
     }
 
+    pub fn into_string(self) -> String {
+        self.cmd
+    }
+
     fn add_space(&mut self) {
         self.cmd += SPACE;
     }
@@ -33,6 +33,8 @@ pub const DCT_TRANSFER_FUNC_NAME: &str = "DCTTransfer";
 pub const NFT_TRANSFER_FUNC_NAME: &str = "DCTNFTTransfer";
 pub const MULTI_TRANSFER_FUNC_NAME: &str = "MultiDCTNFTTransfer";
 
+pub const MOAX_TOKEN_IDENTIFIER: &str = "MOAX";
+
 // default proxies
 pub const TESTNET_PROXY: &str = "https://testnet-gateway.dharitri.com";
 pub const DEVNET_PROXY: &str = "https://devnet-gateway.dharitri.com";
@@ -6,10 +6,30 @@ use num_traits::Zero;
 mod cmd_builder;
 mod constants;
 mod helper_types;
+mod interaction_script;
 
 use cmd_builder::*;
 use constants::*;
 use helper_types::*;
+use interaction_script::InteractionScriptGenerator;
+
+const INTERACTION_SCRIPT_COMMAND_NAME: &str = "interaction-script";
+const DEFAULT_GAS_LIMIT: u64 = 100_000_000;
+
+const USAGE: &str = "usage:
+    erdpy-snippet-generator
+        prints example snippets
+    erdpy-snippet-generator interaction-script <abi.json> [options]
+        generates an interaction script for the contract
+        --network testnet|devnet|mainnet    (default: devnet)
+        --proxy <url> --chain <chain id>    custom network, instead of --network
+        --pem <path>                        (default: wallet.pem)
+        --keyfile <path> --passfile <path>  instead of --pem
+        --bytecode <path>                   (default: output/<contract crate>.wasm)
+        --project <path>                    instead of --bytecode
+        --user <bech32 address>             wallet address, needed for NFT and multi-token payments
+        --gas-limit <gas>                   (default: 100000000)
+        --out <path>                        (default: standard output)";
 
 #[derive(PartialEq, Eq)]
 pub enum PrintOption {
@@ -448,6 +468,104 @@ impl ErdpySnippetGenerator {
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        None => print_examples(),
+        Some(INTERACTION_SCRIPT_COMMAND_NAME) if args.len() > 2 => {
+            if let Err(err) = generate_interaction_script(&args[2], &args[3..]) {
+                eprintln!("error: {}", err);
+                std::process::exit(1);
+            }
+        },
+        _ => {
+            println!("{}", USAGE);
+            std::process::exit(1);
+        },
+    }
+}
+
+fn generate_interaction_script(abi_path: &str, options: &[String]) -> Result<(), String> {
+    let mut chain_config = ChainConfig::Devnet;
+    let mut custom_proxy = None;
+    let mut custom_chain_id = None;
+    let mut wallet_type = WalletType::PemPath("wallet.pem".to_owned());
+    let mut keyfile_path = None;
+    let mut passfile_path = None;
+    let mut deploy_type = None;
+    let mut user_address = None;
+    let mut gas_limit = DEFAULT_GAS_LIMIT;
+    let mut out_path = None;
+
+    let mut option_iter = options.iter();
+    while let Some(option) = option_iter.next() {
+        let value = option_iter
+            .next()
+            .ok_or_else(|| format!("missing value for {}", option))?
+            .clone();
+        match option.as_str() {
+            "--network" => {
+                chain_config = match value.as_str() {
+                    "testnet" => ChainConfig::Testnet,
+                    "devnet" => ChainConfig::Devnet,
+                    "mainnet" => ChainConfig::Mainnet,
+                    _ => return Err(format!("unknown network `{}`", value)),
+                }
+            },
+            "--proxy" => custom_proxy = Some(value),
+            "--chain" => custom_chain_id = Some(value),
+            "--pem" => wallet_type = WalletType::PemPath(value),
+            "--keyfile" => keyfile_path = Some(value),
+            "--passfile" => passfile_path = Some(value),
+            "--bytecode" => deploy_type = Some(DeployType::WasmFilePath(value)),
+            "--project" => deploy_type = Some(DeployType::ProjectPath(value)),
+            "--user" => user_address = Some(value),
+            "--gas-limit" => {
+                gas_limit = value
+                    .parse()
+                    .map_err(|_| format!("invalid gas limit `{}`", value))?
+            },
+            "--out" => out_path = Some(value),
+            _ => return Err(format!("unknown option `{}`\n{}", option, USAGE)),
+        }
+    }
+
+    match (custom_proxy, custom_chain_id) {
+        (Some(proxy), Some(chain_id)) => chain_config = ChainConfig::Custom { proxy, chain_id },
+        (None, None) => {},
+        _ => return Err("--proxy and --chain must be given together".to_owned()),
+    }
+    match (keyfile_path, passfile_path) {
+        (Some(keyfile_path), Some(passfile_path)) => {
+            wallet_type = WalletType::KeyFile {
+                keyfile_path,
+                passfile_path,
+            }
+        },
+        (None, None) => {},
+        _ => return Err("--keyfile and --passfile must be given together".to_owned()),
+    }
+
+    let mut generator =
+        InteractionScriptGenerator::load(abi_path, chain_config, wallet_type, gas_limit)?;
+    if let Some(deploy_type) = deploy_type {
+        generator.set_deploy_type(deploy_type);
+    }
+    if let Some(user_address) = user_address {
+        generator.set_user_address(user_address);
+    }
+
+    let script = generator.generate();
+    match out_path {
+        Some(path) => std::fs::write(&path, script)
+            .map_err(|err| format!("could not write {}: {}", path, err)),
+        None => {
+            print!("{}", script);
+            Ok(())
+        },
+    }
+}
+
+fn print_examples() {
     // sc deploy
     let mut generator = ErdpySnippetGenerator::new_sc_deploy(
         ChainConfig::Devnet,
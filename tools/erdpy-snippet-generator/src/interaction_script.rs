@@ -0,0 +1,684 @@
+use std::{fmt::Write, fs, path::Path};
+
+use dharitri_wasm_debug::abi_json::{
+    ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson, OutputAbiJson,
+};
+
+use crate::{cmd_builder::CmdBuilder, constants::*, helper_types::*};
+
+const ABI_CODEC_PROGRAM_NAME: &str = "abi-codec";
+const CONSTRUCTOR_NAME: &str = "init";
+/// `abi-codec` looks up the `#[upgrade]` method under this name.
+const UPGRADE_CONSTRUCTOR_NAME: &str = "upgrade";
+const PAYABLE_ANY_TOKEN: &str = "*";
+
+/// Helpers shared by all the generated functions.
+///
+/// Argument encoding and result decoding are delegated to the `abi-codec` tool,
+/// so the script only deals with hex arguments and `erdpy`.
+const SCRIPT_HELPERS: &str = r#"# Prints the contract address, either given explicitly, or saved by `deploy`.
+contract_address() {
+    if [ -n "${CONTRACT_ADDRESS}" ]; then
+        echo "${CONTRACT_ADDRESS}"
+    else
+        python3 -c "import json; print(json.load(open('${OUT_FILE}'))['contractAddress'])"
+    fi
+}
+
+# Top-encodes a single JSON value of the given ABI type, as an erdpy hex argument.
+encode_value() {
+    local encoded
+    encoded=$(${ABI_CODEC} encode-value "${ABI}" "$1" "$2") || return 1
+    echo "0x${encoded}"
+}
+
+# Encodes the JSON arguments of an endpoint into HEX_ARGS, one erdpy hex argument each.
+encode_args() {
+    local endpoint=$1
+    shift
+    local call_data
+    call_data=$(${ABI_CODEC} encode "${ABI}" "${endpoint}" "[$(IFS=,; echo "$*")]") || return 1
+    HEX_ARGS=()
+    if [[ ${call_data} != *@* ]]; then
+        return 0
+    fi
+    local rest=${call_data#*@}
+    while true; do
+        HEX_ARGS+=("0x${rest%%@*}")
+        if [[ ${rest} != *@* ]]; then
+            break
+        fi
+        rest=${rest#*@}
+    done
+}
+
+# The erdpy `--arguments` flag, followed by HEX_ARGS, or nothing if there are no arguments.
+arguments_flag() {
+    ARGUMENTS=()
+    if [ ${#HEX_ARGS[@]} -gt 0 ]; then
+        ARGUMENTS=(--arguments "${HEX_ARGS[@]}")
+    fi
+}
+
+# Calls an endpoint, wrapping the token payments in the appropriate built-in function call.
+# Payments are given as a space separated list of `<token identifier>:<nonce>:<amount>` entries,
+# where a `{moax_token_identifier}:0:<amount>` entry stands for a MOAX payment.
+call_endpoint() {
+    local endpoint=$1
+    local payments=$2
+    shift 2
+    encode_args "${endpoint}" "$@" || return 1
+    local transfers
+    read -r -a transfers <<< "${payments}"
+    local receiver
+    receiver=$(contract_address) || return 1
+    local function=${endpoint}
+    local value=0
+    local endpoint_arg
+    endpoint_arg=$(encode_value bytes "\"${endpoint}\"") || return 1
+
+    if [ ${#transfers[@]} -eq 1 ]; then
+        local token nonce amount
+        IFS=: read -r token nonce amount <<< "${transfers[0]}"
+        if [ "${token}" == "{moax_token_identifier}" ]; then
+            value=${amount}
+        elif [ "${nonce}" == "0" ]; then
+            function={dct_transfer_func_name}
+            HEX_ARGS=(
+                "$(encode_value TokenIdentifier "\"${token}\"")"
+                "$(encode_value BigUint "\"${amount}\"")"
+                "${endpoint_arg}"
+                "${HEX_ARGS[@]}"
+            )
+        else
+            # NFT or SFT, the transfer is a call to self
+            function={nft_transfer_func_name}
+            HEX_ARGS=(
+                "$(encode_value TokenIdentifier "\"${token}\"")"
+                "$(encode_value u64 "${nonce}")"
+                "$(encode_value BigUint "\"${amount}\"")"
+                "$(encode_value Address "\"${receiver}\"")"
+                "${endpoint_arg}"
+                "${HEX_ARGS[@]}"
+            )
+            receiver=${USER_ADDRESS}
+        fi
+    elif [ ${#transfers[@]} -gt 1 ]; then
+        function={multi_transfer_func_name}
+        local transfer_args=(
+            "$(encode_value Address "\"${receiver}\"")"
+            "$(encode_value u32 "${#transfers[@]}")"
+        )
+        local transfer token nonce amount
+        for transfer in "${transfers[@]}"; do
+            IFS=: read -r token nonce amount <<< "${transfer}"
+            transfer_args+=(
+                "$(encode_value TokenIdentifier "\"${token}\"")"
+                "$(encode_value u64 "${nonce}")"
+                "$(encode_value BigUint "\"${amount}\"")"
+            )
+        done
+        HEX_ARGS=("${transfer_args[@]}" "${endpoint_arg}" "${HEX_ARGS[@]}")
+        receiver=${USER_ADDRESS}
+    fi
+
+    if [ -z "${receiver}" ]; then
+        echo "USER_ADDRESS is required for NFT and multi-token transfers" >&2
+        return 1
+    fi
+    arguments_flag
+    {call_command}
+}
+
+# Queries a view function and decodes its results.
+query_endpoint() {
+    local endpoint=$1
+    shift
+    encode_args "${endpoint}" "$@" || return 1
+    arguments_flag
+    local address
+    address=$(contract_address) || return 1
+    local results
+    mapfile -t results < <(
+        {query_command} \
+            | python3 -c "import json, sys; sys.stdout.writelines(r['hex'] + '\n' for r in json.load(sys.stdin))"
+    )
+    ${ABI_CODEC} decode "${ABI}" "${endpoint}" "${results[@]}"
+}
+"#;
+
+/// Generates a complete `erdpy` interaction shell script from a contract ABI.
+///
+/// The script contains `deploy` and `upgrade` functions, and one function per endpoint.
+/// Arguments are given as JSON values, in the format accepted by `abi-codec`,
+/// e.g. `'"moa1..."'` for an address or `'[1, 2, 3]'` for a variadic argument.
+/// Payable endpoints take the payments first, view functions print their decoded results.
+pub struct InteractionScriptGenerator {
+    abi: ContractAbiJson,
+    abi_path: String,
+    chain_config: ChainConfig,
+    wallet_type: WalletType,
+    deploy_type: Option<DeployType>,
+    user_address_bech32: Option<String>,
+    gas_limit: u64,
+}
+
+impl InteractionScriptGenerator {
+    pub fn new(
+        abi: ContractAbiJson,
+        abi_path: String,
+        chain_config: ChainConfig,
+        wallet_type: WalletType,
+        gas_limit: u64,
+    ) -> Self {
+        InteractionScriptGenerator {
+            abi,
+            abi_path,
+            chain_config,
+            wallet_type,
+            deploy_type: None,
+            user_address_bech32: None,
+            gas_limit: core::cmp::min(gas_limit, MAX_GAS_LIMIT),
+        }
+    }
+
+    pub fn load(
+        abi_path: &str,
+        chain_config: ChainConfig,
+        wallet_type: WalletType,
+        gas_limit: u64,
+    ) -> Result<Self, String> {
+        let json = fs::read_to_string(Path::new(abi_path))
+            .map_err(|err| format!("could not read {}: {}", abi_path, err))?;
+        let abi = serde_json::from_str(json.as_str())
+            .map_err(|err| format!("invalid ABI {}: {}", abi_path, err))?;
+        Ok(Self::new(
+            abi,
+            abi_path.to_owned(),
+            chain_config,
+            wallet_type,
+            gas_limit,
+        ))
+    }
+
+    /// Defaults to the wasm file in the `output` folder of the contract crate.
+    pub fn set_deploy_type(&mut self, deploy_type: DeployType) {
+        self.deploy_type = Some(deploy_type);
+    }
+
+    /// Only needed for NFT and multi-token transfers, which are sent to self.
+    pub fn set_user_address(&mut self, user_address_bech32: String) {
+        // fail early, rather than when running the script
+        let _ = bech32_to_bytes(user_address_bech32.as_str());
+        self.user_address_bech32 = Some(user_address_bech32);
+    }
+
+    pub fn generate(&self) -> String {
+        let mut script = String::new();
+        self.write_header(&mut script);
+        script += SCRIPT_HELPERS
+            .replace("{moax_token_identifier}", MOAX_TOKEN_IDENTIFIER)
+            .replace("{dct_transfer_func_name}", DCT_TRANSFER_FUNC_NAME)
+            .replace("{nft_transfer_func_name}", NFT_TRANSFER_FUNC_NAME)
+            .replace("{multi_transfer_func_name}", MULTI_TRANSFER_FUNC_NAME)
+            .replace("{call_command}", call_command().as_str())
+            .replace("{query_command}", query_command().as_str())
+            .as_str();
+
+        self.write_deploy_functions(&mut script);
+        for endpoint in &self.abi.endpoints {
+            write_endpoint_function(&mut script, endpoint);
+        }
+
+        script += r#"
+# Allows calling the functions directly, e.g. `./script.sh deploy`, besides sourcing the script.
+if [[ "${BASH_SOURCE[0]}" == "${0}" ]]; then
+    "$@"
+fi
+"#;
+        script
+    }
+
+    fn write_header(&self, script: &mut String) {
+        let (proxy, chain_id) = self.chain_config.to_strings();
+        let wallet_args = match &self.wallet_type {
+            WalletType::PemPath(path) => format!("--{} \"{}\"", PEM_PATH_ARG_NAME, path),
+            WalletType::KeyFile {
+                keyfile_path,
+                passfile_path,
+            } => format!(
+                "--{} \"{}\" --{} \"{}\"",
+                KEYFILE_PATH_ARG_NAME, keyfile_path, PASSFILE_PATH_ARG_NAME, passfile_path
+            ),
+        };
+        let (deploy_arg_name, deploy_path) = match &self.deploy_type {
+            Some(DeployType::ProjectPath(path)) => (PROJECT_ARG_NAME, path.clone()),
+            Some(DeployType::WasmFilePath(path)) => (WASM_PATH_ARG_NAME, path.clone()),
+            None => (
+                WASM_PATH_ARG_NAME,
+                format!("output/{}.wasm", self.abi.build_info.contract_crate.name),
+            ),
+        };
+
+        writeln!(script, "#!/bin/bash").unwrap();
+        writeln!(
+            script,
+            "# Interaction script for the `{}` contract, generated from {}.",
+            self.abi.name, self.abi_path
+        )
+        .unwrap();
+        writeln!(script, "#").unwrap();
+        writeln!(
+            script,
+            "# Requires `{}` and `{}` in PATH, or ERDPY and ABI_CODEC set accordingly.",
+            ERDPY_PROGRAM_NAME, ABI_CODEC_PROGRAM_NAME
+        )
+        .unwrap();
+        writeln!(script).unwrap();
+        writeln!(script, "ERDPY=${{ERDPY:-{}}}", ERDPY_PROGRAM_NAME).unwrap();
+        writeln!(
+            script,
+            "ABI_CODEC=${{ABI_CODEC:-{}}}",
+            ABI_CODEC_PROGRAM_NAME
+        )
+        .unwrap();
+        writeln!(script, "ABI=\"{}\"", self.abi_path).unwrap();
+        writeln!(script).unwrap();
+        writeln!(script, "PROXY=${{PROXY:-\"{}\"}}", proxy).unwrap();
+        writeln!(script, "CHAIN_ID=${{CHAIN_ID:-\"{}\"}}", chain_id).unwrap();
+        writeln!(script, "WALLET_ARGS=({})", wallet_args).unwrap();
+        writeln!(
+            script,
+            "USER_ADDRESS=${{USER_ADDRESS:-\"{}\"}}",
+            self.user_address_bech32.as_deref().unwrap_or_default()
+        )
+        .unwrap();
+        writeln!(script, "GAS_LIMIT=${{GAS_LIMIT:-{}}}", self.gas_limit).unwrap();
+        writeln!(script).unwrap();
+        writeln!(
+            script,
+            "DEPLOY_ARGS=(--{} \"{}\")",
+            deploy_arg_name, deploy_path
+        )
+        .unwrap();
+        writeln!(
+            script,
+            "OUT_FILE=${{OUT_FILE:-\"deploy-{}.json\"}}",
+            chain_id.to_lowercase()
+        )
+        .unwrap();
+        writeln!(
+            script,
+            "# Leave empty to use the address of the last deploy, saved in OUT_FILE."
+        )
+        .unwrap();
+        writeln!(script, "CONTRACT_ADDRESS=${{CONTRACT_ADDRESS:-}}").unwrap();
+        writeln!(script).unwrap();
+    }
+
+    /// Without an `#[upgrade]` method, upgrading calls the `#[init]` constructor again.
+    fn write_deploy_functions(&self, script: &mut String) {
+        let upgrade = match &self.abi.upgrade_constructor {
+            Some(upgrade_constructor) => (Some(upgrade_constructor), UPGRADE_CONSTRUCTOR_NAME),
+            None => (self.abi.constructor.as_ref(), CONSTRUCTOR_NAME),
+        };
+        let functions = [
+            (
+                DEPLOY_COMMAND_NAME,
+                (self.abi.constructor.as_ref(), CONSTRUCTOR_NAME),
+            ),
+            (UPGRADE_COMMAND_NAME, upgrade),
+        ];
+
+        for (command_name, (constructor, constructor_name)) in functions {
+            let (docs, inputs, payable_in_moax) = match constructor {
+                Some(constructor) => (
+                    constructor.docs.as_slice(),
+                    constructor.inputs.as_slice(),
+                    !constructor.payable_in_tokens.is_empty(),
+                ),
+                None => (&[][..], &[][..], false),
+            };
+
+            writeln!(script).unwrap();
+            write_doc_comments(script, docs);
+            if payable_in_moax {
+                writeln!(script, "# $1 - MOAX value").unwrap();
+            }
+            write_input_comments(script, inputs, payable_in_moax as usize);
+
+            writeln!(script, "{}() {{", command_name).unwrap();
+            let value = if payable_in_moax {
+                writeln!(script, "    local value=$1").unwrap();
+                writeln!(script, "    shift").unwrap();
+                "${value}"
+            } else {
+                "0"
+            };
+            writeln!(
+                script,
+                "    encode_args {} \"$@\" || return 1",
+                constructor_name
+            )
+            .unwrap();
+            writeln!(script, "    arguments_flag").unwrap();
+            writeln!(
+                script,
+                "    {}",
+                deploy_command(command_name == UPGRADE_COMMAND_NAME, value)
+            )
+            .unwrap();
+            writeln!(script, "}}").unwrap();
+        }
+    }
+}
+
+fn write_endpoint_function(script: &mut String, endpoint: &EndpointAbiJson) {
+    writeln!(script).unwrap();
+    write_doc_comments(script, endpoint.docs.as_slice());
+
+    let is_view = matches!(
+        endpoint.mutability,
+        EndpointMutabilityAbiJson::Readonly | EndpointMutabilityAbiJson::Pure
+    );
+    let payable_tokens = endpoint.payable_in_tokens.as_slice();
+    let payments_expr = if is_view || payable_tokens.is_empty() {
+        "\"\""
+    } else if payable_tokens == [MOAX_TOKEN_IDENTIFIER] {
+        writeln!(script, "# $1 - MOAX value").unwrap();
+        "\"MOAX:0:$1\""
+    } else {
+        if payable_tokens == [PAYABLE_ANY_TOKEN] {
+            writeln!(script, "# $1 - payments, any token").unwrap();
+        } else {
+            writeln!(
+                script,
+                "# $1 - payments, accepted tokens: {}",
+                payable_tokens.join(", ")
+            )
+            .unwrap();
+        }
+        "\"$1\""
+    };
+    let arg_offset = if payments_expr == "\"\"" { 0 } else { 1 };
+    write_input_comments(script, endpoint.inputs.as_slice(), arg_offset);
+    if is_view {
+        write_output_comments(script, endpoint.outputs.as_slice());
+    }
+
+    writeln!(script, "{}() {{", endpoint.name).unwrap();
+    if is_view {
+        writeln!(script, "    query_endpoint {} \"$@\"", endpoint.name).unwrap();
+    } else if arg_offset == 0 {
+        writeln!(script, "    call_endpoint {} \"\" \"$@\"", endpoint.name).unwrap();
+    } else {
+        writeln!(
+            script,
+            "    call_endpoint {} {} \"${{@:2}}\"",
+            endpoint.name, payments_expr
+        )
+        .unwrap();
+    }
+    writeln!(script, "}}").unwrap();
+}
+
+fn write_doc_comments(script: &mut String, docs: &[String]) {
+    for doc in docs {
+        writeln!(script, "# {}", doc.strip_prefix(' ').unwrap_or(doc)).unwrap();
+    }
+}
+
+fn write_input_comments(script: &mut String, inputs: &[InputAbiJson], arg_offset: usize) {
+    for (index, input) in inputs.iter().enumerate() {
+        writeln!(
+            script,
+            "# ${} - {}: {}",
+            index + arg_offset + 1,
+            input.arg_name,
+            input.type_name
+        )
+        .unwrap();
+    }
+}
+
+fn write_output_comments(script: &mut String, outputs: &[OutputAbiJson]) {
+    let output_types: Vec<&str> = outputs
+        .iter()
+        .map(|output| output.type_name.as_str())
+        .collect();
+    if !output_types.is_empty() {
+        writeln!(script, "# Prints: [{}]", output_types.join(", ")).unwrap();
+    }
+}
+
+fn common_tx_args(cmd_builder: &mut CmdBuilder, value: &str) {
+    cmd_builder.append_string_no_quotes("\"${WALLET_ARGS[@]}\"");
+    cmd_builder.add_flag(RECALL_NONCE_FLAG);
+    cmd_builder.add_raw_named_argument(GAS_LIMIT_ARG_NAME, "${GAS_LIMIT}");
+    cmd_builder.add_raw_named_argument(MOAX_VALUE_ARG_NAME, value);
+    cmd_builder.append_string_no_quotes("\"${ARGUMENTS[@]}\"");
+    cmd_builder.add_raw_named_argument(PROXY_ARG_NAME, "${PROXY}");
+    cmd_builder.add_raw_named_argument(CHAIN_ID_ARG_NAME, "${CHAIN_ID}");
+    cmd_builder.add_flag(SEND_FLAG);
+}
+
+fn deploy_command(is_upgrade: bool, value: &str) -> String {
+    let mut cmd_builder = CmdBuilder::new("${ERDPY}");
+    cmd_builder.add_flag(VERBOSE_FLAG);
+    cmd_builder.add_command(CONTRACT_COMMAND_NAME);
+    if is_upgrade {
+        cmd_builder.add_command(UPGRADE_COMMAND_NAME);
+        cmd_builder.append_string_no_quotes("\"$(contract_address)\"");
+    } else {
+        cmd_builder.add_command(DEPLOY_COMMAND_NAME);
+    }
+    cmd_builder.append_string_no_quotes("\"${DEPLOY_ARGS[@]}\"");
+    cmd_builder.add_raw_named_argument(OUT_FILE_PATH_ARG_NAME, "${OUT_FILE}");
+    common_tx_args(&mut cmd_builder, value);
+    cmd_builder.into_string()
+}
+
+fn call_command() -> String {
+    let mut cmd_builder = CmdBuilder::new("${ERDPY}");
+    cmd_builder.add_flag(VERBOSE_FLAG);
+    cmd_builder.add_command(CONTRACT_COMMAND_NAME);
+    cmd_builder.add_command(CALL_COMMAND_NAME);
+    cmd_builder.append_string_no_quotes("\"${receiver}\"");
+    cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, "${function}");
+    common_tx_args(&mut cmd_builder, "${value}");
+    cmd_builder.into_string()
+}
+
+fn query_command() -> String {
+    let mut cmd_builder = CmdBuilder::new("${ERDPY}");
+    cmd_builder.add_command(CONTRACT_COMMAND_NAME);
+    cmd_builder.add_command(QUERY_COMMAND_NAME);
+    cmd_builder.append_string_no_quotes("\"${address}\"");
+    cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, "${endpoint}");
+    cmd_builder.append_string_no_quotes("\"${ARGUMENTS[@]}\"");
+    cmd_builder.add_raw_named_argument(PROXY_ARG_NAME, "${PROXY}");
+    cmd_builder.into_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use dharitri_wasm::abi::ContractAbi;
+
+    const USER_ADDRESS: &str = "moa1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssfq94h8";
+
+    fn sample_generator() -> InteractionScriptGenerator {
+        let mut abi = ContractAbiJson::from(&ContractAbi::default());
+        abi.name = "Sample".to_string();
+        abi.endpoints = serde_json::from_value(serde_json::json!([
+            {
+                "name": "setValue",
+                "mutability": "mutable",
+                "inputs": [{ "name": "value", "type": "u32" }],
+                "outputs": []
+            },
+            {
+                "name": "deposit",
+                "mutability": "mutable",
+                "payableInTokens": ["MOAX"],
+                "inputs": [{ "name": "note", "type": "bytes" }],
+                "outputs": []
+            },
+            {
+                "name": "stake",
+                "mutability": "mutable",
+                "payableInTokens": ["STAKE-123456"],
+                "inputs": [],
+                "outputs": []
+            },
+            {
+                "name": "depositNfts",
+                "mutability": "mutable",
+                "payableInTokens": ["*"],
+                "inputs": [{ "name": "lock", "type": "bool" }],
+                "outputs": []
+            },
+            {
+                "name": "getValue",
+                "mutability": "readonly",
+                "inputs": [{ "name": "key", "type": "u64" }],
+                "outputs": [{ "type": "u32" }]
+            },
+            {
+                "name": "computeValue",
+                "mutability": "pure",
+                "payableInTokens": ["*"],
+                "inputs": [],
+                "outputs": [{ "type": "BigUint" }]
+            }
+        ]))
+        .unwrap();
+        let mut generator = InteractionScriptGenerator::new(
+            abi,
+            "output/sample.abi.json".to_string(),
+            ChainConfig::Devnet,
+            WalletType::PemPath("wallet.pem".to_string()),
+            50_000_000,
+        );
+        generator.set_user_address(USER_ADDRESS.to_string());
+        generator
+    }
+
+    fn upgradeable_generator() -> InteractionScriptGenerator {
+        let mut generator = sample_generator();
+        generator.abi.constructor = serde_json::from_value(serde_json::json!({
+            "inputs": [{ "name": "initial_value", "type": "u32" }],
+            "outputs": []
+        }))
+        .unwrap();
+        generator.abi.upgrade_constructor = serde_json::from_value(serde_json::json!({
+            "docs": [" Migrates the storage to the latest schema."],
+            "payableInTokens": ["MOAX"],
+            "inputs": [{ "name": "schema_version", "type": "u32" }],
+            "outputs": []
+        }))
+        .unwrap();
+        generator
+    }
+
+    #[test]
+    fn deploy_and_upgrade_test() {
+        let script = upgradeable_generator().generate();
+        assert!(script.contains(
+            "# $1 - initial_value: u32
+deploy() {
+    encode_args init \"$@\" || return 1"
+        ));
+        // `#[upgrade]` has its own docs, inputs and payability
+        assert!(script.contains(
+            "# Migrates the storage to the latest schema.
+# $1 - MOAX value
+# $2 - schema_version: u32
+upgrade() {
+    local value=$1
+    shift
+    encode_args upgrade \"$@\" || return 1"
+        ));
+
+        // without `#[upgrade]`, upgrading calls the constructor again
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "upgrade() {
+    encode_args init \"$@\" || return 1"
+        ));
+    }
+
+    #[test]
+    fn plain_endpoint_test() {
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "# $1 - value: u32
+setValue() {
+    call_endpoint setValue \"\" \"$@\"
+}"
+        ));
+        assert!(script.contains("ABI=\"output/sample.abi.json\""));
+        assert!(script.contains("GAS_LIMIT=${GAS_LIMIT:-50000000}"));
+    }
+
+    #[test]
+    fn payable_moax_endpoint_test() {
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "# $1 - MOAX value
+# $2 - note: bytes
+deposit() {
+    call_endpoint deposit \"MOAX:0:$1\" \"${@:2}\"
+}"
+        ));
+    }
+
+    #[test]
+    fn payable_dct_endpoint_test() {
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "# $1 - payments, accepted tokens: STAKE-123456
+stake() {
+    call_endpoint stake \"$1\" \"${@:2}\"
+}"
+        ));
+        assert!(script.contains("function=DCTTransfer"));
+    }
+
+    #[test]
+    fn nft_and_multi_transfer_endpoint_test() {
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "# $1 - payments, any token
+# $2 - lock: bool
+depositNfts() {
+    call_endpoint depositNfts \"$1\" \"${@:2}\"
+}"
+        ));
+        // both are sent to self, so they need the user address
+        assert!(script.contains("function=DCTNFTTransfer"));
+        assert!(script.contains("function=MultiDCTNFTTransfer"));
+        assert!(script
+            .contains(format!("USER_ADDRESS=${{USER_ADDRESS:-\"{}\"}}", USER_ADDRESS).as_str()));
+    }
+
+    #[test]
+    fn view_test() {
+        let script = sample_generator().generate();
+        assert!(script.contains(
+            "# $1 - key: u64
+# Prints: [u32]
+getValue() {
+    query_endpoint getValue \"$@\"
+}"
+        ));
+
+        // pure functions are queried too, payments are never sent with queries
+        assert!(script.contains(
+            "# Prints: [BigUint]
+computeValue() {
+    query_endpoint computeValue \"$@\"
+}"
+        ));
+    }
+}